@@ -17,6 +17,7 @@ enum ParsedElementChild {
 pub struct ParsedElement {
     ty: TypePath,                         // 元素类型
     props: Punctuated<FieldValue, Comma>, // 属性列表
+    component_ref: Option<Expr>,          // ref 属性，绑定 ComponentRef
     children: Vec<ParsedElementChild>,    // 子元素列表
 }
 
@@ -26,14 +27,25 @@ impl Parse for ParsedElement {
         // 解析类型名
         let ty: TypePath = input.parse()?;
 
-        // 解析属性 (可选)
-        let props = if input.peek(Paren) {
+        // 解析属性 (可选)，ref 是关键字，需要单独解析
+        let mut props = Punctuated::new();
+        let mut component_ref = None;
+        if input.peek(Paren) {
             let props_input;
             parenthesized!(props_input in input);
-            Punctuated::parse_terminated(&props_input)?
-        } else {
-            Punctuated::new()
-        };
+            while !props_input.is_empty() {
+                if props_input.peek(Token![ref]) {
+                    props_input.parse::<Token![ref]>()?;
+                    props_input.parse::<Token![:]>()?;
+                    component_ref = Some(props_input.parse()?);
+                } else {
+                    props.push(props_input.parse::<FieldValue>()?);
+                }
+                if !props_input.is_empty() {
+                    props_input.parse::<Comma>()?;
+                }
+            }
+        }
 
         // 解析子元素 (可选)
         let mut children = Vec::new();
@@ -56,6 +68,7 @@ impl Parse for ParsedElement {
 
         Ok(Self {
             props,
+            component_ref,
             ty,
             children,
         })
@@ -79,13 +92,24 @@ impl ToTokens for ParsedElement {
                 _ => None,
             })
             .unwrap_or_else(|| quote!(#decl_key));
+        // portable_key 属性生成可迁移的 key，不包含声明位置，在不同位置声明的元素之间迁移
+        let key = self
+            .props
+            .iter()
+            .find_map(|FieldValue { member, expr, .. }| match member {
+                Member::Named(ident) if ident == "portable_key" => {
+                    Some(quote!(::ratatui_kit_principle::element::ElementKey::portable(#expr)))
+                }
+                _ => None,
+            })
+            .unwrap_or_else(|| quote!(::ratatui_kit_principle::element::ElementKey::new(#key)));
 
         // 生成属性赋值代码，支持百分比语法 sugar（如 50pct）
         let prop_assignments = self
             .props
             .iter()
             .filter_map(|FieldValue { member, expr, .. }| match member {
-                Member::Named(ident) if ident == "key" => None, // key 属性不赋值到 props
+                Member::Named(ident) if ident == "key" || ident == "portable_key" => None, // key 属性不赋值到 props
                 _ => Some(match expr {
                     Expr::Lit(lit) => match &lit.lit {
                         Lit::Int(lit) if lit.suffix() == "pct" => {
@@ -116,6 +140,12 @@ impl ToTokens for ParsedElement {
             None
         };
 
+        // 绑定 ComponentRef，attach 的参数类型保证 ref 与元素类型一致
+        let attach_ref = self
+            .component_ref
+            .as_ref()
+            .map(|component_ref| quote!((#component_ref).attach(&_element);));
+
         // 生成最终 Element 构造代码
         tokens.extend(quote! {
             {
@@ -123,10 +153,11 @@ impl ToTokens for ParsedElement {
                 let mut _props: Props = Default::default();
                 #(#prop_assignments;)*
                 let mut _element = ::ratatui_kit_principle::element::Element::<#ty>{
-                    key: ::ratatui_kit_principle::element::ElementKey::new(#key),
+                    key: #key,
                     props: _props,
                 };
                 #set_children
+                #attach_ref
                 _element
             }
         });
@@ -2,9 +2,17 @@ use proc_macro::TokenStream;
 use quote::ToTokens;
 
 mod element;
+mod props;
 
 #[proc_macro]
 pub fn element(input: TokenStream) -> TokenStream {
     let element = syn::parse_macro_input!(input as element::ParsedElement);
     element.to_token_stream().into()
 }
+
+/// 为 Props 类型实现 `Props` 标记 trait，并在编译期检查类型对其生命周期参数协变
+#[proc_macro_derive(Props)]
+pub fn derive_props(input: TokenStream) -> TokenStream {
+    let props = syn::parse_macro_input!(input as props::ParsedProps);
+    props.to_token_stream().into()
+}
@@ -0,0 +1,74 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{ToTokens, quote};
+use syn::{
+    DeriveInput, GenericParam, Lifetime, LifetimeParam, Result,
+    parse::{Parse, ParseStream},
+};
+
+/// 解析后的 Props 类型定义
+pub struct ParsedProps {
+    input: DeriveInput,
+}
+
+impl Parse for ParsedProps {
+    fn parse(input: ParseStream) -> Result<Self> {
+        Ok(Self {
+            input: input.parse()?,
+        })
+    }
+}
+
+impl ToTokens for ParsedProps {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let ident = &self.input.ident;
+        let (impl_generics, ty_generics, where_clause) = self.input.generics.split_for_impl();
+
+        // 用两组生命周期分别替换所有生命周期参数，得到同一类型的“短”与“长”两个实例
+        let short = Lifetime::new("'__props_short", Span::call_site());
+        let long = Lifetime::new("'__props_long", Span::call_site());
+        let substitute = |lifetime: &Lifetime| -> Vec<TokenStream> {
+            self.input
+                .generics
+                .params
+                .iter()
+                .map(|param| match param {
+                    GenericParam::Lifetime(_) => lifetime.to_token_stream(),
+                    GenericParam::Type(ty) => ty.ident.to_token_stream(),
+                    GenericParam::Const(constant) => constant.ident.to_token_stream(),
+                })
+                .collect()
+        };
+        let short_args = substitute(&short);
+        let long_args = substitute(&long);
+        let other_params = self
+            .input
+            .generics
+            .params
+            .iter()
+            .filter(|param| !matches!(param, GenericParam::Lifetime(_)));
+        let check_lifetimes = [
+            LifetimeParam::new(short.clone()),
+            LifetimeParam {
+                bounds: [short.clone()].into_iter().collect(),
+                colon_token: Some(Default::default()),
+                ..LifetimeParam::new(long.clone())
+            },
+        ];
+
+        tokens.extend(quote! {
+            unsafe impl #impl_generics ::ratatui_kit_principle::props::Props
+                for #ident #ty_generics #where_clause {}
+
+            // 只有当类型对其生命周期参数协变时，长生命周期的实例才能放进短生命周期的数组
+            const _: () = {
+                #[allow(dead_code)]
+                fn check_covariance<#(#check_lifetimes,)* #(#other_params,)*>(
+                    short: #ident<#(#short_args,)*>,
+                    long: #ident<#(#long_args,)*>,
+                ) {
+                    let _ = [short, long];
+                }
+            };
+        });
+    }
+}
@@ -0,0 +1,205 @@
+//! 动画：缓动函数、可插值的值以及驱动动画的帧时钟
+//!
+//! 有动画在进行时，渲染循环按 [`AnimationClock`] 的帧率持续刷新；所有动画结束后不再产生任何定时刷新。
+//! 组件通过 [`use_animation`](crate::hooks::use_animation::UseAnimation::use_animation) 使用。
+
+use std::{
+    f64::consts::PI,
+    future::poll_fn,
+    sync::{Arc, Mutex},
+    task::{Poll, Waker},
+    time::Duration,
+};
+
+use ratatui::style::Color;
+
+use crate::{clock, theme};
+
+/// 默认帧率
+const DEFAULT_FRAME_RATE: u32 = 60;
+
+/// Easing：缓动函数，把 0..=1 的时间进度映射为 0..=1 的数值进度
+#[derive(Clone, Copy, Debug, Default)]
+pub enum Easing {
+    Linear,
+    EaseIn,
+    EaseOut,
+    #[default]
+    EaseInOut,
+    /// 三次方的 ease-in
+    CubicIn,
+    /// 三次方的 ease-out，适合滑入的面板
+    CubicOut,
+    CubicInOut,
+    /// 超过目标后回弹
+    BackOut,
+    /// 落地后弹跳几次
+    BounceOut,
+    /// 自定义缓动函数
+    Custom(fn(f64) -> f64),
+}
+
+impl Easing {
+    /// 计算 t（会被限制在 0..=1）对应的进度
+    pub fn apply(self, t: f64) -> f64 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Easing::Linear => t,
+            Easing::EaseIn => 1.0 - (t * PI / 2.0).cos(),
+            Easing::EaseOut => (t * PI / 2.0).sin(),
+            Easing::EaseInOut => -((PI * t).cos() - 1.0) / 2.0,
+            Easing::CubicIn => t * t * t,
+            Easing::CubicOut => 1.0 - (1.0 - t).powi(3),
+            Easing::CubicInOut => {
+                if t < 0.5 {
+                    4.0 * t * t * t
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(3) / 2.0
+                }
+            }
+            Easing::BackOut => {
+                const C1: f64 = 1.70158;
+                const C3: f64 = C1 + 1.0;
+                1.0 + C3 * (t - 1.0).powi(3) + C1 * (t - 1.0).powi(2)
+            }
+            Easing::BounceOut => bounce_out(t),
+            Easing::Custom(f) => f(t),
+        }
+    }
+}
+
+fn bounce_out(t: f64) -> f64 {
+    const N1: f64 = 7.5625;
+    const D1: f64 = 2.75;
+    if t < 1.0 / D1 {
+        N1 * t * t
+    } else if t < 2.0 / D1 {
+        let t = t - 1.5 / D1;
+        N1 * t * t + 0.75
+    } else if t < 2.5 / D1 {
+        let t = t - 2.25 / D1;
+        N1 * t * t + 0.9375
+    } else {
+        let t = t - 2.625 / D1;
+        N1 * t * t + 0.984375
+    }
+}
+
+/// Interpolate：可以在两个值之间插值的类型
+pub trait Interpolate: Clone {
+    /// progress 为 0 时返回 self，为 1 时返回 to；缓动函数可能让 progress 略超出 0..=1
+    fn interpolate(&self, to: &Self, progress: f64) -> Self;
+}
+
+impl Interpolate for f64 {
+    fn interpolate(&self, to: &Self, progress: f64) -> Self {
+        self + (to - self) * progress
+    }
+}
+
+impl Interpolate for f32 {
+    fn interpolate(&self, to: &Self, progress: f64) -> Self {
+        (*self as f64).interpolate(&(*to as f64), progress) as f32
+    }
+}
+
+macro_rules! impl_interpolate_integer {
+    ($($ty:ty),*) => {
+        $(
+            impl Interpolate for $ty {
+                fn interpolate(&self, to: &Self, progress: f64) -> Self {
+                    // 超出类型范围时由 as 转换截断到边界
+                    (*self as f64).interpolate(&(*to as f64), progress).round() as $ty
+                }
+            }
+        )*
+    };
+}
+
+impl_interpolate_integer!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+impl<A: Interpolate, B: Interpolate> Interpolate for (A, B) {
+    fn interpolate(&self, to: &Self, progress: f64) -> Self {
+        (
+            self.0.interpolate(&to.0, progress),
+            self.1.interpolate(&to.1, progress),
+        )
+    }
+}
+
+impl Interpolate for Color {
+    /// 按 xterm 调色板换算为 RGB 后逐通道插值，见 [`theme::mix`]
+    fn interpolate(&self, to: &Self, progress: f64) -> Self {
+        theme::mix(*self, *to, progress)
+    }
+}
+
+struct ClockState {
+    /// 进行中的动画数量
+    active: usize,
+    frame: Duration,
+    waker: Option<Waker>,
+}
+
+/// AnimationClock：动画的帧时钟
+///
+/// 由 [`SystemContext::animations`](crate::context::SystemContext::animations) 提供。
+/// 只要有动画在进行，渲染循环就按帧率刷新；没有动画时时钟不产生任何刷新。
+#[derive(Clone)]
+pub struct AnimationClock {
+    inner: Arc<Mutex<ClockState>>,
+}
+
+impl Default for AnimationClock {
+    fn default() -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(ClockState {
+                active: 0,
+                frame: Duration::from_secs(1) / DEFAULT_FRAME_RATE,
+                waker: None,
+            })),
+        }
+    }
+}
+
+impl AnimationClock {
+    /// 是否有动画在进行
+    pub fn is_animating(&self) -> bool {
+        self.inner.lock().unwrap().active > 0
+    }
+
+    /// 设置动画进行时的刷新帧率，默认 60
+    pub fn set_frame_rate(&self, fps: u32) {
+        self.inner.lock().unwrap().frame = Duration::from_secs(1) / fps.max(1);
+    }
+
+    /// 登记一个开始的动画
+    pub(crate) fn start(&self) {
+        let mut state = self.inner.lock().unwrap();
+        state.active += 1;
+        if let Some(waker) = state.waker.take() {
+            waker.wake();
+        }
+    }
+
+    /// 登记一个结束的动画
+    pub(crate) fn stop(&self) {
+        let mut state = self.inner.lock().unwrap();
+        state.active = state.active.saturating_sub(1);
+    }
+
+    /// 等待下一帧；没有动画时一直等待到有动画开始
+    pub(crate) async fn next_frame(&self) {
+        let frame = poll_fn(|cx| {
+            let mut state = self.inner.lock().unwrap();
+            if state.active > 0 {
+                Poll::Ready(state.frame)
+            } else {
+                state.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        })
+        .await;
+        clock::sleep(frame).await;
+    }
+}
@@ -0,0 +1,140 @@
+//! 文本资源登记表：logo、字符画、边框字符、旋转指示器帧等
+//!
+//! 应用启动时按名称登记一次，props 中只传递名称，内置组件在 update 时通过 [`SystemContext::assets`]
+//! 解析，避免每一帧都在 props 中复制大段字符串常量。内容相同的资源只保存一份。
+//!
+//! [`SystemContext::assets`]: crate::context::SystemContext::assets
+
+use std::{
+    collections::HashMap,
+    hash::{DefaultHasher, Hash, Hasher},
+    sync::{Arc, RwLock},
+};
+
+/// 内置的盲文点阵旋转指示器
+pub const SPINNER_DOTS: &str = "spinner.dots";
+/// 内置的 `-\|/` 旋转指示器
+pub const SPINNER_LINE: &str = "spinner.line";
+
+/// Asset：一份文本资源，由若干片段组成
+///
+/// 字符画的每个片段是一行，旋转指示器的每个片段是一帧。克隆只增加引用计数。
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Asset {
+    parts: Arc<[String]>,
+}
+
+impl Asset {
+    /// 由多行文本创建，每行一个片段
+    pub fn text(text: &str) -> Self {
+        Self::parts(text.lines())
+    }
+
+    pub fn parts<S: Into<String>>(parts: impl IntoIterator<Item = S>) -> Self {
+        Self {
+            parts: parts.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// 内容的哈希值，内容相同的资源 id 相同
+    pub fn id(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.parts.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    pub fn len(&self) -> usize {
+        self.parts.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parts.is_empty()
+    }
+
+    pub fn lines(&self) -> &[String] {
+        &self.parts
+    }
+
+    /// 循环取第 index 帧，资源为空时返回空字符串
+    pub fn frame(&self, index: usize) -> &str {
+        if self.parts.is_empty() {
+            return "";
+        }
+        &self.parts[index % self.parts.len()]
+    }
+
+    /// 以换行符连接所有片段
+    pub fn to_text(&self) -> String {
+        self.parts.join("\n")
+    }
+}
+
+#[derive(Default)]
+struct AssetsInner {
+    names: HashMap<String, Asset>,
+    contents: HashMap<u64, Asset>,
+}
+
+/// Assets：按名称登记的文本资源
+///
+/// 由 [`SystemContext::assets`](crate::context::SystemContext::assets) 提供，内置了
+/// [`SPINNER_DOTS`] 与 [`SPINNER_LINE`] 两组旋转指示器帧。
+///
+/// ```ignore
+/// system.assets().register("logo", Asset::text(include_str!("logo.txt")));
+/// element!(GlobalSpinner(spinner: SPINNER_LINE))
+/// ```
+#[derive(Clone)]
+pub struct Assets {
+    inner: Arc<RwLock<AssetsInner>>,
+}
+
+impl Default for Assets {
+    fn default() -> Self {
+        let assets = Self {
+            inner: Default::default(),
+        };
+        assets.register(
+            SPINNER_DOTS,
+            Asset::parts(["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"]),
+        );
+        assets.register(SPINNER_LINE, Asset::parts(["-", "\\", "|", "/"]));
+        assets
+    }
+}
+
+impl Assets {
+    /// 以 name 登记资源，同名资源重复登记时以后者为准；内容与已有资源相同时共享同一份数据
+    pub fn register(&self, name: impl Into<String>, asset: Asset) -> Asset {
+        let mut inner = self.inner.write().unwrap();
+        let asset = match inner.contents.get(&asset.id()) {
+            Some(existing) if *existing == asset => existing.clone(),
+            _ => {
+                inner.contents.insert(asset.id(), asset.clone());
+                asset
+            }
+        };
+        inner.names.insert(name.into(), asset.clone());
+        asset
+    }
+
+    pub fn get(&self, name: &str) -> Option<Asset> {
+        self.inner.read().unwrap().names.get(name).cloned()
+    }
+
+    /// 按内容的哈希值查找资源
+    pub fn get_by_id(&self, id: u64) -> Option<Asset> {
+        self.inner.read().unwrap().contents.get(&id).cloned()
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.inner.read().unwrap().names.contains_key(name)
+    }
+
+    /// 按名称查找资源，找不到时依次尝试 fallback，都没有时返回空资源
+    pub(crate) fn resolve(&self, name: &str, fallback: &str) -> Asset {
+        self.get(name)
+            .or_else(|| self.get(fallback))
+            .unwrap_or_default()
+    }
+}
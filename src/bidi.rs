@@ -0,0 +1,242 @@
+//! 从右向左（RTL）的文字方向支持
+//!
+//! 终端按单元格从左到右绘制，阿拉伯文、希伯来文需要在绘制前重排为视觉顺序。
+//! 这里实现的是 Unicode 双向算法的一个简化版本：按强方向字符划分方向段，
+//! 中性字符（空白、标点）跟随两侧一致的方向、否则跟随基础方向，数字视为从左向右；
+//! 不支持显式的方向控制字符与多级嵌套，足以应对界面中常见的混排文本。
+//!
+//! 方向由 [`SystemContext::direction`](crate::context::SystemContext::direction) 统一配置，
+//! 子树可以用 `ContextProvider<TextDirection>` 覆盖，组件通过
+//! [`use_direction`](crate::hooks::use_direction::UseDirection::use_direction) 读取。
+
+use ratatui::{
+    layout::{Alignment, Flex},
+    style::Style,
+    text::{Line, Span, StyledGrapheme},
+};
+use unicode_segmentation::UnicodeSegmentation;
+
+/// TextDirection：文字的基础方向
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum TextDirection {
+    /// 从左向右
+    #[default]
+    Ltr,
+    /// 从右向左
+    Rtl,
+}
+
+impl TextDirection {
+    /// 根据 locale 判断方向，例如 `ar_EG.UTF-8`、`he`
+    pub fn from_locale(locale: &str) -> Self {
+        let language = locale
+            .split(['_', '-', '.', '@'])
+            .next()
+            .unwrap_or_default();
+        if [
+            "ar", "he", "iw", "fa", "ur", "yi", "ps", "sd", "ug", "dv", "ckb",
+        ]
+        .contains(&language)
+        {
+            TextDirection::Rtl
+        } else {
+            TextDirection::Ltr
+        }
+    }
+
+    /// 根据环境变量 `LC_ALL`、`LC_MESSAGES`、`LANG` 中的 locale 判断方向
+    pub fn from_env() -> Self {
+        ["LC_ALL", "LC_MESSAGES", "LANG"]
+            .iter()
+            .find_map(|key| std::env::var(key).ok().filter(|value| !value.is_empty()))
+            .map_or(TextDirection::Ltr, |locale| Self::from_locale(&locale))
+    }
+
+    /// 按第一个强方向字符判断文本的方向，没有强方向字符时返回 None
+    pub fn detect(text: &str) -> Option<Self> {
+        text.chars().find_map(|c| match class(c) {
+            Class::Strong(direction) => Some(direction),
+            Class::Number | Class::Neutral => None,
+        })
+    }
+
+    pub fn is_rtl(self) -> bool {
+        self == TextDirection::Rtl
+    }
+
+    /// 镜像对齐方式：RTL 时左右互换
+    pub fn align(self, alignment: Alignment) -> Alignment {
+        match (self, alignment) {
+            (TextDirection::Rtl, Alignment::Left) => Alignment::Right,
+            (TextDirection::Rtl, Alignment::Right) => Alignment::Left,
+            _ => alignment,
+        }
+    }
+
+    /// 镜像主轴对齐：RTL 时 Start 与 End 互换
+    pub fn flex(self, flex: Flex) -> Flex {
+        match (self, flex) {
+            (TextDirection::Rtl, Flex::Start) => Flex::End,
+            (TextDirection::Rtl, Flex::End) => Flex::Start,
+            _ => flex,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Class {
+    Strong(TextDirection),
+    Number,
+    Neutral,
+}
+
+fn class(c: char) -> Class {
+    let rtl = matches!(
+        c as u32,
+        0x0590..=0x08FF | 0xFB1D..=0xFDFF | 0xFE70..=0xFEFF | 0x10800..=0x10FFF | 0x1E800..=0x1EFFF
+    );
+    if rtl {
+        // 阿拉伯-印度数字按数字处理
+        if matches!(c as u32, 0x0660..=0x0669 | 0x06F0..=0x06F9) {
+            Class::Number
+        } else {
+            Class::Strong(TextDirection::Rtl)
+        }
+    } else if c.is_ascii_digit() {
+        Class::Number
+    } else if c.is_alphabetic() {
+        Class::Strong(TextDirection::Ltr)
+    } else {
+        Class::Neutral
+    }
+}
+
+fn grapheme_class(grapheme: &str) -> Class {
+    grapheme.chars().next().map_or(Class::Neutral, class)
+}
+
+/// RTL 段中需要镜像显示的括号
+fn mirror(grapheme: &str) -> &str {
+    match grapheme {
+        "(" => ")",
+        ")" => "(",
+        "[" => "]",
+        "]" => "[",
+        "{" => "}",
+        "}" => "{",
+        "<" => ">",
+        ">" => "<",
+        "«" => "»",
+        "»" => "«",
+        _ => grapheme,
+    }
+}
+
+/// 计算每个字素簇解析后的方向
+fn resolve(classes: &[Class], base: TextDirection) -> Vec<TextDirection> {
+    let strong = |class: Class| match class {
+        Class::Strong(direction) => Some(direction),
+        // 数字本身从左向右排列，但不影响两侧中性字符的方向
+        Class::Number | Class::Neutral => None,
+    };
+    let mut resolved = Vec::with_capacity(classes.len());
+    for (i, class) in classes.iter().enumerate() {
+        let direction = match *class {
+            Class::Strong(direction) => direction,
+            Class::Number => TextDirection::Ltr,
+            Class::Neutral => {
+                let before = classes[..i].iter().rev().find_map(|c| strong(*c));
+                let after = classes[i + 1..].iter().find_map(|c| strong(*c));
+                match (before, after) {
+                    (Some(before), Some(after)) if before == after => before,
+                    _ => base,
+                }
+            }
+        };
+        resolved.push(direction);
+    }
+    // RTL 上下文中的数字与其间的分隔符（如 "1,234"、"3.5"）作为一个整体
+    for i in 0..classes.len() {
+        if classes[i] == Class::Neutral
+            && i > 0
+            && i + 1 < classes.len()
+            && classes[i - 1] == Class::Number
+            && classes[i + 1] == Class::Number
+        {
+            resolved[i] = TextDirection::Ltr;
+        }
+    }
+    resolved
+}
+
+fn has_rtl(text: &str) -> bool {
+    text.chars()
+        .any(|c| class(c) == Class::Strong(TextDirection::Rtl))
+}
+
+/// 把逻辑顺序的下标按 base 方向重排为视觉顺序
+fn visual_order(directions: &[TextDirection], base: TextDirection) -> Vec<usize> {
+    // 按方向划分连续的段
+    let mut runs: Vec<(TextDirection, Vec<usize>)> = Vec::new();
+    for (i, direction) in directions.iter().enumerate() {
+        match runs.last_mut() {
+            Some((run_direction, indices)) if run_direction == direction => indices.push(i),
+            _ => runs.push((*direction, vec![i])),
+        }
+    }
+    for (direction, indices) in runs.iter_mut() {
+        if direction.is_rtl() {
+            indices.reverse();
+        }
+    }
+    if base.is_rtl() {
+        runs.reverse();
+    }
+    runs.into_iter().flat_map(|(_, indices)| indices).collect()
+}
+
+/// 把一行逻辑顺序的文本重排为从左到右绘制的视觉顺序
+///
+/// 只包含从左向右文字的文本在 Ltr 基础方向下原样返回。
+pub fn reorder(text: &str, base: TextDirection) -> String {
+    if !base.is_rtl() && !has_rtl(text) {
+        return text.to_string();
+    }
+    let graphemes: Vec<&str> = text.graphemes(true).collect();
+    let classes: Vec<Class> = graphemes.iter().map(|g| grapheme_class(g)).collect();
+    let directions = resolve(&classes, base);
+    visual_order(&directions, base)
+        .into_iter()
+        .map(|i| {
+            if directions[i].is_rtl() {
+                mirror(graphemes[i])
+            } else {
+                graphemes[i]
+            }
+        })
+        .collect()
+}
+
+/// 按 base 方向重排一行带样式的文本，保留每个字素簇的样式，对齐方式按方向镜像
+pub fn reorder_line(line: &Line<'_>, base: TextDirection) -> Line<'static> {
+    let graphemes: Vec<StyledGrapheme> = line.styled_graphemes(Style::default()).collect();
+    let classes: Vec<Class> = graphemes.iter().map(|g| grapheme_class(g.symbol)).collect();
+    let directions = resolve(&classes, base);
+
+    let mut spans: Vec<Span<'static>> = Vec::new();
+    for i in visual_order(&directions, base) {
+        let grapheme = &graphemes[i];
+        let symbol = if directions[i].is_rtl() {
+            mirror(grapheme.symbol)
+        } else {
+            grapheme.symbol
+        };
+        match spans.last_mut() {
+            Some(span) if span.style == grapheme.style => span.content.to_mut().push_str(symbol),
+            _ => spans.push(Span::styled(symbol.to_string(), grapheme.style)),
+        }
+    }
+    let mut reordered = Line::from(spans).style(line.style);
+    reordered.alignment = line.alignment.map(|alignment| base.align(alignment));
+    reordered
+}
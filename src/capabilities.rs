@@ -0,0 +1,120 @@
+//! 终端能力检测
+
+/// Capabilities：当前终端实际可用的能力
+///
+/// 由 [`SystemContext::capabilities`](crate::context::SystemContext::capabilities) 提供，
+/// 渲染循环启动终端后填入检测结果，内置组件据此调整显示，例如 [`Hint`](crate::components::Hint)
+/// 在鼠标可用时提示点击、否则提示按键。
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Capabilities {
+    /// 是否开启了鼠标捕获
+    pub mouse: bool,
+    /// 是否支持 OSC 8 超链接
+    pub hyperlinks: bool,
+    /// East Asian Ambiguous 字符是否显示为两列宽，见 [`width`](crate::width)
+    pub ambiguous_wide: bool,
+    /// 可用的颜色数量，见 [`quantize`](crate::theme::quantize)
+    pub colors: ColorDepth,
+}
+
+/// ColorDepth：终端支持的颜色数量
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ColorDepth {
+    /// 16 色 ANSI
+    Ansi16,
+    /// 256 色调色板
+    Ansi256,
+    /// 24 位真彩色
+    #[default]
+    TrueColor,
+}
+
+impl Default for Capabilities {
+    /// 渲染循环启动前的默认值：鼠标视为不可用，超链接、歧义字符宽度与颜色数量按环境变量判断
+    fn default() -> Self {
+        Self {
+            mouse: false,
+            hyperlinks: supports_hyperlinks(),
+            ambiguous_wide: ambiguous_wide(),
+            colors: color_depth(),
+        }
+    }
+}
+
+/// 是否应当开启鼠标捕获：标准输出不是终端或者设置了环境变量 `NO_MOUSE` 时不开启
+pub(crate) fn wants_mouse() -> bool {
+    use std::io::IsTerminal;
+
+    std::io::stdout().is_terminal()
+        && std::env::var("NO_MOUSE").map_or(true, |value| value.is_empty() || value == "0")
+}
+
+/// 根据环境变量粗略判断当前终端是否支持 OSC 8 超链接
+pub fn supports_hyperlinks() -> bool {
+    let env = |key: &str| std::env::var(key).unwrap_or_default();
+
+    if !env("KITTY_WINDOW_ID").is_empty() || !env("WT_SESSION").is_empty() {
+        return true;
+    }
+    if matches!(
+        env("TERM_PROGRAM").as_str(),
+        "iTerm.app" | "WezTerm" | "vscode" | "ghostty" | "Hyper"
+    ) {
+        return true;
+    }
+    if env("VTE_VERSION").parse::<u32>().is_ok_and(|v| v >= 5000) {
+        return true;
+    }
+    let term = env("TERM");
+    ["kitty", "alacritty", "foot", "wezterm", "ghostty"]
+        .iter()
+        .any(|name| term.contains(name))
+}
+
+/// East Asian Ambiguous 字符是否按两列显示
+///
+/// 终端无法可靠地查询这一设置，因此由环境变量 `AMBIGUOUS_WIDE` 显式指定；
+/// 未设置时按 locale 判断，中日韩 locale 下的终端通常把这些字符显示为两列。
+pub fn ambiguous_wide() -> bool {
+    if let Ok(value) = std::env::var("AMBIGUOUS_WIDE") {
+        return !value.is_empty() && value != "0";
+    }
+    let locale = ["LC_ALL", "LC_CTYPE", "LANG"]
+        .iter()
+        .find_map(|key| std::env::var(key).ok().filter(|value| !value.is_empty()))
+        .unwrap_or_default();
+    ["zh", "ja", "ko"]
+        .iter()
+        .any(|language| locale.starts_with(language))
+}
+
+/// 根据环境变量判断终端支持的颜色数量
+///
+/// `COLORTERM` 为 `truecolor` / `24bit` 或者已知支持真彩色的终端时为真彩色，
+/// `TERM` 包含 `256color` 时为 256 色，否则保守地按 16 色处理。
+pub fn color_depth() -> ColorDepth {
+    let env = |key: &str| std::env::var(key).unwrap_or_default();
+
+    if matches!(env("COLORTERM").as_str(), "truecolor" | "24bit") {
+        return ColorDepth::TrueColor;
+    }
+    if !env("WT_SESSION").is_empty()
+        || matches!(
+            env("TERM_PROGRAM").as_str(),
+            "iTerm.app" | "WezTerm" | "vscode" | "ghostty"
+        )
+    {
+        return ColorDepth::TrueColor;
+    }
+    let term = env("TERM");
+    if ["kitty", "alacritty", "foot", "wezterm", "ghostty", "direct"]
+        .iter()
+        .any(|name| term.contains(name))
+    {
+        ColorDepth::TrueColor
+    } else if term.contains("256color") {
+        ColorDepth::Ansi256
+    } else {
+        ColorDepth::Ansi16
+    }
+}
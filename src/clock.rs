@@ -0,0 +1,218 @@
+//! 时钟：框架内所有计时的统一来源
+//!
+//! 默认使用真实时间。测试中通过 [`VirtualClock::install`] 切换到由测试代码推进的虚拟时间后，
+//! `use_interval`、`use_debounce`、`use_throttle`、动画、过渡与帧率限制等都只在
+//! [`VirtualClock::advance`] 时前进，同样的输入与推进顺序总是得到同样的每一帧画面。
+//!
+//! 框架本身不使用随机数：元素的 key 在编译期生成，加载动画的相位由计时决定，因此虚拟时钟之下的渲染结果是确定的。
+//! 组件自己的计时也应当使用这里的 [`now`] 与 [`sleep`]，而不是 `Instant::now` 与 `tokio::time::sleep`。
+
+use std::{
+    pin::Pin,
+    sync::{
+        Mutex,
+        atomic::{AtomicBool, Ordering},
+    },
+    task::{Context, Poll, Waker},
+    time::{Duration, Instant},
+};
+
+/// 是否安装了虚拟时钟，未安装时跳过加锁
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static VIRTUAL: Mutex<Option<VirtualState>> = Mutex::new(None);
+
+struct VirtualState {
+    now: Instant,
+    /// 等待虚拟时间到达截止时间的任务
+    sleepers: Vec<(Instant, Waker)>,
+}
+
+/// 当前时间，安装了虚拟时钟时为虚拟时间
+pub fn now() -> Instant {
+    if ENABLED.load(Ordering::Acquire)
+        && let Some(state) = &*VIRTUAL.lock().unwrap()
+    {
+        return state.now;
+    }
+    Instant::now()
+}
+
+/// 从 since 到现在经过的时间，相当于按 [`now`] 计算的 `since.elapsed()`
+pub fn elapsed(since: Instant) -> Duration {
+    now().saturating_duration_since(since)
+}
+
+/// 等待 duration 之后完成
+pub fn sleep(duration: Duration) -> Sleep {
+    sleep_until(now() + duration)
+}
+
+/// 等到 deadline 之后完成
+pub fn sleep_until(deadline: Instant) -> Sleep {
+    Sleep {
+        deadline,
+        timer: None,
+    }
+}
+
+/// Sleep：[`sleep`] 返回的 Future
+///
+/// 每次轮询时判断是否安装了虚拟时钟：安装时等待虚拟时间到达截止时间，否则使用 tokio 的计时器。
+pub struct Sleep {
+    deadline: Instant,
+    timer: Option<Pin<Box<tokio::time::Sleep>>>,
+}
+
+impl Sleep {
+    pub fn deadline(&self) -> Instant {
+        self.deadline
+    }
+
+    /// 修改截止时间，之前的截止时间作废
+    pub fn reset(&mut self, deadline: Instant) {
+        self.deadline = deadline;
+        if let Some(timer) = &mut self.timer {
+            timer.as_mut().reset(deadline.into());
+        }
+    }
+}
+
+impl Future for Sleep {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if ENABLED.load(Ordering::Acquire)
+            && let Some(state) = &mut *VIRTUAL.lock().unwrap()
+        {
+            if state.now >= self.deadline {
+                return Poll::Ready(());
+            }
+            let deadline = self.deadline;
+            if !state
+                .sleepers
+                .iter()
+                .any(|(at, waker)| *at == deadline && waker.will_wake(cx.waker()))
+            {
+                state.sleepers.push((deadline, cx.waker().clone()));
+            }
+            return Poll::Pending;
+        }
+        let deadline = self.deadline;
+        self.timer
+            .get_or_insert_with(|| Box::pin(tokio::time::sleep_until(deadline.into())))
+            .as_mut()
+            .poll(cx)
+    }
+}
+
+/// Interval：按固定周期触发的计时器，错过的触发被跳过而不是集中补齐
+pub struct Interval {
+    period: Duration,
+    sleep: Sleep,
+}
+
+/// 从 start 开始，每隔 period 触发一次，period 为 0 时会 panic
+pub fn interval_at(start: Instant, period: Duration) -> Interval {
+    assert!(!period.is_zero(), "interval period must be non-zero");
+    Interval {
+        period,
+        sleep: sleep_until(start),
+    }
+}
+
+impl Interval {
+    pub fn period(&self) -> Duration {
+        self.period
+    }
+
+    /// 轮询下一次触发，触发时返回本次触发的计划时间
+    pub fn poll_tick(&mut self, cx: &mut Context<'_>) -> Poll<Instant> {
+        if Pin::new(&mut self.sleep).poll(cx).is_pending() {
+            return Poll::Pending;
+        }
+        let tick = self.sleep.deadline();
+        // 跳过已经错过的触发，下一次安排在现在之后最近的周期点
+        let missed = (elapsed(tick).as_nanos() / self.period.as_nanos()) as u32;
+        self.sleep.reset(tick + self.period * (missed + 1));
+        Poll::Ready(tick)
+    }
+}
+
+/// VirtualClock：由测试代码推进的虚拟时钟
+///
+/// 安装后框架内的计时都使用虚拟时间，句柄释放时恢复真实时间。虚拟时钟是全局的，同一时间只应安装一个，
+/// 需要并行运行的测试应当串行化使用虚拟时钟的部分。
+///
+/// ```ignore
+/// let clock = VirtualClock::install();
+/// tree.render(&mut terminal)?;
+/// // 推进一帧动画的时间，之后渲染的结果总是相同
+/// clock.advance(Duration::from_millis(16));
+/// tokio::task::yield_now().await;
+/// tree.render(&mut terminal)?;
+/// ```
+pub struct VirtualClock {
+    origin: Instant,
+}
+
+impl VirtualClock {
+    /// 安装虚拟时钟，虚拟时间从当前的真实时间开始，之后只在 [`VirtualClock::advance`] 时前进
+    pub fn install() -> Self {
+        let origin = Instant::now();
+        *VIRTUAL.lock().unwrap() = Some(VirtualState {
+            now: origin,
+            sleepers: Vec::new(),
+        });
+        ENABLED.store(true, Ordering::Release);
+        Self { origin }
+    }
+
+    /// 当前的虚拟时间
+    pub fn now(&self) -> Instant {
+        now()
+    }
+
+    /// 安装以来推进的总时长
+    pub fn elapsed(&self) -> Duration {
+        elapsed(self.origin)
+    }
+
+    /// 把虚拟时间推进 duration，唤醒所有到期的计时器
+    ///
+    /// 被唤醒的任务在执行器下一次调度时才会运行，需要观察结果时先让出执行权，例如 `tokio::task::yield_now().await`。
+    pub fn advance(&self, duration: Duration) {
+        let wakers = {
+            let mut guard = VIRTUAL.lock().unwrap();
+            let Some(state) = &mut *guard else {
+                return;
+            };
+            state.now += duration;
+            let now = state.now;
+            let (due, pending) = std::mem::take(&mut state.sleepers)
+                .into_iter()
+                .partition::<Vec<_>, _>(|(deadline, _)| *deadline <= now);
+            state.sleepers = pending;
+            due
+        };
+        // 在锁外唤醒，被唤醒的任务可能立即重新轮询
+        for (_, waker) in wakers {
+            waker.wake();
+        }
+    }
+}
+
+impl Drop for VirtualClock {
+    fn drop(&mut self) {
+        ENABLED.store(false, Ordering::Release);
+        let sleepers = VIRTUAL
+            .lock()
+            .unwrap()
+            .take()
+            .map(|state| state.sleepers)
+            .unwrap_or_default();
+        // 恢复真实时间后重新轮询，改为使用 tokio 的计时器
+        for (_, waker) in sleepers {
+            waker.wake();
+        }
+    }
+}
@@ -24,6 +24,9 @@ pub trait ComponentHelperExt: Any + Send + Sync {
     );
 
     fn component_type_id(&self) -> TypeId;
+
+    // 组件的类型名，用于调试输出
+    fn component_name(&self) -> &'static str;
 }
 
 // 通用组件辅助结构体，用于泛型组件的构造和管理
@@ -74,4 +77,8 @@ where
     fn component_type_id(&self) -> TypeId {
         TypeId::of::<T>()
     }
+
+    fn component_name(&self) -> &'static str {
+        std::any::type_name::<T>()
+    }
 }
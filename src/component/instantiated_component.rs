@@ -1,21 +1,33 @@
 use futures::future::poll_fn;
-use ratatui::layout::{Constraint, Direction};
+use ratatui::layout::{Constraint, Direction, Size};
 
 use super::component_helper::ComponentHelperExt;
 use crate::{
-    component::AnyComponent,
-    context::ContextStack,
+    clock::{self, Sleep},
+    component::{AnyComponent, Component},
+    context::{ContextStack, SystemContext},
     element::key::ElementKey,
     hooks::{AnyHook, Hook, Hooks},
     multimap::RemoveOnlyMultimap,
     props::AnyProps,
-    render::{drawer::ComponentDrawer, layout_style::LayoutStyle, updater::ComponentUpdater},
+    render::{
+        blocking,
+        drawer::ComponentDrawer,
+        layout_style::LayoutStyle,
+        snapshot::{self, StateSnapshot},
+        transition::{Lifecycle, Presence},
+        updater::{ComponentUpdater, ReparentPool},
+        watchdog::ComponentTiming,
+    },
     terminal::Terminal,
 };
 use std::{
+    any::{Any, TypeId},
+    future::Future,
     ops::{Deref, DerefMut},
     pin::Pin,
     task::{Context, Poll},
+    time::{Duration, Instant},
 };
 
 pub struct InstantiatedComponent {
@@ -26,6 +38,19 @@ pub struct InstantiatedComponent {
     layout_style: LayoutStyle,
     hooks: Vec<Box<dyn AnyHook>>,
     first_update: bool,
+    // 最近一次 update 与绘制的耗时，包含子组件
+    update_time: Duration,
+    draw_time: Duration,
+    // 自身状态变化引起重新渲染的最小间隔，见 use_max_refresh_rate
+    max_refresh_rate: Option<Duration>,
+    last_update: Option<Instant>,
+    // 间隔内被推迟的刷新
+    deferred: Option<Pin<Box<Sleep>>>,
+    // 挂载时间与进入、离开过渡
+    lifecycle: Lifecycle,
+    // 自上一次 update 以来自身 Hook 或子树中有状态变化，此时不能跳过 update
+    dirty: bool,
+    subtree_dirty: bool,
 }
 
 impl InstantiatedComponent {
@@ -40,16 +65,42 @@ impl InstantiatedComponent {
             layout_style: LayoutStyle::default(),
             hooks: Default::default(),
             first_update: true,
+            update_time: Duration::ZERO,
+            draw_time: Duration::ZERO,
+            max_refresh_rate: None,
+            last_update: None,
+            deferred: None,
+            lifecycle: Lifecycle::default(),
+            dirty: false,
+            subtree_dirty: false,
         }
     }
 
+    pub fn key(&self) -> &ElementKey {
+        &self.key
+    }
+
+    pub(crate) fn lifecycle_mut(&mut self) -> &mut Lifecycle {
+        &mut self.lifecycle
+    }
+
     /// 递归渲染当前组件及其所有子组件，自动处理布局和 Hook 生命周期
     pub fn draw(&mut self, drawer: &mut ComponentDrawer) {
+        let started_at = Instant::now();
+        let _section = blocking::enter("draw", self.helper.component_name(), &self.key);
         let layout_style = &self.layout_style;
 
         // 1. 计算应用 margin/offset 后的实际区域
         let area = layout_style.inner_area(drawer.area);
         drawer.area = area;
+        // 祖先离开中时整棵子树都视为离开中
+        let inherited = drawer.presence();
+        let presence = match self.lifecycle.presence() {
+            Presence::Present if inherited.is_exiting() => inherited,
+            presence => presence,
+        };
+        drawer.set_presence(presence);
+        let parent = drawer.set_component(self.helper.component_name());
         // 渲染前调用所有 Hook 的 pre_component_draw 钩子
         self.hooks.pre_component_draw(drawer);
         // 2. 绘制当前组件内容
@@ -64,23 +115,50 @@ impl InstantiatedComponent {
         for (child, child_area) in self.children.iter_mut().zip(children_areas) {
             drawer.area = child_area;
             child.draw(drawer);
+            drawer.set_presence(presence);
+            drawer.set_component(self.helper.component_name());
         }
         // 渲染后调用所有 Hook 的 post_component_draw 钩子
         self.hooks.post_component_draw(drawer);
+        drawer.set_presence(inherited);
+        drawer.set_component(parent);
+        self.draw_time = started_at.elapsed();
     }
 
     /// 更新当前组件及其子组件的状态，驱动 Hook 生命周期和属性变更
     pub fn update(
         &mut self,
-        props: AnyProps,
+        mut props: AnyProps,
         terminal: &mut Terminal,
         context_stack: &mut ContextStack,
     ) {
+        let started_at = Instant::now();
+        let _section = blocking::enter("update", self.helper.component_name(), &self.key);
+        // 子树没有状态变化且组件认为 props 没有变化时跳过整棵子树的 update，沿用上一次的结果。
+        // 有动画在进行时无法得知是哪个组件在播放，不做跳过
+        let animating = context_stack
+            .get_context::<SystemContext>()
+            .is_some_and(|system| system.animations().is_animating());
+        if !self.first_update
+            && !self.dirty
+            && !self.subtree_dirty
+            && !animating
+            && !self.component.should_update(props.borrow())
+        {
+            // 父组件 update 时会从子树收集焦点、列宽等数据，按上一次的结果重新登记
+            self.replay(terminal, context_stack);
+            self.update_time = started_at.elapsed();
+            return;
+        }
+        self.dirty = false;
+        self.subtree_dirty = false;
         // 构造组件更新辅助器，便于管理子组件和布局
         let mut updater = ComponentUpdater::new(
             self.key.clone(),
             &mut self.children,
             &mut self.layout_style,
+            &mut self.max_refresh_rate,
+            &mut self.lifecycle,
             terminal,
             context_stack,
         );
@@ -100,18 +178,229 @@ impl InstantiatedComponent {
 
         // 首次 update 标记为 false，后续渲染复用 Hook
         self.first_update = false;
+        // 本次 update 已经读取了最新的状态，推迟中的刷新不再需要
+        self.last_update = Some(clock::now());
+        self.deferred = None;
+        self.update_time = started_at.elapsed();
+    }
+
+    /// 跳过 update 时代替 update 调用：依次调用 Hook 的 replay_component_update 与组件的 replay_update，
+    /// 再递归处理子组件。本组件提供给子组件的上下文此时不存在，该类型的上下文对子组件不可见
+    fn replay(&mut self, terminal: &mut Terminal, context_stack: &mut ContextStack) {
+        let mut updater = ComponentUpdater::new(
+            self.key.clone(),
+            &mut self.children,
+            &mut self.layout_style,
+            &mut self.max_refresh_rate,
+            &mut self.lifecycle,
+            terminal,
+            context_stack,
+        );
+        self.hooks.replay_component_update(&mut updater);
+        self.component.replay_update(&mut updater);
+
+        let children = &mut self.children;
+        context_stack.with_shadow(children.context_type, |context_stack| {
+            for child in children.iter_mut() {
+                child.replay(terminal, context_stack);
+            }
+        });
+    }
+
+    /// 卸载当前组件及其整棵子树：依次调用 Hook 的 on_unmount、组件的 unmount，再递归卸载子组件
+    pub(crate) fn unmount(&mut self) {
+        let _section = blocking::enter("unmount", self.helper.component_name(), &self.key);
+        self.hooks.on_unmount();
+        self.component.unmount();
+        for child in self.children.iter_mut() {
+            child.unmount();
+        }
+    }
+
+    /// 丢弃当前组件
+    ///
+    /// 有 pool 时，带可迁移 key 的组件连同子树交给 pool 等待接管；
+    /// 其余组件依次卸载，子树中带可迁移 key 的组件同样交给 pool 而不是卸载。
+    pub(crate) fn discard(mut self, pool: Option<&ReparentPool>) {
+        let Some(pool) = pool else {
+            self.unmount();
+            return;
+        };
+        if self.key.is_portable() {
+            pool.put(self);
+            return;
+        }
+        {
+            let _section = blocking::enter("unmount", self.helper.component_name(), &self.key);
+            self.hooks.on_unmount();
+            self.component.unmount();
+        }
+        let children = std::mem::take(&mut self.children.components);
+        for (_, child) in children.into_remaining() {
+            child.discard(Some(pool));
+        }
+    }
+
+    /// 收集当前组件及其子树中每个组件最近一帧的耗时（不含子组件）
+    pub(crate) fn collect_timings(&self, timings: &mut Vec<ComponentTiming>) {
+        let children = self.children.iter();
+        let (children_update, children_draw) = children
+            .fold((Duration::ZERO, Duration::ZERO), |(update, draw), child| {
+                (update + child.update_time, draw + child.draw_time)
+            });
+        timings.push(ComponentTiming {
+            name: self.helper.component_name(),
+            update: self.update_time.saturating_sub(children_update),
+            draw: self.draw_time.saturating_sub(children_draw),
+        });
+        for child in self.children.iter() {
+            child.collect_timings(timings);
+        }
+    }
+
+    /// 收集当前组件及其子树中可序列化的 Hook 状态，path 为当前组件在快照中的路径
+    pub(crate) fn snapshot_states(&mut self, path: &str, snapshot: &mut StateSnapshot) {
+        for (index, hook) in self.hooks.iter_mut().enumerate() {
+            if let Some(value) = hook.persistable().and_then(|hook| hook.snapshot()) {
+                snapshot.insert(format!("{path}#{index}"), value);
+            }
+        }
+        for (index, child) in self.children.iter_mut().enumerate() {
+            let path = format!(
+                "{path}/{}[{index}]",
+                snapshot::segment(child.helper.component_name())
+            );
+            child.snapshot_states(&path, snapshot);
+        }
+    }
+
+    /// 用快照中路径一致的条目恢复当前组件及其子树的 Hook 状态，用过的条目从快照中移除
+    pub(crate) fn restore_states(&mut self, path: &str, snapshot: &mut StateSnapshot) {
+        for (index, hook) in self.hooks.iter_mut().enumerate() {
+            if let Some(hook) = hook.persistable()
+                && let Some(value) = snapshot.take(&format!("{path}#{index}"))
+            {
+                hook.restore(value);
+            }
+        }
+        for (index, child) in self.children.iter_mut().enumerate() {
+            let path = format!(
+                "{path}/{}[{index}]",
+                snapshot::segment(child.helper.component_name())
+            );
+            child.restore_states(&path, snapshot);
+        }
+    }
+
+    /// 当前组件在快照中的路径，用于根组件
+    pub(crate) fn snapshot_path(&self) -> String {
+        snapshot::segment(self.helper.component_name()).to_string()
     }
 
     pub fn component(&self) -> &dyn AnyComponent {
         &*self.component
     }
 
+    /// 尝试将组件实例向下转型为具体的组件类型
+    pub fn downcast_ref<T: Component>(&self) -> Option<&T> {
+        (&*self.component as &dyn Any).downcast_ref::<T>()
+    }
+
+    /// 尝试将组件实例向下转型为具体组件类型的可变引用
+    pub fn downcast_mut<T: Component>(&mut self) -> Option<&mut T> {
+        (&mut *self.component as &mut dyn Any).downcast_mut::<T>()
+    }
+
+    /// 获取当前组件的子组件集合
+    pub fn children(&self) -> &Components {
+        &self.children
+    }
+
+    /// 获取当前组件的布局样式
+    pub fn layout_style(&self) -> &LayoutStyle {
+        &self.layout_style
+    }
+
+    /// 测量组件的固有尺寸
+    ///
+    /// - 组件自身实现了 measure 时直接使用其结果
+    /// - 否则沿主轴累加子组件尺寸（含间距），交叉轴取最大值
+    /// - 宽高约束为 Length 时以约束值为准
+    pub fn measure(&self) -> Size {
+        let layout_style = &self.layout_style;
+        let mut size = self.component.measure().unwrap_or_else(|| {
+            let sizes = self.children.measure();
+            // 子组件很多或尺寸很大时在 u16::MAX 处截断，而不是溢出
+            let gaps = u16::try_from(sizes.len().saturating_sub(1)).unwrap_or(u16::MAX);
+            let gap = u16::try_from(layout_style.gap.max(0))
+                .unwrap_or(u16::MAX)
+                .saturating_mul(gaps);
+            let total = |extent: fn(&Size) -> u16| {
+                sizes
+                    .iter()
+                    .map(extent)
+                    .fold(gap, |total, extent| total.saturating_add(extent))
+            };
+            match layout_style.flex_direction {
+                Direction::Horizontal => Size {
+                    width: total(|s| s.width),
+                    height: sizes.iter().map(|s| s.height).max().unwrap_or_default(),
+                },
+                Direction::Vertical => Size {
+                    width: sizes.iter().map(|s| s.width).max().unwrap_or_default(),
+                    height: total(|s| s.height),
+                },
+            }
+        });
+
+        if let Constraint::Length(width) = layout_style.width {
+            size.width = width;
+        }
+        if let Constraint::Length(height) = layout_style.height {
+            size.height = height;
+        }
+        size
+    }
+
+    /// 获取组件文本基线相对顶部的偏移
+    ///
+    /// 组件自身未声明基线时取第一个子组件的基线，没有子组件时以测量高度的最后一行为基线
+    pub fn baseline(&self) -> u16 {
+        self.component
+            .baseline()
+            .or_else(|| self.children.iter().next().map(|child| child.baseline()))
+            .unwrap_or_else(|| self.measure().height.saturating_sub(1))
+    }
+
     /// 递归检查当前组件及其所有 Hook、子组件是否有状态变更需要刷新
     pub fn poll_change(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<()> {
         // 先检查自身 hooks 是否有变化
-        let hooks_status = Pin::new(&mut self.hooks).poll_change(cx);
+        let mut hooks_status = {
+            let _section = blocking::enter("poll", self.helper.component_name(), &self.key);
+            Pin::new(&mut self.hooks).poll_change(cx)
+        };
+        // 限制了刷新频率时，间隔内的变化推迟到间隔结束时再刷新
+        if let Some(interval) = self.max_refresh_rate {
+            if hooks_status.is_ready() {
+                let next = self.last_update.map(|last| last + interval);
+                if self.deferred.is_some() {
+                    hooks_status = Poll::Pending;
+                } else if let Some(next) = next.filter(|next| *next > clock::now()) {
+                    self.deferred = Some(Box::pin(clock::sleep_until(next)));
+                    hooks_status = Poll::Pending;
+                }
+            }
+            if let Some(deferred) = &mut self.deferred
+                && deferred.as_mut().poll(cx).is_ready()
+            {
+                self.deferred = None;
+                hooks_status = Poll::Ready(());
+            }
+        }
         // 再检查所有子组件是否有变化
         let children_status = Pin::new(&mut self.children).poll_change(cx);
+        self.dirty |= hooks_status.is_ready();
+        self.subtree_dirty |= children_status.is_ready();
 
         // 只要有一个就绪，则整个组件需要刷新
         if hooks_status.is_ready() || children_status.is_ready() {
@@ -131,6 +420,8 @@ impl InstantiatedComponent {
 #[derive(Default)]
 pub struct Components {
     pub components: RemoveOnlyMultimap<ElementKey, InstantiatedComponent>,
+    /// 最近一次协调时提供给子组件的上下文类型
+    pub(crate) context_type: Option<TypeId>,
 }
 
 impl Deref for Components {
@@ -164,6 +455,29 @@ impl Components {
             .collect()
     }
 
+    /// 依次测量所有子组件的固有尺寸
+    pub fn measure(&self) -> Vec<Size> {
+        self.components.iter().map(|c| c.measure()).collect()
+    }
+
+    /// 依次获取所有子组件的基线偏移
+    pub fn baselines(&self) -> Vec<u16> {
+        self.components.iter().map(|c| c.baseline()).collect()
+    }
+
+    /// 在整棵子树中按 key 查找组件实例（深度优先）
+    pub fn find_mut(&mut self, key: &ElementKey) -> Option<&mut InstantiatedComponent> {
+        for component in self.components.iter_mut() {
+            if component.key == *key {
+                return Some(component);
+            }
+            if let Some(found) = component.children.find_mut(key) {
+                return Some(found);
+            }
+        }
+        None
+    }
+
     pub fn poll_change(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<()> {
         let mut is_ready = false;
 
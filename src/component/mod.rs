@@ -1,19 +1,23 @@
 use std::any::Any;
 
-use ratatui::layout::{Direction, Layout};
+use ratatui::layout::{Direction, Flex, Layout, Size};
 
 use crate::{
     component::instantiated_component::Components,
     hooks::Hooks,
-    props::AnyProps,
-    render::{drawer::ComponentDrawer, layout_style::LayoutStyle, updater::ComponentUpdater},
+    props::{AnyProps, Props},
+    render::{
+        drawer::ComponentDrawer,
+        layout_style::{AlignItems, LayoutStyle},
+        updater::ComponentUpdater,
+    },
 };
 
 pub mod component_helper;
 pub mod instantiated_component;
 
 pub trait Component: Any + Send + Sync {
-    type Props<'a>
+    type Props<'a>: Props
     where
         Self: 'a;
 
@@ -21,6 +25,18 @@ pub trait Component: Any + Send + Sync {
 
     fn draw(&self, _drawer: &mut ComponentDrawer<'_, '_>) {}
 
+    // 测量组件内容的固有尺寸，用于跨兄弟节点的对齐计算
+    // 返回 None 表示没有固有尺寸，由子组件的尺寸推导
+    fn measure(&self) -> Option<Size> {
+        None
+    }
+
+    // 文本基线相对组件顶部的偏移，用于 AlignItems::Baseline
+    // 返回 None 表示由子组件推导
+    fn baseline(&self) -> Option<u16> {
+        None
+    }
+
     // 默认使用flex布局计算子组件的area
     fn calc_children_areas(
         &self,
@@ -28,24 +44,7 @@ pub trait Component: Any + Send + Sync {
         layout_style: &LayoutStyle,
         drawer: &mut ComponentDrawer<'_, '_>,
     ) -> Vec<ratatui::prelude::Rect> {
-        let layout = layout_style
-            .get_layout()
-            .constraints(children.get_constraints(layout_style.flex_direction));
-
-        let areas = layout.split(drawer.area);
-
-        let mut children_areas: Vec<ratatui::prelude::Rect> = vec![];
-
-        let rev_direction = match layout_style.flex_direction {
-            Direction::Horizontal => Direction::Vertical,
-            Direction::Vertical => Direction::Horizontal,
-        };
-        for (area, constraint) in areas.iter().zip(children.get_constraints(rev_direction)) {
-            let area = Layout::new(rev_direction, [constraint]).split(*area)[0];
-            children_areas.push(area);
-        }
-
-        children_areas
+        flex_children_areas(children, layout_style, drawer)
     }
 
     fn update(
@@ -55,11 +54,92 @@ pub trait Component: Any + Send + Sync {
         _updater: &mut ComponentUpdater<'_, '_>,
     ) {
     }
+
+    // 组件已经 update 过、且自身与子树都没有状态变化时，在 update 之前调用
+    // 返回 false 时跳过本组件及整个子树的 update 与子组件协调，沿用上一次的结果，默认总是 update
+    // 通常与 update 中保存的上一次 props 比较。注意通过上下文（如主题）读取的值、
+    // 以及 props 中 State 等句柄指向的值变化时句柄本身不变，需要由组件自行判断
+    // 被跳过的组件及其子树会收到 replay_update，向祖先重新登记上一次的焦点、列宽等数据
+    fn should_update(&self, _props: &Self::Props<'_>) -> bool {
+        true
+    }
+
+    // 本组件因 should_update 返回 false 或位于被跳过的子树中而没有 update 时调用
+    // 祖先在 update 中从子树收集的数据（如 ColumnGroup 的列宽）需要在这里按上一次的结果重新登记
+    fn replay_update(&mut self, _updater: &mut ComponentUpdater<'_, '_>) {}
+
+    // 组件被卸载时调用：从 children 中移除（声明了离开过渡时在过渡结束后）或渲染循环结束时
+    // 适合结束子进程、刷新文件等清理工作，先于子组件调用，调用后组件实例随即被丢弃
+    fn unmount(&mut self) {}
+}
+
+/// 按 flex 布局计算子组件的区域，是 [`Component::calc_children_areas`] 的默认实现，
+/// 覆盖该方法的组件可以在需要时回退到它
+pub fn flex_children_areas(
+    children: &Components,
+    layout_style: &LayoutStyle,
+    drawer: &mut ComponentDrawer<'_, '_>,
+) -> Vec<ratatui::prelude::Rect> {
+    let layout = layout_style
+        .get_layout()
+        .constraints(children.get_constraints(layout_style.flex_direction));
+
+    let areas = layout.split(drawer.area);
+
+    let mut children_areas: Vec<ratatui::prelude::Rect> = vec![];
+
+    let rev_direction = match layout_style.flex_direction {
+        Direction::Horizontal => Direction::Vertical,
+        Direction::Vertical => Direction::Horizontal,
+    };
+    // 基线对齐时，先求出所有子组件中最大的基线偏移，其余子组件向下平移补齐
+    let baseline_align = layout_style.align_items == AlignItems::Baseline
+        && layout_style.flex_direction == Direction::Horizontal;
+    // 基线需要递归测量子树，只在基线对齐时计算
+    let baselines = if baseline_align {
+        children.baselines()
+    } else {
+        Vec::new()
+    };
+    let max_baseline = baselines.iter().copied().max().unwrap_or_default();
+
+    let flex = match layout_style.align_items {
+        AlignItems::Center => Flex::Center,
+        AlignItems::End => Flex::End,
+        AlignItems::Start | AlignItems::Baseline => Flex::Start,
+    };
+
+    for ((area, constraint), baseline) in areas
+        .iter()
+        .zip(children.get_constraints(rev_direction))
+        .zip(baselines.into_iter().chain(std::iter::repeat(0)))
+    {
+        let mut area = *area;
+        if baseline_align {
+            let offset = (max_baseline - baseline).min(area.height);
+            area.y += offset;
+            area.height -= offset;
+        }
+        let mut area = Layout::new(rev_direction, [constraint])
+            .flex(flex)
+            .split(area)[0];
+        if layout_style.mirrored {
+            // 在父区域中左右镜像
+            area.x = drawer.area.x + drawer.area.right().saturating_sub(area.right());
+        }
+        children_areas.push(area);
+    }
+
+    children_areas
 }
 
 pub trait AnyComponent: Any + Send + Sync {
     fn draw(&self, drawer: &mut ComponentDrawer<'_, '_>);
 
+    fn measure(&self) -> Option<Size>;
+
+    fn baseline(&self) -> Option<u16>;
+
     fn calc_children_areas(
         &self,
         children: &Components,
@@ -68,6 +148,12 @@ pub trait AnyComponent: Any + Send + Sync {
     ) -> Vec<ratatui::prelude::Rect>;
 
     fn update(&mut self, props: AnyProps, hooks: Hooks, updater: &mut ComponentUpdater<'_, '_>);
+
+    fn should_update(&self, props: AnyProps) -> bool;
+
+    fn replay_update(&mut self, updater: &mut ComponentUpdater<'_, '_>);
+
+    fn unmount(&mut self);
 }
 
 // 为所有实现了 Component trait 的类型自动实现 AnyComponent trait
@@ -80,6 +166,14 @@ where
         Component::draw(self, drawer);
     }
 
+    fn measure(&self) -> Option<Size> {
+        Component::measure(self)
+    }
+
+    fn baseline(&self) -> Option<u16> {
+        Component::baseline(self)
+    }
+
     fn calc_children_areas(
         &self,
         children: &Components,
@@ -102,4 +196,16 @@ where
             updater,
         );
     }
+
+    fn should_update(&self, props: AnyProps) -> bool {
+        Component::should_update(self, unsafe { props.downcast_ref_unchecked() })
+    }
+
+    fn replay_update(&mut self, updater: &mut ComponentUpdater<'_, '_>) {
+        Component::replay_update(self, updater);
+    }
+
+    fn unmount(&mut self) {
+        Component::unmount(self);
+    }
 }
@@ -0,0 +1,171 @@
+use std::sync::{Arc, Mutex};
+
+use ratatui::layout::{Constraint, Direction, Rect};
+
+use crate::{
+    component::{Component, instantiated_component::Components},
+    context::Context,
+    element::AnyElement,
+    hooks::{Hooks, use_context::UseContext},
+    props::Props,
+    render::{drawer::ComponentDrawer, layout_style::LayoutStyle, updater::ComponentUpdater},
+};
+
+/// ColumnTracks：列宽轨道
+///
+/// 由 ColumnGroup 通过上下文注册，同一组内的所有 Row 在 update 阶段登记各列的固有宽度，
+/// 轨道保留每一列的最大值，绘制阶段 Row 再按共同的列宽布局，从而实现跨行的表格式对齐。
+#[derive(Clone, Default)]
+pub struct ColumnTracks {
+    widths: Arc<Mutex<Vec<u16>>>,
+}
+
+impl ColumnTracks {
+    /// 清空所有列宽，每轮 update 开始时调用
+    pub fn reset(&self) {
+        self.widths.lock().unwrap().clear();
+    }
+
+    /// 登记一行的各列宽度，每列保留最大值
+    pub fn register(&self, widths: impl IntoIterator<Item = u16>) {
+        let mut tracks = self.widths.lock().unwrap();
+        for (index, width) in widths.into_iter().enumerate() {
+            match tracks.get_mut(index) {
+                Some(track) => *track = (*track).max(width),
+                None => tracks.push(width),
+            }
+        }
+    }
+
+    /// 获取当前各列的共同宽度
+    pub fn widths(&self) -> Vec<u16> {
+        self.widths.lock().unwrap().clone()
+    }
+}
+
+#[derive(Default, Props)]
+pub struct ColumnGroupProps<'a> {
+    /// 行间距
+    pub gap: i32,
+    pub children: Vec<AnyElement<'a>>,
+}
+
+/// ColumnGroup：列组容器，为子树中的 Row 提供共享的列宽轨道
+pub struct ColumnGroup {
+    tracks: ColumnTracks,
+}
+
+impl Component for ColumnGroup {
+    type Props<'a> = ColumnGroupProps<'a>;
+
+    fn new(_props: &Self::Props<'_>) -> Self {
+        Self {
+            tracks: ColumnTracks::default(),
+        }
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        _hooks: Hooks,
+        updater: &mut ComponentUpdater<'_, '_>,
+    ) {
+        updater.set_layout_style(LayoutStyle {
+            flex_direction: Direction::Vertical,
+            gap: props.gap,
+            ..Default::default()
+        });
+
+        // 重新登记本轮的列宽，避免内容变窄后列宽无法收缩
+        self.tracks.reset();
+        updater.update_children(
+            props.children.iter_mut(),
+            Some(Context::owned(self.tracks.clone())),
+        );
+    }
+}
+
+#[derive(Default, Props)]
+pub struct RowProps<'a> {
+    /// 列间距
+    pub gap: i32,
+    pub children: Vec<AnyElement<'a>>,
+}
+
+/// Row：表格行，子组件即单元格
+///
+/// 位于 ColumnGroup 内时按共享列宽布局，否则按自身单元格的固有宽度布局。
+pub struct Row {
+    tracks: Option<ColumnTracks>,
+    widths: Vec<u16>,
+}
+
+impl Component for Row {
+    type Props<'a> = RowProps<'a>;
+
+    fn new(_props: &Self::Props<'_>) -> Self {
+        Self {
+            tracks: None,
+            widths: Vec::new(),
+        }
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        mut hooks: Hooks,
+        updater: &mut ComponentUpdater<'_, '_>,
+    ) {
+        self.tracks = hooks
+            .with_context_stack(updater.component_context_stack())
+            .try_use_context::<ColumnTracks>()
+            .map(|tracks| tracks.clone());
+
+        updater.update_children(props.children.iter_mut(), None);
+
+        // 子组件更新完毕后测量单元格，并登记到共享轨道
+        let sizes = updater.measure_children();
+        self.widths = sizes.iter().map(|size| size.width).collect();
+        if let Some(tracks) = &self.tracks {
+            tracks.register(self.widths.iter().copied());
+        }
+
+        let height = sizes.iter().map(|size| size.height).max().unwrap_or(1);
+        updater.set_layout_style(LayoutStyle {
+            flex_direction: Direction::Horizontal,
+            gap: props.gap,
+            height: Constraint::Length(height),
+            ..Default::default()
+        });
+    }
+
+    fn replay_update(&mut self, _updater: &mut ComponentUpdater<'_, '_>) {
+        // ColumnGroup 每轮 update 都会清空轨道，被跳过时按上一次的宽度重新登记
+        if let Some(tracks) = &self.tracks {
+            tracks.register(self.widths.iter().copied());
+        }
+    }
+
+    fn calc_children_areas(
+        &self,
+        _children: &Components,
+        layout_style: &LayoutStyle,
+        drawer: &mut ComponentDrawer<'_, '_>,
+    ) -> Vec<Rect> {
+        let widths = match &self.tracks {
+            Some(tracks) => tracks.widths(),
+            None => self.widths.clone(),
+        };
+
+        layout_style
+            .get_layout()
+            .constraints(
+                widths
+                    .into_iter()
+                    .take(self.widths.len())
+                    .map(Constraint::Length),
+            )
+            .split(drawer.area)
+            .to_vec()
+    }
+}
@@ -0,0 +1,58 @@
+use std::{any::Any, marker::PhantomData};
+
+use crate::{
+    component::Component, context::Context, element::Children, hooks::Hooks, props::Props,
+    render::updater::ComponentUpdater,
+};
+
+#[derive(Props)]
+pub struct ContextProviderProps<'a, T> {
+    /// 提供给子树的值，为 None 时不提供
+    pub value: Option<T>,
+    pub children: Children<'a>,
+}
+
+impl<T> Default for ContextProviderProps<'_, T> {
+    fn default() -> Self {
+        Self {
+            value: None,
+            children: Children::default(),
+        }
+    }
+}
+
+/// ContextProvider：把 value 作为上下文提供给子树，子组件通过 `use_context::<T>()` 读取
+///
+/// 值只在 update 时读取，需要在值变化时通知子组件，可以提供 [`Store<T>`](crate::hooks::use_store::Store)
+/// 并在子组件中使用 `use_context_value`。
+///
+/// ```ignore
+/// element!(ContextProvider<Theme>(value: Theme::dark()) {
+///     Text(text: "themed")
+/// })
+/// ```
+pub struct ContextProvider<T> {
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T: Any + Send + Sync> Component for ContextProvider<T> {
+    type Props<'a> = ContextProviderProps<'a, T>;
+
+    fn new(_props: &Self::Props<'_>) -> Self {
+        Self {
+            _marker: PhantomData,
+        }
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        _hooks: Hooks,
+        updater: &mut ComponentUpdater<'_, '_>,
+    ) {
+        updater.update_children(
+            props.children.iter_mut(),
+            props.value.as_mut().map(Context::form_mut),
+        );
+    }
+}
@@ -0,0 +1,297 @@
+use crossterm::event::{Event, KeyCode, KeyEventKind};
+use ratatui::{
+    layout::Constraint,
+    style::{Modifier, Style},
+    widgets::{Cell, Row, Table},
+};
+
+use crate::{
+    component::Component,
+    components::InputBuffer,
+    hooks::{
+        Hooks,
+        use_events::UseEvents,
+        use_focus::UseFocus,
+        use_state::{State, UseState},
+    },
+    props::{Handler, Props},
+    render::{drawer::ComponentDrawer, updater::ComponentUpdater},
+};
+
+/// 表格列定义
+#[derive(Clone, Debug)]
+pub struct DataGridColumn {
+    /// 列标题
+    pub title: String,
+    /// 初始列宽
+    pub width: u16,
+}
+
+impl DataGridColumn {
+    pub fn new(title: impl Into<String>, width: u16) -> Self {
+        Self {
+            title: title.into(),
+            width,
+        }
+    }
+}
+
+/// 一次提交的单元格编辑
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CellEdit {
+    pub row: usize,
+    pub column: usize,
+    pub value: String,
+}
+
+/// 表格的交互状态
+#[derive(Clone, Debug, Default)]
+struct GridState {
+    row: usize,
+    column: usize,
+    /// 正在编辑的单元格内容
+    editing: Option<InputBuffer>,
+    /// 用户调整后的列宽
+    widths: Vec<u16>,
+    /// 已提交、等待回调的编辑
+    pending: Vec<CellEdit>,
+}
+
+#[derive(Props)]
+pub struct DataGridProps<'a> {
+    pub columns: Vec<DataGridColumn>,
+    pub rows: Vec<Vec<String>>,
+    /// 是否响应键盘操作
+    pub focused: bool,
+    /// 单元格编辑提交时的回调
+    pub on_edit: Handler<'a, CellEdit>,
+}
+
+impl Default for DataGridProps<'_> {
+    fn default() -> Self {
+        Self {
+            columns: Vec::new(),
+            rows: Vec::new(),
+            focused: true,
+            on_edit: Handler::default(),
+        }
+    }
+}
+
+/// DataGrid：可编辑的数据表格
+///
+/// 按键：
+/// - 方向键/hjkl 在单元格间移动，Tab 移到下一个单元格，在最后一个单元格上按 Tab 时焦点移出表格
+/// - Enter/F2 进入编辑，编辑时 Enter 提交、Esc 取消
+/// - `<`/`>` 调整当前列宽
+///
+/// 表格不直接修改数据，提交的编辑通过 on_edit 回调交给调用方处理。
+pub struct DataGrid {
+    columns: Vec<DataGridColumn>,
+    rows: Vec<Vec<String>>,
+    state: Option<State<GridState>>,
+    focused: bool,
+}
+
+impl Component for DataGrid {
+    type Props<'a> = DataGridProps<'a>;
+
+    fn new(props: &Self::Props<'_>) -> Self {
+        Self {
+            columns: props.columns.clone(),
+            rows: props.rows.clone(),
+            state: None,
+            focused: props.focused,
+        }
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        mut hooks: Hooks,
+        _updater: &mut ComponentUpdater<'_, '_>,
+    ) {
+        self.focused = props.focused;
+        self.columns = props.columns.clone();
+        self.rows = props.rows.clone();
+
+        let columns = props.columns.clone();
+        let state = hooks.use_state(|| GridState {
+            widths: columns.iter().map(|c| c.width).collect(),
+            ..Default::default()
+        });
+
+        // 列数变化时补齐或截断列宽
+        if state.read().widths.len() != columns.len() {
+            let mut state = state.write();
+            let widths = columns
+                .iter()
+                .enumerate()
+                .map(|(i, c)| state.widths.get(i).copied().unwrap_or(c.width));
+            state.widths = widths.collect();
+        }
+
+        // 行数或列数减少后光标可能越界，收回到最后一个单元格，正在编辑的单元格已经不存在时取消编辑
+        {
+            let (row, column) = {
+                let state = state.read();
+                (state.row, state.column)
+            };
+            let max_row = self.rows.len().saturating_sub(1);
+            let max_column = columns.len().saturating_sub(1);
+            if row > max_row || column > max_column {
+                let mut state = state.write();
+                state.row = row.min(max_row);
+                state.column = column.min(max_column);
+                state.editing = None;
+            }
+        }
+
+        if !state.read().pending.is_empty() {
+            let pending = std::mem::take(&mut state.write().pending);
+            for edit in pending {
+                props.on_edit.invoke(edit);
+            }
+        }
+
+        // 编辑中或者还有下一个单元格时由表格处理 Tab，编辑中 Ctrl+K 删除到行尾，这些按键不移动焦点
+        let claimed: &[KeyCode] = if props.focused {
+            let state = state.read();
+            let last = (
+                self.rows.len().saturating_sub(1),
+                columns.len().saturating_sub(1),
+            );
+            if state.editing.is_some() {
+                &[KeyCode::Tab, KeyCode::Char('k')]
+            } else if (state.row, state.column) != last {
+                &[KeyCode::Tab]
+            } else {
+                &[]
+            }
+        } else {
+            &[]
+        };
+        hooks.use_claim_keys(claimed);
+
+        let rows = self.rows.clone();
+        let focused = props.focused;
+        hooks.use_events(move |event| {
+            let Event::Key(key) = event else {
+                return;
+            };
+            if !focused || key.kind != KeyEventKind::Press {
+                return;
+            }
+
+            let mut next = state.read().clone();
+            let (row_count, column_count) = (rows.len(), columns.len());
+            if row_count == 0 || column_count == 0 {
+                return;
+            }
+            next.row = next.row.min(row_count - 1);
+            next.column = next.column.min(column_count - 1);
+
+            if let Some(buffer) = &mut next.editing {
+                match key.code {
+                    KeyCode::Enter => {
+                        next.pending.push(CellEdit {
+                            row: next.row,
+                            column: next.column,
+                            value: buffer.value().to_string(),
+                        });
+                        next.editing = None;
+                    }
+                    KeyCode::Esc => next.editing = None,
+                    _ => {
+                        if !buffer.handle_key(key) {
+                            return;
+                        }
+                    }
+                }
+                *state.write() = next;
+                return;
+            }
+
+            match key.code {
+                KeyCode::Up | KeyCode::Char('k') => next.row = next.row.saturating_sub(1),
+                KeyCode::Down | KeyCode::Char('j') => next.row = (next.row + 1).min(row_count - 1),
+                KeyCode::Left | KeyCode::Char('h') => next.column = next.column.saturating_sub(1),
+                KeyCode::Right | KeyCode::Char('l') => {
+                    next.column = (next.column + 1).min(column_count - 1)
+                }
+                // 最后一个单元格上的 Tab 交给焦点管理
+                KeyCode::Tab if next.row == row_count - 1 && next.column == column_count - 1 => {
+                    return;
+                }
+                KeyCode::Tab => {
+                    next.column += 1;
+                    if next.column >= column_count {
+                        next.column = 0;
+                        next.row = (next.row + 1).min(row_count - 1);
+                    }
+                }
+                KeyCode::Enter | KeyCode::F(2) => {
+                    let value = rows
+                        .get(next.row)
+                        .and_then(|r| r.get(next.column))
+                        .cloned()
+                        .unwrap_or_default();
+                    next.editing = Some(InputBuffer::new(value));
+                }
+                KeyCode::Char('<') => {
+                    if let Some(width) = next.widths.get_mut(next.column) {
+                        *width = width.saturating_sub(1).max(1);
+                    }
+                }
+                KeyCode::Char('>') => {
+                    if let Some(width) = next.widths.get_mut(next.column) {
+                        *width = width.saturating_add(1);
+                    }
+                }
+                _ => return,
+            }
+            *state.write() = next;
+        });
+
+        self.state = Some(state);
+    }
+
+    fn draw(&self, drawer: &mut ComponentDrawer<'_, '_>) {
+        if self.focused {
+            drawer.mark_focused();
+        }
+        let Some(state) = self.state else {
+            return;
+        };
+        let state = state.read();
+
+        // 保证当前行处于可见区域（减去表头一行）
+        let visible = drawer.area.height.saturating_sub(1) as usize;
+        let offset = (state.row + 1).saturating_sub(visible);
+
+        let header = Row::new(self.columns.iter().map(|c| Cell::from(c.title.clone())))
+            .style(Style::default().add_modifier(Modifier::BOLD | Modifier::UNDERLINED));
+        let rows = self
+            .rows
+            .iter()
+            .enumerate()
+            .skip(offset)
+            .take(visible)
+            .map(|(r, row)| {
+                Row::new((0..self.columns.len()).map(|c| {
+                    let focused = r == state.row && c == state.column;
+                    match (&state.editing, focused) {
+                        (Some(buffer), true) => Cell::from(buffer.line(Style::default(), true)),
+                        (None, true) => Cell::from(row.get(c).cloned().unwrap_or_default())
+                            .style(Style::default().add_modifier(Modifier::REVERSED)),
+                        _ => Cell::from(row.get(c).cloned().unwrap_or_default()),
+                    }
+                }))
+            });
+
+        let table = Table::new(rows, state.widths.iter().map(|w| Constraint::Length(*w)))
+            .header(header)
+            .column_spacing(1);
+        drawer.render_widget(table, drawer.area);
+    }
+}
@@ -0,0 +1,775 @@
+use std::ops::{Index, IndexMut, Range};
+
+use crossterm::event::{Event, KeyCode, KeyEventKind, MouseEventKind};
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::Paragraph,
+};
+
+use crate::{
+    component::Component,
+    hooks::{
+        Hooks,
+        use_events::UseEvents,
+        use_state::{State, UseState},
+        use_theme::UseTheme,
+    },
+    props::Props,
+    render::{drawer::ComponentDrawer, updater::ComponentUpdater},
+    theme::Theme,
+};
+
+/// 差异视图的展示模式
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DiffMode {
+    /// 单栏，删除行与新增行上下排列
+    #[default]
+    Inline,
+    /// 左右两栏对照
+    SideBySide,
+}
+
+/// 差异中的一行
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DiffLine {
+    /// 统一 diff 中的 `@@ ... @@` 区块头
+    Hunk(String),
+    /// 两侧相同的行
+    Equal {
+        old: usize,
+        new: usize,
+        text: String,
+    },
+    /// 被删除的行，highlight 为行内发生变化的字节区间
+    Delete {
+        old: usize,
+        text: String,
+        highlight: Option<Range<usize>>,
+    },
+    /// 新增的行，highlight 为行内发生变化的字节区间
+    Insert {
+        new: usize,
+        text: String,
+        highlight: Option<Range<usize>>,
+    },
+}
+
+/// 基于 Myers 差分算法计算两段文本的逐行差异，并标注行内变化
+///
+/// 采用线性空间的分治实现，内存占用与行数成正比，适合比较较大的文件。
+pub fn diff_lines(old: &str, new: &str) -> Vec<DiffLine> {
+    let old: Vec<&str> = old.lines().collect();
+    let new: Vec<&str> = new.lines().collect();
+
+    let max_d = (old.len() + new.len()).div_ceil(2) + 1;
+    let mut myers = Myers {
+        old: &old,
+        new: &new,
+        forward: Diagonals::new(max_d),
+        backward: Diagonals::new(max_d),
+        edits: Vec::new(),
+    };
+    myers.conquer(0..old.len(), 0..new.len());
+
+    // 同一处修改中删除行排在新增行之前，便于逐行对比
+    let mut lines = Vec::with_capacity(myers.edits.len());
+    let mut inserts = Vec::new();
+    for edit in myers.edits {
+        match edit {
+            Edit::Equal(i, j) => {
+                lines.append(&mut inserts);
+                lines.push(DiffLine::Equal {
+                    old: i + 1,
+                    new: j + 1,
+                    text: old[i].to_string(),
+                });
+            }
+            Edit::Delete(i) => lines.push(DiffLine::Delete {
+                old: i + 1,
+                text: old[i].to_string(),
+                highlight: None,
+            }),
+            Edit::Insert(j) => inserts.push(DiffLine::Insert {
+                new: j + 1,
+                text: new[j].to_string(),
+                highlight: None,
+            }),
+        }
+    }
+    lines.append(&mut inserts);
+
+    highlight_changes(&mut lines);
+    lines
+}
+
+/// 逐行编辑操作，保存行在原文本中的下标
+enum Edit {
+    Equal(usize, usize),
+    Delete(usize),
+    Insert(usize),
+}
+
+/// 按对角线 k = x - y 索引的数组，k 可以为负
+struct Diagonals {
+    values: Vec<isize>,
+    offset: isize,
+}
+
+impl Diagonals {
+    fn new(max_d: usize) -> Self {
+        Self {
+            values: vec![0; 2 * max_d + 1],
+            offset: max_d as isize,
+        }
+    }
+}
+
+impl Index<isize> for Diagonals {
+    type Output = isize;
+
+    fn index(&self, k: isize) -> &isize {
+        &self.values[(k + self.offset) as usize]
+    }
+}
+
+impl IndexMut<isize> for Diagonals {
+    fn index_mut(&mut self, k: isize) -> &mut isize {
+        &mut self.values[(k + self.offset) as usize]
+    }
+}
+
+struct Myers<'a> {
+    old: &'a [&'a str],
+    new: &'a [&'a str],
+    forward: Diagonals,
+    backward: Diagonals,
+    edits: Vec<Edit>,
+}
+
+impl Myers<'_> {
+    /// 剥离公共前后缀后以中间蛇为界分成两半递归求解
+    fn conquer(&mut self, mut a: Range<usize>, mut b: Range<usize>) {
+        let prefix = self.old[a.clone()]
+            .iter()
+            .zip(&self.new[b.clone()])
+            .take_while(|(x, y)| x == y)
+            .count();
+        self.edits
+            .extend((0..prefix).map(|k| Edit::Equal(a.start + k, b.start + k)));
+        a.start += prefix;
+        b.start += prefix;
+
+        let suffix = self.old[a.clone()]
+            .iter()
+            .rev()
+            .zip(self.new[b.clone()].iter().rev())
+            .take_while(|(x, y)| x == y)
+            .count();
+        a.end -= suffix;
+        b.end -= suffix;
+
+        if a.is_empty() {
+            self.edits.extend(b.clone().map(Edit::Insert));
+        } else if b.is_empty() {
+            self.edits.extend(a.clone().map(Edit::Delete));
+        } else {
+            let (x, y) = self.middle_snake(a.clone(), b.clone());
+            self.conquer(a.start..x, b.start..y);
+            self.conquer(x..a.end, y..b.end);
+        }
+
+        self.edits
+            .extend((0..suffix).map(|k| Edit::Equal(a.end + k, b.end + k)));
+    }
+
+    /// 从两端同时搜索，返回最短编辑路径中间那段公共行（蛇）的起点
+    fn middle_snake(&mut self, a: Range<usize>, b: Range<usize>) -> (usize, usize) {
+        let (old, new) = (&self.old[a.clone()], &self.new[b.clone()]);
+        let (n, m) = (old.len() as isize, new.len() as isize);
+        let delta = n - m;
+        let odd = delta & 1 == 1;
+        let (forward, backward) = (&mut self.forward, &mut self.backward);
+        forward[1] = 0;
+        backward[1] = 0;
+
+        for d in 0..=(n + m + 1) / 2 {
+            for k in (-d..=d).rev().step_by(2) {
+                let mut x = if k == -d || (k != d && forward[k - 1] < forward[k + 1]) {
+                    forward[k + 1]
+                } else {
+                    forward[k - 1] + 1
+                };
+                let mut y = x - k;
+                let start = (x, y);
+                while x < n && y < m && old[x as usize] == new[y as usize] {
+                    x += 1;
+                    y += 1;
+                }
+                forward[k] = x;
+                if odd && (k - delta).abs() < d && forward[k] + backward[delta - k] >= n {
+                    return (a.start + start.0 as usize, b.start + start.1 as usize);
+                }
+            }
+
+            for k in (-d..=d).rev().step_by(2) {
+                let mut x = if k == -d || (k != d && backward[k - 1] < backward[k + 1]) {
+                    backward[k + 1]
+                } else {
+                    backward[k - 1] + 1
+                };
+                let mut y = x - k;
+                while x < n && y < m && old[(n - x - 1) as usize] == new[(m - y - 1) as usize] {
+                    x += 1;
+                    y += 1;
+                }
+                backward[k] = x;
+                if !odd && (k - delta).abs() <= d && backward[k] + forward[delta - k] >= n {
+                    return (a.start + (n - x) as usize, b.start + (m - y) as usize);
+                }
+            }
+        }
+        unreachable!("两段非空的序列之间一定存在中间蛇")
+    }
+}
+
+/// 解析统一 diff 格式（`git diff` 输出），并标注行内变化
+///
+/// 按 hunk 头中的行数判断 hunk 的范围，范围之外的 `---`/`+++` 才视为文件头，
+/// 删除的 `-- comment` 这类以 `---` 开头的内容行不会被丢弃。
+pub fn parse_unified_diff(diff: &str) -> Vec<DiffLine> {
+    let mut lines = Vec::new();
+    let (mut old, mut new) = (0, 0);
+    // 当前 hunk 中尚未出现的旧文件与新文件行数
+    let (mut old_left, mut new_left) = (0usize, 0usize);
+
+    for line in diff.lines() {
+        let in_hunk = old_left > 0 || new_left > 0;
+        if line.starts_with("@@") {
+            // @@ -l,s +l,s @@，省略行数时为 1
+            let mut ranges = line.split_whitespace().skip(1);
+            let mut range = |prefix: char| {
+                let mut parts = ranges
+                    .next()
+                    .and_then(|r| r.strip_prefix(prefix))
+                    .unwrap_or_default()
+                    .split(',')
+                    .map(|n| n.parse::<usize>().ok());
+                let start = parts.next().flatten().unwrap_or(1);
+                let len = parts.next().flatten().unwrap_or(1);
+                (start, len)
+            };
+            (old, old_left) = range('-');
+            (new, new_left) = range('+');
+            lines.push(DiffLine::Hunk(line.to_string()));
+        } else if !in_hunk && (line.starts_with("---") || line.starts_with("+++")) {
+            continue;
+        } else if let Some(text) = line.strip_prefix('+') {
+            lines.push(DiffLine::Insert {
+                new,
+                text: text.to_string(),
+                highlight: None,
+            });
+            new += 1;
+            new_left = new_left.saturating_sub(1);
+        } else if let Some(text) = line.strip_prefix('-') {
+            lines.push(DiffLine::Delete {
+                old,
+                text: text.to_string(),
+                highlight: None,
+            });
+            old += 1;
+            old_left = old_left.saturating_sub(1);
+        } else if let Some(text) = line
+            .strip_prefix(' ')
+            // 有的编辑器会去掉空的上下文行行首的空格
+            .or(Some(line).filter(|line| in_hunk && line.is_empty()))
+        {
+            lines.push(DiffLine::Equal {
+                old,
+                new,
+                text: text.to_string(),
+            });
+            old += 1;
+            new += 1;
+            old_left = old_left.saturating_sub(1);
+            new_left = new_left.saturating_sub(1);
+        }
+    }
+
+    highlight_changes(&mut lines);
+    lines
+}
+
+/// 将相邻的删除块与新增块逐行配对，计算每对行中发生变化的区间
+fn highlight_changes(lines: &mut [DiffLine]) {
+    let mut i = 0;
+    while i < lines.len() {
+        let deletes = lines[i..]
+            .iter()
+            .take_while(|l| matches!(l, DiffLine::Delete { .. }))
+            .count();
+        let inserts = lines[i + deletes..]
+            .iter()
+            .take_while(|l| matches!(l, DiffLine::Insert { .. }))
+            .count();
+
+        for k in 0..deletes.min(inserts) {
+            let (left, right) = lines.split_at_mut(i + deletes + k);
+            if let (
+                DiffLine::Delete {
+                    text: old,
+                    highlight: old_highlight,
+                    ..
+                },
+                DiffLine::Insert {
+                    text: new,
+                    highlight: new_highlight,
+                    ..
+                },
+            ) = (&mut left[i + k], &mut right[0])
+            {
+                let (a, b) = changed_ranges(old, new);
+                *old_highlight = Some(a);
+                *new_highlight = Some(b);
+            }
+        }
+
+        i += (deletes + inserts).max(1);
+    }
+}
+
+/// 通过公共前后缀求出两行之间发生变化的字节区间
+fn changed_ranges(old: &str, new: &str) -> (Range<usize>, Range<usize>) {
+    let prefix: usize = old
+        .chars()
+        .zip(new.chars())
+        .take_while(|(a, b)| a == b)
+        .map(|(c, _)| c.len_utf8())
+        .sum();
+    let suffix: usize = old[prefix..]
+        .chars()
+        .rev()
+        .zip(new[prefix..].chars().rev())
+        .take_while(|(a, b)| a == b)
+        .map(|(c, _)| c.len_utf8())
+        .sum();
+    (prefix..old.len() - suffix, prefix..new.len() - suffix)
+}
+
+#[derive(Clone, Copy)]
+struct DiffStyles {
+    equal: Style,
+    insert: Style,
+    delete: Style,
+    hunk: Style,
+    gutter: Style,
+}
+
+impl DiffStyles {
+    fn from_theme(theme: &Theme) -> Self {
+        Self {
+            equal: theme.text,
+            insert: theme.success(),
+            delete: theme.error(),
+            hunk: theme.primary(),
+            gutter: theme.muted(),
+        }
+    }
+}
+
+#[derive(Props)]
+pub struct DiffViewProps<'a> {
+    /// 旧文本
+    pub old: &'a str,
+    /// 新文本
+    pub new: &'a str,
+    /// 统一 diff 文本，设置后忽略 old/new 直接解析
+    pub unified: Option<&'a str>,
+    /// 展示模式
+    pub mode: DiffMode,
+    /// 是否显示行号
+    pub line_numbers: bool,
+    /// 是否响应键盘滚动（↑↓/jk/PageUp/PageDown/Home/End）
+    pub focused: bool,
+}
+
+impl Default for DiffViewProps<'_> {
+    fn default() -> Self {
+        Self {
+            old: "",
+            new: "",
+            unified: None,
+            mode: DiffMode::default(),
+            line_numbers: true,
+            focused: true,
+        }
+    }
+}
+
+/// DiffView：差异视图组件
+///
+/// 支持直接对比两段文本或解析统一 diff，提供单栏/双栏两种展示模式，
+/// 以颜色区分新增与删除，并高亮行内变化部分。支持键盘与鼠标滚轮滚动。
+pub struct DiffView {
+    source: (String, String, Option<String>),
+    lines: Vec<DiffLine>,
+    mode: DiffMode,
+    line_numbers: bool,
+    scroll: Option<State<usize>>,
+    styles: DiffStyles,
+    focused: bool,
+}
+
+impl DiffView {
+    fn gutter(&self, number: Option<usize>) -> Span<'static> {
+        match number {
+            Some(n) if self.line_numbers => Span::styled(format!("{n:>4} "), self.styles.gutter),
+            None if self.line_numbers => Span::raw("     "),
+            _ => Span::raw(""),
+        }
+    }
+
+    /// 将一行文本按高亮区间拆分为多个 Span
+    fn content(text: &str, highlight: &Option<Range<usize>>, style: Style) -> Vec<Span<'static>> {
+        match highlight {
+            Some(range) if !range.is_empty() => vec![
+                Span::styled(text[..range.start].to_string(), style),
+                Span::styled(
+                    text[range.clone()].to_string(),
+                    style.add_modifier(Modifier::REVERSED),
+                ),
+                Span::styled(text[range.end..].to_string(), style),
+            ],
+            _ => vec![Span::styled(text.to_string(), style)],
+        }
+    }
+
+    fn inline_line(&self, line: &DiffLine) -> Line<'static> {
+        let styles = self.styles;
+        let (old, new, sign, text, highlight, style) = match line {
+            DiffLine::Hunk(header) => return Line::styled(header.clone(), styles.hunk),
+            DiffLine::Equal { old, new, text } => {
+                (Some(*old), Some(*new), " ", text, &None, styles.equal)
+            }
+            DiffLine::Delete {
+                old,
+                text,
+                highlight,
+            } => (Some(*old), None, "-", text, highlight, styles.delete),
+            DiffLine::Insert {
+                new,
+                text,
+                highlight,
+            } => (None, Some(*new), "+", text, highlight, styles.insert),
+        };
+
+        let mut spans = vec![
+            self.gutter(old),
+            self.gutter(new),
+            Span::styled(sign.to_string(), style),
+        ];
+        spans.extend(Self::content(text, highlight, style));
+        Line::from(spans)
+    }
+
+    fn side_line(&self, line: Option<&DiffLine>) -> Line<'static> {
+        let styles = self.styles;
+        let Some(line) = line else {
+            return Line::default();
+        };
+        let (number, text, highlight, style) = match line {
+            DiffLine::Hunk(header) => return Line::styled(header.clone(), styles.hunk),
+            DiffLine::Equal { old, text, .. } => (*old, text, &None, styles.equal),
+            DiffLine::Delete {
+                old,
+                text,
+                highlight,
+            } => (*old, text, highlight, styles.delete),
+            DiffLine::Insert {
+                new,
+                text,
+                highlight,
+            } => (*new, text, highlight, styles.insert),
+        };
+        let mut spans = vec![self.gutter(Some(number))];
+        spans.extend(Self::content(text, highlight, style));
+        Line::from(spans)
+    }
+
+    /// 双栏模式下的行：删除块与新增块逐行并排，相同行两侧都显示
+    fn side_rows(&self) -> Vec<(Line<'static>, Line<'static>)> {
+        let mut rows = Vec::new();
+        let mut i = 0;
+        while i < self.lines.len() {
+            match &self.lines[i] {
+                DiffLine::Delete { .. } | DiffLine::Insert { .. } => {
+                    let deletes: Vec<_> = self.lines[i..]
+                        .iter()
+                        .take_while(|l| matches!(l, DiffLine::Delete { .. }))
+                        .collect();
+                    let inserts: Vec<_> = self.lines[i + deletes.len()..]
+                        .iter()
+                        .take_while(|l| matches!(l, DiffLine::Insert { .. }))
+                        .collect();
+                    for k in 0..deletes.len().max(inserts.len()) {
+                        rows.push((
+                            self.side_line(deletes.get(k).copied()),
+                            self.side_line(inserts.get(k).copied()),
+                        ));
+                    }
+                    i += deletes.len() + inserts.len();
+                }
+                line => {
+                    rows.push((self.side_line(Some(line)), self.side_line(Some(line))));
+                    i += 1;
+                }
+            }
+        }
+        rows
+    }
+
+    fn row_count(&self) -> usize {
+        match self.mode {
+            DiffMode::Inline => self.lines.len(),
+            DiffMode::SideBySide => self.side_rows().len(),
+        }
+    }
+}
+
+impl Component for DiffView {
+    type Props<'a> = DiffViewProps<'a>;
+
+    fn new(props: &Self::Props<'_>) -> Self {
+        Self {
+            source: Default::default(),
+            lines: Vec::new(),
+            mode: props.mode,
+            line_numbers: props.line_numbers,
+            scroll: None,
+            styles: DiffStyles::from_theme(&Theme::default()),
+            focused: props.focused,
+        }
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        mut hooks: Hooks,
+        updater: &mut ComponentUpdater<'_, '_>,
+    ) {
+        self.focused = props.focused;
+        let theme = hooks
+            .with_context_stack(updater.component_context_stack())
+            .use_theme();
+        self.styles = DiffStyles::from_theme(&theme);
+
+        // 输入不变时复用上一次的差异结果，直接与保存的文本比较，不必每帧复制
+        let (old, new, unified) = &self.source;
+        let changed = old != props.old || new != props.new || unified.as_deref() != props.unified;
+        if changed || self.lines.is_empty() {
+            self.lines = match props.unified {
+                Some(unified) => parse_unified_diff(unified),
+                None => diff_lines(props.old, props.new),
+            };
+            self.source = (
+                props.old.to_string(),
+                props.new.to_string(),
+                props.unified.map(str::to_string),
+            );
+        }
+        self.mode = props.mode;
+        self.line_numbers = props.line_numbers;
+
+        let mut scroll = hooks.use_state(|| 0usize);
+        let max_scroll = self.row_count().saturating_sub(1);
+        if scroll.get() > max_scroll {
+            scroll.set(max_scroll);
+        }
+        self.scroll = Some(scroll);
+
+        let focused = props.focused;
+        hooks.use_local_events(move |event| {
+            let current = scroll.get();
+            let next = match event {
+                Event::Key(key) if focused && key.kind == KeyEventKind::Press => match key.code {
+                    KeyCode::Up | KeyCode::Char('k') => current.saturating_sub(1),
+                    KeyCode::Down | KeyCode::Char('j') => current + 1,
+                    KeyCode::PageUp => current.saturating_sub(10),
+                    KeyCode::PageDown => current + 10,
+                    KeyCode::Home => 0,
+                    KeyCode::End => max_scroll,
+                    _ => return,
+                },
+                Event::Mouse(mouse) => match mouse.kind {
+                    MouseEventKind::ScrollUp => current.saturating_sub(3),
+                    MouseEventKind::ScrollDown => current + 3,
+                    _ => return,
+                },
+                _ => return,
+            };
+            let next = next.min(max_scroll);
+            if next != current {
+                scroll.set(next);
+            }
+        });
+    }
+
+    fn draw(&self, drawer: &mut ComponentDrawer<'_, '_>) {
+        if self.focused {
+            drawer.mark_focused();
+        }
+        let offset = self.scroll.map(|s| s.get()).unwrap_or_default();
+        let height = drawer.area.height as usize;
+
+        match self.mode {
+            DiffMode::Inline => {
+                let lines: Vec<_> = self
+                    .lines
+                    .iter()
+                    .skip(offset)
+                    .take(height)
+                    .map(|line| self.inline_line(line))
+                    .collect();
+                drawer.render_widget(Paragraph::new(lines), drawer.area);
+            }
+            DiffMode::SideBySide => {
+                let [left, right]: [Rect; 2] =
+                    Layout::horizontal([Constraint::Fill(1), Constraint::Fill(1)])
+                        .spacing(1)
+                        .areas(drawer.area);
+                let (old, new): (Vec<_>, Vec<_>) = self
+                    .side_rows()
+                    .into_iter()
+                    .skip(offset)
+                    .take(height)
+                    .unzip();
+                drawer.render_widget(Paragraph::new(old), left);
+                drawer.render_widget(Paragraph::new(new), right);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_content_lines_that_look_like_file_headers() {
+        let diff = "\
+diff --git a/query.sql b/query.sql
+--- a/query.sql
++++ b/query.sql
+@@ -1,3 +1,3 @@
+ select 1;
+--- comment
++++ counter
+ select 2;
+--- a/other.sql
++++ b/other.sql
+@@ -10 +10,2 @@
+-old
++new
++
+";
+        let lines = parse_unified_diff(diff);
+        let summary: Vec<String> = lines
+            .iter()
+            .map(|line| match line {
+                DiffLine::Hunk(header) => header.clone(),
+                DiffLine::Equal { old, new, text } => format!("{old},{new} {text}"),
+                DiffLine::Delete { old, text, .. } => format!("{old}- {text}"),
+                DiffLine::Insert { new, text, .. } => format!("{new}+ {text}"),
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                "@@ -1,3 +1,3 @@",
+                "1,1 select 1;",
+                "2- -- comment",
+                "2+ ++ counter",
+                "3,3 select 2;",
+                "@@ -10 +10,2 @@",
+                "10- old",
+                "10+ new",
+                "11+ ",
+            ]
+        );
+    }
+
+    fn summarize(lines: &[DiffLine]) -> Vec<String> {
+        lines
+            .iter()
+            .map(|line| match line {
+                DiffLine::Hunk(header) => header.clone(),
+                DiffLine::Equal { old, new, text } => format!("{old},{new} {text}"),
+                DiffLine::Delete { old, text, .. } => format!("{old}- {text}"),
+                DiffLine::Insert { new, text, .. } => format!("{new}+ {text}"),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn diffs_lines_with_deletes_before_inserts() {
+        let lines = diff_lines("a\nb\nc\nd\ne", "a\nc\nx\nd\ny");
+        assert_eq!(
+            summarize(&lines),
+            ["1,1 a", "2- b", "3,2 c", "3+ x", "4,4 d", "5- e", "5+ y"]
+        );
+        assert!(matches!(
+            &lines[6],
+            DiffLine::Insert {
+                highlight: Some(_),
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn diff_lines_is_minimal_and_reconstructs_both_sides() {
+        // 用简单的伪随机序列与 LCS 动态规划的结果对照
+        let mut seed = 7u32;
+        let mut next = move || {
+            seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+            (seed >> 16) % 4
+        };
+        for _ in 0..200 {
+            let old: Vec<String> = (0..next() * 3).map(|_| next().to_string()).collect();
+            let new: Vec<String> = (0..next() * 3).map(|_| next().to_string()).collect();
+            let lines = diff_lines(&old.join("\n"), &new.join("\n"));
+
+            let mut old_side = Vec::new();
+            let mut new_side = Vec::new();
+            let mut equal = 0;
+            for line in &lines {
+                match line {
+                    DiffLine::Equal { text, .. } => {
+                        old_side.push(text.clone());
+                        new_side.push(text.clone());
+                        equal += 1;
+                    }
+                    DiffLine::Delete { text, .. } => old_side.push(text.clone()),
+                    DiffLine::Insert { text, .. } => new_side.push(text.clone()),
+                    DiffLine::Hunk(_) => unreachable!(),
+                }
+            }
+            assert_eq!(old_side, old);
+            assert_eq!(new_side, new);
+
+            let mut lcs = vec![vec![0; new.len() + 1]; old.len() + 1];
+            for i in (0..old.len()).rev() {
+                for j in (0..new.len()).rev() {
+                    lcs[i][j] = if old[i] == new[j] {
+                        lcs[i + 1][j + 1] + 1
+                    } else {
+                        lcs[i + 1][j].max(lcs[i][j + 1])
+                    };
+                }
+            }
+            assert_eq!(equal, lcs[0][0], "{old:?} -> {new:?}");
+        }
+    }
+}
@@ -0,0 +1,396 @@
+use std::{
+    collections::HashMap,
+    fmt,
+    sync::{Arc, RwLock},
+};
+
+use ratatui::{
+    style::Style,
+    widgets::{Paragraph, Wrap},
+};
+use serde::{Deserialize, de::DeserializeOwned};
+
+use crate::{
+    component::Component,
+    context::SystemContext,
+    element::{AnyElement, Children, Element, ElementKey},
+    hooks::{Hooks, use_theme::UseTheme},
+    props::Props,
+    render::{drawer::ComponentDrawer, updater::ComponentUpdater},
+};
+
+/// 按名称创建组件时的错误
+#[derive(Debug)]
+pub enum DynamicError {
+    /// 没有以该名称注册的组件
+    NotRegistered(String),
+    /// props 无法反序列化为组件的 Props
+    InvalidProps(String, serde_json::Error),
+    /// 界面描述的格式不正确
+    InvalidDescription(String),
+}
+
+impl fmt::Display for DynamicError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DynamicError::NotRegistered(name) => write!(f, "component `{name}` is not registered"),
+            DynamicError::InvalidProps(name, error) => {
+                write!(f, "invalid props for component `{name}`: {error}")
+            }
+            DynamicError::InvalidDescription(reason) => {
+                write!(f, "invalid ui description: {reason}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for DynamicError {}
+
+type Factory = Arc<
+    dyn Fn(&serde_json::Value, Children<'static>) -> serde_json::Result<AnyElement<'static>>
+        + Send
+        + Sync,
+>;
+
+/// ComponentRegistry：按名称注册的组件工厂
+///
+/// 由 [`SystemContext::components`] 提供，插件在运行时注册组件，
+/// 再由 [`DynamicElement`] 按名称和 JSON 形式的 props 实例化，适合仪表盘搭建这类可扩展的应用。
+/// 也可以通过 [`load`](Self::load) 从界面描述创建整棵元素树，让布局不需要重新编译就能修改。
+///
+/// ```ignore
+/// system.components().register::<Gauge>("gauge");
+/// element!(DynamicElement(name: "gauge", props: json!({ "value": 42 })))
+/// ```
+#[derive(Clone, Default)]
+pub struct ComponentRegistry {
+    factories: Arc<RwLock<HashMap<String, Factory>>>,
+}
+
+impl ComponentRegistry {
+    /// 内置了 `Stack`、`Divider` 与 `Text` 的注册表
+    pub fn with_builtins() -> Self {
+        let registry = Self::default();
+        registry.register_with("Stack", builtin::stack);
+        registry.register_with("Divider", builtin::divider);
+        registry.register_with("Text", builtin::text);
+        registry
+    }
+
+    /// 以 name 注册组件 C，props 从 JSON 反序列化得到；同名组件重复注册时以后者为准
+    ///
+    /// 注册的组件不接收子元素，容器组件需要通过 [`register_with`](Self::register_with) 注册。
+    pub fn register<C>(&self, name: impl Into<String>)
+    where
+        C: Component,
+        C::Props<'static>: DeserializeOwned,
+    {
+        self.register_with(name, |props, _children| {
+            Ok(Element::<C> {
+                key: ElementKey::new("dynamic"),
+                props: C::Props::<'static>::deserialize(props)?,
+            }
+            .into())
+        });
+    }
+
+    /// 以自定义的工厂注册组件，工厂接收 JSON 形式的 props 与已经创建好的子元素
+    pub fn register_with(
+        &self,
+        name: impl Into<String>,
+        factory: impl Fn(
+            &serde_json::Value,
+            Children<'static>,
+        ) -> serde_json::Result<AnyElement<'static>>
+        + Send
+        + Sync
+        + 'static,
+    ) {
+        self.factories
+            .write()
+            .unwrap()
+            .insert(name.into(), Arc::new(factory));
+    }
+
+    pub fn unregister(&self, name: &str) {
+        self.factories.write().unwrap().remove(name);
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.factories.read().unwrap().contains_key(name)
+    }
+
+    /// 已注册的组件名称，按字母顺序排列
+    pub fn names(&self) -> Vec<String> {
+        let mut names: Vec<_> = self.factories.read().unwrap().keys().cloned().collect();
+        names.sort();
+        names
+    }
+
+    /// 按名称创建组件
+    pub fn create(
+        &self,
+        name: &str,
+        props: &serde_json::Value,
+        children: Children<'static>,
+    ) -> Result<AnyElement<'static>, DynamicError> {
+        let factory = self
+            .factories
+            .read()
+            .unwrap()
+            .get(name)
+            .cloned()
+            .ok_or_else(|| DynamicError::NotRegistered(name.to_string()))?;
+        factory(props, children)
+            .map_err(|error| DynamicError::InvalidProps(name.to_string(), error))
+    }
+
+    /// 从界面描述创建元素树，描述中的每个节点形如
+    ///
+    /// ```json
+    /// {
+    ///     "component": "Stack",
+    ///     "key": "main",
+    ///     "props": { "direction": "horizontal", "gap": 1 },
+    ///     "children": [{ "component": "gauge", "props": { "value": 42 } }]
+    /// }
+    /// ```
+    ///
+    /// 其中 key、props 与 children 都可以省略，省略 key 时以组件名称作为 key。
+    pub fn load(
+        &self,
+        description: &serde_json::Value,
+    ) -> Result<AnyElement<'static>, DynamicError> {
+        let node = description
+            .as_object()
+            .ok_or_else(|| DynamicError::InvalidDescription("node must be an object".into()))?;
+        let name = node
+            .get("component")
+            .and_then(serde_json::Value::as_str)
+            .ok_or_else(|| {
+                DynamicError::InvalidDescription("node is missing `component`".into())
+            })?;
+        let children = match node.get("children") {
+            None => Children::new(),
+            Some(serde_json::Value::Array(children)) => children
+                .iter()
+                .map(|child| self.load(child))
+                .collect::<Result<_, _>>()?,
+            Some(_) => {
+                return Err(DynamicError::InvalidDescription(format!(
+                    "`children` of `{name}` must be an array"
+                )));
+            }
+        };
+        let props = node.get("props").unwrap_or(&serde_json::Value::Null);
+        let mut element = self.create(name, props, children)?;
+        element.key = match node.get("key") {
+            None => ElementKey::new(name.to_string()),
+            Some(serde_json::Value::String(key)) => ElementKey::new(key.clone()),
+            Some(key) => ElementKey::new(key.to_string()),
+        };
+        Ok(element)
+    }
+
+    /// 从 JSON 文本加载界面描述
+    pub fn load_str(&self, description: &str) -> Result<AnyElement<'static>, DynamicError> {
+        let description = serde_json::from_str(description)
+            .map_err(|error| DynamicError::InvalidDescription(error.to_string()))?;
+        self.load(&description)
+    }
+}
+
+/// 内置组件的工厂，ratatui 的类型没有实现反序列化，这里手动解析 props
+mod builtin {
+    use ratatui::layout::{Alignment, Direction, Flex};
+    use serde::de::Error;
+    use serde_json::{Map, Value};
+
+    use crate::{
+        components::{Divider, DividerProps, Stack, StackProps, Text, TextProps},
+        element::{AnyElement, Children, Element, ElementKey},
+    };
+
+    fn object(props: &Value) -> serde_json::Result<Map<String, Value>> {
+        match props {
+            Value::Null => Ok(Map::new()),
+            Value::Object(props) => Ok(props.clone()),
+            _ => Err(Error::custom("props must be an object")),
+        }
+    }
+
+    fn string<'a>(
+        props: &'a Map<String, Value>,
+        field: &str,
+    ) -> serde_json::Result<Option<&'a str>> {
+        match props.get(field) {
+            None | Some(Value::Null) => Ok(None),
+            Some(Value::String(value)) => Ok(Some(value)),
+            Some(_) => Err(Error::custom(format!("`{field}` must be a string"))),
+        }
+    }
+
+    fn boolean(props: &Map<String, Value>, field: &str) -> serde_json::Result<bool> {
+        match props.get(field) {
+            None | Some(Value::Null) => Ok(false),
+            Some(Value::Bool(value)) => Ok(*value),
+            Some(_) => Err(Error::custom(format!("`{field}` must be a boolean"))),
+        }
+    }
+
+    fn direction(props: &Map<String, Value>, default: Direction) -> serde_json::Result<Direction> {
+        match string(props, "direction")? {
+            None => Ok(default),
+            Some("horizontal") => Ok(Direction::Horizontal),
+            Some("vertical") => Ok(Direction::Vertical),
+            Some(other) => Err(Error::custom(format!("unknown direction `{other}`"))),
+        }
+    }
+
+    pub(super) fn stack(
+        props: &Value,
+        children: Children<'static>,
+    ) -> serde_json::Result<AnyElement<'static>> {
+        let props = object(props)?;
+        let gap = match props.get("gap") {
+            None => 0,
+            Some(gap) => gap
+                .as_i64()
+                .and_then(|gap| i32::try_from(gap).ok())
+                .ok_or_else(|| Error::custom("`gap` must be an integer"))?,
+        };
+        let justify_content = match string(&props, "justify_content")? {
+            None | Some("legacy") => Flex::Legacy,
+            Some("start") => Flex::Start,
+            Some("end") => Flex::End,
+            Some("center") => Flex::Center,
+            Some("space_between") => Flex::SpaceBetween,
+            Some("space_around") => Flex::SpaceAround,
+            Some(other) => return Err(Error::custom(format!("unknown justify_content `{other}`"))),
+        };
+        let divider = match props.get("divider") {
+            None | Some(Value::Null) | Some(Value::Bool(false)) => None,
+            Some(Value::Bool(true)) => Some(DividerProps::default()),
+            Some(Value::String(label)) => Some(DividerProps {
+                label: Some(label.clone()),
+                ..Default::default()
+            }),
+            Some(_) => return Err(Error::custom("`divider` must be a boolean or a label")),
+        };
+        Ok(Element::<Stack> {
+            key: ElementKey::new("Stack"),
+            props: StackProps {
+                direction: direction(&props, Direction::Vertical)?,
+                gap,
+                justify_content,
+                divider,
+                children,
+            },
+        }
+        .into())
+    }
+
+    pub(super) fn divider(
+        props: &Value,
+        _children: Children<'static>,
+    ) -> serde_json::Result<AnyElement<'static>> {
+        let props = object(props)?;
+        Ok(Element::<Divider> {
+            key: ElementKey::new("Divider"),
+            props: DividerProps {
+                direction: direction(&props, Direction::Horizontal)?,
+                label: string(&props, "label")?.map(str::to_string),
+                ..Default::default()
+            },
+        }
+        .into())
+    }
+
+    pub(super) fn text(
+        props: &Value,
+        _children: Children<'static>,
+    ) -> serde_json::Result<AnyElement<'static>> {
+        let props = object(props)?;
+        let alignment = match string(&props, "alignment")? {
+            None | Some("left") => Alignment::Left,
+            Some("center") => Alignment::Center,
+            Some("right") => Alignment::Right,
+            Some(other) => return Err(Error::custom(format!("unknown alignment `{other}`"))),
+        };
+        Ok(Element::<Text> {
+            key: ElementKey::new("Text"),
+            props: TextProps {
+                text: string(&props, "text")?.unwrap_or_default().to_string(),
+                alignment,
+                wrap: boolean(&props, "wrap")?,
+                trim: boolean(&props, "trim")?,
+                ..Default::default()
+            },
+        }
+        .into())
+    }
+}
+
+#[derive(Default, Props)]
+pub struct DynamicElementProps {
+    /// 注册时使用的组件名称
+    pub name: String,
+    /// 组件的 props，以 JSON 形式给出
+    pub props: serde_json::Value,
+}
+
+/// DynamicElement：按名称实例化 [`ComponentRegistry`] 中注册的组件
+///
+/// 名称变化时会重新挂载组件；组件不存在或 props 无效时显示错误信息。
+pub struct DynamicElement {
+    error: Option<String>,
+    error_style: Style,
+}
+
+impl Component for DynamicElement {
+    type Props<'a> = DynamicElementProps;
+
+    fn new(_props: &Self::Props<'_>) -> Self {
+        Self {
+            error: None,
+            error_style: Style::default(),
+        }
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        mut hooks: Hooks,
+        updater: &mut ComponentUpdater<'_, '_>,
+    ) {
+        self.error_style = hooks
+            .with_context_stack(updater.component_context_stack())
+            .use_theme()
+            .error();
+        let registry = updater
+            .component_context_stack()
+            .get_context::<SystemContext>()
+            .map(|system| system.components())
+            .unwrap_or_default();
+        match registry.create(&props.name, &props.props, Children::new()) {
+            Ok(mut element) => {
+                self.error = None;
+                element.key = ElementKey::new(props.name.clone());
+                updater.update_children([element], None);
+            }
+            Err(error) => {
+                self.error = Some(error.to_string());
+                updater.update_children(Vec::<AnyElement>::new(), None);
+            }
+        }
+    }
+
+    fn draw(&self, drawer: &mut ComponentDrawer<'_, '_>) {
+        if let Some(error) = &self.error {
+            let paragraph = Paragraph::new(error.as_str())
+                .style(self.error_style)
+                .wrap(Wrap { trim: true });
+            drawer.render_widget(paragraph, drawer.area);
+        }
+    }
+}
@@ -0,0 +1,92 @@
+use ratatui::{style::Style, widgets::Block, widgets::BorderType};
+
+use crate::{
+    component::Component,
+    context::Context,
+    element::Children,
+    hooks::{Hooks, use_focus::FocusWithin, use_theme::UseTheme},
+    props::Props,
+    render::{drawer::ComponentDrawer, updater::ComponentUpdater},
+};
+
+#[derive(Default, Props)]
+pub struct FocusRingProps<'a> {
+    /// 显式指定是否聚焦，适用于通过 `focused` 属性控制焦点的组件（如 List、Table）；
+    /// 为 None 时由子树中通过 `use_focus` 注册的组件决定
+    pub focused: Option<bool>,
+    /// 叠加在主题焦点色之上的边框样式
+    pub style: Style,
+    /// 叠加在主题次要色之上的未聚焦时的边框样式
+    pub border_style: Style,
+    pub children: Children<'a>,
+}
+
+/// FocusRing：在子组件外绘制边框，焦点位于子树内时以主题的焦点色高亮
+///
+/// 子树中任意通过 `use_focus` 注册的组件获得焦点时即视为聚焦，不需要每个可交互组件各自绘制焦点样式。
+/// 未聚焦时仍然绘制次要色的边框，焦点切换不会改变布局。
+///
+/// ```ignore
+/// element!(FocusRing {
+///     FormField(form: form, name: "email", label: "邮箱")
+/// })
+/// element!(FocusRing(focused: props.focused) {
+///     List(items: items, focused: props.focused)
+/// })
+/// ```
+pub struct FocusRing {
+    focused: bool,
+    border_type: BorderType,
+    style: Style,
+    border_style: Style,
+}
+
+impl Component for FocusRing {
+    type Props<'a> = FocusRingProps<'a>;
+
+    fn new(props: &Self::Props<'_>) -> Self {
+        Self {
+            focused: props.focused.unwrap_or_default(),
+            border_type: BorderType::Plain,
+            style: props.style,
+            border_style: props.border_style,
+        }
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        mut hooks: Hooks,
+        updater: &mut ComponentUpdater<'_, '_>,
+    ) {
+        let theme = hooks
+            .with_context_stack(updater.component_context_stack())
+            .use_theme();
+        self.border_type = theme.border_type;
+        self.style = theme.focus_ring().patch(props.style);
+        self.border_style = theme.muted().patch(props.border_style);
+
+        // 子组件在 update 中把焦点状态写回 within
+        let mut within = FocusWithin::default();
+        updater.update_children(
+            props.children.iter_mut(),
+            Some(Context::form_mut(&mut within)),
+        );
+        self.focused = props.focused.unwrap_or(within.focused);
+    }
+
+    fn draw(&self, drawer: &mut ComponentDrawer<'_, '_>) {
+        let style = if self.focused {
+            self.style
+        } else {
+            self.border_style
+        };
+        let block = Block::bordered()
+            .border_type(self.border_type)
+            .border_style(style);
+        let inner = block.inner(drawer.area);
+        drawer.render_widget(block, drawer.area);
+        // 子组件绘制在边框内
+        drawer.area = inner;
+    }
+}
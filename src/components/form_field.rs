@@ -0,0 +1,210 @@
+use crossterm::event::{Event, KeyCode, KeyEventKind};
+use ratatui::{
+    layout::{Constraint, Rect, Size},
+    style::{Modifier, Style},
+    text::{Line, Span},
+};
+
+use crate::{
+    component::Component,
+    components::InputBuffer,
+    context::SystemContext,
+    hooks::{
+        Hooks, use_context::UseContext, use_events::UseEvents, use_focus::UseFocus, use_form::Form,
+        use_previous::UsePrevious, use_state::UseState, use_theme::UseTheme,
+    },
+    props::Props,
+    render::{drawer::ComponentDrawer, layout_style::LayoutStyle, updater::ComponentUpdater},
+    width,
+};
+
+#[derive(Default, Props)]
+pub struct FormFieldProps {
+    /// 通过 `use_form` 创建的表单
+    pub form: Option<Form>,
+    /// 对应 [`Field`](crate::hooks::use_form::Field) 的名称
+    pub name: String,
+    /// 输入框上方的标签，必填字段会在后面加上 `*`
+    pub label: String,
+    /// 内容为空时显示的占位文本
+    pub placeholder: String,
+    /// 叠加在主题正文样式之上的样式
+    pub style: Style,
+    /// 叠加在主题次要色之上的标签样式，获得焦点时使用焦点色
+    pub label_style: Style,
+    /// 叠加在主题错误色之上的样式
+    pub error_style: Style,
+}
+
+/// FormField：绑定到表单字段的单行输入框
+///
+/// 共三行：标签、输入框与错误信息。通过 `use_focus` 参与焦点管理，Tab/Shift+Tab 在字段之间切换，
+/// Enter 移到下一个字段；离开字段后标记为已访问，之后显示校验错误。
+/// 编辑按键与 [`InputBuffer`] 相同，Ctrl+K 删除到行尾，不会同时把焦点移到上方的字段。
+///
+/// ```ignore
+/// element!(FormField(form: form, name: "email", label: "邮箱", placeholder: "name@example.com"))
+/// ```
+pub struct FormField {
+    buffer: InputBuffer,
+    label: String,
+    placeholder: String,
+    error: Option<String>,
+    focused: bool,
+    style: Style,
+    label_style: Style,
+    placeholder_style: Style,
+    error_style: Style,
+}
+
+impl Component for FormField {
+    type Props<'a> = FormFieldProps;
+
+    fn new(props: &Self::Props<'_>) -> Self {
+        Self {
+            buffer: InputBuffer::default(),
+            label: props.label.clone(),
+            placeholder: props.placeholder.clone(),
+            error: None,
+            focused: false,
+            style: props.style,
+            label_style: props.label_style,
+            placeholder_style: Style::default(),
+            error_style: props.error_style,
+        }
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        mut hooks: Hooks,
+        updater: &mut ComponentUpdater<'_, '_>,
+    ) {
+        let (theme, focus_manager) = {
+            let hooks = hooks.with_context_stack(updater.component_context_stack());
+            (
+                hooks.use_theme(),
+                hooks.use_context::<SystemContext>().focus(),
+            )
+        };
+        let focused = hooks.use_focus().is_focused();
+        // 编辑时 Ctrl+K 删除到行尾，不再同时把焦点移到上方
+        hooks.use_claim_keys(if focused { &[KeyCode::Char('k')] } else { &[] });
+        let was_focused = hooks.use_previous(focused);
+        let mut buffer = hooks.use_state(InputBuffer::default);
+        let name = props.name.clone();
+
+        if let Some(mut form) = props.form {
+            // 失去焦点时标记为已访问
+            if was_focused == Some(true) && !focused {
+                form.touch(&name);
+            }
+            // 表单的值被外部修改（例如 reset）时，同步到编辑缓冲区
+            let value = form.value(&name);
+            if buffer.read().value() != value {
+                buffer.set(InputBuffer::new(value));
+            }
+            self.error = form.error(&name);
+            self.label = if form.is_required(&name) {
+                format!("{} *", props.label)
+            } else {
+                props.label.clone()
+            };
+        } else {
+            self.error = None;
+            self.label = props.label.clone();
+        }
+
+        let form = props.form;
+        hooks.use_events(move |event| {
+            let Some(mut form) = form else {
+                return;
+            };
+            if let Event::Paste(text) = &event
+                && focused
+            {
+                let mut next = buffer.read().clone();
+                next.paste(text);
+                form.set_value(&name, next.value());
+                buffer.set(next);
+                return;
+            }
+            let Event::Key(key) = event else {
+                return;
+            };
+            if !focused || key.kind != KeyEventKind::Press {
+                return;
+            }
+            if key.code == KeyCode::Enter {
+                form.touch(&name);
+                focus_manager.focus_next();
+                return;
+            }
+            let mut next = buffer.read().clone();
+            if next.handle_key(key) {
+                form.set_value(&name, next.value());
+                buffer.set(next);
+            }
+        });
+
+        updater.set_layout_style(LayoutStyle {
+            height: Constraint::Length(3),
+            ..Default::default()
+        });
+
+        self.buffer = buffer.read().clone();
+        self.placeholder = props.placeholder.clone();
+        self.focused = focused;
+        self.style = theme.text.patch(props.style);
+        self.label_style = if focused {
+            theme.focus_ring()
+        } else {
+            theme.muted()
+        }
+        .patch(props.label_style);
+        self.placeholder_style = theme.muted();
+        self.error_style = theme.error().patch(props.error_style);
+    }
+
+    fn measure(&self) -> Option<Size> {
+        let width = [
+            width::str_width(&self.label),
+            width::str_width(self.buffer.value()) + 1,
+            width::str_width(&self.placeholder),
+            self.error.as_deref().map_or(0, width::str_width),
+        ]
+        .into_iter()
+        .max()
+        .unwrap_or_default();
+        Some(Size::new(width as u16, 3))
+    }
+
+    fn draw(&self, drawer: &mut ComponentDrawer<'_, '_>) {
+        let area = drawer.area;
+        let row = |y: u16| Rect::new(area.x, area.y + y, area.width, 1).intersection(area);
+
+        drawer.render_widget(Line::styled(self.label.as_str(), self.label_style), row(0));
+
+        let input = if self.buffer.value().is_empty() && !self.placeholder.is_empty() {
+            let mut spans = vec![Span::styled(
+                self.placeholder.as_str(),
+                self.placeholder_style,
+            )];
+            if self.focused {
+                // 占位文本前显示光标
+                spans.insert(
+                    0,
+                    Span::styled(" ", self.style.add_modifier(Modifier::REVERSED)),
+                );
+            }
+            Line::from(spans)
+        } else {
+            self.buffer.line(self.style, self.focused)
+        };
+        drawer.render_widget(input, row(1));
+
+        if let Some(error) = &self.error {
+            drawer.render_widget(Line::styled(error.as_str(), self.error_style), row(2));
+        }
+    }
+}
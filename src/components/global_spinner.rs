@@ -0,0 +1,124 @@
+use std::time::Duration;
+
+use ratatui::{
+    layout::Size,
+    style::Style,
+    text::{Line, Span},
+};
+
+use crate::{
+    assets::{Asset, SPINNER_DOTS},
+    component::Component,
+    context::SystemContext,
+    hooks::{
+        Hooks, use_context::UseContext, use_interval::UseInterval, use_resource::LoadingWatcher,
+        use_state::UseState, use_theme::UseTheme,
+    },
+    props::Props,
+    render::{drawer::ComponentDrawer, updater::ComponentUpdater},
+};
+
+#[derive(Props)]
+pub struct GlobalSpinnerProps<'a> {
+    /// 指示器后显示的文字
+    pub label: &'a str,
+    /// 是否显示进行中的操作数量
+    pub show_count: bool,
+    /// 叠加在主题主色之上的样式
+    pub style: Style,
+    /// 旋转指示器帧的资源名称，见 [`crate::assets`]
+    pub spinner: &'a str,
+}
+
+impl Default for GlobalSpinnerProps<'_> {
+    fn default() -> Self {
+        Self {
+            label: "Loading",
+            show_count: true,
+            style: Style::default(),
+            spinner: SPINNER_DOTS,
+        }
+    }
+}
+
+/// GlobalSpinner：全局加载指示器
+///
+/// 只要有任何 `use_resource` 等异步操作在进行中就显示旋转指示器，空闲时不绘制任何内容，也不定时刷新。
+/// 计数来自 [`SystemContext::loading`]，无需为每个请求手动接线。
+pub struct GlobalSpinner {
+    count: usize,
+    frame: usize,
+    spinner: Asset,
+    label: String,
+    show_count: bool,
+    style: Style,
+}
+
+impl Component for GlobalSpinner {
+    type Props<'a> = GlobalSpinnerProps<'a>;
+
+    fn new(props: &Self::Props<'_>) -> Self {
+        Self {
+            count: 0,
+            frame: 0,
+            spinner: Asset::default(),
+            label: props.label.to_string(),
+            show_count: props.show_count,
+            style: props.style,
+        }
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        mut hooks: Hooks,
+        updater: &mut ComponentUpdater<'_, '_>,
+    ) {
+        let (loading, assets, theme) = {
+            let hooks = hooks.with_context_stack(updater.component_context_stack());
+            let context = hooks.use_context::<SystemContext>();
+            (context.loading(), context.assets(), hooks.use_theme())
+        };
+
+        // 计数变化时立即刷新，只在有操作进行时转动指示器
+        hooks.use_hook(|| LoadingWatcher::new(loading.clone()));
+        let mut frame = hooks.use_state(|| 0usize);
+        hooks.use_interval_while(
+            loading.is_loading(),
+            Duration::from_millis(100),
+            move || frame.set(frame.get().wrapping_add(1)),
+        );
+
+        self.count = loading.count();
+        self.frame = frame.get();
+        self.spinner = assets.resolve(props.spinner, SPINNER_DOTS);
+        self.label = props.label.to_string();
+        self.show_count = props.show_count;
+        self.style = theme.primary().patch(props.style);
+    }
+
+    fn measure(&self) -> Option<Size> {
+        Some(Size::new(self.line().width() as u16, 1))
+    }
+
+    fn draw(&self, drawer: &mut ComponentDrawer<'_, '_>) {
+        drawer.render_widget(self.line(), drawer.area);
+    }
+}
+
+impl GlobalSpinner {
+    fn line(&self) -> Line<'_> {
+        if self.count == 0 {
+            return Line::default();
+        }
+        let mut spans = vec![
+            Span::styled(self.spinner.frame(self.frame), self.style),
+            Span::raw(" "),
+            Span::styled(self.label.as_str(), self.style),
+        ];
+        if self.show_count {
+            spans.push(Span::raw(format!(" ({})", self.count)));
+        }
+        Line::from(spans)
+    }
+}
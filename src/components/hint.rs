@@ -0,0 +1,86 @@
+use ratatui::{
+    layout::Size,
+    style::Style,
+    text::{Line, Span},
+};
+
+use crate::{
+    component::Component,
+    context::SystemContext,
+    hooks::{Hooks, use_theme::UseTheme},
+    props::Props,
+    render::{drawer::ComponentDrawer, updater::ComponentUpdater},
+};
+
+#[derive(Default, Props)]
+pub struct HintProps<'a> {
+    /// 触发操作的按键，例如 `Enter`
+    pub keys: &'a str,
+    /// 鼠标可用时的操作方式，例如 `click`；为空时总是提示按键
+    pub mouse: &'a str,
+    /// 操作的说明，例如 `open`
+    pub action: &'a str,
+    /// 叠加在主题次要色之上的样式
+    pub style: Style,
+    /// 叠加在主题警示色之上的样式
+    pub key_style: Style,
+}
+
+/// Hint：操作提示，按 [`Capabilities::mouse`](crate::capabilities::Capabilities::mouse)
+/// 显示 "click to open" 或 "press Enter to open"
+///
+/// ```ignore
+/// element!(Hint(keys: "Enter", mouse: "click", action: "open"))
+/// ```
+pub struct Hint {
+    line: Line<'static>,
+}
+
+impl Component for Hint {
+    type Props<'a> = HintProps<'a>;
+
+    fn new(_props: &Self::Props<'_>) -> Self {
+        Self {
+            line: Line::default(),
+        }
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        mut hooks: Hooks,
+        updater: &mut ComponentUpdater<'_, '_>,
+    ) {
+        let theme = hooks
+            .with_context_stack(updater.component_context_stack())
+            .use_theme();
+        let style = theme.muted().patch(props.style);
+        let key_style = theme.warning().patch(props.key_style);
+        let mouse = updater
+            .component_context_stack()
+            .get_context::<SystemContext>()
+            .is_some_and(|system| system.capabilities().mouse);
+        let (verb, trigger) = if mouse && !props.mouse.is_empty() {
+            (None, props.mouse)
+        } else {
+            (Some("press "), props.keys)
+        };
+        let mut spans: Vec<Span<'static>> = Vec::new();
+        if let Some(verb) = verb {
+            spans.push(Span::styled(verb, style));
+        }
+        spans.push(Span::styled(trigger.to_string(), key_style));
+        if !props.action.is_empty() {
+            spans.push(Span::styled(format!(" to {}", props.action), style));
+        }
+        self.line = Line::from(spans);
+    }
+
+    fn measure(&self) -> Option<Size> {
+        Some(Size::new(self.line.width() as u16, 1))
+    }
+
+    fn draw(&self, drawer: &mut ComponentDrawer<'_, '_>) {
+        drawer.render_widget(&self.line, drawer.area);
+    }
+}
@@ -0,0 +1,321 @@
+use std::sync::Arc;
+
+use crossterm::event::{Event, KeyCode, KeyEventKind};
+use ratatui::{
+    layout::{Constraint, Layout},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::Paragraph,
+};
+use serde_json::Value;
+
+use crate::{
+    component::Component,
+    components::{TreeItem, TreeState},
+    hooks::{
+        Hooks,
+        use_clipboard::UseClipboard,
+        use_events::UseEvents,
+        use_state::{State, UseState},
+        use_theme::UseTheme,
+    },
+    props::{Handler, Props},
+    render::{drawer::ComponentDrawer, updater::ComponentUpdater},
+    theme::Theme,
+    width,
+};
+
+/// 将 JSON 值转换为树节点，节点 id 为 `$.key[0]` 形式的访问路径，使用默认主题着色
+pub fn json_tree_items(value: &Value) -> Vec<TreeItem> {
+    json_tree_items_with_theme(value, &Theme::default())
+}
+
+/// 同 [`json_tree_items`]，按 theme 着色
+pub fn json_tree_items_with_theme(value: &Value, theme: &Theme) -> Vec<TreeItem> {
+    vec![json_item("$".to_string(), "$".to_string(), value, theme)]
+}
+
+fn json_item(path: String, key: String, value: &Value, theme: &Theme) -> TreeItem {
+    let key_span = Span::styled(key, theme.accent());
+    let (preview, children) = match value {
+        Value::Object(map) => (
+            Span::styled(format!("{{…}} {} keys", map.len()), theme.muted()),
+            map.iter()
+                .map(|(k, v)| {
+                    let child = if is_identifier(k) {
+                        format!("{path}.{k}")
+                    } else {
+                        format!("{path}[{}]", Value::String(k.clone()))
+                    };
+                    json_item(child, k.clone(), v, theme)
+                })
+                .collect(),
+        ),
+        Value::Array(items) => (
+            Span::styled(format!("[…] {} items", items.len()), theme.muted()),
+            items
+                .iter()
+                .enumerate()
+                .map(|(i, v)| json_item(format!("{path}[{i}]"), i.to_string(), v, theme))
+                .collect(),
+        ),
+        Value::String(s) => (
+            Span::styled(Value::String(s.clone()).to_string(), theme.success()),
+            Vec::new(),
+        ),
+        Value::Number(n) => (Span::styled(n.to_string(), theme.primary()), Vec::new()),
+        Value::Bool(b) => (Span::styled(b.to_string(), theme.warning()), Vec::new()),
+        Value::Null => (Span::styled("null", theme.muted()), Vec::new()),
+    };
+
+    TreeItem::new(path, Line::from(vec![key_span, Span::raw(": "), preview]))
+        .with_children(children)
+}
+
+fn is_identifier(key: &str) -> bool {
+    !key.is_empty()
+        && !key.starts_with(|c: char| c.is_ascii_digit())
+        && key.chars().all(|c| c.is_alphanumeric() || c == '_')
+}
+
+/// 按深度优先顺序收集所有与关键字匹配的节点 id（忽略大小写）
+fn search_items(items: &[TreeItem], query: &str) -> Vec<String> {
+    fn walk(items: &[TreeItem], query: &str, out: &mut Vec<String>) {
+        for item in items {
+            if item.label.to_string().to_lowercase().contains(query) {
+                out.push(item.id.clone());
+            }
+            walk(&item.children, query, out);
+        }
+    }
+
+    let mut out = Vec::new();
+    walk(items, &query.to_lowercase(), &mut out);
+    out
+}
+
+/// 搜索状态
+#[derive(Default, Clone)]
+struct JsonSearch {
+    /// 是否正在输入搜索词
+    editing: bool,
+    query: String,
+    /// 当前命中的序号
+    current: usize,
+}
+
+#[derive(Props)]
+pub struct JsonViewProps<'a> {
+    /// 要展示的 JSON 值
+    pub value: Option<&'a Value>,
+    /// 是否响应键盘操作
+    pub focused: bool,
+    /// 选中行的样式
+    pub highlight_style: Style,
+    /// 按 `y` 把选中节点的路径复制到剪贴板之后的回调
+    pub on_copy_path: Handler<'a, String>,
+}
+
+impl Default for JsonViewProps<'_> {
+    fn default() -> Self {
+        Self {
+            value: None,
+            focused: true,
+            highlight_style: Style::default().add_modifier(Modifier::REVERSED),
+            on_copy_path: Handler::default(),
+        }
+    }
+}
+
+/// JsonView：JSON 结构化数据查看器
+///
+/// 基于 TreeState 以可折叠树的形式展示 JSON，按键：
+/// - 方向键/hjkl 导航与展开折叠
+/// - `/` 输入搜索词，`n`/`N` 跳转到下一个/上一个匹配
+/// - `y` 把选中节点的访问路径复制到剪贴板，随后调用 on_copy_path
+pub struct JsonView {
+    value: Option<Value>,
+    /// 由 value 构建的树，只在 value 或主题变化时重建，事件回调共享同一份数据
+    items: Arc<[TreeItem]>,
+    state: Option<State<TreeState>>,
+    search: Option<State<JsonSearch>>,
+    highlight_style: Style,
+    theme: Theme,
+    focused: bool,
+}
+
+impl Component for JsonView {
+    type Props<'a> = JsonViewProps<'a>;
+
+    fn new(props: &Self::Props<'_>) -> Self {
+        Self {
+            value: None,
+            items: Arc::new([]),
+            state: None,
+            search: None,
+            highlight_style: props.highlight_style,
+            theme: Theme::default(),
+            focused: props.focused,
+        }
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        mut hooks: Hooks,
+        updater: &mut ComponentUpdater<'_, '_>,
+    ) {
+        self.focused = props.focused;
+        let (theme, clipboard) = {
+            let hooks = hooks.with_context_stack(updater.component_context_stack());
+            (hooks.use_theme(), hooks.use_clipboard())
+        };
+        // JSON 值与主题不变时复用已构建的树
+        if self.value.as_ref() != props.value || self.theme != theme || self.items.is_empty() {
+            self.value = props.value.cloned();
+            self.theme = theme;
+            self.items = self
+                .value
+                .as_ref()
+                .map(|value| json_tree_items_with_theme(value, &theme))
+                .unwrap_or_default()
+                .into();
+        }
+        self.highlight_style = props.highlight_style;
+
+        let state = hooks.use_state(|| TreeState {
+            expanded: ["$".to_string()].into(),
+            selected: 0,
+        });
+        TreeState::clamp_selected(state, &self.items);
+        let search = hooks.use_state(JsonSearch::default);
+        let mut copy_request = hooks.use_state(|| None::<String>);
+
+        // 事件回调中无法访问带生命周期的 props，复制请求先记录在状态里，下一轮 update 时再回调
+        if copy_request.read().is_some()
+            && let Some(path) = copy_request.write().take()
+        {
+            props.on_copy_path.invoke(path);
+        }
+
+        let items = self.items.clone();
+        let focused = props.focused;
+        hooks.use_events(move |event| {
+            let Event::Key(key) = event else {
+                return;
+            };
+            if !focused || key.kind != KeyEventKind::Press {
+                return;
+            }
+
+            let mut current = search.read().clone();
+            if current.editing {
+                match key.code {
+                    KeyCode::Char(c) => current.query.push(c),
+                    KeyCode::Backspace => {
+                        current.query.pop();
+                    }
+                    KeyCode::Esc => current = JsonSearch::default(),
+                    KeyCode::Enter => {
+                        current.editing = false;
+                        current.current = 0;
+                        jump(&items, state, &current);
+                    }
+                    _ => return,
+                }
+                *search.write() = current;
+                return;
+            }
+
+            match key.code {
+                KeyCode::Char('/') => {
+                    *search.write() = JsonSearch {
+                        editing: true,
+                        ..Default::default()
+                    };
+                }
+                KeyCode::Char('n') | KeyCode::Char('N') if !current.query.is_empty() => {
+                    let matches = search_items(&items, &current.query);
+                    if matches.is_empty() {
+                        return;
+                    }
+                    current.current = if key.code == KeyCode::Char('n') {
+                        (current.current + 1) % matches.len()
+                    } else {
+                        (current.current + matches.len() - 1) % matches.len()
+                    };
+                    jump(&items, state, &current);
+                    *search.write() = current;
+                }
+                KeyCode::Char('y') => {
+                    if let Some(item) = state.read().selected_item(&items) {
+                        clipboard.copy(item.id.clone());
+                        copy_request.set(Some(item.id.clone()));
+                    }
+                }
+                code => {
+                    let mut next = state.read().clone();
+                    if next.handle_key(&items, code) {
+                        *state.write() = next;
+                    }
+                }
+            }
+        });
+
+        self.state = Some(state);
+        self.search = Some(search);
+    }
+
+    fn draw(&self, drawer: &mut ComponentDrawer<'_, '_>) {
+        if self.focused {
+            drawer.mark_focused();
+        }
+        let (Some(state), Some(search)) = (self.state, self.search) else {
+            return;
+        };
+        let search = search.read();
+        let show_status = search.editing || !search.query.is_empty();
+
+        let [tree_area, status_area] =
+            Layout::vertical([Constraint::Fill(1), Constraint::Length(show_status as u16)])
+                .areas(drawer.area);
+
+        let lines =
+            state
+                .read()
+                .lines(&self.items, tree_area.height as usize, self.highlight_style);
+        drawer.render_widget(Paragraph::new(lines), tree_area);
+
+        if show_status {
+            let matches = search_items(&self.items, &search.query);
+            let status = if search.editing {
+                format!("/{}", search.query)
+            } else if matches.is_empty() {
+                format!("/{}  no matches", search.query)
+            } else {
+                format!(
+                    "/{}  {}/{}",
+                    search.query,
+                    search.current % matches.len() + 1,
+                    matches.len()
+                )
+            };
+            let status = width::middle_ellipsis(&status, status_area.width as usize);
+            drawer.render_widget(
+                Paragraph::new(status).style(self.theme.warning()),
+                status_area,
+            );
+        }
+    }
+}
+
+/// 跳转到当前序号对应的匹配节点
+fn jump(items: &[TreeItem], mut state: State<TreeState>, search: &JsonSearch) {
+    let matches = search_items(items, &search.query);
+    if let Some(id) = matches.get(search.current % matches.len().max(1)) {
+        let mut next = state.read().clone();
+        if next.select_id(items, id) {
+            state.set(next);
+        }
+    }
+}
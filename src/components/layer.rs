@@ -0,0 +1,150 @@
+use ratatui::{
+    buffer::Cell,
+    layout::{Constraint, Rect},
+    style::Style,
+};
+
+use super::portal::PortalTargets;
+use crate::{
+    component::Component,
+    element::Children,
+    hooks::{Hook, Hooks},
+    props::Props,
+    render::{drawer::ComponentDrawer, layout_style::LayoutStyle, updater::ComponentUpdater},
+};
+
+#[derive(Default, Props)]
+pub struct LayerProps<'a> {
+    /// 层级，数值大的绘制在上面；所有层都位于普通组件树之上，层级相同时按绘制顺序叠加
+    pub z_index: i32,
+    /// 相对整个 frame 的绝对区域，此时 Layer 在布局中不占空间；
+    /// 为 None 时使用 Layer 在布局中分配到的区域
+    pub area: Option<Rect>,
+    pub children: Children<'a>,
+}
+
+/// 层绘制的位置
+#[derive(Default)]
+pub(crate) enum Anchor {
+    /// 组件在布局中分配到的区域
+    #[default]
+    Flow,
+    /// 相对整个 frame 的绝对区域
+    Absolute(Rect),
+    /// 名为 name 的 PortalTarget 的区域，为 None 或找不到时为整个 frame
+    Target(PortalTargets, Option<String>),
+}
+
+/// 子组件没有绘制过的格子的占位符号
+const UNTOUCHED: &str = "\u{10FFFF}";
+
+/// 把子组件的绘制结果截取下来，推迟到覆盖层中按层级贴回
+#[derive(Default)]
+pub(crate) struct LayerCapture {
+    pub(crate) z_index: i32,
+    pub(crate) anchor: Anchor,
+    /// 是否遮住整个区域；否则只贴回子组件绘制过的格子，其余位置透出下层内容
+    pub(crate) opaque: bool,
+    /// 本次绘制使用的区域
+    target: Rect,
+    /// 子组件绘制之前该区域原有的内容
+    below: Vec<Cell>,
+}
+
+impl Hook for LayerCapture {
+    fn pre_component_draw(&mut self, drawer: &mut ComponentDrawer) {
+        let frame_area = drawer.frame_area();
+        self.target = match &self.anchor {
+            Anchor::Flow => drawer.area,
+            Anchor::Absolute(area) => area.intersection(frame_area),
+            Anchor::Target(targets, name) => name
+                .as_deref()
+                .and_then(|name| targets.get(name))
+                .unwrap_or(frame_area)
+                .intersection(frame_area),
+        };
+        drawer.area = self.target;
+        let mut blank = Cell::default();
+        if !self.opaque {
+            blank.set_symbol(UNTOUCHED);
+        }
+        let buffer = drawer.buffer_mut();
+        self.below = self
+            .target
+            .positions()
+            .map(|position| std::mem::replace(&mut buffer[position], blank.clone()))
+            .collect();
+    }
+
+    fn post_component_draw(&mut self, drawer: &mut ComponentDrawer) {
+        let below = std::mem::take(&mut self.below);
+        let buffer = drawer.buffer_mut();
+        // 还原原有内容，子组件绘制的内容留到覆盖层中再贴回
+        let layer: Vec<Cell> = self
+            .target
+            .positions()
+            .zip(below)
+            .map(|(position, cell)| std::mem::replace(&mut buffer[position], cell))
+            .collect();
+        let target = self.target;
+        let opaque = self.opaque;
+        drawer.overlay_at(self.z_index, move |drawer| {
+            let buffer = drawer.buffer_mut();
+            for (position, cell) in target.positions().zip(layer) {
+                if opaque || cell.symbol() != UNTOUCHED {
+                    buffer[position] = cell;
+                } else if cell.style() != Style::default() {
+                    // 只设置了样式的格子保留下层的字符
+                    buffer[position].set_style(cell.style());
+                }
+            }
+        });
+    }
+}
+
+/// Layer：把子组件绘制在普通组件树之上的独立层中，用于模态框、下拉框、提示消息等
+///
+/// 子组件照常参与 update 与事件处理，只是绘制结果在整棵组件树绘制完成后按 z_index 贴回；
+/// 层覆盖整个区域，区域内子组件没有绘制的位置显示为空白。
+/// 不设置 z_index 的 Layer 与 [`ComponentDrawer::overlay`] 登记的覆盖层同级，按绘制顺序叠加。
+///
+/// ```ignore
+/// element!(View {
+///     Content()
+///     #(show_menu.get().then(|| element!(Layer(z_index: 10, area: Rect::new(4, 2, 20, 6)) {
+///         Menu(items: items)
+///     })))
+/// })
+/// ```
+pub struct Layer;
+
+impl Component for Layer {
+    type Props<'a> = LayerProps<'a>;
+
+    fn new(_props: &Self::Props<'_>) -> Self {
+        Self
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        mut hooks: Hooks,
+        updater: &mut ComponentUpdater<'_, '_>,
+    ) {
+        let capture = hooks.use_hook(LayerCapture::default);
+        capture.z_index = props.z_index;
+        capture.opaque = true;
+        capture.anchor = match props.area {
+            Some(area) => Anchor::Absolute(area),
+            None => Anchor::Flow,
+        };
+        if props.area.is_some() {
+            updater.set_layout_style(LayoutStyle {
+                width: Constraint::Length(0),
+                height: Constraint::Length(0),
+                ..Default::default()
+            });
+        }
+        updater.update_children(props.children.iter_mut(), None);
+    }
+}
@@ -0,0 +1,119 @@
+use ratatui::{
+    layout::Size,
+    style::{Style, Stylize},
+    text::{Line, Span},
+};
+
+use crate::{
+    capabilities::supports_hyperlinks,
+    component::Component,
+    hooks::Hooks,
+    props::Props,
+    render::{drawer::ComponentDrawer, updater::ComponentUpdater},
+    width,
+};
+
+#[derive(Props)]
+pub struct LinkProps<'a> {
+    /// 链接地址
+    pub url: &'a str,
+    /// 显示文本，为空时显示 url
+    pub text: &'a str,
+    /// 链接样式
+    pub style: Style,
+    /// 强制开启/关闭 OSC 8，None 时自动检测
+    pub osc8: Option<bool>,
+}
+
+impl Default for LinkProps<'_> {
+    fn default() -> Self {
+        Self {
+            url: "",
+            text: "",
+            style: Style::default().underlined().light_blue(),
+            osc8: None,
+        }
+    }
+}
+
+/// Link：可点击的超链接组件
+///
+/// 在支持 OSC 8 的终端中输出可点击的链接；不支持时以带样式的文本显示，
+/// 并在显示文本与地址不同时附带地址，保证用户仍然能看到并复制链接。
+pub struct Link {
+    url: String,
+    text: String,
+    style: Style,
+    osc8: bool,
+}
+
+impl Link {
+    fn from_props(props: &LinkProps) -> Self {
+        let text = if props.text.is_empty() {
+            props.url
+        } else {
+            props.text
+        };
+        Self {
+            url: props.url.to_string(),
+            text: text.to_string(),
+            style: props.style,
+            osc8: props.osc8.unwrap_or_else(supports_hyperlinks),
+        }
+    }
+
+    fn line(&self) -> Line<'_> {
+        if self.osc8 || self.text == self.url {
+            Line::from(Span::styled(self.text.as_str(), self.style))
+        } else {
+            Line::from(vec![
+                Span::styled(self.text.as_str(), self.style),
+                Span::raw(format!(" <{}>", self.url)),
+            ])
+        }
+    }
+}
+
+impl Component for Link {
+    type Props<'a> = LinkProps<'a>;
+
+    fn new(props: &Self::Props<'_>) -> Self {
+        Self::from_props(props)
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        _hooks: Hooks,
+        _updater: &mut ComponentUpdater<'_, '_>,
+    ) {
+        *self = Self::from_props(props);
+    }
+
+    fn measure(&self) -> Option<Size> {
+        Some(Size::new(self.line().width() as u16, 1))
+    }
+
+    fn draw(&self, drawer: &mut ComponentDrawer<'_, '_>) {
+        let area = drawer.area;
+        drawer.render_widget(self.line(), area);
+
+        if !self.osc8 || area.is_empty() {
+            return;
+        }
+
+        // ratatui 会按符号宽度计算光标位置，转义序列会打乱计算结果，
+        // 这里沿用官方示例的做法：每两个单元格合并为一个符号并包裹在 OSC 8 中，
+        // 被合并的后一个单元格则由 diff 跳过
+        let width = (width::str_width(&self.text) as u16).min(area.width);
+        let buffer = drawer.buffer_mut();
+        for x in (area.x..area.x + width).step_by(2) {
+            let mut symbol = buffer[(x, area.y)].symbol().to_string();
+            if x + 1 < area.x + width {
+                symbol.push_str(buffer[(x + 1, area.y)].symbol());
+            }
+            let hyperlink = format!("\x1B]8;;{}\x07{}\x1B]8;;\x07", self.url, symbol);
+            buffer[(x, area.y)].set_symbol(&hyperlink);
+        }
+    }
+}
@@ -0,0 +1,311 @@
+use std::sync::{
+    Arc,
+    atomic::{AtomicUsize, Ordering},
+};
+
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyEventKind};
+use ratatui::{
+    layout::{Rect, Size},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::Paragraph,
+};
+
+use crate::{
+    component::Component,
+    components::Selection,
+    hooks::{
+        Hooks,
+        use_events::UseEvents,
+        use_mode::{Mode, UseMode},
+        use_previous::UsePrevious,
+        use_scroll::{ScrollEdge, ScrollState, UseScroll},
+        use_state::{State, UseState},
+        use_theme::UseTheme,
+    },
+    props::Props,
+    render::{drawer::ComponentDrawer, updater::ComponentUpdater},
+    theme::{self, Theme},
+};
+
+#[derive(Props)]
+pub struct ListProps {
+    pub items: Vec<Line<'static>>,
+    /// 受控的选择状态，为 None 时使用组件内部状态
+    pub selection: Option<State<Selection>>,
+    /// 是否开启多选
+    pub multi_select: bool,
+    /// 是否响应键盘导航
+    pub focused: bool,
+    /// 光标所在行的样式
+    pub highlight_style: Style,
+    /// 与 [`Scrollbar`](crate::components::Scrollbar) 共享的滚动状态，为 None 时总是滚动到光标可见
+    pub scroll: Option<State<ScrollState>>,
+}
+
+impl Default for ListProps {
+    fn default() -> Self {
+        Self {
+            items: Vec::new(),
+            selection: None,
+            multi_select: false,
+            focused: true,
+            highlight_style: Style::default().add_modifier(Modifier::REVERSED),
+            scroll: None,
+        }
+    }
+}
+
+/// 返回能让光标保持可见的首行下标
+pub(crate) fn scroll_offset(cursor: usize, height: usize) -> usize {
+    (cursor + 1).saturating_sub(height)
+}
+
+/// 绘制时把内容行数与可见高度写入共享的滚动状态，返回首行下标
+///
+/// 可见高度变化时（包括首次绘制）滚动到光标可见，其余时候保留 Scrollbar 设置的位置。
+pub(crate) fn sync_scroll(
+    mut scroll: State<ScrollState>,
+    content: usize,
+    viewport: usize,
+    cursor: usize,
+) -> usize {
+    let current = scroll.get();
+    let mut next = current;
+    next.set_content(content);
+    next.set_viewport(viewport);
+    if current.viewport() != viewport {
+        next.scroll_into_view(cursor);
+    }
+    if next != current {
+        scroll.set(next);
+    }
+    next.offset()
+}
+
+/// 光标移动后把共享的滚动状态滚动到光标可见
+pub(crate) fn follow_cursor(
+    hooks: &mut Hooks,
+    scroll: Option<State<ScrollState>>,
+    content: usize,
+    cursor: usize,
+) {
+    let previous = hooks.use_previous(cursor);
+    if let Some(mut scroll) = scroll
+        && previous != Some(cursor)
+    {
+        let current = scroll.get();
+        let mut next = current;
+        next.set_content(content);
+        next.scroll_into_view(cursor);
+        if next != current {
+            scroll.set(next);
+        }
+    }
+}
+
+/// 光标已经在第一行（最后一行）时继续向上（向下）移动的按键对应的边界
+pub(crate) fn overscroll_edge(key: KeyEvent, cursor: usize, len: usize) -> Option<ScrollEdge> {
+    if len == 0 {
+        return None;
+    }
+    match key.code {
+        KeyCode::Up | KeyCode::Char('k') | KeyCode::PageUp | KeyCode::Home | KeyCode::Char('g')
+            if cursor == 0 =>
+        {
+            Some(ScrollEdge::Top)
+        }
+        KeyCode::Down
+        | KeyCode::Char('j')
+        | KeyCode::PageDown
+        | KeyCode::End
+        | KeyCode::Char('G')
+            if cursor + 1 >= len =>
+        {
+            Some(ScrollEdge::Bottom)
+        }
+        _ => None,
+    }
+}
+
+/// 越界提示的样式：从焦点色渐隐为次要色
+pub(crate) fn overscroll_style(theme: &Theme, intensity: f64) -> Style {
+    Style::new()
+        .fg(theme::mix(theme.muted, theme.focus, intensity))
+        .add_modifier(Modifier::BOLD)
+}
+
+/// 在 rows 区域的首行或末行右端绘制越界提示
+pub(crate) fn draw_overscroll(
+    drawer: &mut ComponentDrawer<'_, '_>,
+    rows: Rect,
+    overscroll: Option<(ScrollEdge, Style)>,
+) {
+    let Some((edge, style)) = overscroll else {
+        return;
+    };
+    if rows.is_empty() {
+        return;
+    }
+    let (y, symbol) = match edge {
+        ScrollEdge::Top => (rows.top(), "▲"),
+        ScrollEdge::Bottom => (rows.bottom() - 1, "▼"),
+    };
+    drawer.buffer_mut()[(rows.right() - 1, y)]
+        .set_symbol(symbol)
+        .set_style(style);
+}
+
+/// 多选模式下每行前的选中标记
+pub(crate) fn selection_marker(selected: bool) -> &'static str {
+    if selected { "[x] " } else { "[ ] " }
+}
+
+/// List：可选择的列表组件
+///
+/// ↑/↓、PageUp/PageDown、Home/End 移动光标；开启 multi_select 后，
+/// Space 切换选中、Shift+↑/↓ 范围选择、Ctrl+A 全选，每行前显示选中标记。
+/// 启用模式输入后，Insert 模式下不响应按键，多选列表在 Visual 模式下移动光标即扩展选择范围。
+/// 光标已在首行（末行）时继续向上（向下）移动，会在该行右端短暂显示渐隐的 ▲（▼）提示。
+pub struct List {
+    items: Vec<Line<'static>>,
+    selection: Selection,
+    multi_select: bool,
+    highlight_style: Style,
+    /// 最近一次绘制时的可见高度，作为翻页的行数
+    page: Arc<AtomicUsize>,
+    /// 当前使用的选择状态，供 scroll_to 修改
+    state: Option<State<Selection>>,
+    scroll: Option<State<ScrollState>>,
+    /// 正在提示的越界边界与样式
+    overscroll: Option<(ScrollEdge, Style)>,
+    focused: bool,
+}
+
+impl List {
+    /// 把光标移动到 index，列表会滚动到该行可见，可以通过 ComponentRef 调用
+    pub fn scroll_to(&mut self, index: usize) {
+        let len = self.items.len();
+        if let Some(mut state) = self.state {
+            let mut next = state.read().clone();
+            next.set_cursor(index, len);
+            state.set(next);
+        }
+    }
+}
+
+impl Component for List {
+    type Props<'a> = ListProps;
+
+    fn new(props: &Self::Props<'_>) -> Self {
+        Self {
+            items: Vec::new(),
+            selection: Selection::default(),
+            multi_select: props.multi_select,
+            highlight_style: props.highlight_style,
+            page: Arc::default(),
+            state: None,
+            scroll: None,
+            overscroll: None,
+            focused: props.focused,
+        }
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        mut hooks: Hooks,
+        updater: &mut ComponentUpdater<'_, '_>,
+    ) {
+        self.focused = props.focused;
+        let theme = hooks
+            .with_context_stack(updater.component_context_stack())
+            .use_theme();
+        let internal = hooks.use_state(Selection::default);
+        let mut selection = props.selection.unwrap_or(internal);
+        let mut overscroll = hooks.use_overscroll();
+
+        let len = props.items.len();
+        let multi = props.multi_select;
+        let focused = props.focused;
+        let page = self.page.clone();
+        let mode = hooks.use_mode().mode();
+        hooks.use_events(move |event| {
+            if let Event::Key(key) = event
+                && focused
+                && key.kind == KeyEventKind::Press
+            {
+                let page = page.load(Ordering::Relaxed);
+                let mut next = selection.read().clone();
+                let edge = overscroll_edge(key, next.cursor(), len);
+                let handled = match mode {
+                    // Insert 模式下按键用于输入文本，列表不响应
+                    Some(Mode::Insert) => false,
+                    Some(Mode::Visual) if multi => next.handle_visual_key(key, len, page),
+                    _ => next.handle_key(key, len, multi, page),
+                };
+                if handled {
+                    if let Some(edge) = edge {
+                        overscroll.hit(edge);
+                    }
+                    selection.set(next);
+                }
+            }
+        });
+
+        self.items = props.items.clone();
+        self.selection = selection.read().clone();
+        self.selection.clamp(len);
+        self.multi_select = multi;
+        self.highlight_style = props.highlight_style;
+        self.state = Some(selection);
+        follow_cursor(&mut hooks, props.scroll, len, self.selection.cursor());
+        self.scroll = props.scroll;
+        self.overscroll = overscroll
+            .current()
+            .map(|(edge, intensity)| (edge, overscroll_style(&theme, intensity)));
+    }
+
+    fn measure(&self) -> Option<Size> {
+        let marker = if self.multi_select { 4 } else { 0 };
+        let width = self.items.iter().map(Line::width).max().unwrap_or_default() + marker;
+        Some(Size::new(width as u16, self.items.len() as u16))
+    }
+
+    fn draw(&self, drawer: &mut ComponentDrawer<'_, '_>) {
+        if self.focused {
+            drawer.mark_focused();
+        }
+        let height = drawer.area.height as usize;
+        self.page.store(height, Ordering::Relaxed);
+        let cursor = self.selection.cursor();
+        let offset = match self.scroll {
+            Some(scroll) => sync_scroll(scroll, self.items.len(), height, cursor),
+            None => scroll_offset(cursor, height),
+        };
+        let lines: Vec<Line> = self
+            .items
+            .iter()
+            .enumerate()
+            .skip(offset)
+            .take(height)
+            .map(|(index, item)| {
+                let mut line = item.clone();
+                if self.multi_select {
+                    let marker = selection_marker(self.selection.is_selected(index));
+                    line.spans.insert(0, Span::raw(marker));
+                }
+                if index == self.selection.cursor() {
+                    line = line.patch_style(self.highlight_style);
+                }
+                line
+            })
+            .collect();
+        let rows = Rect {
+            height: lines.len() as u16,
+            ..drawer.area
+        };
+        drawer.render_widget(Paragraph::new(lines), drawer.area);
+        draw_overscroll(drawer, rows, self.overscroll);
+    }
+}
@@ -0,0 +1,65 @@
+mod column_group;
+pub use column_group::{ColumnGroup, ColumnGroupProps, ColumnTracks, Row, RowProps};
+mod text;
+pub use text::{Text, TextProps};
+mod rich_text;
+pub use rich_text::{Line, LineProps, RichText, RichTextProps, Span, SpanProps};
+mod hint;
+pub use hint::{Hint, HintProps};
+mod link;
+pub use crate::capabilities::supports_hyperlinks;
+pub use link::{Link, LinkProps};
+mod diff_view;
+pub use diff_view::{DiffLine, DiffMode, DiffView, DiffViewProps, diff_lines, parse_unified_diff};
+mod tree_view;
+pub use tree_view::{FlatTreeItem, TreeItem, TreeState, TreeView, TreeViewProps};
+mod json_view;
+pub use json_view::{JsonView, JsonViewProps, json_tree_items, json_tree_items_with_theme};
+mod timeline;
+pub use timeline::{Timeline, TimelineItem, TimelineProps};
+mod text_input;
+pub use text_input::{InputBuffer, TextInput, TextInputProps};
+mod form_field;
+pub use form_field::{FormField, FormFieldProps};
+mod focus_ring;
+pub use focus_ring::{FocusRing, FocusRingProps};
+mod layer;
+pub use layer::{Layer, LayerProps};
+mod modal;
+pub use modal::{Modal, ModalProps};
+mod portal;
+pub use portal::{Portal, PortalProps, PortalTarget, PortalTargetProps, PortalTargets};
+mod data_grid;
+pub use data_grid::{CellEdit, DataGrid, DataGridColumn, DataGridProps};
+mod prompt;
+pub use prompt::{History, Prompt, PromptProps};
+mod progress_list;
+pub use progress_list::{ProgressList, ProgressListProps};
+mod toast_manager;
+pub use toast_manager::{ToastManager, ToastManagerProps, ToastPosition};
+mod global_spinner;
+pub use global_spinner::{GlobalSpinner, GlobalSpinnerProps};
+mod skeleton;
+pub use skeleton::{Skeleton, SkeletonProps, SkeletonShape};
+mod selection;
+pub use selection::Selection;
+mod list;
+pub use list::{List, ListProps};
+mod scrollbar;
+pub use scrollbar::{Scrollbar, ScrollbarProps};
+mod table;
+pub use table::{Table, TableProps};
+mod reorderable_list;
+pub use reorderable_list::{ReorderableList, ReorderableListProps};
+mod pane_manager;
+pub use pane_manager::{PaneId, PaneLayout, PaneManager, PaneManagerProps};
+mod context_provider;
+pub use context_provider::{ContextProvider, ContextProviderProps};
+mod dynamic;
+pub use dynamic::{ComponentRegistry, DynamicElement, DynamicElementProps, DynamicError};
+mod ui_file;
+pub use ui_file::{UiFile, UiFileProps};
+mod router;
+pub use router::{Route, RouteProps, Routes, RoutesProps, match_path};
+mod stack;
+pub use stack::{Divider, DividerProps, Stack, StackProps};
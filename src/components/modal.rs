@@ -0,0 +1,248 @@
+use std::{
+    pin::{Pin, pin},
+    task::{Context as TaskContext, Poll},
+};
+
+use crossterm::event::{Event, KeyCode, KeyEventKind};
+use futures::Stream;
+use ratatui::{
+    layout::{Constraint, Flex, Layout, Rect},
+    style::{Modifier, Style},
+    widgets::{Block, BorderType, Clear, Widget},
+};
+
+use super::layer::{Anchor, LayerCapture};
+use crate::{
+    component::Component,
+    context::{Context, SystemContext},
+    element::{AnyElement, Children},
+    hooks::{Hook, Hooks, use_focus::UseFocus, use_theme::UseTheme},
+    props::{Handler, Props},
+    render::{drawer::ComponentDrawer, layout_style::LayoutStyle, updater::ComponentUpdater},
+    terminal::{InputLayer, InputLayers, TerminalEvents},
+};
+
+#[derive(Props)]
+pub struct ModalProps<'a> {
+    pub open: bool,
+    pub title: Option<String>,
+    /// 对话框的宽度，相对整个 frame 计算
+    pub width: Constraint,
+    /// 对话框的高度，相对整个 frame 计算
+    pub height: Constraint,
+    /// 层级，与 [`Layer`](super::Layer) 的 z_index 相同
+    pub z_index: i32,
+    /// 叠加在主题焦点色之上的边框样式
+    pub border_style: Style,
+    /// 叠加在对话框外的背景上的样式，默认变暗
+    pub backdrop_style: Style,
+    /// 按下 Esc 时的回调，由调用方把 open 置为 false
+    pub on_close: Handler<'a, ()>,
+    pub children: Children<'a>,
+}
+
+impl Default for ModalProps<'_> {
+    fn default() -> Self {
+        Self {
+            open: false,
+            title: None,
+            width: Constraint::Percentage(60),
+            height: Constraint::Percentage(50),
+            z_index: 100,
+            border_style: Style::default(),
+            backdrop_style: Style::default().add_modifier(Modifier::DIM),
+            on_close: Handler::default(),
+            children: Children::default(),
+        }
+    }
+}
+
+/// 对话框占据的输入层级：打开时放到最上层，关闭或卸载时移除，并在该层级中监听 Esc
+struct ModalLayer {
+    layer: InputLayer,
+    open: bool,
+    /// 是否已经放到 layers 中，避免每次 update 都把它移到其他覆盖层之上
+    pushed: bool,
+    close_requested: bool,
+    layers: Option<InputLayers>,
+    events: Option<TerminalEvents>,
+}
+
+impl ModalLayer {
+    fn sync(&mut self) {
+        let Some(layers) = &self.layers else {
+            return;
+        };
+        if self.open && !self.pushed {
+            layers.push(self.layer);
+        } else if !self.open && self.pushed {
+            layers.remove(self.layer);
+        }
+        self.pushed = self.open;
+    }
+}
+
+impl Hook for ModalLayer {
+    fn poll_change(mut self: Pin<&mut Self>, cx: &mut TaskContext) -> Poll<()> {
+        let mut changed = false;
+        while let Some(Poll::Ready(Some(event))) = self
+            .events
+            .as_mut()
+            .map(|events| pin!(events).poll_next(cx))
+        {
+            if let Event::Key(key) = event
+                && self.open
+                && key.kind == KeyEventKind::Press
+                && key.code == KeyCode::Esc
+            {
+                self.close_requested = true;
+                changed = true;
+            }
+        }
+        if changed {
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    }
+
+    fn post_component_update(&mut self, updater: &mut ComponentUpdater) {
+        if self.events.is_none() {
+            self.events = Some(updater.terminal().layer_events(self.layer));
+        }
+        if self.layers.is_none() {
+            self.layers = updater
+                .component_context_stack()
+                .get_context::<SystemContext>()
+                .map(|system| system.input_layers());
+        }
+        self.sync();
+    }
+
+    fn on_unmount(&mut self) {
+        self.open = false;
+        self.sync();
+    }
+}
+
+/// Modal：居中显示在整个界面之上的对话框
+///
+/// 打开时背景变暗，并占据最上层的 [`InputLayer`]：按键、鼠标与粘贴事件只分发给对话框内的组件，
+/// 背景中的 List、TextInput 等组件即使 focused 也不会响应。Tab/Shift+Tab 只在对话框内的组件之间切换焦点，
+/// 关闭后焦点回到打开之前的组件；按下 Esc 时调用 on_close。对话框在布局中不占空间，关闭时不绘制也不挂载子组件。
+///
+/// ```ignore
+/// let mut open = hooks.use_state(|| false);
+/// element!(View {
+///     Content()
+///     Modal(open: open.get(), title: "重命名", on_close: move |_| open.set(false)) {
+///         TextInput(value: name)
+///     }
+/// })
+/// ```
+pub struct Modal {
+    open: bool,
+    title: Option<String>,
+    width: Constraint,
+    height: Constraint,
+    border_type: BorderType,
+    border_style: Style,
+    backdrop_style: Style,
+}
+
+impl Component for Modal {
+    type Props<'a> = ModalProps<'a>;
+
+    fn new(props: &Self::Props<'_>) -> Self {
+        Self {
+            open: props.open,
+            title: props.title.clone(),
+            width: props.width,
+            height: props.height,
+            border_type: BorderType::Plain,
+            border_style: props.border_style,
+            backdrop_style: props.backdrop_style,
+        }
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        mut hooks: Hooks,
+        updater: &mut ComponentUpdater<'_, '_>,
+    ) {
+        let theme = hooks
+            .with_context_stack(updater.component_context_stack())
+            .use_theme();
+        let capture = hooks.use_hook(LayerCapture::default);
+        capture.z_index = props.z_index;
+        // 关闭时绘制区域为空，不截取任何内容
+        capture.anchor = if props.open {
+            Anchor::Absolute(Rect::new(0, 0, u16::MAX, u16::MAX))
+        } else {
+            Anchor::Absolute(Rect::default())
+        };
+        hooks.use_focus_trap(props.open);
+
+        let input = hooks.use_hook(|| ModalLayer {
+            layer: InputLayer::new(),
+            open: false,
+            pushed: false,
+            close_requested: false,
+            layers: None,
+            events: None,
+        });
+        input.open = props.open;
+        let layer = input.layer;
+        if std::mem::take(&mut input.close_requested) {
+            props.on_close.invoke(());
+        }
+
+        self.open = props.open;
+        self.title = props.title.clone();
+        self.width = props.width;
+        self.height = props.height;
+        self.border_type = theme.border_type;
+        self.border_style = theme.focus_ring().patch(props.border_style);
+        self.backdrop_style = props.backdrop_style;
+        updater.set_layout_style(LayoutStyle {
+            width: Constraint::Length(0),
+            height: Constraint::Length(0),
+            ..Default::default()
+        });
+        if props.open {
+            updater.update_children(props.children.iter_mut(), Some(Context::owned(layer)));
+        } else {
+            updater.update_children(Vec::<AnyElement>::new(), None);
+        }
+    }
+
+    fn draw(&self, drawer: &mut ComponentDrawer<'_, '_>) {
+        if !self.open {
+            return;
+        }
+        let frame_area = drawer.area;
+        // 只设置样式，背景的字符保持不变
+        drawer
+            .buffer_mut()
+            .set_style(frame_area, self.backdrop_style);
+
+        let [area] = Layout::vertical([self.height])
+            .flex(Flex::Center)
+            .areas(frame_area);
+        let [area] = Layout::horizontal([self.width])
+            .flex(Flex::Center)
+            .areas(area);
+        Clear.render(area, drawer.buffer_mut());
+        let mut block = Block::bordered()
+            .border_type(self.border_type)
+            .border_style(self.border_style);
+        if let Some(title) = &self.title {
+            block = block.title(title.as_str());
+        }
+        let inner = block.inner(area);
+        drawer.render_widget(block, area);
+        // 子组件绘制在对话框内
+        drawer.area = inner;
+    }
+}
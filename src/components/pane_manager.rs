@@ -0,0 +1,449 @@
+use std::sync::{Arc, Mutex};
+
+use crossterm::event::{Event, KeyCode, KeyEventKind, KeyModifiers};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::Style,
+    widgets::{Block, BorderType},
+};
+
+use crate::{
+    component::{Component, instantiated_component::Components},
+    context::Context,
+    element::{AnyElement, Element, ElementKey},
+    hooks::{
+        Hooks,
+        use_events::UseEvents,
+        use_focus::{FocusDirection, FocusWithin, UseFocus, nearest_in_direction},
+        use_state::{State, UseState},
+        use_theme::UseTheme,
+    },
+    props::Props,
+    render::{drawer::ComponentDrawer, layout_style::LayoutStyle, updater::ComponentUpdater},
+};
+
+/// 面板的唯一标识
+pub type PaneId = u64;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum PaneNode {
+    Leaf(PaneId),
+    Split {
+        direction: Direction,
+        children: Vec<PaneNode>,
+    },
+}
+
+impl PaneNode {
+    fn collect(&self, panes: &mut Vec<PaneId>) {
+        match self {
+            PaneNode::Leaf(id) => panes.push(*id),
+            PaneNode::Split { children, .. } => {
+                children.iter().for_each(|child| child.collect(panes))
+            }
+        }
+    }
+
+    /// 拆分 target 所在的叶子节点，返回是否找到
+    fn split(&mut self, target: PaneId, direction: Direction, new: PaneId) -> bool {
+        match self {
+            PaneNode::Leaf(id) if *id == target => {
+                *self = PaneNode::Split {
+                    direction,
+                    children: vec![PaneNode::Leaf(target), PaneNode::Leaf(new)],
+                };
+                true
+            }
+            PaneNode::Leaf(_) => false,
+            PaneNode::Split {
+                direction: own,
+                children,
+            } => {
+                // 方向相同时直接插入为兄弟节点，避免无意义的嵌套
+                if *own == direction
+                    && let Some(index) = children
+                        .iter()
+                        .position(|child| *child == PaneNode::Leaf(target))
+                {
+                    children.insert(index + 1, PaneNode::Leaf(new));
+                    return true;
+                }
+                children
+                    .iter_mut()
+                    .any(|child| child.split(target, direction, new))
+            }
+        }
+    }
+
+    /// 移除 target，只剩一个子节点的拆分会被合并
+    fn remove(&mut self, target: PaneId) {
+        if let PaneNode::Split { children, .. } = self {
+            children.retain(|child| *child != PaneNode::Leaf(target));
+            children.iter_mut().for_each(|child| child.remove(target));
+            if children.len() == 1 {
+                *self = children.remove(0);
+            }
+        }
+    }
+
+    fn areas(&self, area: Rect, areas: &mut Vec<(PaneId, Rect)>) {
+        match self {
+            PaneNode::Leaf(id) => areas.push((*id, area)),
+            PaneNode::Split {
+                direction,
+                children,
+            } => {
+                let rects =
+                    Layout::new(*direction, vec![Constraint::Fill(1); children.len()]).split(area);
+                for (child, rect) in children.iter().zip(rects.iter()) {
+                    child.areas(*rect, areas);
+                }
+            }
+        }
+    }
+}
+
+/// PaneLayout：面板的平铺布局
+///
+/// 以树的形式记录面板的拆分关系，同时维护当前聚焦的面板与最大化状态。
+#[derive(Clone, Debug)]
+pub struct PaneLayout {
+    root: PaneNode,
+    focused: PaneId,
+    zoomed: bool,
+    next_id: PaneId,
+}
+
+impl Default for PaneLayout {
+    fn default() -> Self {
+        Self {
+            root: PaneNode::Leaf(0),
+            focused: 0,
+            zoomed: false,
+            next_id: 1,
+        }
+    }
+}
+
+impl PaneLayout {
+    /// 所有面板，按从左到右、从上到下的顺序
+    pub fn panes(&self) -> Vec<PaneId> {
+        let mut panes = Vec::new();
+        self.root.collect(&mut panes);
+        panes
+    }
+
+    pub fn focused(&self) -> PaneId {
+        self.focused
+    }
+
+    pub fn focus(&mut self, pane: PaneId) {
+        if self.panes().contains(&pane) {
+            self.focused = pane;
+        }
+    }
+
+    pub fn is_zoomed(&self) -> bool {
+        self.zoomed
+    }
+
+    /// 切换当前面板的最大化状态
+    pub fn toggle_zoom(&mut self) {
+        self.zoomed = !self.zoomed;
+    }
+
+    /// 拆分当前面板并聚焦新面板
+    ///
+    /// Horizontal 表示左右排列，Vertical 表示上下排列。
+    pub fn split(&mut self, direction: Direction) -> PaneId {
+        let pane = self.next_id;
+        self.next_id += 1;
+        self.root.split(self.focused, direction, pane);
+        self.focused = pane;
+        self.zoomed = false;
+        pane
+    }
+
+    /// 关闭面板，最后一个面板无法关闭
+    pub fn close(&mut self, pane: PaneId) -> bool {
+        let panes = self.panes();
+        let Some(index) = panes.iter().position(|id| *id == pane) else {
+            return false;
+        };
+        if panes.len() == 1 {
+            return false;
+        }
+        self.root.remove(pane);
+        if self.focused == pane {
+            // 聚焦前一个面板，关闭的是第一个面板时聚焦下一个
+            let neighbour = if index == 0 { 1 } else { index - 1 };
+            self.focused = panes[neighbour];
+        }
+        self.zoomed = false;
+        true
+    }
+
+    /// 计算各面板的区域，最大化时只包含当前面板
+    pub fn areas(&self, area: Rect) -> Vec<(PaneId, Rect)> {
+        if self.zoomed {
+            return vec![(self.focused, area)];
+        }
+        let mut areas = Vec::new();
+        self.root.areas(area, &mut areas);
+        areas
+    }
+}
+
+#[derive(Default, Props)]
+pub struct PaneManagerProps<'a> {
+    /// 受控的布局状态，为 None 时使用组件内部状态
+    pub layout: Option<State<PaneLayout>>,
+    /// 渲染面板内容，参数为面板标识与是否聚焦；返回的元素需要拥有自己的属性，以保证 Props 协变
+    pub render_pane: Option<Box<dyn FnMut(PaneId, bool) -> AnyElement<'static> + Send + 'a>>,
+    /// 叠加在主题次要色之上的边框样式
+    pub border_style: Style,
+    /// 叠加在主题焦点色之上的聚焦面板边框样式
+    pub focused_border_style: Style,
+}
+
+/// PaneManager：平铺式面板管理器
+///
+/// 快捷键：Alt+v 左右拆分、Alt+s 上下拆分、Alt+x 关闭当前面板、Alt+z 最大化/还原、
+/// Alt+h/j/k/l 按方向切换面板、Alt+o 按顺序切换面板。
+/// 每个面板的内容由 render_pane 生成，面板标识作为元素的 key，拆分和关闭不会影响其他面板的状态；
+/// 最大化时其他面板依然挂载，只是不绘制，还原后保留原来的状态。
+///
+/// 面板接入 [`FocusManager`](crate::hooks::use_focus::FocusManager)：切换面板时焦点移到面板内第一个通过
+/// `use_focus` 注册的组件，Tab 或 Ctrl+方向键把焦点移入某个面板时该面板随之成为当前面板。
+/// 内容中没有可聚焦组件的面板自身作为一个焦点位置。
+pub struct PaneManager {
+    layout: PaneLayout,
+    border_type: BorderType,
+    border_style: Style,
+    focused_border_style: Style,
+    /// 最近一次绘制时各面板的区域，用于方向导航
+    areas: Arc<Mutex<Vec<(PaneId, Rect)>>>,
+}
+
+impl Component for PaneManager {
+    type Props<'a> = PaneManagerProps<'a>;
+
+    fn new(props: &Self::Props<'_>) -> Self {
+        Self {
+            layout: PaneLayout::default(),
+            border_type: BorderType::Plain,
+            border_style: props.border_style,
+            focused_border_style: props.focused_border_style,
+            areas: Arc::default(),
+        }
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        mut hooks: Hooks,
+        updater: &mut ComponentUpdater<'_, '_>,
+    ) {
+        let internal = hooks.use_state(PaneLayout::default);
+        let mut layout = props.layout.unwrap_or(internal);
+
+        let areas = self.areas.clone();
+        hooks.use_events(move |event| {
+            let Event::Key(key) = event else {
+                return;
+            };
+            if key.kind != KeyEventKind::Press || !key.modifiers.contains(KeyModifiers::ALT) {
+                return;
+            }
+            let mut next = layout.read().clone();
+            let direction = match key.code {
+                KeyCode::Char('v') => {
+                    next.split(Direction::Horizontal);
+                    None
+                }
+                KeyCode::Char('s') => {
+                    next.split(Direction::Vertical);
+                    None
+                }
+                KeyCode::Char('x') => {
+                    next.close(next.focused());
+                    None
+                }
+                KeyCode::Char('z') => {
+                    next.toggle_zoom();
+                    None
+                }
+                KeyCode::Char('o') => {
+                    let panes = next.panes();
+                    let index = panes.iter().position(|id| *id == next.focused());
+                    let target = index.map_or(0, |index| (index + 1) % panes.len());
+                    next.focus(panes[target]);
+                    None
+                }
+                KeyCode::Char('h') => Some(FocusDirection::Left),
+                KeyCode::Char('l') => Some(FocusDirection::Right),
+                KeyCode::Char('k') => Some(FocusDirection::Up),
+                KeyCode::Char('j') => Some(FocusDirection::Down),
+                _ => return,
+            };
+            if let Some(direction) = direction {
+                let areas = areas.lock().unwrap().clone();
+                let Some(from) = areas
+                    .iter()
+                    .find(|(id, _)| *id == next.focused())
+                    .map(|(_, area)| *area)
+                else {
+                    return;
+                };
+                let candidates = areas.into_iter().filter(|(id, _)| *id != next.focused());
+                match nearest_in_direction(from, candidates, direction) {
+                    Some(pane) => next.focus(pane),
+                    None => return,
+                }
+            }
+            layout.set(next);
+        });
+
+        self.layout = layout.read().clone();
+        let theme = hooks
+            .with_context_stack(updater.component_context_stack())
+            .use_theme();
+        self.border_type = theme.border_type;
+        self.border_style = theme.muted().patch(props.border_style);
+        self.focused_border_style = theme.focus_ring().patch(props.focused_border_style);
+
+        // 最大化时当前面板排在最前，只有它分到绘制区域，其余面板保持挂载
+        let focused = self.layout.focused();
+        let mut panes = self.layout.panes();
+        if self.layout.is_zoomed() {
+            panes.sort_by_key(|pane| *pane != focused);
+        }
+        let children: Vec<AnyElement> = match &mut props.render_pane {
+            Some(render_pane) => panes
+                .into_iter()
+                .map(|pane| {
+                    Element::<PaneSlot> {
+                        key: ElementKey::new(("pane", pane)),
+                        props: PaneSlotProps {
+                            pane,
+                            layout: Some(layout),
+                            content: Some(render_pane(pane, pane == focused)),
+                        },
+                    }
+                    .into()
+                })
+                .collect(),
+            None => Vec::new(),
+        };
+        updater.update_children(children, None);
+    }
+
+    fn draw(&self, drawer: &mut ComponentDrawer<'_, '_>) {
+        let areas = self.layout.areas(drawer.area);
+        for (pane, area) in &areas {
+            let style = if *pane == self.layout.focused() {
+                self.focused_border_style
+            } else {
+                self.border_style
+            };
+            drawer.render_widget(
+                Block::bordered()
+                    .border_type(self.border_type)
+                    .border_style(style),
+                *area,
+            );
+        }
+        *self.areas.lock().unwrap() = areas;
+    }
+
+    fn calc_children_areas(
+        &self,
+        _children: &Components,
+        _layout_style: &LayoutStyle,
+        drawer: &mut ComponentDrawer<'_, '_>,
+    ) -> Vec<Rect> {
+        // 可见面板按顺序对应前面的子组件，最大化时其余子组件分不到区域而不绘制；内容绘制在边框内
+        self.layout
+            .areas(drawer.area)
+            .into_iter()
+            .map(|(_, area)| Block::bordered().inner(area))
+            .collect()
+    }
+}
+
+#[derive(Default, Props)]
+struct PaneSlotProps {
+    pane: PaneId,
+    layout: Option<State<PaneLayout>>,
+    content: Option<AnyElement<'static>>,
+}
+
+/// 包裹单个面板的内容，在面板布局与焦点管理之间同步当前面板
+struct PaneSlot {
+    /// 上一次 update 时焦点是否在面板内
+    focused: bool,
+    /// 上一次 update 时是否为布局中的当前面板
+    current: bool,
+}
+
+impl Component for PaneSlot {
+    type Props<'a> = PaneSlotProps;
+
+    fn new(_props: &Self::Props<'_>) -> Self {
+        Self {
+            focused: false,
+            current: false,
+        }
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        mut hooks: Hooks,
+        updater: &mut ComponentUpdater<'_, '_>,
+    ) {
+        let handle = hooks
+            .with_context_stack(updater.component_context_stack())
+            .use_focus();
+        let mut within = FocusWithin::default();
+        updater.update_children(
+            props.content.iter_mut(),
+            Some(Context::form_mut(&mut within)),
+        );
+        // 内容中有可聚焦组件时，面板自身不作为焦点位置
+        handle.set_passive(within.first.is_some());
+        let focused = handle.is_focused() || within.focused;
+        if within.focused
+            && let Some(mut outer) = updater
+                .component_context_stack()
+                .get_context_mut::<FocusWithin>()
+        {
+            outer.focused = true;
+        }
+
+        let Some(layout) = props.layout else {
+            return;
+        };
+        let mut current = layout.read().focused() == props.pane;
+        if focused && !self.focused && !current {
+            // 焦点通过 Tab 或方向键移入了面板
+            layout.write().focus(props.pane);
+            current = true;
+        } else if current && !self.current && !focused {
+            // 通过快捷键切换到了面板
+            within.first.as_ref().unwrap_or(&handle).focus();
+        }
+        self.focused = focused;
+        self.current = current;
+    }
+
+    fn replay_update(&mut self, updater: &mut ComponentUpdater<'_, '_>) {
+        if self.focused
+            && let Some(mut outer) = updater
+                .component_context_stack()
+                .get_context_mut::<FocusWithin>()
+        {
+            outer.focused = true;
+        }
+    }
+}
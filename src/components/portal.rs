@@ -0,0 +1,163 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use ratatui::layout::{Constraint, Rect};
+
+use super::layer::{Anchor, LayerCapture};
+use crate::{
+    component::Component,
+    context::SystemContext,
+    element::Children,
+    hooks::Hooks,
+    props::Props,
+    render::{drawer::ComponentDrawer, layout_style::LayoutStyle, updater::ComponentUpdater},
+};
+
+/// PortalTargets：各个 [`PortalTarget`] 最近一次绘制时的区域，由 [`SystemContext::portal_targets`] 提供
+#[derive(Clone, Default)]
+pub struct PortalTargets {
+    areas: Arc<Mutex<HashMap<String, Rect>>>,
+}
+
+impl PortalTargets {
+    /// 名为 name 的目标最近一次绘制时的区域
+    pub fn get(&self, name: &str) -> Option<Rect> {
+        self.areas.lock().unwrap().get(name).copied()
+    }
+
+    fn set(&self, name: &str, area: Rect) {
+        let mut areas = self.areas.lock().unwrap();
+        if areas.get(name) != Some(&area) {
+            areas.insert(name.to_string(), area);
+        }
+    }
+
+    fn remove(&self, name: &str) {
+        self.areas.lock().unwrap().remove(name);
+    }
+}
+
+#[derive(Default, Props)]
+pub struct PortalProps<'a> {
+    /// 绘制到的 [`PortalTarget`] 的名称，为 None 或目标不存在时绘制到整个 frame
+    pub target: Option<String>,
+    /// 层级，与 [`Layer`](super::Layer) 的 z_index 相同
+    pub z_index: i32,
+    pub children: Children<'a>,
+}
+
+/// Portal：把子组件绘制到组件树的根部或指定的 [`PortalTarget`]，而不是自己在布局中的位置
+///
+/// Portal 在布局中不占空间，子组件的状态与上下文仍然来自 Portal 所在的位置，
+/// 只有绘制区域换成了目标区域，因此不受父组件布局与裁剪的影响，适合下拉菜单、提示框等。
+/// 绘制结果与 [`Layer`](super::Layer) 一样按 z_index 盖在组件树之上，但只覆盖子组件实际绘制过的格子，
+/// 其余位置透出下层内容。
+///
+/// ```ignore
+/// element!(View {
+///     Button(label: "选项")
+///     #(open.get().then(|| element!(Portal(z_index: 10) {
+///         View(
+///             offset: Offset { x: anchor.x as i32, y: anchor.y as i32 + 1 },
+///             width: Constraint::Length(20),
+///             height: Constraint::Length(6),
+///         ) {
+///             Menu(items: items)
+///         }
+///     })))
+/// })
+/// ```
+pub struct Portal;
+
+impl Component for Portal {
+    type Props<'a> = PortalProps<'a>;
+
+    fn new(_props: &Self::Props<'_>) -> Self {
+        Self
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        mut hooks: Hooks,
+        updater: &mut ComponentUpdater<'_, '_>,
+    ) {
+        let targets = updater
+            .component_context_stack()
+            .get_context::<SystemContext>()
+            .map(|system| system.portal_targets())
+            .unwrap_or_default();
+        let capture = hooks.use_hook(LayerCapture::default);
+        capture.z_index = props.z_index;
+        capture.anchor = Anchor::Target(targets, props.target.clone());
+        updater.set_layout_style(LayoutStyle {
+            width: Constraint::Length(0),
+            height: Constraint::Length(0),
+            ..Default::default()
+        });
+        updater.update_children(props.children.iter_mut(), None);
+    }
+}
+
+#[derive(Default, Props)]
+pub struct PortalTargetProps<'a> {
+    pub name: String,
+    pub children: Children<'a>,
+}
+
+/// PortalTarget：以 name 标记一个区域，`Portal(target: name)` 的子组件绘制在这里
+///
+/// 自身与子组件照常参与布局与绘制。区域在绘制时记录，绘制顺序位于目标之前的 Portal 使用上一帧的区域。
+///
+/// ```ignore
+/// element!(View {
+///     View(height: Constraint::Length(3)) {
+///         PortalTarget(name: "toasts")
+///     }
+///     Content()
+/// })
+/// ```
+pub struct PortalTarget {
+    name: String,
+    targets: PortalTargets,
+}
+
+impl Component for PortalTarget {
+    type Props<'a> = PortalTargetProps<'a>;
+
+    fn new(props: &Self::Props<'_>) -> Self {
+        Self {
+            name: props.name.clone(),
+            targets: PortalTargets::default(),
+        }
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        _hooks: Hooks,
+        updater: &mut ComponentUpdater<'_, '_>,
+    ) {
+        if let Some(system) = updater
+            .component_context_stack()
+            .get_context::<SystemContext>()
+        {
+            self.targets = system.portal_targets();
+        }
+        if props.name != self.name {
+            self.targets.remove(&self.name);
+            self.name = props.name.clone();
+        }
+        updater.update_children(props.children.iter_mut(), None);
+    }
+
+    fn draw(&self, drawer: &mut ComponentDrawer<'_, '_>) {
+        self.targets.set(&self.name, drawer.area);
+    }
+
+    fn unmount(&mut self) {
+        self.targets.remove(&self.name);
+    }
+}
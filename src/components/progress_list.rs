@@ -0,0 +1,172 @@
+use std::time::Duration;
+
+use ratatui::{
+    layout::Size,
+    style::Style,
+    text::{Line, Span},
+    widgets::Paragraph,
+};
+
+use crate::{
+    assets::{Asset, SPINNER_DOTS},
+    capabilities::ColorDepth,
+    component::Component,
+    context::SystemContext,
+    hooks::{
+        Hooks,
+        use_context::UseContext,
+        use_interval::UseInterval,
+        use_progress::{ProgressJob, ProgressManager, ProgressWatcher},
+        use_state::UseState,
+        use_theme::UseTheme,
+    },
+    props::Props,
+    render::{drawer::ComponentDrawer, updater::ComponentUpdater},
+    theme::{ColorScale, quantize},
+    width,
+};
+
+#[derive(Props)]
+pub struct ProgressListProps {
+    /// 通过 `use_progress` 创建的登记表
+    pub manager: ProgressManager,
+    /// 进度条宽度
+    pub bar_width: u16,
+    /// 叠加在主题主色之上的样式
+    pub bar_style: Style,
+    /// 设置后进度条的颜色按进度从色阶中取值，并按终端支持的颜色数量量化
+    pub bar_scale: Option<ColorScale>,
+    /// 旋转指示器帧的资源名称，见 [`crate::assets`]
+    pub spinner: String,
+}
+
+impl Default for ProgressListProps {
+    fn default() -> Self {
+        Self {
+            manager: ProgressManager::default(),
+            bar_width: 20,
+            bar_style: Style::default(),
+            bar_scale: None,
+            spinner: SPINNER_DOTS.to_string(),
+        }
+    }
+}
+
+/// ProgressList：渲染所有进行中的后台任务
+///
+/// 每个任务占一行：旋转指示器、名称、进度条（进度已知时）与状态描述。
+/// 有任务进行时指示器每 100ms 刷新一次，没有任务时不定时刷新；任务句柄 drop 后对应行自动消失。
+pub struct ProgressList {
+    jobs: Vec<ProgressJob>,
+    frame: usize,
+    spinner: Asset,
+    bar_width: u16,
+    bar_style: Style,
+    bar_scale: Option<ColorScale>,
+    colors: ColorDepth,
+    muted_style: Style,
+}
+
+impl Component for ProgressList {
+    type Props<'a> = ProgressListProps;
+
+    fn new(props: &Self::Props<'_>) -> Self {
+        Self {
+            jobs: Vec::new(),
+            frame: 0,
+            spinner: Asset::default(),
+            bar_width: props.bar_width,
+            bar_style: props.bar_style,
+            bar_scale: props.bar_scale.clone(),
+            colors: ColorDepth::default(),
+            muted_style: Style::default(),
+        }
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        mut hooks: Hooks,
+        updater: &mut ComponentUpdater<'_, '_>,
+    ) {
+        let (assets, colors, theme) = {
+            let hooks = hooks.with_context_stack(updater.component_context_stack());
+            let context = hooks.use_context::<SystemContext>();
+            (
+                context.assets(),
+                context.capabilities().colors,
+                hooks.use_theme(),
+            )
+        };
+        // 登记表变化时立即刷新，只在有任务时转动指示器
+        let watcher = hooks.use_hook(|| ProgressWatcher::new(props.manager.clone()));
+        if !watcher.manager.same(&props.manager) {
+            *watcher = ProgressWatcher::new(props.manager.clone());
+        }
+        let mut frame = hooks.use_state(|| 0usize);
+        hooks.use_interval_while(
+            !props.manager.is_empty(),
+            Duration::from_millis(100),
+            move || frame.set(frame.get().wrapping_add(1)),
+        );
+
+        self.jobs = props.manager.jobs();
+        self.frame = frame.get();
+        self.spinner = assets.resolve(&props.spinner, SPINNER_DOTS);
+        self.bar_width = props.bar_width;
+        self.bar_style = theme.primary().patch(props.bar_style);
+        self.bar_scale = props.bar_scale.clone();
+        self.colors = colors;
+        self.muted_style = theme.muted();
+    }
+
+    fn measure(&self) -> Option<Size> {
+        let width = self
+            .jobs
+            .iter()
+            .map(|job| width::str_width(&job.name) + width::str_width(&job.message))
+            .max()
+            .unwrap_or_default() as u16;
+        Some(Size::new(
+            width + self.bar_width + 10,
+            self.jobs.len() as u16,
+        ))
+    }
+
+    fn draw(&self, drawer: &mut ComponentDrawer<'_, '_>) {
+        let spinner = self.spinner.frame(self.frame);
+        // 按显示宽度对齐名称，进度条才能上下对齐
+        let name_width = self
+            .jobs
+            .iter()
+            .map(|job| width::str_width(&job.name))
+            .max()
+            .unwrap_or_default();
+        let lines: Vec<Line> = self
+            .jobs
+            .iter()
+            .map(|job| {
+                let mut spans = vec![
+                    Span::styled(format!("{spinner} "), self.bar_style),
+                    Span::raw(format!("{} ", width::pad_to_width(&job.name, name_width))),
+                ];
+                if let Some(fraction) = job.fraction {
+                    let width = self.bar_width as usize;
+                    let filled = (fraction * width as f64).round() as usize;
+                    let bar_style = match &self.bar_scale {
+                        Some(scale) => self
+                            .bar_style
+                            .fg(quantize(scale.color_at(fraction), self.colors)),
+                        None => self.bar_style,
+                    };
+                    spans.push(Span::styled("█".repeat(filled), bar_style));
+                    spans.push(Span::styled("░".repeat(width - filled), self.muted_style));
+                    spans.push(Span::raw(format!(" {:>3.0}% ", fraction * 100.0)));
+                }
+                spans.push(Span::styled(job.message.clone(), self.muted_style));
+                Line::from(spans)
+            })
+            .collect();
+        drawer.render_widget(Paragraph::new(lines), drawer.area);
+    }
+}
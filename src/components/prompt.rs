@@ -0,0 +1,466 @@
+use std::{
+    fs::{self, OpenOptions},
+    io::Write,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use crossterm::event::{Event, KeyCode, KeyEventKind, KeyModifiers};
+use ratatui::{
+    layout::{Constraint, Layout},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::Paragraph,
+};
+
+use crate::{
+    component::Component,
+    components::InputBuffer,
+    hooks::{
+        Hooks,
+        use_events::UseEvents,
+        use_state::{State, UseState},
+        use_theme::UseTheme,
+    },
+    props::Props,
+    render::{drawer::ComponentDrawer, updater::ComponentUpdater},
+};
+
+/// History：命令历史
+///
+/// 设置文件路径后会从文件加载历史，并在每次追加时写回文件。
+/// 多行命令在文件中以转义的 `\n` 保存，每条命令占一行。
+#[derive(Clone, Debug, Default)]
+pub struct History {
+    entries: Vec<String>,
+    path: Option<PathBuf>,
+}
+
+impl History {
+    /// 创建仅保存在内存中的历史
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 从文件加载历史，文件不存在时视为空历史
+    pub fn load(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        let entries = fs::read_to_string(&path)
+            .map(|content| content.lines().map(unescape).collect())
+            .unwrap_or_default();
+        Self {
+            entries,
+            path: Some(path),
+        }
+    }
+
+    pub fn entries(&self) -> &[String] {
+        &self.entries
+    }
+
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    /// 追加一条历史，忽略空命令和与上一条重复的命令
+    pub fn push(&mut self, entry: impl Into<String>) {
+        let entry = entry.into();
+        if entry.trim().is_empty() || self.entries.last() == Some(&entry) {
+            return;
+        }
+        if let Some(path) = &self.path {
+            // 持久化失败不影响交互，只丢失这一条记录
+            let _ = OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(path)
+                .and_then(|mut file| writeln!(file, "{}", escape(&entry)));
+        }
+        self.entries.push(entry);
+    }
+
+    /// 从下标 before 之前向更早的历史反向搜索包含 query 的命令
+    pub fn search(&self, query: &str, before: usize) -> Option<usize> {
+        self.entries[..before.min(self.entries.len())]
+            .iter()
+            .rposition(|entry| entry.contains(query))
+    }
+}
+
+fn escape(entry: &str) -> String {
+    entry.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+fn unescape(line: &str) -> String {
+    let mut result = String::with_capacity(line.len());
+    let mut chars = line.chars();
+    while let Some(c) = chars.next() {
+        match (c, chars.clone().next()) {
+            ('\\', Some('n')) => {
+                result.push('\n');
+                chars.next();
+            }
+            ('\\', Some('\\')) => {
+                result.push('\\');
+                chars.next();
+            }
+            _ => result.push(c),
+        }
+    }
+    result
+}
+
+/// Ctrl+R 反向搜索的状态
+#[derive(Clone, Debug, Default)]
+struct HistorySearch {
+    query: String,
+    /// 当前匹配的历史下标
+    matched: Option<usize>,
+}
+
+/// 提示符的交互状态
+///
+/// 每次按键都会复制一份状态再修改，不断增长的历史与输出记录放在 Arc 中共享，只在追加时复制。
+#[derive(Clone, Debug, Default)]
+struct PromptState {
+    input: InputBuffer,
+    /// 以 `\` 结尾继续输入的前几行
+    continued: Vec<String>,
+    history: Arc<History>,
+    /// 正在翻阅的历史下标
+    recall: Option<usize>,
+    /// 开始翻阅历史前的输入
+    draft: String,
+    search: Option<HistorySearch>,
+    transcript: Arc<Vec<Line<'static>>>,
+    /// 距离底部的滚动行数
+    scroll: usize,
+    /// 已提交、等待回调的命令
+    pending: Vec<String>,
+}
+
+impl PromptState {
+    fn recall(&mut self, index: Option<usize>) {
+        if self.recall.is_none() {
+            self.draft = self.input.value().to_string();
+        }
+        self.recall = index;
+        let value = match index {
+            Some(index) => self.history.entries()[index].clone(),
+            None => std::mem::take(&mut self.draft),
+        };
+        self.input.set_value(value);
+    }
+
+    /// 把当前输入回显到输出记录并清空，返回完整的多行内容
+    fn take_input(&mut self, prompt: &str, continuation: &str, prompt_style: Style) -> Vec<String> {
+        let mut lines = std::mem::take(&mut self.continued);
+        lines.push(self.input.value().to_string());
+        let transcript = Arc::make_mut(&mut self.transcript);
+        for (i, line) in lines.iter().enumerate() {
+            let prefix = if i == 0 { prompt } else { continuation };
+            transcript.push(Line::from(vec![
+                Span::styled(prefix.to_string(), prompt_style),
+                Span::raw(line.clone()),
+            ]));
+        }
+        self.input = InputBuffer::default();
+        self.recall = None;
+        self.scroll = 0;
+        lines
+    }
+
+    fn submit(&mut self, prompt: &str, continuation: &str, prompt_style: Style) {
+        let command = self
+            .take_input(prompt, continuation, prompt_style)
+            .join("\n");
+        Arc::make_mut(&mut self.history).push(command.clone());
+        self.pending.push(command);
+    }
+
+    /// Ctrl+C：放弃当前输入，在最后一行追加 muted 样式的 `^C`
+    fn abort(&mut self, prompt: &str, continuation: &str, prompt_style: Style, muted: Style) {
+        self.take_input(prompt, continuation, prompt_style);
+        if let Some(last) = Arc::make_mut(&mut self.transcript).last_mut() {
+            last.spans.push(Span::styled("^C", muted));
+        }
+    }
+}
+
+#[derive(Props)]
+pub struct PromptProps<'a> {
+    /// 首行提示符
+    pub prompt: &'a str,
+    /// 多行续行提示符
+    pub continuation: &'a str,
+    /// 历史文件路径，为 None 时历史只保存在内存中
+    pub history_file: Option<PathBuf>,
+    /// 叠加在主题正文样式之上的样式
+    pub style: Style,
+    /// 叠加在主题成功色之上的提示符样式
+    pub prompt_style: Style,
+    /// 是否获得焦点
+    pub focused: bool,
+    /// 提交命令时的回调，返回的行会追加到输出区域
+    pub on_submit: Option<Box<dyn FnMut(String) -> Vec<Line<'static>> + Send + 'a>>,
+}
+
+impl Default for PromptProps<'_> {
+    fn default() -> Self {
+        Self {
+            prompt: "> ",
+            continuation: "... ",
+            history_file: None,
+            style: Style::default(),
+            prompt_style: Style::default().add_modifier(Modifier::BOLD),
+            focused: true,
+            on_submit: None,
+        }
+    }
+}
+
+/// Prompt：交互式命令行组件
+///
+/// 上方为输出记录区域，下方为输入行。支持以下操作：
+/// - ↑/↓ 翻阅历史，Ctrl+R 反向搜索历史（再次 Ctrl+R 查找更早的匹配，Enter 采用，Esc 取消）
+/// - 行尾输入 `\` 后按 Enter 续行，多行内容以换行符连接后提交
+/// - Ctrl+C 放弃当前输入，PageUp/PageDown 滚动输出记录
+///
+/// 渲染循环默认拦截 Ctrl+C 并退出，需要通过 [`Tree::set_exit_on_ctrl_c`](crate::render::tree::Tree::set_exit_on_ctrl_c)
+/// 关闭拦截后 Ctrl+C 才会交给 Prompt 处理。
+pub struct Prompt {
+    state: Option<State<PromptState>>,
+    prompt: String,
+    continuation: String,
+    style: Style,
+    prompt_style: Style,
+    focused: bool,
+}
+
+impl Component for Prompt {
+    type Props<'a> = PromptProps<'a>;
+
+    fn new(props: &Self::Props<'_>) -> Self {
+        Self {
+            state: None,
+            prompt: props.prompt.to_string(),
+            continuation: props.continuation.to_string(),
+            style: props.style,
+            prompt_style: props.prompt_style,
+            focused: props.focused,
+        }
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        mut hooks: Hooks,
+        updater: &mut ComponentUpdater<'_, '_>,
+    ) {
+        let theme = hooks
+            .with_context_stack(updater.component_context_stack())
+            .use_theme();
+        let history_file = props.history_file.clone();
+        let mut state: State<PromptState> = hooks.use_state(move || PromptState {
+            history: Arc::new(history_file.map(History::load).unwrap_or_default()),
+            ..Default::default()
+        });
+
+        // 在 update 中调用回调，并把输出追加到记录区域
+        if !state.read().pending.is_empty() {
+            let pending = std::mem::take(&mut state.write().pending);
+            if let Some(on_submit) = &mut props.on_submit {
+                let output: Vec<_> = pending.into_iter().flat_map(&mut *on_submit).collect();
+                Arc::make_mut(&mut state.write().transcript).extend(output);
+            }
+        }
+
+        let focused = props.focused;
+        let prompt = props.prompt.to_string();
+        let continuation = props.continuation.to_string();
+        let prompt_style = theme.success().patch(props.prompt_style);
+        let muted = theme.muted();
+        hooks.use_events(move |event| {
+            if let Event::Paste(text) = &event {
+                if !focused || state.read().search.is_some() {
+                    return;
+                }
+                // 多行文本除最后一行外都作为续行，粘贴后不会直接提交
+                let mut next = state.read().clone();
+                let mut lines = text.split('\n').map(|line| line.trim_end_matches('\r'));
+                if let Some(first) = lines.next() {
+                    next.input.insert_str(first);
+                }
+                for line in lines {
+                    let done = std::mem::take(&mut next.input);
+                    next.continued.push(done.value().to_string());
+                    next.input.insert_str(line);
+                }
+                state.set(next);
+                return;
+            }
+            let Event::Key(key) = event else {
+                return;
+            };
+            if !focused || key.kind != KeyEventKind::Press {
+                return;
+            }
+            let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+            let mut next = state.read().clone();
+
+            if let Some(search) = &mut next.search {
+                match key.code {
+                    KeyCode::Char('r') if ctrl => {
+                        let before = search.matched.unwrap_or(next.history.entries().len());
+                        if let Some(index) = next.history.search(&search.query, before) {
+                            search.matched = Some(index);
+                        }
+                    }
+                    KeyCode::Char('g') if ctrl => next.search = None,
+                    KeyCode::Esc => next.search = None,
+                    KeyCode::Char(c) if !ctrl => {
+                        search.query.push(c);
+                        search.matched = next
+                            .history
+                            .search(&search.query, next.history.entries().len());
+                    }
+                    KeyCode::Backspace => {
+                        search.query.pop();
+                        search.matched = next
+                            .history
+                            .search(&search.query, next.history.entries().len());
+                    }
+                    KeyCode::Enter => {
+                        if let Some(index) = search.matched {
+                            next.input.set_value(next.history.entries()[index].clone());
+                        }
+                        next.search = None;
+                    }
+                    _ => return,
+                }
+                state.set(next);
+                return;
+            }
+
+            match key.code {
+                KeyCode::Char('r') if ctrl => next.search = Some(HistorySearch::default()),
+                KeyCode::Char('c') if ctrl => {
+                    next.abort(&prompt, &continuation, prompt_style, muted);
+                }
+                KeyCode::Up => {
+                    let index = next.recall.unwrap_or(next.history.entries().len());
+                    if index == 0 {
+                        return;
+                    }
+                    next.recall(Some(index - 1));
+                }
+                KeyCode::Down => {
+                    let Some(index) = next.recall else {
+                        return;
+                    };
+                    let index = Some(index + 1).filter(|i| *i < next.history.entries().len());
+                    next.recall(index);
+                }
+                KeyCode::PageUp => {
+                    next.scroll = (next.scroll + 5).min(next.transcript.len());
+                }
+                KeyCode::PageDown => next.scroll = next.scroll.saturating_sub(5),
+                KeyCode::Enter => {
+                    if let Some(line) = next.input.value().strip_suffix('\\') {
+                        next.continued.push(line.to_string());
+                        next.input = InputBuffer::default();
+                    } else {
+                        next.submit(&prompt, &continuation, prompt_style);
+                    }
+                }
+                _ => {
+                    if !next.input.handle_key(key) {
+                        return;
+                    }
+                }
+            }
+            state.set(next);
+        });
+
+        self.state = Some(state);
+        self.prompt = props.prompt.to_string();
+        self.continuation = props.continuation.to_string();
+        self.style = theme.text.patch(props.style);
+        self.prompt_style = prompt_style;
+        self.focused = props.focused;
+    }
+
+    fn draw(&self, drawer: &mut ComponentDrawer<'_, '_>) {
+        if self.focused {
+            drawer.mark_focused();
+        }
+        let Some(state) = self.state else {
+            return;
+        };
+        let state = state.read();
+        let mut input_lines: Vec<Line> = state
+            .continued
+            .iter()
+            .enumerate()
+            .map(|(i, line)| {
+                let prefix = if i == 0 {
+                    &self.prompt
+                } else {
+                    &self.continuation
+                };
+                Line::from(vec![
+                    Span::styled(prefix.as_str(), self.prompt_style),
+                    Span::styled(line.as_str(), self.style),
+                ])
+            })
+            .collect();
+
+        let current = if let Some(search) = &state.search {
+            let matched = search
+                .matched
+                .map(|index| state.history.entries()[index].replace('\n', " "))
+                .unwrap_or_default();
+            Line::from(vec![
+                Span::styled(
+                    format!("(reverse-i-search)'{}': ", search.query),
+                    self.prompt_style,
+                ),
+                Span::styled(matched, self.style),
+            ])
+        } else {
+            let prefix = if state.continued.is_empty() {
+                &self.prompt
+            } else {
+                &self.continuation
+            };
+            let mut line = state.input.line(self.style, self.focused);
+            line.spans
+                .insert(0, Span::styled(prefix.clone(), self.prompt_style));
+            line
+        };
+        input_lines.push(current);
+
+        let [transcript_area, input_area] = Layout::vertical([
+            Constraint::Fill(1),
+            Constraint::Length(input_lines.len() as u16),
+        ])
+        .areas(drawer.area);
+
+        // 默认停留在输出记录的底部
+        let height = transcript_area.height as usize;
+        let offset = state
+            .transcript
+            .len()
+            .saturating_sub(height)
+            .saturating_sub(state.scroll);
+        let transcript: Vec<Line> = state
+            .transcript
+            .iter()
+            .skip(offset)
+            .take(height)
+            .cloned()
+            .collect();
+        drawer.render_widget(Paragraph::new(transcript), transcript_area);
+        drawer.render_widget(Paragraph::new(input_lines), input_area);
+    }
+}
@@ -0,0 +1,244 @@
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use crossterm::event::{Event, KeyCode, KeyEventKind, KeyModifiers, MouseButton, MouseEventKind};
+use ratatui::{
+    layout::{Position, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::Paragraph,
+};
+
+use crate::{
+    animation::Easing,
+    component::Component,
+    components::{Selection, list::scroll_offset},
+    hooks::{
+        Hooks, use_animation::UseAnimation, use_events::UseEvents, use_state::UseState,
+        use_theme::UseTheme,
+    },
+    props::{Handler, Props},
+    render::{drawer::ComponentDrawer, updater::ComponentUpdater},
+};
+
+#[derive(Props)]
+pub struct ReorderableListProps<'a> {
+    pub items: Vec<Line<'static>>,
+    /// 是否响应键盘操作
+    pub focused: bool,
+    /// 光标所在行的样式
+    pub highlight_style: Style,
+    /// 拖拽占位行的样式，叠加在主题警示色之上
+    pub placeholder_style: Style,
+    /// 条目从 from 移动到 to 时的回调，由调用方更新 items
+    pub on_reorder: Handler<'a, (usize, usize)>,
+}
+
+impl Default for ReorderableListProps<'_> {
+    fn default() -> Self {
+        Self {
+            items: Vec::new(),
+            focused: true,
+            highlight_style: Style::default().add_modifier(Modifier::REVERSED),
+            placeholder_style: Style::default().add_modifier(Modifier::BOLD),
+            on_reorder: Handler::default(),
+        }
+    }
+}
+
+/// 拖拽目标变化时占位行滑动到新位置的时长
+const GAP_DURATION: Duration = Duration::from_millis(120);
+
+/// 列表的交互状态
+#[derive(Clone, Debug, Default)]
+struct ReorderState {
+    selection: Selection,
+    /// 正在拖拽的条目与目标位置
+    drag: Option<(usize, usize)>,
+    /// 等待回调的移动
+    pending: Vec<(usize, usize)>,
+}
+
+/// ReorderableList：可调整顺序的列表
+///
+/// Alt+↑/↓ 把光标所在条目上移或下移；也可以用鼠标按住条目拖动，
+/// 拖动过程中在目标位置显示占位行，目标变化时占位行通过 `use_animation` 滑动过去
+/// （开启 reduced_motion 时直接跳到目标位置），松开后触发 on_reorder。
+pub struct ReorderableList {
+    items: Vec<Line<'static>>,
+    state: ReorderState,
+    /// 占位行当前所在的行，拖拽中随动画逼近目标位置
+    gap: usize,
+    /// 上一次 update 时是否正在拖拽
+    dragging: bool,
+    highlight_style: Style,
+    placeholder_style: Style,
+    /// 最近一次绘制的区域与滚动位置，用于鼠标命中判断
+    geometry: Arc<Mutex<(Rect, usize)>>,
+    focused: bool,
+}
+
+impl Component for ReorderableList {
+    type Props<'a> = ReorderableListProps<'a>;
+
+    fn new(props: &Self::Props<'_>) -> Self {
+        Self {
+            items: Vec::new(),
+            state: ReorderState::default(),
+            gap: 0,
+            dragging: false,
+            highlight_style: props.highlight_style,
+            placeholder_style: props.placeholder_style,
+            geometry: Arc::default(),
+            focused: props.focused,
+        }
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        mut hooks: Hooks,
+        updater: &mut ComponentUpdater<'_, '_>,
+    ) {
+        self.focused = props.focused;
+        let mut state = hooks.use_state(ReorderState::default);
+
+        if !state.read().pending.is_empty() {
+            let pending = std::mem::take(&mut state.write().pending);
+            for (from, to) in pending {
+                props.on_reorder.invoke((from, to));
+            }
+        }
+
+        let len = props.items.len();
+        let focused = props.focused;
+        let geometry = self.geometry.clone();
+        hooks.use_events(move |event| {
+            let mut next = state.read().clone();
+            let changed = match event {
+                Event::Key(key) if focused && key.kind == KeyEventKind::Press => {
+                    let cursor = next.selection.cursor();
+                    if key.modifiers.contains(KeyModifiers::ALT) {
+                        let target = match key.code {
+                            KeyCode::Up => cursor.checked_sub(1),
+                            KeyCode::Down => Some(cursor + 1).filter(|i| *i < len),
+                            _ => None,
+                        };
+                        target.is_some_and(|target| {
+                            next.pending.push((cursor, target));
+                            next.selection.set_cursor(target, len);
+                            true
+                        })
+                    } else {
+                        next.selection.handle_key(key, len, false, 1)
+                    }
+                }
+                Event::Mouse(mouse) => {
+                    let (area, offset) = *geometry.lock().unwrap();
+                    let position = Position::new(mouse.column, mouse.row);
+                    let row = (mouse.row.saturating_sub(area.y) as usize + offset)
+                        .min(len.saturating_sub(1));
+                    match mouse.kind {
+                        MouseEventKind::Down(MouseButton::Left)
+                            if area.contains(position) && row < len =>
+                        {
+                            next.selection.set_cursor(row, len);
+                            next.drag = Some((row, row));
+                            true
+                        }
+                        MouseEventKind::Drag(MouseButton::Left) => match &mut next.drag {
+                            Some((_, to)) if *to != row => {
+                                *to = row;
+                                true
+                            }
+                            _ => false,
+                        },
+                        MouseEventKind::Up(MouseButton::Left) => match next.drag.take() {
+                            Some((from, to)) => {
+                                if from != to {
+                                    next.pending.push((from, to));
+                                }
+                                next.selection.set_cursor(to, len);
+                                true
+                            }
+                            None => false,
+                        },
+                        _ => false,
+                    }
+                }
+                _ => false,
+            };
+            if changed {
+                state.set(next);
+            }
+        });
+
+        self.items = props.items.clone();
+        self.state = state.read().clone();
+        self.state.selection.clamp(len);
+        self.highlight_style = props.highlight_style;
+
+        // 拖拽开始时占位行直接出现在原位置，之后目标每次变化都从当前位置滑动过去
+        let dragging = self.state.drag.is_some();
+        let target = self.state.drag.map_or(self.gap, |(_, to)| to);
+        let duration = if dragging && self.dragging {
+            GAP_DURATION
+        } else {
+            Duration::ZERO
+        };
+        let (theme, gap) = {
+            let mut hooks = hooks.with_context_stack(updater.component_context_stack());
+            (
+                hooks.use_theme(),
+                hooks.use_animation(target, target, duration, Easing::CubicOut),
+            )
+        };
+        self.gap = gap;
+        self.dragging = dragging;
+        self.placeholder_style = theme.warning().patch(props.placeholder_style);
+    }
+
+    fn draw(&self, drawer: &mut ComponentDrawer<'_, '_>) {
+        if self.focused {
+            drawer.mark_focused();
+        }
+        let area = drawer.area;
+        let height = area.height as usize;
+        let cursor = self.state.selection.cursor();
+
+        // 拖拽时把条目从原位置取出，作为占位行插入到动画中的位置
+        let mut rows = self.items.clone();
+        let focus = match self.state.drag {
+            Some((from, to)) if from < rows.len() => {
+                let item = rows.remove(from);
+                let mut placeholder = Line::from(Span::styled("▶ ", self.placeholder_style));
+                placeholder
+                    .spans
+                    .extend(item.patch_style(self.placeholder_style).spans);
+                rows.insert(self.gap.min(rows.len()), placeholder);
+                to
+            }
+            _ => cursor,
+        };
+
+        let offset = scroll_offset(focus, height);
+        *self.geometry.lock().unwrap() = (area, offset);
+
+        let lines: Vec<Line> = rows
+            .into_iter()
+            .enumerate()
+            .skip(offset)
+            .take(height)
+            .map(|(position, line)| {
+                if self.state.drag.is_none() && position == cursor {
+                    line.patch_style(self.highlight_style)
+                } else {
+                    line
+                }
+            })
+            .collect();
+        drawer.render_widget(Paragraph::new(lines), area);
+    }
+}
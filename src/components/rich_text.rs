@@ -0,0 +1,240 @@
+use ratatui::{
+    layout::{Alignment, Rect, Size},
+    style::Style,
+    text::{self, Text},
+    widgets::Paragraph,
+};
+
+use crate::{
+    component::{Component, instantiated_component::Components},
+    context::SystemContext,
+    element::AnyElement,
+    hooks::Hooks,
+    props::Props,
+    render::{drawer::ComponentDrawer, layout_style::LayoutStyle, updater::ComponentUpdater},
+    wrap::WrapCache,
+};
+
+#[derive(Default, Props)]
+pub struct SpanProps<'a> {
+    /// 文本内容
+    pub text: &'a str,
+    /// 文本样式
+    pub style: Style,
+}
+
+/// Span：一段带样式的内联文本，作为 Line/RichText 的子元素使用
+///
+/// Span 本身不绘制任何内容，由外层的 Line 或 RichText 收集后统一排版。
+pub struct Span {
+    span: text::Span<'static>,
+}
+
+impl Span {
+    /// 获取对应的 ratatui Span
+    pub fn span(&self) -> &text::Span<'static> {
+        &self.span
+    }
+}
+
+impl Component for Span {
+    type Props<'a> = SpanProps<'a>;
+
+    fn new(props: &Self::Props<'_>) -> Self {
+        Self {
+            span: text::Span::styled(props.text.to_string(), props.style),
+        }
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        _hooks: Hooks,
+        _updater: &mut ComponentUpdater<'_, '_>,
+    ) {
+        self.span = text::Span::styled(props.text.to_string(), props.style);
+    }
+
+    fn measure(&self) -> Option<Size> {
+        Some(Size::new(self.span.width() as u16, 1))
+    }
+}
+
+#[derive(Default, Props)]
+pub struct LineProps<'a> {
+    /// 整行的基础样式，会与子 Span 的样式叠加
+    pub style: Style,
+    /// 对齐方式
+    pub alignment: Option<Alignment>,
+    pub children: Vec<AnyElement<'a>>,
+}
+
+/// Line：由若干 Span 组成的一行文本
+///
+/// 可以单独使用（直接绘制到自身区域），也可以作为 RichText 的子元素组成多行文本。
+pub struct Line {
+    line: text::Line<'static>,
+}
+
+impl Line {
+    /// 获取收集好的 ratatui Line
+    pub fn line(&self) -> &text::Line<'static> {
+        &self.line
+    }
+}
+
+impl Component for Line {
+    type Props<'a> = LineProps<'a>;
+
+    fn new(_props: &Self::Props<'_>) -> Self {
+        Self {
+            line: text::Line::default(),
+        }
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        _hooks: Hooks,
+        updater: &mut ComponentUpdater<'_, '_>,
+    ) {
+        updater.update_children(props.children.iter_mut(), None);
+
+        let mut line = text::Line::from(collect_spans(updater.components())).style(props.style);
+        line.alignment = props.alignment;
+        self.line = line;
+    }
+
+    fn measure(&self) -> Option<Size> {
+        Some(Size::new(self.line.width() as u16, 1))
+    }
+
+    fn calc_children_areas(
+        &self,
+        children: &Components,
+        _layout_style: &LayoutStyle,
+        _drawer: &mut ComponentDrawer<'_, '_>,
+    ) -> Vec<Rect> {
+        // 子 Span 由当前组件统一绘制，不再分配区域
+        vec![Rect::default(); children.iter().count()]
+    }
+
+    fn draw(&self, drawer: &mut ComponentDrawer<'_, '_>) {
+        drawer.render_widget(&self.line, drawer.area);
+    }
+}
+
+#[derive(Default, Props)]
+pub struct RichTextProps<'a> {
+    /// 整段文本的基础样式
+    pub style: Style,
+    /// 对齐方式
+    pub alignment: Alignment,
+    /// 是否自动换行
+    pub wrap: bool,
+    pub children: Vec<AnyElement<'a>>,
+}
+
+/// RichText：声明式的富文本组件
+///
+/// 子元素可以是 Span 或 Line：连续的 Span 会合并为同一行，Line 则单独成行，例如：
+///
+/// ```ignore
+/// element!(RichText {
+///     Span(text: "press ")
+///     Span(text: "Enter", style: Style::default().bold())
+///     Span(text: " to continue")
+/// })
+/// ```
+pub struct RichText {
+    text: Text<'static>,
+    alignment: Alignment,
+    wrap: bool,
+    wrap_cache: WrapCache,
+}
+
+impl Component for RichText {
+    type Props<'a> = RichTextProps<'a>;
+
+    fn new(props: &Self::Props<'_>) -> Self {
+        Self {
+            text: Text::default(),
+            alignment: props.alignment,
+            wrap: props.wrap,
+            wrap_cache: WrapCache::default(),
+        }
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        _hooks: Hooks,
+        updater: &mut ComponentUpdater<'_, '_>,
+    ) {
+        updater.update_children(props.children.iter_mut(), None);
+
+        let mut lines = Vec::new();
+        let mut current = Vec::new();
+        for child in updater.components().iter() {
+            if let Some(span) = child.downcast_ref::<Span>() {
+                current.push(span.span().clone());
+            } else if let Some(line) = child.downcast_ref::<Line>() {
+                // 遇到 Line 时先结束当前累积的内联 Span
+                if !current.is_empty() {
+                    lines.push(text::Line::from(std::mem::take(&mut current)));
+                }
+                lines.push(line.line().clone());
+            }
+        }
+        if !current.is_empty() {
+            lines.push(text::Line::from(current));
+        }
+
+        self.text = Text::from(lines).style(props.style);
+        self.alignment = props.alignment;
+        self.wrap = props.wrap;
+        if let Some(system) = updater
+            .component_context_stack()
+            .get_context::<SystemContext>()
+        {
+            self.wrap_cache = system.wrap_cache();
+        }
+    }
+
+    fn measure(&self) -> Option<Size> {
+        Some(Size::new(
+            self.text.width() as u16,
+            self.text.height() as u16,
+        ))
+    }
+
+    fn calc_children_areas(
+        &self,
+        children: &Components,
+        _layout_style: &LayoutStyle,
+        _drawer: &mut ComponentDrawer<'_, '_>,
+    ) -> Vec<Rect> {
+        vec![Rect::default(); children.iter().count()]
+    }
+
+    fn draw(&self, drawer: &mut ComponentDrawer<'_, '_>) {
+        // 换行结果按内容与宽度缓存，尺寸不变时不再重新换行
+        let text = if self.wrap {
+            let lines = self.wrap_cache.wrap(&self.text, drawer.area.width);
+            Text::from(lines.to_vec()).style(self.text.style)
+        } else {
+            self.text.clone()
+        };
+        let paragraph = Paragraph::new(text).alignment(self.alignment);
+        drawer.render_widget(paragraph, drawer.area);
+    }
+}
+
+/// 从子组件中收集所有 Span
+fn collect_spans(children: &Components) -> Vec<text::Span<'static>> {
+    children
+        .iter()
+        .filter_map(|child| child.downcast_ref::<Span>())
+        .map(|span| span.span().clone())
+        .collect()
+}
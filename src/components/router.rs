@@ -0,0 +1,133 @@
+use std::collections::HashMap;
+
+use crate::{
+    component::Component,
+    context::{Context, SystemContext},
+    element::{AnyElement, Children},
+    hooks::{
+        Hooks,
+        use_navigate::{RouteParams, UseLocationImpl},
+    },
+    props::Props,
+    render::updater::ComponentUpdater,
+};
+
+/// 用路由模式匹配位置，成功时返回匹配到的参数
+///
+/// 模式按 `/` 分段逐一比较：`:name` 匹配任意一段并记录为参数，
+/// 末尾的 `*` 匹配剩余的所有段（可以为空），其余段需要完全相同。
+///
+/// ```ignore
+/// let params = match_path("/users/:id", "/users/42").unwrap();
+/// assert_eq!(params["id"], "42");
+/// ```
+pub fn match_path(pattern: &str, location: &str) -> Option<HashMap<String, String>> {
+    let mut params = HashMap::new();
+    let mut segments = location.split('/').filter(|segment| !segment.is_empty());
+    for part in pattern.split('/').filter(|part| !part.is_empty()) {
+        if part == "*" {
+            params.insert("*".to_string(), segments.collect::<Vec<_>>().join("/"));
+            return Some(params);
+        }
+        let segment = segments.next()?;
+        match part.strip_prefix(':') {
+            Some(name) => {
+                params.insert(name.to_string(), segment.to_string());
+            }
+            None if part == segment => {}
+            None => return None,
+        }
+    }
+    segments.next().is_none().then_some(params)
+}
+
+#[derive(Default, Props)]
+pub struct RouteProps<'a> {
+    /// 路由模式，见 [`match_path`]
+    pub path: String,
+    pub children: Children<'a>,
+}
+
+/// Route：`Routes` 中的一个页面，只在匹配当前位置时显示
+pub struct Route;
+
+impl Component for Route {
+    type Props<'a> = RouteProps<'a>;
+
+    fn new(_props: &Self::Props<'_>) -> Self {
+        Self
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        _hooks: Hooks,
+        updater: &mut ComponentUpdater<'_, '_>,
+    ) {
+        updater.update_children(props.children.iter_mut(), None);
+    }
+}
+
+#[derive(Default, Props)]
+pub struct RoutesProps<'a> {
+    /// 候选的 `Route`，其他类型的子元素会被忽略
+    pub children: Children<'a>,
+}
+
+/// Routes：按 [`SystemContext::navigator`] 的当前位置显示第一个匹配的 `Route`
+///
+/// 匹配到的参数以 [`RouteParams`] 的形式提供给该 `Route` 的子树，通过 `use_params` 读取。
+/// 切换到其他 `Route` 时原页面会被卸载。嵌套的 `Routes` 同样使用完整路径匹配，
+/// 外层可以用 `/settings/*` 这样的模式把一组页面交给内层处理。
+///
+/// ```ignore
+/// element!(Routes {
+///     Route(path: "/") { Home }
+///     Route(path: "/users/:id") { UserDetail }
+///     Route(path: "*") { NotFound }
+/// })
+/// ```
+pub struct Routes;
+
+impl Component for Routes {
+    type Props<'a> = RoutesProps<'a>;
+
+    fn new(_props: &Self::Props<'_>) -> Self {
+        Self
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        mut hooks: Hooks,
+        updater: &mut ComponentUpdater<'_, '_>,
+    ) {
+        let navigator = updater
+            .component_context_stack()
+            .get_context::<SystemContext>()
+            .map(|system| system.navigator())
+            .unwrap_or_default();
+        hooks.use_hook(UseLocationImpl::new).subscribe(&navigator);
+
+        let location = navigator.location();
+        let matched = props.children.iter_mut().find_map(|child| {
+            if !child.is::<Route>() {
+                return None;
+            }
+            // SAFETY: 上面已经确认元素的组件类型为 Route
+            let route = unsafe { child.props.downcast_ref_unchecked::<RouteProps>() };
+            let params = match_path(&route.path, &location)?;
+            Some((child, params))
+        });
+        match matched {
+            Some((route, params)) => updater.update_children(
+                [route],
+                Some(Context::owned(RouteParams {
+                    location: location.clone(),
+                    params,
+                })),
+            ),
+            None => updater.update_children(Vec::<AnyElement>::new(), None),
+        }
+    }
+}
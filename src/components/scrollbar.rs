@@ -0,0 +1,185 @@
+use std::sync::{
+    Arc,
+    atomic::{AtomicUsize, Ordering},
+};
+
+use crossterm::event::{Event, KeyEventKind, MouseButton};
+use ratatui::{
+    layout::{Constraint, Size},
+    style::Style,
+};
+
+use crate::{
+    component::Component,
+    hooks::{
+        Hooks,
+        use_events::UseEvents,
+        use_mouse::{MouseTransition, UseMouse},
+        use_scroll::{ScrollState, UseScroll},
+        use_state::State,
+        use_theme::UseTheme,
+    },
+    props::Props,
+    render::{drawer::ComponentDrawer, layout_style::LayoutStyle, updater::ComponentUpdater},
+};
+
+#[derive(Default, Props)]
+pub struct ScrollbarProps {
+    /// 与滚动内容共享的滚动状态，通过 `use_scroll` 创建
+    pub state: Option<State<ScrollState>>,
+    /// 是否响应 ↑/↓、PageUp/PageDown、Home/End，内容组件自身处理按键时保持关闭
+    pub focused: bool,
+    /// 叠加在主题次要色之上的轨道样式
+    pub style: Style,
+    /// 叠加在主题主色之上的滑块样式
+    pub thumb_style: Style,
+}
+
+/// 轨道长度为 track 时滑块的起始位置与长度
+fn thumb(state: &ScrollState, track: usize) -> (usize, usize) {
+    if !state.is_scrollable() || track == 0 {
+        return (0, track);
+    }
+    let len = (state.viewport() * track / state.content()).clamp(1, track);
+    let start = (state.offset() * (track - len) + state.max_offset() / 2) / state.max_offset();
+    (start, len)
+}
+
+/// 滑块起始位置对应的滚动位置
+fn offset_at(state: &ScrollState, track: usize, start: usize) -> usize {
+    let (_, len) = thumb(state, track);
+    let range = track.saturating_sub(len);
+    if range == 0 {
+        return 0;
+    }
+    (start.min(range) * state.max_offset() + range / 2) / range
+}
+
+/// Scrollbar：与 [`ScrollState`] 同步的纵向滚动条
+///
+/// 宽度为一列，放在滚动内容的右侧。拖动滑块滚动到对应位置，点击滑块上方、下方的轨道向上、向下翻页；
+/// 开启 focused 后也可以用键盘滚动。内容没有超出可见区域时只绘制轨道。
+///
+/// ```ignore
+/// let scroll = hooks.use_scroll();
+/// element!(View(flex_direction: Direction::Horizontal) {
+///     List(items: items, scroll: scroll)
+///     Scrollbar(state: scroll)
+/// })
+/// ```
+pub struct Scrollbar {
+    /// 在绘制时读取，同一帧中先绘制的内容组件写入的 content 与 viewport 立即生效
+    state: Option<State<ScrollState>>,
+    style: Style,
+    thumb_style: Style,
+    /// 拖动时按下位置相对滑块顶部的偏移
+    grab: Option<usize>,
+    /// 最近一次绘制时的轨道长度
+    track: Arc<AtomicUsize>,
+    focused: bool,
+}
+
+impl Component for Scrollbar {
+    type Props<'a> = ScrollbarProps;
+
+    fn new(props: &Self::Props<'_>) -> Self {
+        Self {
+            state: None,
+            style: props.style,
+            thumb_style: props.thumb_style,
+            grab: None,
+            track: Arc::default(),
+            focused: props.focused,
+        }
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        mut hooks: Hooks,
+        updater: &mut ComponentUpdater<'_, '_>,
+    ) {
+        self.focused = props.focused;
+        let theme = hooks
+            .with_context_stack(updater.component_context_stack())
+            .use_theme();
+        let internal = hooks.use_scroll();
+        let mut state = props.state.unwrap_or(internal);
+
+        let focused = props.focused;
+        hooks.use_events(move |event| {
+            if let Event::Key(key) = event
+                && focused
+                && key.kind == KeyEventKind::Press
+            {
+                let mut next = state.get();
+                if next.handle_key(key) {
+                    state.set(next);
+                }
+            }
+        });
+
+        let track = self.track.load(Ordering::Relaxed);
+        let current = state.get();
+        let mut next = current;
+        match hooks.use_mouse().transition {
+            Some(MouseTransition::Pressed(MouseButton::Left, position)) => {
+                let (start, len) = thumb(&current, track);
+                let y = position.y as usize;
+                if y < start {
+                    next.page_up();
+                } else if y >= start + len {
+                    next.page_down();
+                } else {
+                    self.grab = Some(y - start);
+                }
+            }
+            Some(MouseTransition::Dragged(MouseButton::Left, position)) => {
+                if let Some(grab) = self.grab {
+                    let start = (position.y as usize).saturating_sub(grab);
+                    next.scroll_to(offset_at(&current, track, start));
+                }
+            }
+            Some(MouseTransition::Released(..)) => self.grab = None,
+            _ => {}
+        }
+        if next != current {
+            state.set(next);
+        }
+
+        self.state = Some(state);
+        self.style = theme.muted().patch(props.style);
+        self.thumb_style = theme.primary().patch(props.thumb_style);
+        updater.set_layout_style(LayoutStyle {
+            width: Constraint::Length(1),
+            ..Default::default()
+        });
+    }
+
+    fn measure(&self) -> Option<Size> {
+        Some(Size::new(1, 0))
+    }
+
+    fn draw(&self, drawer: &mut ComponentDrawer<'_, '_>) {
+        if self.focused {
+            drawer.mark_focused();
+        }
+        let area = drawer.area;
+        self.track.store(area.height as usize, Ordering::Relaxed);
+        if area.is_empty() {
+            return;
+        }
+        let state = self.state.map(|state| state.get()).unwrap_or_default();
+        let (start, len) = thumb(&state, area.height as usize);
+        let scrollable = state.is_scrollable();
+        let buffer = drawer.buffer_mut();
+        for (index, y) in (area.top()..area.bottom()).enumerate() {
+            let (symbol, style) = if scrollable && (start..start + len).contains(&index) {
+                ("█", self.thumb_style)
+            } else {
+                ("│", self.style)
+            };
+            buffer[(area.x, y)].set_symbol(symbol).set_style(style);
+        }
+    }
+}
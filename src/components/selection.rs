@@ -0,0 +1,144 @@
+use std::collections::BTreeSet;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+/// Selection：List/Table 共用的选择模型
+///
+/// 维护光标位置与选中集合。单选模式下选中集合始终为空，以光标所在行作为当前项；
+/// 多选模式下支持 Space 切换、Shift+↑/↓ 范围选择与 Ctrl+A 全选。
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Selection {
+    cursor: usize,
+    selected: BTreeSet<usize>,
+    /// 范围选择的起点，以及开始范围选择前已选中的集合
+    anchor: Option<(usize, BTreeSet<usize>)>,
+}
+
+impl Selection {
+    /// 光标所在行
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    /// 已选中的行（多选模式）
+    pub fn selected(&self) -> &BTreeSet<usize> {
+        &self.selected
+    }
+
+    pub fn is_selected(&self, index: usize) -> bool {
+        self.selected.contains(&index)
+    }
+
+    /// 移动光标，超出范围时截断到最后一行
+    pub fn set_cursor(&mut self, cursor: usize, len: usize) {
+        self.cursor = cursor.min(len.saturating_sub(1));
+        self.anchor = None;
+    }
+
+    /// 切换某一行的选中状态
+    pub fn toggle(&mut self, index: usize) {
+        if !self.selected.remove(&index) {
+            self.selected.insert(index);
+        }
+        self.anchor = None;
+    }
+
+    /// 全选；已经全选时取消全选
+    pub fn select_all(&mut self, len: usize) {
+        if self.selected.len() == len {
+            self.selected.clear();
+        } else {
+            self.selected = (0..len).collect();
+        }
+        self.anchor = None;
+    }
+
+    pub fn clear(&mut self) {
+        self.selected.clear();
+        self.anchor = None;
+    }
+
+    /// 列表长度变化后移除越界的选择
+    pub fn clamp(&mut self, len: usize) {
+        self.cursor = self.cursor.min(len.saturating_sub(1));
+        self.selected.retain(|index| *index < len);
+        if self
+            .anchor
+            .as_ref()
+            .is_some_and(|(anchor, _)| *anchor >= len)
+        {
+            self.anchor = None;
+        }
+    }
+
+    /// 以 Shift 扩展范围的方式移动光标
+    fn extend_to(&mut self, cursor: usize) {
+        let (anchor, base) = self
+            .anchor
+            .get_or_insert_with(|| (self.cursor, self.selected.clone()));
+        let range = (*anchor).min(cursor)..=(*anchor).max(cursor);
+        self.selected = base.iter().copied().chain(range).collect();
+        self.cursor = cursor;
+    }
+
+    /// 导航按键对应的目标行
+    fn target(&self, key: KeyEvent, last: usize, page: usize) -> Option<usize> {
+        let target = match key.code {
+            KeyCode::Up | KeyCode::Char('k') => self.cursor.saturating_sub(1),
+            KeyCode::Down | KeyCode::Char('j') => (self.cursor + 1).min(last),
+            KeyCode::PageUp => self.cursor.saturating_sub(page.max(1)),
+            KeyCode::PageDown => (self.cursor + page.max(1)).min(last),
+            KeyCode::Home | KeyCode::Char('g') => 0,
+            KeyCode::End | KeyCode::Char('G') => last,
+            _ => return None,
+        };
+        Some(target)
+    }
+
+    /// 处理导航与选择按键，返回按键是否被消费
+    ///
+    /// page 为 PageUp/PageDown 一次移动的行数。
+    pub fn handle_key(&mut self, key: KeyEvent, len: usize, multi: bool, page: usize) -> bool {
+        if len == 0 {
+            return false;
+        }
+        match key.code {
+            KeyCode::Char(' ') if multi => {
+                self.toggle(self.cursor);
+                return true;
+            }
+            KeyCode::Char('a') if multi && key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.select_all(len);
+                return true;
+            }
+            _ => {}
+        }
+        let Some(target) = self.target(key, len - 1, page) else {
+            return false;
+        };
+        // 字符按键（如 G）本身带有 Shift 修饰，不视为范围选择
+        let shift = multi
+            && key.modifiers.contains(KeyModifiers::SHIFT)
+            && !matches!(key.code, KeyCode::Char(_));
+        if shift {
+            self.extend_to(target);
+        } else {
+            self.set_cursor(target, len);
+        }
+        true
+    }
+
+    /// Visual 模式下的按键处理：所有移动都从进入时的光标位置扩展范围
+    pub fn handle_visual_key(&mut self, key: KeyEvent, len: usize, page: usize) -> bool {
+        if len == 0 {
+            return false;
+        }
+        match self.target(key, len - 1, page) {
+            Some(target) => {
+                self.extend_to(target);
+                true
+            }
+            None => false,
+        }
+    }
+}
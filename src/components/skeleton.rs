@@ -0,0 +1,216 @@
+use std::time::Duration;
+
+use ratatui::{
+    layout::{Rect, Size},
+    style::{Color, Style},
+};
+
+use crate::{
+    component::{Component, flex_children_areas, instantiated_component::Components},
+    context::{Context, SystemContext},
+    element::AnyElement,
+    hooks::{
+        Hooks,
+        use_context::UseContext,
+        use_interval::UseInterval,
+        use_resource::{LoadingTracker, LoadingWatcher},
+        use_state::UseState,
+        use_theme::UseTheme,
+    },
+    props::Props,
+    render::{drawer::ComponentDrawer, layout_style::LayoutStyle, updater::ComponentUpdater},
+};
+
+/// 占位块的形状
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SkeletonShape {
+    /// 若干行文本，最后一行较短
+    Lines(u16),
+    /// 填满整个区域的块
+    Box,
+}
+
+impl Default for SkeletonShape {
+    fn default() -> Self {
+        Self::Lines(3)
+    }
+}
+
+/// 高光带的宽度
+const SHIMMER_WIDTH: u16 = 6;
+
+#[derive(Props)]
+pub struct SkeletonProps<'a> {
+    pub shape: SkeletonShape,
+    /// 是否处于加载中，为 false 时渲染子组件
+    pub loading: bool,
+    /// 作为加载边界，忽略 loading：子组件始终挂载，子树中的请求未完成时显示占位块
+    pub suspense: bool,
+    /// 叠加在主题次要色之上的样式
+    pub style: Style,
+    /// 高光带的样式
+    pub shimmer_style: Style,
+    pub children: Vec<AnyElement<'a>>,
+}
+
+impl Default for SkeletonProps<'_> {
+    fn default() -> Self {
+        Self {
+            shape: SkeletonShape::default(),
+            loading: true,
+            suspense: false,
+            style: Style::default(),
+            shimmer_style: Style::default().fg(Color::Gray),
+            children: Vec::new(),
+        }
+    }
+}
+
+/// Skeleton：加载占位组件
+///
+/// 加载中时按声明的形状绘制占位块，并有一条高光带从左到右扫过；
+/// 加载完成后渲染子组件，可以直接作为异步数据的回退内容使用：
+///
+/// ```ignore
+/// element!(Skeleton(loading: resource.is_loading(), shape: SkeletonShape::Lines(2)) {
+///     Text(text: resource.value().unwrap_or_default())
+/// })
+/// ```
+///
+/// 开启 suspense 后作为 Suspense 式的加载边界：子组件始终挂载，
+/// 子树中通过 `use_resource`/`use_query` 发起的请求未完成时显示占位块，全部完成后显示子组件，
+/// 不需要把加载状态逐层传上来：
+///
+/// ```ignore
+/// element!(Skeleton(suspense: true, shape: SkeletonShape::Box) {
+///     UserCard(id: 42)
+/// })
+/// ```
+///
+/// 高光带只在加载中播放，加载完成后不再定时刷新。
+/// 开启 [`SystemContext::reduced_motion`] 时只绘制静态的占位块。
+pub struct Skeleton {
+    shape: SkeletonShape,
+    loading: bool,
+    style: Style,
+    shimmer_style: Style,
+    frame: u16,
+    animated: bool,
+}
+
+impl Component for Skeleton {
+    type Props<'a> = SkeletonProps<'a>;
+
+    fn new(props: &Self::Props<'_>) -> Self {
+        Self {
+            shape: props.shape,
+            loading: props.loading,
+            style: props.style,
+            shimmer_style: props.shimmer_style,
+            frame: 0,
+            animated: false,
+        }
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        mut hooks: Hooks,
+        updater: &mut ComponentUpdater<'_, '_>,
+    ) {
+        let (reduced_motion, theme) = {
+            let hooks = hooks.with_context_stack(updater.component_context_stack());
+            let context = hooks.use_context::<SystemContext>();
+            (context.reduced_motion(), hooks.use_theme())
+        };
+
+        // 子树中的请求登记在 boundary 上，计数变化时重新渲染
+        let boundary = hooks
+            .use_hook(|| LoadingWatcher::new(LoadingTracker::default()))
+            .tracker
+            .clone();
+        let loading = if props.suspense {
+            updater.update_children(
+                props.children.iter_mut(),
+                Some(Context::owned(boundary.clone())),
+            );
+            boundary.is_loading()
+        } else {
+            if props.loading {
+                updater.update_children(Vec::<AnyElement>::new(), None);
+            } else {
+                updater.update_children(props.children.iter_mut(), None);
+            }
+            props.loading
+        };
+
+        let mut frame = hooks.use_state(|| 0u16);
+        let animated = loading && !reduced_motion;
+        hooks.use_interval_while(animated, Duration::from_millis(80), move || {
+            frame.set(frame.get().wrapping_add(1))
+        });
+
+        self.shape = props.shape;
+        self.loading = loading;
+        self.style = theme.muted().patch(props.style);
+        self.shimmer_style = props.shimmer_style;
+        self.frame = frame.get();
+        self.animated = animated;
+    }
+
+    fn measure(&self) -> Option<Size> {
+        if !self.loading {
+            return None;
+        }
+        match self.shape {
+            SkeletonShape::Lines(lines) => Some(Size::new(20, lines)),
+            SkeletonShape::Box => None,
+        }
+    }
+
+    fn calc_children_areas(
+        &self,
+        children: &Components,
+        layout_style: &LayoutStyle,
+        drawer: &mut ComponentDrawer<'_, '_>,
+    ) -> Vec<Rect> {
+        // 加载中保持挂载的子组件不参与绘制
+        if self.loading {
+            return Vec::new();
+        }
+        flex_children_areas(children, layout_style, drawer)
+    }
+
+    fn draw(&self, drawer: &mut ComponentDrawer<'_, '_>) {
+        if !self.loading {
+            return;
+        }
+        let area = drawer.area;
+        let rows = match self.shape {
+            SkeletonShape::Lines(lines) => lines.min(area.height),
+            SkeletonShape::Box => area.height,
+        };
+        // 高光带从左侧外部进入，扫过右侧后重新开始
+        let period = (area.width + SHIMMER_WIDTH * 2) as u32;
+        let shimmer = (self.frame as u32 * 2 % period) as i32 - SHIMMER_WIDTH as i32;
+
+        let buffer = drawer.buffer_mut();
+        for row in 0..rows {
+            let width = match self.shape {
+                SkeletonShape::Lines(lines) if lines > 1 && row == lines - 1 => area.width * 3 / 5,
+                _ => area.width,
+            };
+            for column in 0..width {
+                let offset = column as i32 - shimmer;
+                let style = if self.animated && (0..SHIMMER_WIDTH as i32).contains(&offset) {
+                    self.shimmer_style
+                } else {
+                    self.style
+                };
+                buffer[(area.x + column, area.y + row)]
+                    .set_symbol("░")
+                    .set_style(style);
+            }
+        }
+    }
+}
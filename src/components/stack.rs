@@ -0,0 +1,190 @@
+use ratatui::{
+    layout::{Constraint, Direction, Flex, Size},
+    style::Style,
+    text::{Line, Span},
+    widgets::Paragraph,
+};
+
+use crate::{
+    component::Component,
+    element::{AnyElement, Children, Element, ElementKey},
+    hooks::{Hooks, use_direction::UseDirection, use_theme::UseTheme},
+    props::Props,
+    render::{drawer::ComponentDrawer, layout_style::LayoutStyle, updater::ComponentUpdater},
+    width,
+};
+
+#[derive(Clone, Props)]
+pub struct DividerProps {
+    /// 分隔线方向：Horizontal 为横线，Vertical 为竖线
+    pub direction: Direction,
+    /// 横线中间显示的标签，竖线忽略
+    pub label: Option<String>,
+    /// 叠加在主题弱化色之上的样式
+    pub style: Style,
+    pub label_style: Style,
+}
+
+impl Default for DividerProps {
+    fn default() -> Self {
+        Self {
+            direction: Direction::Horizontal,
+            label: None,
+            style: Style::default(),
+            label_style: Style::default(),
+        }
+    }
+}
+
+/// Divider：分隔线，横线占一行、竖线占一列
+pub struct Divider {
+    props: DividerProps,
+}
+
+impl Component for Divider {
+    type Props<'a> = DividerProps;
+
+    fn new(props: &Self::Props<'_>) -> Self {
+        Self {
+            props: props.clone(),
+        }
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        mut hooks: Hooks,
+        updater: &mut ComponentUpdater<'_, '_>,
+    ) {
+        let theme = hooks
+            .with_context_stack(updater.component_context_stack())
+            .use_theme();
+        self.props = props.clone();
+        self.props.style = theme.muted().patch(props.style);
+        updater.set_layout_style(match props.direction {
+            Direction::Horizontal => LayoutStyle {
+                height: Constraint::Length(1),
+                ..Default::default()
+            },
+            Direction::Vertical => LayoutStyle {
+                width: Constraint::Length(1),
+                ..Default::default()
+            },
+        });
+    }
+
+    fn measure(&self) -> Option<Size> {
+        let size = match self.props.direction {
+            Direction::Horizontal => {
+                let label = self
+                    .props
+                    .label
+                    .as_deref()
+                    .map_or(0, |label| width::str_width(label) + 2);
+                Size::new(label as u16 + 2, 1)
+            }
+            Direction::Vertical => Size::new(1, 1),
+        };
+        Some(size)
+    }
+
+    fn draw(&self, drawer: &mut ComponentDrawer<'_, '_>) {
+        let area = drawer.area;
+        let lines = match self.props.direction {
+            Direction::Horizontal => {
+                let width = area.width as usize;
+                let line = match &self.props.label {
+                    Some(label) => {
+                        // 标签放不下时在中间省略，两侧至少各保留一段分隔线
+                        let label = width::middle_ellipsis(label, width.saturating_sub(4));
+                        let label = format!(" {label} ");
+                        let label_width = width::str_width(&label);
+                        let left = width.saturating_sub(label_width) / 2;
+                        let right = width.saturating_sub(label_width + left);
+                        Line::from(vec![
+                            Span::styled("─".repeat(left), self.props.style),
+                            Span::styled(label, self.props.label_style),
+                            Span::styled("─".repeat(right), self.props.style),
+                        ])
+                    }
+                    None => Line::styled("─".repeat(width), self.props.style),
+                };
+                vec![line]
+            }
+            Direction::Vertical => (0..area.height)
+                .map(|_| Line::styled("│", self.props.style))
+                .collect(),
+        };
+        drawer.render_widget(Paragraph::new(lines), area);
+    }
+}
+
+#[derive(Default, Props)]
+pub struct StackProps<'a> {
+    /// 排列方向，默认纵向
+    pub direction: Direction,
+    /// 子项间距
+    pub gap: i32,
+    pub justify_content: Flex,
+    /// 设置后在相邻子项之间自动插入分隔线，分隔线方向与排列方向垂直
+    pub divider: Option<DividerProps>,
+    pub children: Children<'a>,
+}
+
+/// Stack：按方向排列子组件的容器，可以自动在子组件之间插入分隔线
+///
+/// ```ignore
+/// element!(Stack(divider: DividerProps::default()) {
+///     Text(text: "first")
+///     Text(text: "second")
+/// })
+/// ```
+pub struct Stack;
+
+impl Component for Stack {
+    type Props<'a> = StackProps<'a>;
+
+    fn new(_props: &Self::Props<'_>) -> Self {
+        Self
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        mut hooks: Hooks,
+        updater: &mut ComponentUpdater<'_, '_>,
+    ) {
+        let direction = hooks
+            .with_context_stack(updater.component_context_stack())
+            .use_direction();
+        updater.set_layout_style(LayoutStyle {
+            flex_direction: props.direction,
+            gap: props.gap,
+            justify_content: props.justify_content,
+            mirrored: direction.is_rtl(),
+            ..Default::default()
+        });
+
+        let children: Children = props.children.iter_mut().map(AnyElement::from).collect();
+        let children = match &props.divider {
+            Some(divider) => {
+                let direction = match props.direction {
+                    Direction::Horizontal => Direction::Vertical,
+                    Direction::Vertical => Direction::Horizontal,
+                };
+                children.interleave(|_| {
+                    Element::<Divider> {
+                        key: ElementKey::new("divider"),
+                        props: DividerProps {
+                            direction,
+                            ..divider.clone()
+                        },
+                    }
+                    .into()
+                })
+            }
+            None => children,
+        };
+        updater.update_children(children, None);
+    }
+}
@@ -0,0 +1,258 @@
+use std::sync::{
+    Arc,
+    atomic::{AtomicUsize, Ordering},
+};
+
+use crossterm::event::{Event, KeyEventKind};
+use ratatui::{
+    layout::{Constraint, Layout, Rect, Size},
+    style::{Modifier, Style},
+    widgets::{self, Cell, TableState},
+};
+
+use crate::{
+    component::Component,
+    components::{
+        Selection,
+        list::{
+            draw_overscroll, follow_cursor, overscroll_edge, overscroll_style, scroll_offset,
+            selection_marker, sync_scroll,
+        },
+    },
+    hooks::{
+        Hooks,
+        use_events::UseEvents,
+        use_scroll::{ScrollEdge, ScrollState, UseScroll},
+        use_state::{State, UseState},
+        use_theme::UseTheme,
+    },
+    props::Props,
+    render::{drawer::ComponentDrawer, updater::ComponentUpdater},
+    width,
+};
+
+#[derive(Props)]
+pub struct TableProps {
+    /// 表头，为空时不显示
+    pub header: Vec<String>,
+    pub rows: Vec<Vec<String>>,
+    /// 各列宽度约束，为空时平均分配
+    pub widths: Vec<Constraint>,
+    /// 受控的选择状态，为 None 时使用组件内部状态
+    pub selection: Option<State<Selection>>,
+    /// 是否开启多选
+    pub multi_select: bool,
+    /// 是否响应键盘导航
+    pub focused: bool,
+    pub header_style: Style,
+    /// 光标所在行的样式
+    pub highlight_style: Style,
+    /// 与 [`Scrollbar`](crate::components::Scrollbar) 共享的滚动状态，为 None 时总是滚动到光标可见
+    pub scroll: Option<State<ScrollState>>,
+}
+
+impl Default for TableProps {
+    fn default() -> Self {
+        Self {
+            header: Vec::new(),
+            rows: Vec::new(),
+            widths: Vec::new(),
+            selection: None,
+            multi_select: false,
+            focused: true,
+            header_style: Style::default().add_modifier(Modifier::BOLD),
+            highlight_style: Style::default().add_modifier(Modifier::REVERSED),
+            scroll: None,
+        }
+    }
+}
+
+/// Table：可选择的只读表格组件
+///
+/// 键盘操作及越界提示与 [`List`](crate::components::List) 相同，开启 multi_select 后首列显示选中标记。
+/// 需要编辑单元格时使用 [`DataGrid`](crate::components::DataGrid)。
+pub struct Table {
+    header: Vec<String>,
+    rows: Vec<Vec<String>>,
+    widths: Vec<Constraint>,
+    selection: Selection,
+    multi_select: bool,
+    header_style: Style,
+    highlight_style: Style,
+    /// 最近一次绘制时的可见行数，作为翻页的行数
+    page: Arc<AtomicUsize>,
+    scroll: Option<State<ScrollState>>,
+    /// 正在提示的越界边界与样式
+    overscroll: Option<(ScrollEdge, Style)>,
+    focused: bool,
+}
+
+impl Component for Table {
+    type Props<'a> = TableProps;
+
+    fn new(props: &Self::Props<'_>) -> Self {
+        Self {
+            header: Vec::new(),
+            rows: Vec::new(),
+            widths: Vec::new(),
+            selection: Selection::default(),
+            multi_select: props.multi_select,
+            header_style: props.header_style,
+            highlight_style: props.highlight_style,
+            page: Arc::default(),
+            scroll: None,
+            overscroll: None,
+            focused: props.focused,
+        }
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        mut hooks: Hooks,
+        updater: &mut ComponentUpdater<'_, '_>,
+    ) {
+        self.focused = props.focused;
+        let theme = hooks
+            .with_context_stack(updater.component_context_stack())
+            .use_theme();
+        let internal = hooks.use_state(Selection::default);
+        let mut selection = props.selection.unwrap_or(internal);
+        let mut overscroll = hooks.use_overscroll();
+
+        let len = props.rows.len();
+        let multi = props.multi_select;
+        let focused = props.focused;
+        let page = self.page.clone();
+        hooks.use_events(move |event| {
+            if let Event::Key(key) = event
+                && focused
+                && key.kind == KeyEventKind::Press
+            {
+                let mut next = selection.read().clone();
+                let edge = overscroll_edge(key, next.cursor(), len);
+                if next.handle_key(key, len, multi, page.load(Ordering::Relaxed)) {
+                    if let Some(edge) = edge {
+                        overscroll.hit(edge);
+                    }
+                    selection.set(next);
+                }
+            }
+        });
+
+        self.header = props.header.clone();
+        self.rows = props.rows.clone();
+        self.widths = props.widths.clone();
+        self.selection = selection.read().clone();
+        self.selection.clamp(len);
+        self.multi_select = multi;
+        self.header_style = props.header_style;
+        self.highlight_style = props.highlight_style;
+        follow_cursor(&mut hooks, props.scroll, len, self.selection.cursor());
+        self.scroll = props.scroll;
+        self.overscroll = overscroll
+            .current()
+            .map(|(edge, intensity)| (edge, overscroll_style(&theme, intensity)));
+    }
+
+    fn measure(&self) -> Option<Size> {
+        // 按显示宽度计算每列的最大宽度，列之间留一列间隔
+        let mut columns: Vec<usize> = Vec::new();
+        for row in self.rows.iter().chain([&self.header]) {
+            for (index, cell) in row.iter().enumerate() {
+                let width = width::str_width(cell);
+                match columns.get_mut(index) {
+                    Some(column) => *column = (*column).max(width),
+                    None => columns.push(width),
+                }
+            }
+        }
+        let marker = if self.multi_select { 5 } else { 0 };
+        let width = columns.iter().sum::<usize>() + columns.len().saturating_sub(1) + marker;
+        let header = usize::from(!self.header.is_empty());
+        Some(Size::new(width as u16, (self.rows.len() + header) as u16))
+    }
+
+    fn draw(&self, drawer: &mut ComponentDrawer<'_, '_>) {
+        if self.focused {
+            drawer.mark_focused();
+        }
+        let columns = self
+            .rows
+            .iter()
+            .map(Vec::len)
+            .chain([self.header.len()])
+            .max()
+            .unwrap_or_default();
+        let mut widths = if self.widths.is_empty() {
+            vec![Constraint::Fill(1); columns]
+        } else {
+            self.widths.clone()
+        };
+
+        // 按列宽截断单元格，超出时显示省略号，而不是在宽字符中间截断
+        let mut cells_area = drawer.area;
+        if self.multi_select {
+            cells_area.width = cells_area.width.saturating_sub(5);
+        }
+        let column_widths: Vec<usize> = Layout::horizontal(widths.clone())
+            .spacing(1)
+            .split(cells_area)
+            .iter()
+            .map(|column| column.width as usize)
+            .collect();
+
+        let mut header = self.header.clone();
+        let rows = self.rows.iter().enumerate().map(|(index, row)| {
+            let mut cells: Vec<Cell> = row
+                .iter()
+                .zip(&column_widths)
+                .map(|(cell, &width)| Cell::from(width::truncate_to_width(cell, width)))
+                .collect();
+            if self.multi_select {
+                cells.insert(
+                    0,
+                    Cell::from(selection_marker(self.selection.is_selected(index))),
+                );
+            }
+            widgets::Row::new(cells)
+        });
+        if self.multi_select {
+            widths.insert(0, Constraint::Length(4));
+            if !header.is_empty() {
+                header.insert(0, String::new());
+            }
+        }
+
+        let mut table = widgets::Table::new(rows, widths).row_highlight_style(self.highlight_style);
+        let mut visible = drawer.area.height as usize;
+        if !header.is_empty() {
+            table = table.header(widgets::Row::new(header).style(self.header_style));
+            visible = visible.saturating_sub(1);
+        }
+        self.page.store(visible, Ordering::Relaxed);
+
+        let cursor = self.selection.cursor();
+        let offset = match self.scroll {
+            Some(scroll) => sync_scroll(scroll, self.rows.len(), visible, cursor),
+            None => scroll_offset(cursor, visible),
+        };
+        // ratatui 会滚动到选中行可见，光标被 Scrollbar 滚出可见区域时不传入选中行
+        let selected = (offset..offset + visible)
+            .contains(&cursor)
+            .then_some(cursor);
+        let mut state = TableState::default()
+            .with_offset(offset)
+            .with_selected(selected);
+        let area = drawer.area;
+        drawer.frame.render_stateful_widget(table, area, &mut state);
+
+        let header = (area.height as usize - visible) as u16;
+        let rows = Rect {
+            y: area.y + header,
+            height: self.rows.len().saturating_sub(offset).min(visible) as u16,
+            ..area
+        };
+        draw_overscroll(drawer, rows, self.overscroll);
+    }
+}
@@ -0,0 +1,135 @@
+use ratatui::{
+    layout::{Alignment, Size},
+    style::Style,
+    text::{self, Line},
+    widgets::Paragraph,
+};
+
+use crate::{
+    bidi::{self, TextDirection},
+    component::Component,
+    context::SystemContext,
+    hooks::{Hooks, use_direction::UseDirection},
+    props::Props,
+    render::{drawer::ComponentDrawer, updater::ComponentUpdater},
+    width,
+    wrap::WrapCache,
+};
+
+#[derive(Default, Props)]
+pub struct TextProps {
+    /// 文本内容，可以包含多行
+    pub text: String,
+    pub style: Style,
+    /// 对齐方式，从右向左的布局中左右互换
+    pub alignment: Alignment,
+    /// 是否按绘制区域的宽度自动换行
+    pub wrap: bool,
+    /// 是否去掉每行首尾的空白
+    pub trim: bool,
+}
+
+/// Text：显示一段纯文本
+///
+/// 按行绘制，从右向左的文字方向下逐行重排为视觉顺序。开启 wrap 后按单词换行，换行结果按内容与宽度缓存。
+///
+/// ```ignore
+/// element!(Text(
+///     text: format!("Count: {}", count.get()),
+///     style: Style::default().light_green(),
+///     alignment: Alignment::Center,
+/// ))
+/// ```
+pub struct Text {
+    text: String,
+    style: Style,
+    alignment: Alignment,
+    wrap: bool,
+    direction: TextDirection,
+    wrap_cache: WrapCache,
+}
+
+impl Component for Text {
+    type Props<'a> = TextProps;
+
+    fn new(props: &Self::Props<'_>) -> Self {
+        Self {
+            text: props.text.clone(),
+            style: props.style,
+            alignment: props.alignment,
+            wrap: props.wrap,
+            direction: TextDirection::default(),
+            wrap_cache: WrapCache::default(),
+        }
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        mut hooks: Hooks,
+        updater: &mut ComponentUpdater<'_, '_>,
+    ) {
+        // 从右向左时对齐方式左右互换
+        let direction = hooks
+            .with_context_stack(updater.component_context_stack())
+            .use_direction();
+        self.text = if props.trim {
+            props
+                .text
+                .lines()
+                .map(str::trim)
+                .collect::<Vec<_>>()
+                .join("\n")
+        } else {
+            props.text.clone()
+        };
+        self.style = props.style;
+        self.alignment = direction.align(props.alignment);
+        self.wrap = props.wrap;
+        self.direction = direction;
+        if let Some(system) = updater
+            .component_context_stack()
+            .get_context::<SystemContext>()
+        {
+            self.wrap_cache = system.wrap_cache();
+        }
+    }
+
+    fn measure(&self) -> Option<Size> {
+        // 固有尺寸：最长行的显示宽度 × 行数
+        let width = self
+            .text
+            .lines()
+            .map(width::str_width)
+            .max()
+            .unwrap_or_default();
+        Some(Size::new(width as u16, self.text.lines().count() as u16))
+    }
+
+    fn baseline(&self) -> Option<u16> {
+        // 以最后一行作为文本基线
+        let text = text::Text::raw(self.text.as_str());
+        Some(text.height().saturating_sub(1) as u16)
+    }
+
+    fn draw(&self, drawer: &mut ComponentDrawer<'_, '_>) {
+        // 按逻辑顺序换行后再逐行重排为视觉顺序
+        let logical: Vec<String> = if self.wrap {
+            self.wrap_cache
+                .wrap(&text::Text::raw(self.text.as_str()), drawer.area.width)
+                .iter()
+                .map(|line| line.to_string())
+                .collect()
+        } else {
+            self.text.lines().map(str::to_string).collect()
+        };
+        let lines: Vec<Line> = logical
+            .iter()
+            .map(|line| Line::raw(bidi::reorder(line, self.direction)))
+            .collect();
+        let paragraph = Paragraph::new(lines)
+            .style(self.style)
+            .alignment(self.alignment);
+        drawer.render_widget(paragraph, drawer.area);
+    }
+}
@@ -0,0 +1,518 @@
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use ratatui::{
+    layout::{Rect, Size},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Clear, Paragraph},
+};
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::{
+    component::Component,
+    hooks::{
+        Hooks,
+        use_clipboard::UseClipboard,
+        use_completion::{Completion, CompletionProvider, UseCompletion},
+        use_events::UseEvents,
+        use_focus::UseFocus,
+        use_mode::{Mode, UseMode},
+        use_state::{State, UseState},
+        use_theme::UseTheme,
+    },
+    props::{Handler, Props},
+    render::{drawer::ComponentDrawer, updater::ComponentUpdater},
+    width,
+};
+
+/// InputBuffer：单行文本编辑模型
+///
+/// 维护文本内容与光标位置（按字素簇计，组合字符与 emoji 序列视为一个整体），
+/// TextInput、DataGrid 等需要编辑文本的组件共用。
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct InputBuffer {
+    value: String,
+    cursor: usize,
+}
+
+impl InputBuffer {
+    /// 创建编辑缓冲区，光标位于末尾
+    pub fn new(value: impl Into<String>) -> Self {
+        let value = value.into();
+        let cursor = value.graphemes(true).count();
+        Self { value, cursor }
+    }
+
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// 光标位置（字素簇下标）
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    /// 光标之前的文本占用的列数，用于计算滚动与终端光标位置
+    pub fn cursor_column(&self) -> usize {
+        width::str_width(&self.value[..self.byte_index(self.cursor)])
+    }
+
+    /// 替换全部内容，光标移到末尾
+    pub fn set_value(&mut self, value: impl Into<String>) {
+        *self = Self::new(value);
+    }
+
+    fn len(&self) -> usize {
+        self.value.graphemes(true).count()
+    }
+
+    fn byte_index(&self, cursor: usize) -> usize {
+        self.value
+            .grapheme_indices(true)
+            .nth(cursor)
+            .map(|(i, _)| i)
+            .unwrap_or(self.value.len())
+    }
+
+    /// 在光标处插入文本
+    pub fn insert_str(&mut self, text: &str) {
+        let index = self.byte_index(self.cursor);
+        let after = self.value.len() - index;
+        self.value.insert_str(index, text);
+        // 插入的组合字符会与前面的字符合并，按插入后的位置重新计算光标
+        let end = self.value.len() - after;
+        self.cursor = self.value[..end].graphemes(true).count();
+    }
+
+    /// 在光标处插入粘贴的文本，换行替换为空格
+    pub fn paste(&mut self, text: &str) {
+        self.insert_str(&text.replace("\r\n", " ").replace(['\r', '\n'], " "));
+    }
+
+    /// 删除光标前的一个字素簇
+    pub fn backspace(&mut self) {
+        if self.cursor > 0 {
+            self.cursor -= 1;
+            let (from, to) = (
+                self.byte_index(self.cursor),
+                self.byte_index(self.cursor + 1),
+            );
+            self.value.replace_range(from..to, "");
+        }
+    }
+
+    /// 删除光标处的一个字素簇
+    pub fn delete(&mut self) {
+        if self.cursor < self.len() {
+            let (from, to) = (
+                self.byte_index(self.cursor),
+                self.byte_index(self.cursor + 1),
+            );
+            self.value.replace_range(from..to, "");
+        }
+    }
+
+    /// 删除光标前的一个单词
+    pub fn delete_word(&mut self) {
+        let graphemes: Vec<&str> = self.value.graphemes(true).collect();
+        let is_whitespace = |grapheme: &str| grapheme.chars().all(char::is_whitespace);
+        let mut start = self.cursor;
+        while start > 0 && is_whitespace(graphemes[start - 1]) {
+            start -= 1;
+        }
+        while start > 0 && !is_whitespace(graphemes[start - 1]) {
+            start -= 1;
+        }
+        let (from, to) = (self.byte_index(start), self.byte_index(self.cursor));
+        self.value.replace_range(from..to, "");
+        self.cursor = start;
+    }
+
+    /// 处理编辑按键，返回按键是否被消费
+    ///
+    /// 支持字符输入、Backspace/Delete、←/→/Home/End，以及 Ctrl+A/E/U/K/W 等 readline 风格快捷键
+    pub fn handle_key(&mut self, key: KeyEvent) -> bool {
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        match key.code {
+            KeyCode::Char('a') if ctrl => self.cursor = 0,
+            KeyCode::Char('e') if ctrl => self.cursor = self.len(),
+            KeyCode::Char('u') if ctrl => {
+                let index = self.byte_index(self.cursor);
+                self.value.replace_range(..index, "");
+                self.cursor = 0;
+            }
+            KeyCode::Char('k') if ctrl => {
+                let index = self.byte_index(self.cursor);
+                self.value.truncate(index);
+            }
+            KeyCode::Char('w') if ctrl => self.delete_word(),
+            KeyCode::Char(c) if !ctrl => self.insert_str(c.encode_utf8(&mut [0; 4])),
+            KeyCode::Backspace => self.backspace(),
+            KeyCode::Delete => self.delete(),
+            KeyCode::Left => self.cursor = self.cursor.saturating_sub(1),
+            KeyCode::Right => self.cursor = (self.cursor + 1).min(self.len()),
+            KeyCode::Home => self.cursor = 0,
+            KeyCode::End => self.cursor = self.len(),
+            _ => return false,
+        }
+        true
+    }
+
+    /// 渲染为一行文本，光标处的字素簇以反色显示
+    pub fn line(&self, style: Style, show_cursor: bool) -> Line<'static> {
+        if !show_cursor {
+            return Line::styled(self.value.clone(), style);
+        }
+        let (before, after) = self.value.split_at(self.byte_index(self.cursor));
+        let at_cursor = after.graphemes(true).next().unwrap_or("");
+        let rest = &after[at_cursor.len()..];
+        Line::from(vec![
+            Span::styled(before.to_string(), style),
+            Span::styled(
+                if at_cursor.is_empty() { " " } else { at_cursor }.to_string(),
+                style.add_modifier(Modifier::REVERSED),
+            ),
+            Span::styled(rest.to_string(), style),
+        ])
+    }
+}
+
+#[derive(Props)]
+pub struct TextInputProps<'a> {
+    /// 受控的文本状态，为 None 时使用组件内部状态
+    pub value: Option<State<String>>,
+    /// 内容为空时显示的占位文本
+    pub placeholder: &'a str,
+    /// 叠加在主题正文样式之上的样式
+    pub style: Style,
+    /// 叠加在主题次要色之上的样式
+    pub placeholder_style: Style,
+    /// 是否获得焦点（响应键盘输入并显示光标）
+    pub focused: bool,
+    /// 按下 Enter 时的回调
+    pub on_submit: Handler<'a, String>,
+    /// 补全候选项的提供者
+    pub completion: CompletionProvider,
+    /// 接受补全候选项时的回调
+    pub on_complete: Handler<'a, String>,
+}
+
+impl Default for TextInputProps<'_> {
+    fn default() -> Self {
+        Self {
+            value: None,
+            placeholder: "",
+            style: Style::default(),
+            placeholder_style: Style::default(),
+            focused: true,
+            on_submit: Handler::default(),
+            completion: CompletionProvider::default(),
+            on_complete: Handler::default(),
+        }
+    }
+}
+
+/// before 删除一段连续的文本后得到 after，返回被删除的部分
+fn removed_text(before: &str, after: &str) -> String {
+    let prefix = before
+        .chars()
+        .zip(after.chars())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let removed = before.chars().count() - after.chars().count();
+    before.chars().skip(prefix).take(removed).collect()
+}
+
+/// 补全列表最多显示的候选项数量
+const MAX_VISIBLE_CANDIDATES: usize = 6;
+
+/// TextInput：单行文本输入组件
+///
+/// 设置 completion 后支持补全：输入时自动请求候选项并在输入框下方弹出列表，
+/// Tab/Shift+Tab 循环选择并填入候选项，Enter 接受（触发 on_complete），Esc 收起列表。
+/// 拥有焦点时占用 Ctrl+K，补全输入框还占用 Tab（列表展开时还有 Shift+Tab），这些按键不会同时移动焦点。
+/// 启用模式输入后只在 Insert 模式下编辑，其余模式下 h/l/0/$ 移动光标、x 删除字符。
+/// Ctrl+U/K/W 删除的文本会放入剪贴板，Ctrl+Y 粘贴。
+pub struct TextInput {
+    buffer: InputBuffer,
+    placeholder: String,
+    style: Style,
+    placeholder_style: Style,
+    focused: bool,
+    completion: Option<Completion>,
+}
+
+impl Component for TextInput {
+    type Props<'a> = TextInputProps<'a>;
+
+    fn new(props: &Self::Props<'_>) -> Self {
+        Self {
+            buffer: InputBuffer::default(),
+            placeholder: props.placeholder.to_string(),
+            style: props.style,
+            placeholder_style: props.placeholder_style,
+            focused: props.focused,
+            completion: None,
+        }
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        mut hooks: Hooks,
+        updater: &mut ComponentUpdater<'_, '_>,
+    ) {
+        let (theme, clipboard) = {
+            let hooks = hooks.with_context_stack(updater.component_context_stack());
+            (hooks.use_theme(), hooks.use_clipboard())
+        };
+        let internal = hooks.use_state(String::new);
+        let mut value = props.value.unwrap_or(internal);
+        let mut buffer = hooks.use_state(InputBuffer::default);
+        let mut submitted = hooks.use_state(|| None::<String>);
+        let mut completed = hooks.use_state(|| None::<String>);
+        let has_completion = !props.completion.is_empty();
+        let mut completion = hooks.use_completion(props.completion.clone());
+
+        // 外部修改了受控状态时，同步到编辑缓冲区
+        if buffer.read().value() != value.read().as_str() {
+            buffer.set(InputBuffer::new(value.read().clone()));
+        }
+
+        if submitted.read().is_some()
+            && let Some(text) = submitted.write().take()
+        {
+            props.on_submit.invoke(text);
+        }
+
+        if completed.read().is_some()
+            && let Some(text) = completed.write().take()
+        {
+            props.on_complete.invoke(text);
+        }
+
+        let focused = props.focused;
+        let mode = hooks.use_mode().mode();
+        // 编辑时 Ctrl+K 删除到行尾；补全输入框用 Tab 请求与切换候选项，列表展开时 Shift+Tab 也由它处理。
+        // 这些按键不再同时移动焦点
+        let mut claimed = Vec::new();
+        if focused {
+            if mode.is_none_or(|mode| mode == Mode::Insert) {
+                claimed.push(KeyCode::Char('k'));
+            }
+            if has_completion {
+                claimed.push(KeyCode::Tab);
+                if completion.is_open() {
+                    claimed.push(KeyCode::BackTab);
+                }
+            }
+        }
+        hooks.use_claim_keys(&claimed);
+        hooks.use_events(move |event| {
+            if let Event::Paste(text) = &event {
+                if focused && mode.is_none_or(|mode| mode == Mode::Insert) {
+                    let mut next = buffer.read().clone();
+                    next.paste(text);
+                    value.set(next.value().to_string());
+                    buffer.set(next);
+                }
+                return;
+            }
+            let Event::Key(mut key) = event else {
+                return;
+            };
+            if !focused || key.kind != KeyEventKind::Press {
+                return;
+            }
+            // 启用模式输入后只在 Insert 模式下编辑，其余模式只响应光标移动与提交
+            if mode.is_some_and(|mode| mode != Mode::Insert) {
+                key.code = match key.code {
+                    KeyCode::Char('h') => KeyCode::Left,
+                    KeyCode::Char('l') => KeyCode::Right,
+                    KeyCode::Char('0') => KeyCode::Home,
+                    KeyCode::Char('$') => KeyCode::End,
+                    KeyCode::Char('x') => KeyCode::Delete,
+                    code @ (KeyCode::Left
+                    | KeyCode::Right
+                    | KeyCode::Home
+                    | KeyCode::End
+                    | KeyCode::Enter) => code,
+                    _ => return,
+                };
+                key.modifiers = KeyModifiers::NONE;
+            }
+            let mut fill = |text: String| {
+                buffer.set(InputBuffer::new(text.clone()));
+                value.set(text);
+            };
+
+            if has_completion && completion.is_open() {
+                match key.code {
+                    KeyCode::Tab | KeyCode::Down => {
+                        if let Some(candidate) = completion.select_next() {
+                            fill(candidate);
+                        }
+                        return;
+                    }
+                    KeyCode::BackTab | KeyCode::Up => {
+                        if let Some(candidate) = completion.select_prev() {
+                            fill(candidate);
+                        }
+                        return;
+                    }
+                    KeyCode::Enter => {
+                        if let Some(candidate) = completion.selected_candidate() {
+                            completion.dismiss();
+                            fill(candidate.clone());
+                            completed.set(Some(candidate));
+                            return;
+                        }
+                    }
+                    KeyCode::Esc => {
+                        completion.dismiss();
+                        return;
+                    }
+                    _ => {}
+                }
+            } else if has_completion && key.code == KeyCode::Tab {
+                completion.request(value.read().as_str());
+                return;
+            }
+
+            if key.code == KeyCode::Enter {
+                submitted.set(Some(value.read().clone()));
+                return;
+            }
+            let mut next = buffer.read().clone();
+            let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+            let handled = match key.code {
+                // Ctrl+Y 粘贴剪贴板中的内容
+                KeyCode::Char('y') if ctrl => clipboard
+                    .paste()
+                    .map(|text| next.insert_str(&text))
+                    .is_some(),
+                // Ctrl+U/K/W 删除的文本放入剪贴板
+                KeyCode::Char('u' | 'k' | 'w') if ctrl => {
+                    let before = next.value().to_string();
+                    next.handle_key(key);
+                    let killed = removed_text(&before, next.value());
+                    if !killed.is_empty() {
+                        clipboard.copy(killed);
+                    }
+                    true
+                }
+                _ => next.handle_key(key),
+            };
+            if handled {
+                if next.value() != value.read().as_str() {
+                    value.set(next.value().to_string());
+                    if has_completion {
+                        completion.request(next.value());
+                    }
+                }
+                buffer.set(next);
+            }
+        });
+
+        self.buffer = buffer.read().clone();
+        self.placeholder = props.placeholder.to_string();
+        self.style = theme.text.patch(props.style);
+        self.placeholder_style = theme.muted().patch(props.placeholder_style);
+        self.focused = props.focused;
+        self.completion = has_completion.then_some(completion);
+    }
+
+    fn measure(&self) -> Option<Size> {
+        let width = width::str_width(self.buffer.value()).max(width::str_width(&self.placeholder));
+        Some(Size::new(width as u16 + 1, 1))
+    }
+
+    fn draw(&self, drawer: &mut ComponentDrawer<'_, '_>) {
+        let area = drawer.area;
+        if self.focused {
+            drawer.mark_focused();
+        }
+        if self.buffer.value().is_empty() && !self.placeholder.is_empty() {
+            drawer.render_widget(
+                Line::styled(self.placeholder.as_str(), self.placeholder_style),
+                area,
+            );
+            if self.focused && !area.is_empty() {
+                drawer.buffer_mut()[(area.x, area.y)]
+                    .set_style(Style::default().add_modifier(Modifier::REVERSED));
+            }
+            return;
+        }
+
+        // 光标超出可见宽度时水平滚动，按列而不是字符计算，宽字符在光标处时需要两列
+        let line = self.buffer.line(self.style, self.focused);
+        let cursor_width = line
+            .spans
+            .get(1)
+            .map_or(1, |span| width::str_width(&span.content).max(1));
+        let scroll =
+            (self.buffer.cursor_column() + cursor_width).saturating_sub(area.width as usize) as u16;
+        drawer.render_widget(Paragraph::new(line).scroll((0, scroll)), area);
+
+        if let Some(completion) = self.completion
+            && self.focused
+            && completion.is_open()
+        {
+            self.draw_candidates(drawer, completion);
+        }
+    }
+}
+
+impl TextInput {
+    /// 在输入框下方（空间不足时在上方）绘制候选列表
+    ///
+    /// 候选列表通过覆盖层绘制，不会被之后绘制的兄弟组件盖住。
+    fn draw_candidates(&self, drawer: &mut ComponentDrawer<'_, '_>, completion: Completion) {
+        let candidates = completion.candidates();
+        let selected = completion.selected();
+        let area = drawer.area;
+        let screen = drawer.frame.area();
+
+        let height = candidates.len().min(MAX_VISIBLE_CANDIDATES) as u16 + 2;
+        let width = candidates
+            .iter()
+            .map(|c| width::str_width(c) as u16)
+            .max()
+            .unwrap_or_default()
+            .max(area.width.min(20))
+            + 2;
+        let y = if area.bottom() + height <= screen.bottom() {
+            area.bottom()
+        } else {
+            area.y.saturating_sub(height)
+        };
+        let popover = Rect::new(area.x, y, width, height).intersection(screen);
+
+        // 保证选中项处于可见范围内
+        let offset = selected
+            .map(|s| (s + 1).saturating_sub(MAX_VISIBLE_CANDIDATES))
+            .unwrap_or_default();
+        let lines: Vec<Line> = candidates
+            .iter()
+            .enumerate()
+            .skip(offset)
+            .take(MAX_VISIBLE_CANDIDATES)
+            .map(|(i, candidate)| {
+                let line = Line::raw(candidate.clone());
+                if Some(i) == selected {
+                    line.style(Style::default().add_modifier(Modifier::REVERSED))
+                } else {
+                    line
+                }
+            })
+            .collect();
+
+        let border_style = self.placeholder_style;
+        drawer.overlay(move |drawer| {
+            drawer.render_widget(Clear, popover);
+            drawer.render_widget(
+                Paragraph::new(lines).block(Block::bordered().border_style(border_style)),
+                popover,
+            );
+        });
+    }
+}
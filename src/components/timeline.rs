@@ -0,0 +1,299 @@
+use crossterm::event::{Event, KeyCode, KeyEventKind, MouseEventKind};
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Style},
+    symbols::Marker,
+    text::Line,
+    widgets::{
+        Paragraph,
+        canvas::{self, Canvas},
+    },
+};
+
+use crate::{
+    component::Component,
+    hooks::{
+        Hooks,
+        use_events::UseEvents,
+        use_state::{State, UseState},
+        use_theme::UseTheme,
+    },
+    props::Props,
+    render::{drawer::ComponentDrawer, updater::ComponentUpdater},
+    theme::Theme,
+    width,
+};
+
+/// 时间轴上的一条记录
+#[derive(Clone, Debug)]
+pub struct TimelineItem {
+    /// 行标签
+    pub label: String,
+    /// 开始时间
+    pub start: f64,
+    /// 结束时间
+    pub end: f64,
+    /// 叠加在主题主色之上的条形样式，条形以其前景色绘制
+    pub style: Style,
+}
+
+impl TimelineItem {
+    pub fn new(label: impl Into<String>, start: f64, end: f64) -> Self {
+        Self {
+            label: label.into(),
+            start,
+            end,
+            style: Style::default(),
+        }
+    }
+
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+/// 当前可见的时间窗口与纵向滚动位置
+#[derive(Clone, Copy, Debug, Default)]
+struct TimelineView {
+    start: f64,
+    span: f64,
+    scroll: usize,
+    /// 是否处于全览，没有缩放或平移过时数据范围变化后窗口随之调整
+    fitted: bool,
+}
+
+#[derive(Props)]
+pub struct TimelineProps<'a> {
+    pub items: Vec<TimelineItem>,
+    /// 时间单位，显示在坐标轴刻度后
+    pub unit: &'a str,
+    /// 是否响应键盘缩放/平移
+    pub focused: bool,
+}
+
+impl Default for TimelineProps<'_> {
+    fn default() -> Self {
+        Self {
+            items: Vec::new(),
+            unit: "",
+            focused: true,
+        }
+    }
+}
+
+/// Timeline：甘特图/时间线组件
+///
+/// 左侧为行标签，右侧为沿时间轴排布的条形，底部为刻度。条形通过 ratatui 的 [`Canvas`] 绘制，
+/// 横轴为可见的时间窗口，每行一个单位。按键：
+/// - `+`/`-` 以可见窗口中心缩放，`←`/`→`（`h`/`l`）平移，`0` 恢复全览
+/// - 处于全览时数据范围变化后窗口自动调整，缩放或平移之后保持不变，直到按 `0`
+/// - `↑`/`↓`（`k`/`j`）纵向滚动
+pub struct Timeline {
+    items: Vec<TimelineItem>,
+    unit: String,
+    view: Option<State<TimelineView>>,
+    theme: Theme,
+    focused: bool,
+}
+
+/// 数据的整体时间范围
+fn bounds(items: &[TimelineItem]) -> (f64, f64) {
+    let start = items.iter().map(|i| i.start).fold(f64::INFINITY, f64::min);
+    let end = items
+        .iter()
+        .map(|i| i.end)
+        .fold(f64::NEG_INFINITY, f64::max);
+    if start.is_finite() && end > start {
+        (start, end - start)
+    } else {
+        (0.0, 1.0)
+    }
+}
+
+/// 根据跨度选择刻度的小数位数
+fn format_tick(value: f64, span: f64, unit: &str) -> String {
+    let precision = if span >= 100.0 {
+        0
+    } else if span >= 1.0 {
+        1
+    } else {
+        3
+    };
+    format!("{value:.precision$}{unit}")
+}
+
+impl Component for Timeline {
+    type Props<'a> = TimelineProps<'a>;
+
+    fn new(props: &Self::Props<'_>) -> Self {
+        Self {
+            items: props.items.clone(),
+            unit: props.unit.to_string(),
+            view: None,
+            theme: Theme::default(),
+            focused: props.focused,
+        }
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        mut hooks: Hooks,
+        updater: &mut ComponentUpdater<'_, '_>,
+    ) {
+        self.focused = props.focused;
+        self.theme = hooks
+            .with_context_stack(updater.component_context_stack())
+            .use_theme();
+        self.items = props.items.clone();
+        self.unit = props.unit.to_string();
+
+        let (start, span) = bounds(&self.items);
+        let mut view = hooks.use_state(|| TimelineView {
+            start,
+            span,
+            scroll: 0,
+            fitted: true,
+        });
+
+        let rows = self.items.len();
+        // 数据变化后重新全览，滚动位置不超过最后一行；只在需要调整时写入状态
+        let current = view.get();
+        let mut next = current;
+        if next.fitted {
+            (next.start, next.span) = (start, span);
+        }
+        next.scroll = next.scroll.min(rows.saturating_sub(1));
+        if (next.start, next.span, next.scroll) != (current.start, current.span, current.scroll) {
+            view.set(next);
+        }
+
+        let focused = props.focused;
+        hooks.use_local_events(move |event| {
+            let mut next = view.get();
+            match event {
+                Event::Key(key) if focused && key.kind == KeyEventKind::Press => match key.code {
+                    KeyCode::Char('+') | KeyCode::Char('=') => {
+                        next.start += next.span * 0.25;
+                        next.span *= 0.5;
+                        next.fitted = false;
+                    }
+                    KeyCode::Char('-') => {
+                        next.start -= next.span * 0.5;
+                        next.span *= 2.0;
+                        next.fitted = false;
+                    }
+                    KeyCode::Left | KeyCode::Char('h') => {
+                        next.start -= next.span * 0.1;
+                        next.fitted = false;
+                    }
+                    KeyCode::Right | KeyCode::Char('l') => {
+                        next.start += next.span * 0.1;
+                        next.fitted = false;
+                    }
+                    KeyCode::Up | KeyCode::Char('k') => next.scroll = next.scroll.saturating_sub(1),
+                    KeyCode::Down | KeyCode::Char('j') => {
+                        next.scroll = (next.scroll + 1).min(rows.saturating_sub(1))
+                    }
+                    KeyCode::Char('0') => {
+                        (next.start, next.span) = (start, span);
+                        next.scroll = 0;
+                        next.fitted = true;
+                    }
+                    _ => return,
+                },
+                Event::Mouse(mouse) => match mouse.kind {
+                    MouseEventKind::ScrollUp => next.scroll = next.scroll.saturating_sub(1),
+                    MouseEventKind::ScrollDown => {
+                        next.scroll = (next.scroll + 1).min(rows.saturating_sub(1))
+                    }
+                    _ => return,
+                },
+                _ => return,
+            }
+            view.set(next);
+        });
+
+        self.view = Some(view);
+    }
+
+    fn draw(&self, drawer: &mut ComponentDrawer<'_, '_>) {
+        if self.focused {
+            drawer.mark_focused();
+        }
+        let Some(view) = self.view.map(|v| v.get()) else {
+            return;
+        };
+
+        let label_width = self
+            .items
+            .iter()
+            .map(|i| width::str_width(&i.label))
+            .max()
+            .unwrap_or_default() as u16;
+        let [body, axis] =
+            Layout::vertical([Constraint::Fill(1), Constraint::Length(1)]).areas(drawer.area);
+        let [labels, track] =
+            Layout::horizontal([Constraint::Length(label_width), Constraint::Fill(1)])
+                .spacing(1)
+                .areas(body);
+        if track.width == 0 {
+            return;
+        }
+
+        let visible: Vec<&TimelineItem> = self
+            .items
+            .iter()
+            .skip(view.scroll)
+            .take(body.height as usize)
+            .collect();
+        for (row, item) in visible.iter().enumerate() {
+            drawer.render_widget(
+                Line::raw(item.label.as_str()),
+                Rect::new(labels.x, body.y + row as u16, labels.width, 1),
+            );
+        }
+
+        // 画布纵轴从下往上，第 row 行位于 top - row；只有一行时保留一个单位避免零高度
+        let top = f64::from(body.height.saturating_sub(1).max(1));
+        let end = view.start + view.span;
+        let theme = self.theme;
+        let bars = Canvas::default()
+            .marker(Marker::Block)
+            .x_bounds([view.start, end])
+            .y_bounds([0.0, top])
+            .paint(|ctx| {
+                for (row, item) in visible.iter().enumerate() {
+                    // 条形裁剪到可见窗口内，过短的条形至少占一格
+                    let (from, to) = (item.start.max(view.start), item.end.min(end));
+                    if from > to {
+                        continue;
+                    }
+                    let y = top - row as f64;
+                    let color = theme.primary().patch(item.style).fg.unwrap_or(Color::Reset);
+                    ctx.draw(&canvas::Line::new(from, y, to, y, color));
+                }
+            });
+        drawer.render_widget(bars, track);
+
+        let scale = track.width as f64 / view.span;
+
+        // 底部刻度：大约每 12 列一个刻度
+        let ticks = (track.width / 12).max(1);
+        let mut axis_line = String::new();
+        for i in 0..ticks as usize {
+            // 以 usize 计算，避免较宽的轨道上 u16 乘法溢出
+            let x = i * track.width as usize / ticks as usize;
+            let label = format_tick(view.start + x as f64 / scale, view.span, &self.unit);
+            let padding = x.saturating_sub(width::str_width(&axis_line));
+            axis_line.push_str(&" ".repeat(padding));
+            axis_line.push('|');
+            axis_line.push_str(&label);
+        }
+        drawer.render_widget(
+            Paragraph::new(axis_line).style(self.theme.muted()),
+            Rect::new(track.x, axis.y, track.width, 1),
+        );
+    }
+}
@@ -0,0 +1,222 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use ratatui::{
+    layout::Rect,
+    text::Line,
+    widgets::{Block, Clear, Paragraph, Widget},
+};
+
+use crate::{
+    clock::{self, Sleep},
+    component::Component,
+    context::SystemContext,
+    element::Children,
+    hooks::{
+        Hook, Hooks,
+        use_context::UseContext,
+        use_theme::UseTheme,
+        use_toast::{Toast, ToastLevel, Toasts},
+    },
+    props::Props,
+    render::{drawer::ComponentDrawer, updater::ComponentUpdater},
+    theme::Theme,
+    wrap,
+};
+
+/// 通知堆叠显示的角落
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ToastPosition {
+    TopLeft,
+    #[default]
+    TopRight,
+    BottomLeft,
+    BottomRight,
+}
+
+#[derive(Props)]
+pub struct ToastManagerProps<'a> {
+    pub position: ToastPosition,
+    /// 每条通知的宽度，包含边框
+    pub width: u16,
+    /// 最多同时显示的通知数量，超出时只显示最新的几条
+    pub max_visible: usize,
+    /// 层级，与 [`Layer`](super::Layer) 的 z_index 相同
+    pub z_index: i32,
+    pub children: Children<'a>,
+}
+
+impl Default for ToastManagerProps<'_> {
+    fn default() -> Self {
+        Self {
+            position: ToastPosition::default(),
+            width: 40,
+            max_visible: 4,
+            z_index: 200,
+            children: Children::default(),
+        }
+    }
+}
+
+/// 在通知出现与到期时触发重新渲染
+struct ToastWatcher {
+    toasts: Toasts,
+    /// 上次渲染时通知队列的版本号
+    seen: u64,
+    /// 等待最近一条通知到期
+    expiry: Option<Sleep>,
+}
+
+impl Hook for ToastWatcher {
+    fn poll_change(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<()> {
+        let this = &mut *self;
+        loop {
+            let (version, next_expiry) = this.toasts.poll_state(cx);
+            if version != this.seen {
+                this.seen = version;
+                return Poll::Ready(());
+            }
+            let Some(deadline) = next_expiry else {
+                this.expiry = None;
+                return Poll::Pending;
+            };
+            let expiry = this
+                .expiry
+                .get_or_insert_with(|| clock::sleep_until(deadline));
+            if expiry.deadline() != deadline {
+                expiry.reset(deadline);
+            }
+            if Pin::new(expiry).poll(cx).is_pending() {
+                return Poll::Pending;
+            }
+            // 最近的通知已经到期，重新检查时会被移除
+        }
+    }
+}
+
+/// ToastManager：在界面的一角堆叠显示 `use_toast` 发出的通知，到期后自动消失
+///
+/// 放在组件树的根部附近并包裹整个界面，子组件照常布局与绘制，通知绘制在所有内容之上。
+/// 通知队列来自 [`SystemContext::toasts`]，因此任何位置的组件都可以发出通知，组件树中只需要一个 ToastManager。
+///
+/// ```ignore
+/// element!(ToastManager(position: ToastPosition::BottomRight) {
+///     App()
+/// })
+/// ```
+pub struct ToastManager {
+    toasts: Vec<Toast>,
+    position: ToastPosition,
+    width: u16,
+    z_index: i32,
+    theme: Theme,
+}
+
+impl Component for ToastManager {
+    type Props<'a> = ToastManagerProps<'a>;
+
+    fn new(props: &Self::Props<'_>) -> Self {
+        Self {
+            toasts: Vec::new(),
+            position: props.position,
+            width: props.width,
+            z_index: props.z_index,
+            theme: Theme::default(),
+        }
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        mut hooks: Hooks,
+        updater: &mut ComponentUpdater<'_, '_>,
+    ) {
+        let (toasts, theme) = {
+            let hooks = hooks.with_context_stack(updater.component_context_stack());
+            (
+                hooks.use_context::<SystemContext>().toasts(),
+                hooks.use_theme(),
+            )
+        };
+        let watcher = hooks.use_hook(|| ToastWatcher {
+            toasts: toasts.clone(),
+            seen: 0,
+            expiry: None,
+        });
+        watcher.toasts = toasts.clone();
+
+        let active = toasts.active();
+        let skip = active.len().saturating_sub(props.max_visible);
+        self.toasts = active
+            .into_iter()
+            .skip(skip)
+            .map(|(_, toast)| toast)
+            .collect();
+        self.position = props.position;
+        self.width = props.width;
+        self.z_index = props.z_index;
+        self.theme = theme;
+        updater.update_children(props.children.iter_mut(), None);
+    }
+
+    fn draw(&self, drawer: &mut ComponentDrawer<'_, '_>) {
+        if self.toasts.is_empty() {
+            return;
+        }
+        let toasts = self.toasts.clone();
+        let position = self.position;
+        let width = self.width;
+        let theme = self.theme;
+        drawer.overlay_at(self.z_index, move |drawer| {
+            let frame = drawer.area;
+            let width = width.min(frame.width);
+            if width < 3 {
+                return;
+            }
+            let x = match position {
+                ToastPosition::TopLeft | ToastPosition::BottomLeft => frame.x,
+                ToastPosition::TopRight | ToastPosition::BottomRight => frame.right() - width,
+            };
+            let from_top = matches!(position, ToastPosition::TopLeft | ToastPosition::TopRight);
+            // 最新的通知离角落最近
+            let mut used = 0u16;
+            for toast in toasts.iter().rev() {
+                let lines = wrap::wrap_line(&Line::raw(toast.message.as_str()), width - 2);
+                let height = (lines.len() as u16 + 2).min(frame.height - used);
+                if height < 3 {
+                    break;
+                }
+                let y = if from_top {
+                    frame.y + used
+                } else {
+                    frame.bottom() - used - height
+                };
+                let area = Rect::new(x, y, width, height);
+                used += height;
+                draw_toast(drawer, area, toast.level, lines, &theme);
+            }
+        });
+    }
+}
+
+fn draw_toast(
+    drawer: &mut ComponentDrawer<'_, '_>,
+    area: Rect,
+    level: ToastLevel,
+    lines: Vec<Line<'static>>,
+    theme: &Theme,
+) {
+    let (title, style) = match level {
+        ToastLevel::Info => ("Info", theme.primary()),
+        ToastLevel::Warn => ("Warning", theme.warning()),
+        ToastLevel::Error => ("Error", theme.error()),
+    };
+    let block = Block::bordered()
+        .border_type(theme.border_type)
+        .border_style(style)
+        .title(Line::styled(format!(" {title} "), style));
+    Clear.render(area, drawer.buffer_mut());
+    drawer.render_widget(Paragraph::new(lines).style(theme.text).block(block), area);
+}
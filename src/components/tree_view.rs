@@ -0,0 +1,296 @@
+use std::{collections::HashSet, sync::Arc};
+
+use crossterm::event::{Event, KeyCode, KeyEventKind};
+use ratatui::{
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::Paragraph,
+};
+
+use crate::{
+    component::Component,
+    hooks::{
+        Hooks,
+        use_events::UseEvents,
+        use_state::{State, UseState},
+    },
+    props::Props,
+    render::{drawer::ComponentDrawer, updater::ComponentUpdater},
+};
+
+/// 树中的一个节点
+#[derive(Clone, Debug, Default)]
+pub struct TreeItem {
+    /// 节点的唯一标识，同时用于记录展开状态
+    pub id: String,
+    /// 节点显示的内容
+    pub label: Line<'static>,
+    /// 子节点
+    pub children: Vec<TreeItem>,
+}
+
+impl TreeItem {
+    pub fn new(id: impl Into<String>, label: impl Into<Line<'static>>) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            children: Vec::new(),
+        }
+    }
+
+    pub fn with_children(mut self, children: Vec<TreeItem>) -> Self {
+        self.children = children;
+        self
+    }
+}
+
+/// 展开后的可见节点
+pub struct FlatTreeItem<'t> {
+    /// 节点深度
+    pub depth: usize,
+    pub item: &'t TreeItem,
+    /// 是否处于展开状态
+    pub expanded: bool,
+}
+
+/// TreeState：树的展开与选中状态
+///
+/// 与具体的节点数据分离，TreeView、JsonView 等树形组件共享这一套导航逻辑。
+#[derive(Clone, Debug, Default)]
+pub struct TreeState {
+    /// 已展开节点的 id
+    pub expanded: HashSet<String>,
+    /// 当前选中的可见行
+    pub selected: usize,
+}
+
+impl TreeState {
+    /// 按当前展开状态将树展开为可见行
+    pub fn flatten<'t>(&self, items: &'t [TreeItem]) -> Vec<FlatTreeItem<'t>> {
+        fn walk<'t>(
+            state: &TreeState,
+            items: &'t [TreeItem],
+            depth: usize,
+            out: &mut Vec<FlatTreeItem<'t>>,
+        ) {
+            for item in items {
+                let expanded = state.expanded.contains(&item.id);
+                out.push(FlatTreeItem {
+                    depth,
+                    item,
+                    expanded,
+                });
+                if expanded {
+                    walk(state, &item.children, depth + 1, out);
+                }
+            }
+        }
+
+        let mut out = Vec::new();
+        walk(self, items, 0, &mut out);
+        out
+    }
+
+    /// 获取当前选中的节点
+    pub fn selected_item<'t>(&self, items: &'t [TreeItem]) -> Option<&'t TreeItem> {
+        self.flatten(items).get(self.selected).map(|flat| flat.item)
+    }
+
+    /// 切换节点的展开状态
+    pub fn toggle(&mut self, id: &str) {
+        if !self.expanded.remove(id) {
+            self.expanded.insert(id.to_string());
+        }
+    }
+
+    /// 展开目标节点的所有祖先并选中它，找不到时返回 false
+    pub fn select_id(&mut self, items: &[TreeItem], id: &str) -> bool {
+        fn path<'t>(items: &'t [TreeItem], id: &str, out: &mut Vec<&'t str>) -> bool {
+            for item in items {
+                if item.id == id || path(&item.children, id, out) {
+                    out.push(&item.id);
+                    return true;
+                }
+            }
+            false
+        }
+
+        let mut ancestors = Vec::new();
+        if !path(items, id, &mut ancestors) {
+            return false;
+        }
+        // ancestors[0] 是目标节点本身，其余为祖先
+        for ancestor in ancestors.iter().skip(1) {
+            self.expanded.insert(ancestor.to_string());
+        }
+        if let Some(index) = self.flatten(items).iter().position(|f| f.item.id == id) {
+            self.selected = index;
+        }
+        true
+    }
+
+    /// 处理导航按键：↑↓/jk 移动，←/h 折叠，→/l 展开，Enter/Space 切换，Home/End 跳转
+    ///
+    /// 返回按键是否被处理
+    pub fn handle_key(&mut self, items: &[TreeItem], code: KeyCode) -> bool {
+        let flat = self.flatten(items);
+        let Some(current) = flat.get(self.selected.min(flat.len().saturating_sub(1))) else {
+            return false;
+        };
+        let has_children = !current.item.children.is_empty();
+        let id = current.item.id.clone();
+        let depth = current.depth;
+
+        match code {
+            KeyCode::Up | KeyCode::Char('k') => self.selected = self.selected.saturating_sub(1),
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.selected = (self.selected + 1).min(flat.len() - 1)
+            }
+            KeyCode::Home => self.selected = 0,
+            KeyCode::End => self.selected = flat.len() - 1,
+            KeyCode::Right | KeyCode::Char('l') if has_children => {
+                self.expanded.insert(id);
+            }
+            KeyCode::Left | KeyCode::Char('h') => {
+                if current.expanded {
+                    self.expanded.remove(&id);
+                } else if let Some(parent) =
+                    flat[..self.selected].iter().rposition(|f| f.depth < depth)
+                {
+                    // 已折叠时跳到父节点
+                    self.selected = parent;
+                }
+            }
+            KeyCode::Enter | KeyCode::Char(' ') if has_children => self.toggle(&id),
+            _ => return false,
+        }
+        true
+    }
+
+    /// 节点减少后把选中行限制在可见行内
+    ///
+    /// 只在越界时写入状态，避免每轮 update 都触发重新渲染。
+    pub(crate) fn clamp_selected(state: State<TreeState>, items: &[TreeItem]) {
+        let visible = state.read().flatten(items).len();
+        if state.read().selected >= visible.max(1) {
+            state.write().selected = visible.saturating_sub(1);
+        }
+    }
+
+    /// 渲染可见区域内的行，自动滚动保证选中行可见
+    pub fn lines(
+        &self,
+        items: &[TreeItem],
+        height: usize,
+        highlight_style: Style,
+    ) -> Vec<Line<'static>> {
+        let offset = (self.selected + 1).saturating_sub(height);
+        self.flatten(items)
+            .into_iter()
+            .enumerate()
+            .skip(offset)
+            .take(height)
+            .map(|(index, flat)| {
+                let marker = match (flat.item.children.is_empty(), flat.expanded) {
+                    (true, _) => "  ",
+                    (false, true) => "▾ ",
+                    (false, false) => "▸ ",
+                };
+                let mut spans = vec![Span::raw(format!("{}{marker}", "  ".repeat(flat.depth)))];
+                spans.extend(flat.item.label.spans.iter().cloned());
+                let line = Line::from(spans);
+                if index == self.selected {
+                    line.patch_style(highlight_style)
+                } else {
+                    line
+                }
+            })
+            .collect()
+    }
+}
+
+#[derive(Props)]
+pub struct TreeViewProps {
+    /// 树的根节点列表，在多轮 update 之间共享而不复制，可以由 `Vec<TreeItem>` 通过 `into` 得到
+    pub items: Arc<[TreeItem]>,
+    /// 是否响应键盘导航
+    pub focused: bool,
+    /// 选中行的样式
+    pub highlight_style: Style,
+}
+
+impl Default for TreeViewProps {
+    fn default() -> Self {
+        Self {
+            items: Arc::new([]),
+            focused: true,
+            highlight_style: Style::default().add_modifier(Modifier::REVERSED),
+        }
+    }
+}
+
+/// TreeView：可折叠的树形列表组件
+pub struct TreeView {
+    items: Arc<[TreeItem]>,
+    state: Option<State<TreeState>>,
+    highlight_style: Style,
+    focused: bool,
+}
+
+impl Component for TreeView {
+    type Props<'a> = TreeViewProps;
+
+    fn new(props: &Self::Props<'_>) -> Self {
+        Self {
+            items: Arc::new([]),
+            state: None,
+            highlight_style: props.highlight_style,
+            focused: props.focused,
+        }
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        mut hooks: Hooks,
+        _updater: &mut ComponentUpdater<'_, '_>,
+    ) {
+        self.focused = props.focused;
+        let state = hooks.use_state(TreeState::default);
+        self.items = props.items.clone();
+        TreeState::clamp_selected(state, &self.items);
+
+        // 事件回调每轮 update 都会被替换，持有本轮节点数据的共享引用即可
+        let items = self.items.clone();
+        let focused = props.focused;
+        hooks.use_events(move |event| {
+            if let Event::Key(key) = event
+                && focused
+                && key.kind == KeyEventKind::Press
+            {
+                let mut next = state.read().clone();
+                if next.handle_key(&items, key.code) {
+                    *state.write() = next;
+                }
+            }
+        });
+
+        self.state = Some(state);
+        self.highlight_style = props.highlight_style;
+    }
+
+    fn draw(&self, drawer: &mut ComponentDrawer<'_, '_>) {
+        if self.focused {
+            drawer.mark_focused();
+        }
+        let Some(state) = self.state else {
+            return;
+        };
+        let lines = state.read().lines(
+            &self.items,
+            drawer.area.height as usize,
+            self.highlight_style,
+        );
+        drawer.render_widget(Paragraph::new(lines), drawer.area);
+    }
+}
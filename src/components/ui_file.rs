@@ -0,0 +1,148 @@
+use std::{path::PathBuf, time::Duration};
+
+use ratatui::{layout::Rect, style::Style, widgets::Paragraph};
+
+use crate::{
+    clock,
+    component::Component,
+    components::DynamicError,
+    context::SystemContext,
+    element::AnyElement,
+    hooks::{Hooks, use_future::UseFuture, use_state::UseState, use_theme::UseTheme},
+    props::Props,
+    render::{drawer::ComponentDrawer, updater::ComponentUpdater},
+};
+
+#[derive(Props)]
+pub struct UiFileProps {
+    /// 界面描述文件的路径，格式见 [`ComponentRegistry::load`](crate::components::ComponentRegistry::load)
+    pub path: PathBuf,
+    /// 是否监视文件变化并热重载，默认只在 debug 构建中开启
+    pub watch: bool,
+    /// 检查文件修改时间的间隔
+    pub poll_interval: Duration,
+}
+
+impl Default for UiFileProps {
+    fn default() -> Self {
+        Self {
+            path: PathBuf::new(),
+            watch: cfg!(debug_assertions),
+            poll_interval: Duration::from_millis(500),
+        }
+    }
+}
+
+/// UiFile：从界面描述文件创建子树，开发时文件保存后自动重建
+///
+/// 重建时按描述中的 key 与原有组件匹配，key 相同的组件保留各自的状态。
+/// 新的描述无法解析或者引用了未注册的组件时，继续显示上一份有效的界面，并在底部一行显示错误信息。
+///
+/// ```ignore
+/// element!(UiFile(path: "layout.json"))
+/// ```
+pub struct UiFile {
+    source: Option<String>,
+    description: Option<serde_json::Value>,
+    error: Option<String>,
+    error_style: Style,
+}
+
+impl Component for UiFile {
+    type Props<'a> = UiFileProps;
+
+    fn new(_props: &Self::Props<'_>) -> Self {
+        Self {
+            source: None,
+            description: None,
+            error: None,
+            error_style: Style::default(),
+        }
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        mut hooks: Hooks,
+        updater: &mut ComponentUpdater<'_, '_>,
+    ) {
+        self.error_style = hooks
+            .with_context_stack(updater.component_context_stack())
+            .use_theme()
+            .error();
+        let source = hooks.use_state(|| None::<String>);
+        let path = props.path.clone();
+        let watch = props.watch;
+        let poll_interval = props.poll_interval;
+        hooks.use_future_with((path.clone(), watch, poll_interval), move || async move {
+            let mut source = source;
+            let mut modified = None;
+            loop {
+                // 只在修改时间变化时重新读取，文件暂时不可读时保留上一次的内容
+                if let Ok(metadata) = tokio::fs::metadata(&path).await {
+                    let current = metadata.modified().ok();
+                    if modified != Some(current)
+                        && let Ok(text) = tokio::fs::read_to_string(&path).await
+                    {
+                        modified = Some(current);
+                        source.set(Some(text));
+                    }
+                }
+                if !watch {
+                    break;
+                }
+                clock::sleep(poll_interval).await;
+            }
+        });
+
+        let registry = updater
+            .component_context_stack()
+            .get_context::<SystemContext>()
+            .map(|system| system.components())
+            .unwrap_or_default();
+        let current = source.read().clone();
+        if current != self.source {
+            self.source = current;
+            if let Some(text) = &self.source {
+                let loaded = serde_json::from_str(text)
+                    .map_err(|error| DynamicError::InvalidDescription(error.to_string()))
+                    .and_then(|description| registry.load(&description).map(|_| description));
+                match loaded {
+                    Ok(description) => {
+                        self.description = Some(description);
+                        self.error = None;
+                    }
+                    Err(error) => self.error = Some(error.to_string()),
+                }
+            }
+        }
+
+        let children: Vec<AnyElement> = match &self.description {
+            Some(description) => match registry.load(description) {
+                Ok(element) => vec![element],
+                Err(error) => {
+                    self.error = Some(error.to_string());
+                    Vec::new()
+                }
+            },
+            None => Vec::new(),
+        };
+        updater.update_children(children, None);
+    }
+
+    fn draw(&self, drawer: &mut ComponentDrawer<'_, '_>) {
+        let Some(error) = self.error.clone() else {
+            return;
+        };
+        let area = drawer.area;
+        let style = self.error_style;
+        // 错误信息需要盖在子组件之上
+        drawer.overlay(move |drawer| {
+            let bottom = Rect::new(area.x, area.bottom().saturating_sub(1), area.width, 1);
+            drawer.render_widget(
+                Paragraph::new(error).style(style),
+                bottom.intersection(drawer.area),
+            );
+        });
+    }
+}
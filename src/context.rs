@@ -1,8 +1,27 @@
 use std::{
-    any::Any,
+    any::{Any, TypeId},
     cell::{Ref, RefCell, RefMut},
 };
 
+use crate::{
+    animation::AnimationClock,
+    assets::Assets,
+    bidi::TextDirection,
+    capabilities::Capabilities,
+    components::{ComponentRegistry, PortalTargets},
+    hooks::{
+        use_clipboard::Clipboard, use_context_menu::ContextMenus, use_exit::ExitSignal,
+        use_focus::FocusManager, use_keyed_state::KeyedStates, use_keymap::KeymapRegistry,
+        use_mode::Modes, use_navigate::Navigator, use_persisted_state::SessionStore,
+        use_query::QueryCache, use_resource::LoadingTracker, use_signal_handler::SignalHandlers,
+        use_store::Stores, use_toast::Toasts,
+    },
+    render::{activity::ActivityTracker, updater::ReparentPool},
+    terminal::InputLayers,
+    theme::Theme,
+    wrap::WrapCache,
+};
+
 /// Context 枚举用于封装不同类型的上下文数据，支持只读引用、可变引用和拥有权三种模式。
 pub enum Context<'a> {
     /// 只读引用上下文
@@ -47,6 +66,15 @@ impl<'a> Context<'a> {
         }
     }
 
+    /// 上下文中值的类型
+    pub(crate) fn value_type_id(&self) -> TypeId {
+        match self {
+            Context::Ref(context) => (**context).type_id(),
+            Context::Mut(context) => (**context).type_id(),
+            Context::Owned(context) => (**context).type_id(),
+        }
+    }
+
     /// 获取 Context 的可变引用副本（Owned 会转为 Mut）
     pub fn borrow(&mut self) -> Context {
         match self {
@@ -61,6 +89,8 @@ impl<'a> Context<'a> {
 pub struct ContextStack<'a> {
     /// 栈结构，存储各层级的 Context
     stack: Vec<RefCell<Context<'a>>>,
+    /// 被遮蔽的类型及遮蔽时栈的高度，查找该类型时不会越过遮蔽的位置
+    shadows: Vec<(usize, TypeId)>,
 }
 
 impl<'a> ContextStack<'a> {
@@ -68,6 +98,7 @@ impl<'a> ContextStack<'a> {
     pub(crate) fn root(root_context: &'a mut (dyn Any + Send + Sync)) -> Self {
         ContextStack {
             stack: vec![RefCell::new(Context::Mut(root_context))],
+            shadows: Vec::new(),
         }
     }
 
@@ -93,9 +124,35 @@ impl<'a> ContextStack<'a> {
         };
     }
 
+    /// 在闭包 f 执行期间遮蔽类型为 type_id 的上下文：栈中已有的该类型上下文都不可见，
+    /// 用于组件跳过 update 时重新登记，原本由被跳过的组件提供的上下文此时并不存在，不能让子组件登记到更外层
+    pub(crate) fn with_shadow<F>(&mut self, type_id: Option<TypeId>, f: F)
+    where
+        F: FnOnce(&mut ContextStack),
+    {
+        let Some(type_id) = type_id else {
+            return f(self);
+        };
+        self.shadows.push((self.stack.len(), type_id));
+        f(self);
+        self.shadows.pop();
+    }
+
+    /// 查找类型 T 时可见的栈
+    fn visible<T: Any>(&self) -> &[RefCell<Context<'a>>] {
+        let floor = self
+            .shadows
+            .iter()
+            .filter(|(_, type_id)| *type_id == TypeId::of::<T>())
+            .map(|(height, _)| *height)
+            .max()
+            .unwrap_or_default();
+        &self.stack[floor..]
+    }
+
     /// 获取栈顶到栈底第一个类型为 T 的只读上下文引用
     pub fn get_context<T: Any>(&self) -> Option<Ref<T>> {
-        for context in self.stack.iter().rev() {
+        for context in self.visible::<T>().iter().rev() {
             if let Ok(context) = context.try_borrow() {
                 if let Ok(res) = Ref::filter_map(context, |context| context.downcast_ref::<T>()) {
                     return Some(res);
@@ -107,7 +164,7 @@ impl<'a> ContextStack<'a> {
 
     /// 获取栈顶到栈底第一个类型为 T 的可变上下文引用
     pub fn get_context_mut<T: Any>(&self) -> Option<RefMut<T>> {
-        for context in self.stack.iter().rev() {
+        for context in self.visible::<T>().iter().rev() {
             if let Ok(context) = context.try_borrow_mut() {
                 if let Ok(res) = RefMut::filter_map(context, |context| context.downcast_mut::<T>())
                 {
@@ -120,7 +177,32 @@ impl<'a> ContextStack<'a> {
 }
 
 pub struct SystemContext {
-    should_exit: bool,
+    exit: ExitSignal,
+    loading: LoadingTracker,
+    toasts: Toasts,
+    reduced_motion: bool,
+    context_menus: ContextMenus,
+    input_layers: InputLayers,
+    focus: FocusManager,
+    session: SessionStore,
+    keyed_states: KeyedStates,
+    modes: Modes,
+    activity: ActivityTracker,
+    keymaps: KeymapRegistry,
+    queries: QueryCache,
+    stores: Stores,
+    components: ComponentRegistry,
+    assets: Assets,
+    navigator: Navigator,
+    capabilities: Capabilities,
+    theme: Theme,
+    clipboard: Clipboard,
+    wrap_cache: WrapCache,
+    portal_targets: PortalTargets,
+    signals: SignalHandlers,
+    animations: AnimationClock,
+    direction: TextDirection,
+    reparenting: ReparentPool,
 }
 
 unsafe impl Send for SystemContext {}
@@ -128,14 +210,197 @@ unsafe impl Sync for SystemContext {}
 
 impl SystemContext {
     pub(crate) fn new() -> Self {
-        Self { should_exit: false }
+        let input_layers = InputLayers::default();
+        Self {
+            exit: ExitSignal::default(),
+            loading: LoadingTracker::default(),
+            toasts: Toasts::default(),
+            reduced_motion: std::env::var("REDUCED_MOTION")
+                .is_ok_and(|value| !value.is_empty() && value != "0"),
+            context_menus: ContextMenus::new(input_layers.clone()),
+            input_layers,
+            focus: FocusManager::default(),
+            session: SessionStore::default(),
+            keyed_states: KeyedStates::default(),
+            modes: Modes::default(),
+            activity: ActivityTracker::default(),
+            keymaps: KeymapRegistry::default(),
+            queries: QueryCache::default(),
+            stores: Stores::default(),
+            components: ComponentRegistry::with_builtins(),
+            assets: Assets::default(),
+            navigator: Navigator::default(),
+            capabilities: Capabilities::default(),
+            theme: Theme::default(),
+            clipboard: Clipboard::default(),
+            wrap_cache: WrapCache::default(),
+            portal_targets: PortalTargets::default(),
+            signals: SignalHandlers::default(),
+            animations: AnimationClock::default(),
+            direction: TextDirection::default(),
+            reparenting: ReparentPool::default(),
+        }
     }
 
     pub(crate) fn should_exit(&self) -> bool {
-        self.should_exit
+        self.exit.is_requested()
     }
 
+    /// 结束渲染循环
     pub fn exit(&mut self) {
-        self.should_exit = true;
+        self.exit.exit();
+    }
+
+    /// 以 value 作为结果结束渲染循环，由 `render_loop_with_result` 返回
+    pub fn finish<T: Send + 'static>(&mut self, value: T) {
+        self.exit.finish(value);
+    }
+
+    /// 结束渲染循环的信号，可以克隆到事件回调或后台任务中
+    pub fn exit_signal(&self) -> ExitSignal {
+        self.exit.clone()
+    }
+
+    /// 全局进行中异步操作的计数器
+    pub fn loading(&self) -> LoadingTracker {
+        self.loading.clone()
+    }
+
+    /// 是否减少动效，默认读取环境变量 `REDUCED_MOTION`
+    ///
+    /// 开启后带动画的组件应当以静态方式渲染。
+    pub fn reduced_motion(&self) -> bool {
+        self.reduced_motion
+    }
+
+    pub fn set_reduced_motion(&mut self, reduced_motion: bool) {
+        self.reduced_motion = reduced_motion;
+    }
+
+    /// 当前终端实际可用的能力，渲染循环启动后才是检测结果
+    pub fn capabilities(&self) -> Capabilities {
+        self.capabilities
+    }
+
+    pub fn set_capabilities(&mut self, capabilities: Capabilities) {
+        self.capabilities = capabilities;
+    }
+
+    /// 根部的主题，默认为 [`Theme::dark`]
+    pub fn theme(&self) -> Theme {
+        self.theme
+    }
+
+    pub fn set_theme(&mut self, theme: Theme) {
+        self.theme = theme;
+    }
+
+    /// 根部的文字方向，默认为 [`TextDirection::Ltr`]
+    pub fn direction(&self) -> TextDirection {
+        self.direction
+    }
+
+    pub fn set_direction(&mut self, direction: TextDirection) {
+        self.direction = direction;
+    }
+
+    /// 全局的剪贴板
+    pub fn clipboard(&self) -> Clipboard {
+        self.clipboard.clone()
+    }
+
+    /// 全局的换行缓存
+    pub fn wrap_cache(&self) -> WrapCache {
+        self.wrap_cache.clone()
+    }
+
+    /// 各个 `PortalTarget` 最近一次绘制时的区域
+    pub fn portal_targets(&self) -> PortalTargets {
+        self.portal_targets.clone()
+    }
+
+    /// 通过 `use_signal_handler` 注册的信号回调
+    pub fn signals(&self) -> SignalHandlers {
+        self.signals.clone()
+    }
+
+    /// 驱动 `use_animation` 的帧时钟
+    pub fn animations(&self) -> AnimationClock {
+        self.animations.clone()
+    }
+
+    /// 等待被接管的可迁移组件，见 [`ElementKey::portable`](crate::element::ElementKey::portable)
+    pub(crate) fn reparenting(&self) -> ReparentPool {
+        self.reparenting.clone()
+    }
+
+    /// 全局的通知队列，见 `use_toast`
+    pub fn toasts(&self) -> Toasts {
+        self.toasts.clone()
+    }
+
+    /// 全局的右键菜单弹层
+    pub fn context_menus(&self) -> ContextMenus {
+        self.context_menus.clone()
+    }
+
+    /// 当前打开的输入层级，模态对话框与右键菜单打开时，按键只分发给其中的组件
+    pub fn input_layers(&self) -> InputLayers {
+        self.input_layers.clone()
+    }
+
+    /// 全局的焦点管理
+    pub fn focus(&self) -> FocusManager {
+        self.focus.clone()
+    }
+
+    /// 跨启动保存的界面状态，默认不启用
+    pub fn session(&self) -> SessionStore {
+        self.session.clone()
+    }
+
+    /// `use_keyed_state` 以全局 key 保存的状态，组件卸载后依然保留
+    pub fn keyed_states(&self) -> KeyedStates {
+        self.keyed_states.clone()
+    }
+
+    /// 全局的输入模式，默认不启用
+    pub fn modes(&self) -> Modes {
+        self.modes.clone()
+    }
+
+    /// 应用的活跃状态，失去焦点或长时间无输入时定时任务应当降低频率
+    pub fn activity(&self) -> ActivityTracker {
+        self.activity.clone()
+    }
+
+    /// 全局的按键绑定登记表
+    pub fn keymaps(&self) -> KeymapRegistry {
+        self.keymaps.clone()
+    }
+
+    /// 全局的查询缓存
+    pub fn queries(&self) -> QueryCache {
+        self.queries.clone()
+    }
+
+    /// 根部安装的全局 store
+    pub fn stores(&self) -> Stores {
+        self.stores.clone()
+    }
+
+    /// 运行时按名称注册的组件，由 `DynamicElement` 实例化
+    pub fn components(&self) -> ComponentRegistry {
+        self.components.clone()
+    }
+
+    /// 按名称登记的文本资源
+    pub fn assets(&self) -> Assets {
+        self.assets.clone()
+    }
+
+    /// 全局的导航栈，由 `Routes` 与 `use_navigate` 使用
+    pub fn navigator(&self) -> Navigator {
+        self.navigator.clone()
     }
 }
@@ -0,0 +1,148 @@
+use std::{
+    any::TypeId,
+    ops::{Deref, DerefMut},
+};
+
+use super::{AnyElement, ElementKey};
+use crate::component::Component;
+
+impl AnyElement<'_> {
+    /// 元素对应组件的类型标识
+    pub fn component_type_id(&self) -> TypeId {
+        self.helper.component_type_id()
+    }
+
+    /// 元素是否为组件 T
+    pub fn is<T: Component>(&self) -> bool {
+        self.component_type_id() == TypeId::of::<T>()
+    }
+}
+
+/// Children：容器组件的子元素列表
+///
+/// 可以直接作为 props 中的 children 字段，`element!` 中的子元素会依次追加进来。
+/// 通过 Deref 可以使用 Vec 的全部方法，另外提供了按类型筛选、包装与插入分隔元素等工具。
+///
+/// ```ignore
+/// let children = std::mem::take(&mut props.children)
+///     .map_wrap(|_, child| element!(Border { #(child) }).into())
+///     .interleave(|_| element!(Separator).into());
+/// updater.update_children(children, None);
+/// ```
+#[derive(Default)]
+pub struct Children<'a> {
+    elements: Vec<AnyElement<'a>>,
+}
+
+impl<'a> Children<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 所有子元素的 key
+    pub fn keys(&self) -> impl Iterator<Item = &ElementKey> {
+        self.elements.iter().map(|element| &element.key)
+    }
+
+    /// 组件类型为 T 的子元素数量
+    pub fn count_of<T: Component>(&self) -> usize {
+        self.elements
+            .iter()
+            .filter(|element| element.is::<T>())
+            .count()
+    }
+
+    /// 只保留满足条件的子元素
+    pub fn filter(mut self, f: impl FnMut(&AnyElement<'a>) -> bool) -> Self {
+        self.elements.retain(f);
+        self
+    }
+
+    /// 用 f 包装每个子元素，f 的第一个参数为子元素下标
+    ///
+    /// 包装后的元素沿用原元素的 key，保证子组件的状态不会因为包装而丢失。
+    pub fn map_wrap(self, mut f: impl FnMut(usize, AnyElement<'a>) -> AnyElement<'a>) -> Self {
+        let elements = self
+            .elements
+            .into_iter()
+            .enumerate()
+            .map(|(index, element)| {
+                let key = element.key.clone();
+                let mut wrapped = f(index, element);
+                wrapped.key = key;
+                wrapped
+            })
+            .collect();
+        Self { elements }
+    }
+
+    /// 在相邻子元素之间插入 f 生成的元素，f 的参数为分隔元素的下标
+    pub fn interleave(self, mut f: impl FnMut(usize) -> AnyElement<'a>) -> Self {
+        let mut elements = Vec::with_capacity(self.elements.len() * 2);
+        for (index, element) in self.elements.into_iter().enumerate() {
+            if index > 0 {
+                let mut separator = f(index - 1);
+                separator.key = ElementKey::new(("__separator__", index - 1));
+                elements.push(separator);
+            }
+            elements.push(element);
+        }
+        Self { elements }
+    }
+
+    pub fn into_vec(self) -> Vec<AnyElement<'a>> {
+        self.elements
+    }
+}
+
+impl<'a> Deref for Children<'a> {
+    type Target = Vec<AnyElement<'a>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.elements
+    }
+}
+
+impl DerefMut for Children<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.elements
+    }
+}
+
+impl<'a> From<Vec<AnyElement<'a>>> for Children<'a> {
+    fn from(elements: Vec<AnyElement<'a>>) -> Self {
+        Self { elements }
+    }
+}
+
+impl<'a> Extend<AnyElement<'a>> for Children<'a> {
+    fn extend<I: IntoIterator<Item = AnyElement<'a>>>(&mut self, iter: I) {
+        self.elements.extend(iter);
+    }
+}
+
+impl<'a> FromIterator<AnyElement<'a>> for Children<'a> {
+    fn from_iter<I: IntoIterator<Item = AnyElement<'a>>>(iter: I) -> Self {
+        Self {
+            elements: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<'a> IntoIterator for Children<'a> {
+    type Item = AnyElement<'a>;
+    type IntoIter = std::vec::IntoIter<AnyElement<'a>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.elements.into_iter()
+    }
+}
+
+impl<'a, 'b> IntoIterator for &'b mut Children<'a> {
+    type Item = &'b mut AnyElement<'a>;
+    type IntoIter = std::slice::IterMut<'b, AnyElement<'a>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.elements.iter_mut()
+    }
+}
@@ -1,6 +1,10 @@
 // Element 扩展 trait 及相关工具，便于统一操作不同类型的 Element
 use super::ElementKey;
-use crate::{component::component_helper::ComponentHelperExt, props::AnyProps};
+use crate::{
+    component::component_helper::ComponentHelperExt,
+    props::AnyProps,
+    render::tree::{RenderExit, render_loop_with_exit},
+};
 use std::io;
 
 /// 私有模块，用于实现 trait 封装，防止外部实现 ElementExt
@@ -33,4 +37,22 @@ pub trait ElementExt: private::Sealed + Sized {
 
     /// 启动渲染主循环
     fn render_loop(&mut self) -> impl Future<Output = io::Result<()>>;
+
+    /// 启动渲染主循环，并返回组件通过 `finish` 传出的结果
+    ///
+    /// ```ignore
+    /// let choice: Option<String> = element!(Picker(items)).render_loop_with_result().await?;
+    /// ```
+    fn render_loop_with_result<T: Send + 'static>(
+        &mut self,
+    ) -> impl Future<Output = io::Result<Option<T>>> {
+        async { Ok(render_loop_with_exit(self).await?.result) }
+    }
+
+    /// 启动渲染主循环，并返回组件传出的结果与退出码，见 [`RenderExit`]
+    fn render_loop_with_exit<T: Send + 'static>(
+        &mut self,
+    ) -> impl Future<Output = io::Result<RenderExit<T>>> {
+        render_loop_with_exit(self)
+    }
 }
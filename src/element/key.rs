@@ -3,7 +3,10 @@ use std::{fmt::Debug, hash::Hash, sync::Arc};
 
 /// ElementKey：用于唯一标识组件树中的节点，支持任意可哈希类型
 #[derive(Clone, PartialEq, Eq, Hash, Debug)]
-pub struct ElementKey(Arc<Box<dyn AnyHash + Send + Sync>>);
+pub struct ElementKey {
+    value: Arc<Box<dyn AnyHash + Send + Sync>>,
+    portable: bool,
+}
 
 impl ElementKey {
     /// 创建新的 ElementKey，支持任意实现了 AnyHash 的类型
@@ -11,6 +14,37 @@ impl ElementKey {
     where
         T: Debug + Send + Sync + AnyHash,
     {
-        Self(Arc::new(Box::new(value)))
+        Self {
+            value: Arc::new(Box::new(value)),
+            portable: false,
+        }
+    }
+
+    /// 创建可迁移的 ElementKey：所在的父组件被替换或移除时，子树不会立即销毁，
+    /// 同一帧中任意位置出现相同 key 且类型一致的元素时直接接管原来的实例，保留全部状态
+    ///
+    /// 在 `element!` 中通过 `portable_key` 属性使用，不同声明位置的相同值视为同一个 key。
+    ///
+    /// ```ignore
+    /// // 在有边框与无边框的容器之间切换，编辑器的状态保持不变
+    /// if bordered {
+    ///     element!(Bordered { Editor(portable_key: "editor") })
+    /// } else {
+    ///     element!(Plain { Editor(portable_key: "editor") })
+    /// }
+    /// ```
+    pub fn portable<T>(value: T) -> Self
+    where
+        T: Debug + Send + Sync + AnyHash,
+    {
+        Self {
+            value: Arc::new(Box::new(value)),
+            portable: true,
+        }
+    }
+
+    /// 是否为可迁移的 key
+    pub fn is_portable(&self) -> bool {
+        self.portable
     }
 }
@@ -6,12 +6,14 @@ use crate::{
         Component,
         component_helper::{ComponentHelper, ComponentHelperExt},
     },
-    props::AnyProps,
+    props::{AnyProps, Props},
     render::tree::render_loop,
 };
 pub use key::ElementKey;
 mod any_element;
 pub use any_element::AnyElement;
+mod children;
+pub use children::Children;
 mod element_ext;
 pub use element_ext::ElementExt;
 mod extend_with_elements;
@@ -19,7 +21,7 @@ pub use extend_with_elements::{ExtendWithElements, extend_with_elements};
 
 /// ElementType trait：为每种组件类型定义 Props 类型，便于泛型处理
 pub trait ElementType {
-    type Props<'a>
+    type Props<'a>: Props
     where
         Self: 'a;
 }
@@ -7,10 +7,55 @@ use std::{
     pin::Pin,
     task::{Context, Poll},
 };
+pub mod use_animation;
+pub mod use_async_task;
+pub mod use_channel;
+pub mod use_clipboard;
+pub mod use_completion;
+pub mod use_component_ref;
+#[cfg(feature = "connectivity")]
+pub mod use_connectivity;
 pub mod use_context;
+pub mod use_context_menu;
+pub mod use_debounce;
+pub mod use_direction;
+pub mod use_effect;
+pub mod use_element_size;
 pub mod use_events;
+pub mod use_exit;
+pub mod use_focus;
+pub mod use_form;
 pub mod use_future;
+pub mod use_history;
+pub mod use_idle;
+pub mod use_interval;
+pub mod use_keyed_state;
+pub mod use_keymap;
+pub mod use_max_refresh_rate;
+pub mod use_memo;
+pub mod use_mode;
+pub mod use_mouse;
+pub mod use_navigate;
+pub mod use_paste;
+pub mod use_persisted_state;
+pub mod use_prepared;
+pub mod use_previous;
+pub mod use_progress;
+pub mod use_query;
+pub mod use_reducer;
+pub mod use_ref;
+pub mod use_resource;
+pub mod use_scroll;
+pub mod use_signal_handler;
 pub mod use_state;
+pub mod use_status;
+pub mod use_store;
+pub mod use_stream;
+pub mod use_terminal_size;
+pub mod use_theme;
+pub mod use_throttle;
+pub mod use_toast;
+pub mod use_worker;
 
 // Hook trait：所有 Hook 类型的基础接口，支持异步轮询
 pub trait Hook: Unpin + Send {
@@ -23,11 +68,33 @@ pub trait Hook: Unpin + Send {
     fn pre_component_update(&mut self, _updater: &mut ComponentUpdater) {}
     // 组件更新后的钩子
     fn post_component_update(&mut self, _updater: &mut ComponentUpdater) {}
+    // 组件跳过 update 时代替上面两个钩子调用，把上一次 update 登记到祖先上下文中的数据重新登记一次
+    fn replay_component_update(&mut self, _updater: &mut ComponentUpdater) {}
 
     // 组件绘制前的钩子
     fn pre_component_draw(&mut self, _drawer: &mut ComponentDrawer) {}
     // 组件绘制后的钩子
     fn post_component_draw(&mut self, _drawer: &mut ComponentDrawer) {}
+
+    // 组件卸载时的钩子，在组件的 unmount 之前调用
+    fn on_unmount(&mut self) {}
+
+    // 状态可以被 Tree::snapshot_state 保存时返回自身，默认不参与快照
+    fn persistable(&mut self) -> Option<&mut dyn PersistableHook> {
+        None
+    }
+}
+
+/// PersistableHook：状态可以序列化的 Hook，参与 [`Tree::snapshot_state`](crate::render::tree::Tree::snapshot_state)
+/// 与 [`Tree::restore_state`](crate::render::tree::Tree::restore_state)
+///
+/// 实现后在 [`Hook::persistable`] 中返回 `Some(self)`。
+pub trait PersistableHook {
+    /// 当前状态，无法序列化时返回 None
+    fn snapshot(&self) -> Option<serde_json::Value>;
+
+    /// 恢复到快照中的状态，值无法解析时忽略
+    fn restore(&mut self, value: serde_json::Value);
 }
 
 // AnyHook trait：用于类型擦除和运行时类型转换
@@ -74,6 +141,12 @@ impl Hook for Vec<Box<dyn AnyHook>> {
         }
     }
 
+    fn replay_component_update(&mut self, _updater: &mut ComponentUpdater) {
+        for hook in self.iter_mut() {
+            hook.replay_component_update(_updater);
+        }
+    }
+
     fn pre_component_draw(&mut self, _updater: &mut ComponentDrawer) {
         for hook in self.iter_mut() {
             hook.pre_component_draw(_updater);
@@ -85,6 +158,12 @@ impl Hook for Vec<Box<dyn AnyHook>> {
             hook.post_component_draw(_updater);
         }
     }
+
+    fn on_unmount(&mut self) {
+        for hook in self.iter_mut() {
+            hook.on_unmount();
+        }
+    }
 }
 
 // Hooks 结构体：管理组件中的所有 Hook 实例
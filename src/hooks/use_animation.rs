@@ -0,0 +1,138 @@
+use std::time::{Duration, Instant};
+
+use super::{Hook, Hooks};
+use crate::{
+    animation::{AnimationClock, Easing, Interpolate},
+    clock,
+    context::SystemContext,
+    render::updater::ComponentUpdater,
+};
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::hooks::Hooks<'_, '_> {}
+}
+
+pub trait UseAnimation: private::Sealed {
+    /// 在 duration 内从 from 过渡到 to，返回当前帧的插值
+    ///
+    /// - 首次渲染时从 from 开始播放
+    /// - 之后 to 变化时，从当前的值出发过渡到新的 to，适合进度条、滑入面板等跟随状态变化的动画
+    /// - 动画进行时渲染循环按帧率刷新，结束后不再产生刷新
+    /// - 开启 [`SystemContext::reduced_motion`] 时直接跳到 to
+    ///
+    /// 通过 `with_context_stack` 提供上下文时首帧就能读取 reduced_motion，否则在下一帧生效。
+    ///
+    /// ```ignore
+    /// let open = hooks.use_state(|| false);
+    /// // 面板从右侧滑入
+    /// let offset = hooks.use_animation(
+    ///     40u16,
+    ///     if open.get() { 0 } else { 40 },
+    ///     Duration::from_millis(200),
+    ///     Easing::CubicOut,
+    /// );
+    /// ```
+    fn use_animation<T>(&mut self, from: T, to: T, duration: Duration, easing: Easing) -> T
+    where
+        T: Interpolate + PartialEq + Send + Unpin + 'static;
+}
+
+struct UseAnimationImpl<T> {
+    start: T,
+    target: T,
+    started_at: Instant,
+    duration: Duration,
+    easing: Easing,
+    clock: Option<AnimationClock>,
+    reduced_motion: bool,
+    /// 是否已在时钟上登记为进行中
+    running: bool,
+    finished: bool,
+}
+
+impl<T: Interpolate> UseAnimationImpl<T> {
+    fn value(&mut self) -> T {
+        if self.finished || self.reduced_motion || self.duration.is_zero() {
+            self.finished = true;
+            return self.target.clone();
+        }
+        let t = clock::elapsed(self.started_at).as_secs_f64() / self.duration.as_secs_f64();
+        if t >= 1.0 {
+            self.finished = true;
+            return self.target.clone();
+        }
+        self.start.interpolate(&self.target, self.easing.apply(t))
+    }
+}
+
+impl<T: Send + Unpin> Hook for UseAnimationImpl<T> {
+    fn post_component_update(&mut self, updater: &mut ComponentUpdater) {
+        if self.clock.is_none()
+            && let Some(system) = updater
+                .component_context_stack()
+                .get_context::<SystemContext>()
+        {
+            self.clock = Some(system.animations());
+            self.reduced_motion = system.reduced_motion();
+        }
+        let Some(clock) = &self.clock else {
+            return;
+        };
+        if self.running == self.finished {
+            if self.finished {
+                clock.stop();
+            } else {
+                clock.start();
+            }
+            self.running = !self.finished;
+        }
+    }
+}
+
+impl<T> Drop for UseAnimationImpl<T> {
+    fn drop(&mut self) {
+        if self.running
+            && let Some(clock) = &self.clock
+        {
+            clock.stop();
+        }
+    }
+}
+
+impl UseAnimation for Hooks<'_, '_> {
+    fn use_animation<T>(&mut self, from: T, to: T, duration: Duration, easing: Easing) -> T
+    where
+        T: Interpolate + PartialEq + Send + Unpin + 'static,
+    {
+        let system = self
+            .context
+            .and_then(|context| context.get_context::<SystemContext>())
+            .map(|system| (system.animations(), system.reduced_motion()));
+        let target = to.clone();
+        let hook = self.use_hook(move || UseAnimationImpl {
+            start: from,
+            target,
+            started_at: clock::now(),
+            duration,
+            easing,
+            clock: None,
+            reduced_motion: false,
+            running: false,
+            finished: false,
+        });
+        if let Some((clock, reduced_motion)) = system {
+            hook.clock.get_or_insert(clock);
+            hook.reduced_motion = reduced_motion;
+        }
+        hook.duration = duration;
+        hook.easing = easing;
+        if hook.target != to {
+            hook.start = hook.value();
+            hook.target = to;
+            hook.started_at = clock::now();
+            hook.finished = false;
+        }
+        hook.value()
+    }
+}
@@ -0,0 +1,142 @@
+use std::{
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll, Waker},
+};
+
+use futures::{FutureExt, future::BoxFuture};
+use tokio::task::JoinHandle;
+
+use super::{Hook, Hooks};
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::hooks::Hooks<'_, '_> {}
+}
+
+type TaskFn = dyn Fn() -> BoxFuture<'static, ()> + Send + Sync;
+
+struct TaskInner {
+    factory: Arc<TaskFn>,
+    handle: Option<JoinHandle<()>>,
+    waker: Option<Waker>,
+}
+
+impl TaskInner {
+    fn spawn(&mut self) {
+        if let Some(handle) = self.handle.take() {
+            handle.abort();
+        }
+        self.handle = Some(tokio::spawn((self.factory)()));
+        if let Some(waker) = self.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+/// TaskHandle：后台任务的控制句柄
+///
+/// 可以克隆并移动到事件回调中，任务结束、取消或重启时组件会重新渲染。
+#[derive(Clone)]
+pub struct TaskHandle {
+    inner: Arc<Mutex<TaskInner>>,
+}
+
+impl TaskHandle {
+    /// 取消任务，已经结束时不做任何事
+    pub fn cancel(&self) {
+        let mut inner = self.inner.lock().unwrap();
+        if let Some(handle) = &inner.handle {
+            handle.abort();
+        }
+        if let Some(waker) = inner.waker.take() {
+            waker.wake();
+        }
+    }
+
+    /// 取消正在运行的任务并重新启动
+    pub fn restart(&self) {
+        self.inner.lock().unwrap().spawn();
+    }
+
+    /// 任务是否仍在运行
+    pub fn is_running(&self) -> bool {
+        self.inner
+            .lock()
+            .unwrap()
+            .handle
+            .as_ref()
+            .is_some_and(|handle| !handle.is_finished())
+    }
+}
+
+pub trait UseAsyncTask: private::Sealed {
+    /// 在 tokio 运行时中启动一个后台任务，并返回控制句柄
+    ///
+    /// - f 在首次渲染以及每次 restart 时调用，用于创建新的 Future
+    /// - 组件卸载时任务会被自动取消
+    ///
+    /// ```ignore
+    /// let task = hooks.use_async_task(move || async move {
+    ///     let data = fetch().await;
+    ///     result.set(Some(data));
+    /// });
+    /// // 在事件回调中：task.cancel() / task.restart()
+    /// ```
+    fn use_async_task<F, Fut>(&mut self, f: F) -> TaskHandle
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static;
+}
+
+struct UseAsyncTaskImpl {
+    task: TaskHandle,
+}
+
+impl Hook for UseAsyncTaskImpl {
+    fn poll_change(self: Pin<&mut Self>, cx: &mut Context) -> Poll<()> {
+        let mut inner = self.task.inner.lock().unwrap();
+        inner.waker = Some(cx.waker().clone());
+        // 任务结束（包括被取消）后清除句柄，并通知组件更新运行状态
+        let finished = inner
+            .handle
+            .as_mut()
+            .is_some_and(|handle| handle.poll_unpin(cx).is_ready());
+        if finished {
+            inner.handle = None;
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+impl Drop for UseAsyncTaskImpl {
+    fn drop(&mut self) {
+        self.task.cancel();
+    }
+}
+
+impl UseAsyncTask for Hooks<'_, '_> {
+    fn use_async_task<F, Fut>(&mut self, f: F) -> TaskHandle
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        self.use_hook(move || {
+            let mut inner = TaskInner {
+                factory: Arc::new(move || f().boxed()),
+                handle: None,
+                waker: None,
+            };
+            inner.spawn();
+            UseAsyncTaskImpl {
+                task: TaskHandle {
+                    inner: Arc::new(Mutex::new(inner)),
+                },
+            }
+        })
+        .task
+        .clone()
+    }
+}
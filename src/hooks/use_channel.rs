@@ -0,0 +1,75 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use tokio::sync::mpsc::{self, UnboundedReceiver, UnboundedSender};
+
+use super::{Hook, Hooks};
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::hooks::Hooks<'_, '_> {}
+}
+
+pub trait UseChannel: private::Sealed {
+    /// 创建一个由组件持有接收端的通道，用于把后台任务的结果传回界面
+    ///
+    /// 返回可克隆的发送端，以及自上一次 update 以来收到的所有值。
+    /// 发送端在组件卸载后会发送失败，不会访问已经释放的状态。
+    ///
+    /// ```ignore
+    /// let (tx, received) = hooks.use_channel::<String>();
+    /// for line in received {
+    ///     log.write().push(line);
+    /// }
+    /// hooks.use_future(async move {
+    ///     while let Some(line) = next_line().await {
+    ///         if tx.send(line).is_err() {
+    ///             break;
+    ///         }
+    ///     }
+    /// });
+    /// ```
+    fn use_channel<T: Send + 'static>(&mut self) -> (UnboundedSender<T>, Vec<T>);
+}
+
+struct UseChannelImpl<T> {
+    sender: UnboundedSender<T>,
+    receiver: UnboundedReceiver<T>,
+    /// 已经收到、等待下一次 update 取走的值
+    received: Vec<T>,
+}
+
+// 不会对字段做 pin 投影，值只在 Vec 中移动，无需要求 T: Unpin
+impl<T> Unpin for UseChannelImpl<T> {}
+
+impl<T: Send> Hook for UseChannelImpl<T> {
+    fn poll_change(self: Pin<&mut Self>, cx: &mut Context) -> Poll<()> {
+        let this = self.get_mut();
+        let mut changed = false;
+        while let Poll::Ready(Some(value)) = this.receiver.poll_recv(cx) {
+            this.received.push(value);
+            changed = true;
+        }
+        if changed {
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+impl UseChannel for Hooks<'_, '_> {
+    fn use_channel<T: Send + 'static>(&mut self) -> (UnboundedSender<T>, Vec<T>) {
+        let hook = self.use_hook(|| {
+            let (sender, receiver) = mpsc::unbounded_channel();
+            UseChannelImpl {
+                sender,
+                receiver,
+                received: Vec::new(),
+            }
+        });
+        (hook.sender.clone(), std::mem::take(&mut hook.received))
+    }
+}
@@ -0,0 +1,122 @@
+use std::{
+    io::{self, Write},
+    sync::{Arc, Mutex},
+};
+
+use super::Hooks;
+use crate::context::SystemContext;
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::hooks::Hooks<'_, '_> {}
+}
+
+struct ClipboardInner {
+    text: Option<String>,
+    osc52: bool,
+}
+
+impl Default for ClipboardInner {
+    fn default() -> Self {
+        Self {
+            text: None,
+            osc52: true,
+        }
+    }
+}
+
+/// Clipboard：剪贴板
+///
+/// 由 [`SystemContext::clipboard`] 提供。复制的内容保存在应用内，同时通过 OSC 52 转义序列写入系统剪贴板，
+/// 在 SSH 与 tmux 中同样有效（终端需要允许 OSC 52）。大多数终端出于安全考虑不允许读取系统剪贴板，
+/// 因此 [`Clipboard::paste`] 返回的是应用内最近一次复制的内容，从外部粘贴请使用终端的粘贴事件。
+#[derive(Clone, Default)]
+pub struct Clipboard {
+    inner: Arc<Mutex<ClipboardInner>>,
+}
+
+impl Clipboard {
+    /// 复制 text
+    pub fn copy(&self, text: impl Into<String>) {
+        let text = text.into();
+        let osc52 = {
+            let mut inner = self.inner.lock().unwrap();
+            inner.text = Some(text.clone());
+            inner.osc52
+        };
+        if osc52 {
+            // 写入失败时只影响系统剪贴板
+            let _ = write_osc52(&text);
+        }
+    }
+
+    /// 应用内最近一次复制的内容
+    pub fn paste(&self) -> Option<String> {
+        self.inner.lock().unwrap().text.clone()
+    }
+
+    /// 是否通过 OSC 52 写入系统剪贴板，默认开启
+    pub fn set_osc52(&self, enabled: bool) {
+        self.inner.lock().unwrap().osc52 = enabled;
+    }
+}
+
+fn write_osc52(text: &str) -> io::Result<()> {
+    let sequence = format!("\x1b]52;c;{}\x07", base64(text.as_bytes()));
+    let mut stdout = io::stdout();
+    if std::env::var_os("TMUX").is_some() {
+        // tmux 需要用 DCS 透传，内部的 ESC 需要重复一次
+        write!(
+            stdout,
+            "\x1bPtmux;{}\x1b\\",
+            sequence.replace('\x1b', "\x1b\x1b")
+        )?;
+    } else {
+        write!(stdout, "{sequence}")?;
+    }
+    stdout.flush()
+}
+
+fn base64(bytes: &[u8]) -> String {
+    const TABLE: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let n = chunk
+            .iter()
+            .enumerate()
+            .fold(0u32, |n, (i, &byte)| n | ((byte as u32) << (16 - 8 * i)));
+        for i in 0..4 {
+            if i <= chunk.len() {
+                encoded.push(TABLE[((n >> (18 - 6 * i)) & 0x3f) as usize] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+    encoded
+}
+
+pub trait UseClipboard: private::Sealed {
+    /// 获取全局的剪贴板
+    ///
+    /// 需要通过 `with_context_stack` 提供上下文，否则返回一个独立的剪贴板。
+    ///
+    /// ```ignore
+    /// let clipboard = hooks.with_context_stack(updater.component_context_stack()).use_clipboard();
+    /// hooks.use_events(move |event| {
+    ///     if is_copy(&event) {
+    ///         clipboard.copy(selected_text());
+    ///     }
+    /// });
+    /// ```
+    fn use_clipboard(&self) -> Clipboard;
+}
+
+impl UseClipboard for Hooks<'_, '_> {
+    fn use_clipboard(&self) -> Clipboard {
+        self.context
+            .and_then(|context| context.get_context::<SystemContext>())
+            .map(|system| system.clipboard())
+            .unwrap_or_default()
+    }
+}
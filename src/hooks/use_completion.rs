@@ -0,0 +1,225 @@
+use std::{
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+};
+
+use futures::{FutureExt, future::BoxFuture};
+
+use super::{
+    Hook, Hooks,
+    use_state::{State, UseState},
+};
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::hooks::Hooks<'_, '_> {}
+}
+
+type CompletionFn = dyn Fn(String) -> BoxFuture<'static, Vec<String>> + Send + Sync;
+
+/// 补全候选项的提供者，支持同步与异步两种形式
+///
+/// 候选项是对整个输入的完整替换值。
+#[derive(Clone, Default)]
+pub struct CompletionProvider {
+    f: Option<Arc<CompletionFn>>,
+}
+
+impl CompletionProvider {
+    /// 由同步函数创建，函数会在 Hook 轮询时调用
+    pub fn new<F>(f: F) -> Self
+    where
+        F: Fn(&str) -> Vec<String> + Send + Sync + 'static,
+    {
+        let f = Arc::new(f);
+        Self {
+            f: Some(Arc::new(move |input: String| {
+                let f = f.clone();
+                async move { f(&input) }.boxed()
+            })),
+        }
+    }
+
+    /// 由异步函数创建，适用于查询数据库、请求网络等场景
+    pub fn from_async<F, Fut>(f: F) -> Self
+    where
+        F: Fn(String) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Vec<String>> + Send + 'static,
+    {
+        Self {
+            f: Some(Arc::new(move |input| f(input).boxed())),
+        }
+    }
+
+    /// 是否为空提供者（不产生任何候选项）
+    pub fn is_empty(&self) -> bool {
+        self.f.is_none()
+    }
+}
+
+/// 补全状态
+#[derive(Default)]
+struct CompletionState {
+    /// 候选项对应的输入
+    input: String,
+    candidates: Vec<String>,
+    /// 当前选中的候选项
+    selected: Option<usize>,
+    /// 候选列表是否展开
+    open: bool,
+    /// 等待 Hook 处理的补全请求
+    requested: Option<String>,
+    /// 是否正在计算候选项
+    loading: bool,
+}
+
+/// Completion：补全句柄，可以在事件回调中自由复制使用
+#[derive(Clone, Copy)]
+pub struct Completion {
+    state: State<CompletionState>,
+}
+
+impl Completion {
+    /// 为给定输入请求候选项，旧的未完成请求会被丢弃
+    pub fn request(&mut self, input: &str) {
+        let mut state = self.state.write();
+        state.requested = Some(input.to_string());
+        state.open = true;
+    }
+
+    /// 当前的候选项
+    pub fn candidates(&self) -> Vec<String> {
+        self.state.read().candidates.clone()
+    }
+
+    /// 当前选中的候选项下标
+    pub fn selected(&self) -> Option<usize> {
+        self.state.read().selected
+    }
+
+    /// 当前选中的候选项
+    pub fn selected_candidate(&self) -> Option<String> {
+        let state = self.state.read();
+        state
+            .selected
+            .and_then(|i| state.candidates.get(i).cloned())
+    }
+
+    /// 选中下一个候选项，到末尾后回到第一个
+    pub fn select_next(&mut self) -> Option<String> {
+        self.cycle(1)
+    }
+
+    /// 选中上一个候选项，到开头后回到最后一个
+    pub fn select_prev(&mut self) -> Option<String> {
+        self.cycle(-1)
+    }
+
+    fn cycle(&mut self, step: isize) -> Option<String> {
+        {
+            let mut state = self.state.write();
+            let len = state.candidates.len() as isize;
+            if len == 0 {
+                return None;
+            }
+            state.open = true;
+            state.selected = Some(match state.selected {
+                Some(i) => (i as isize + step).rem_euclid(len) as usize,
+                None if step > 0 => 0,
+                None => (len - 1) as usize,
+            });
+        }
+        self.selected_candidate()
+    }
+
+    /// 候选列表是否展开且有内容
+    pub fn is_open(&self) -> bool {
+        let state = self.state.read();
+        state.open && !state.candidates.is_empty()
+    }
+
+    /// 是否正在计算候选项
+    pub fn is_loading(&self) -> bool {
+        self.state.read().loading
+    }
+
+    /// 收起候选列表并清除选中
+    pub fn dismiss(&mut self) {
+        let mut state = self.state.write();
+        state.open = false;
+        state.selected = None;
+    }
+}
+
+pub trait UseCompletion: private::Sealed {
+    /// 注册补全 Hook，返回补全句柄
+    ///
+    /// 通过 [`Completion::request`] 提交输入后，Hook 会在轮询时调用 provider 计算候选项，
+    /// 结果就绪后自动触发重新渲染。
+    fn use_completion(&mut self, provider: CompletionProvider) -> Completion;
+}
+
+struct UseCompletionImpl {
+    provider: CompletionProvider,
+    state: State<CompletionState>,
+    pending: Option<(String, BoxFuture<'static, Vec<String>>)>,
+}
+
+impl Hook for UseCompletionImpl {
+    fn poll_change(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<()> {
+        // 先只读检查，避免可变借用误触发状态变更
+        let has_request = self
+            .state
+            .try_read()
+            .is_some_and(|state| state.requested.is_some());
+        let requested = if has_request {
+            self.state.write().requested.take()
+        } else {
+            None
+        };
+        if let Some(input) = requested {
+            match &self.provider.f {
+                Some(f) => {
+                    let future = f(input.clone());
+                    self.pending = Some((input, future));
+                    self.state.write().loading = true;
+                }
+                None => {
+                    let mut state = self.state.write();
+                    state.candidates.clear();
+                    state.selected = None;
+                    state.input = input;
+                }
+            }
+        }
+
+        if let Some((input, future)) = &mut self.pending
+            && let Poll::Ready(candidates) = future.as_mut().poll(cx)
+        {
+            let input = std::mem::take(input);
+            self.pending = None;
+            let mut state = self.state.write();
+            state.candidates = candidates;
+            state.selected = None;
+            state.input = input;
+            state.loading = false;
+        }
+
+        // 状态的变化由 State 自身负责唤醒
+        Poll::Pending
+    }
+}
+
+impl UseCompletion for Hooks<'_, '_> {
+    fn use_completion(&mut self, provider: CompletionProvider) -> Completion {
+        let state = self.use_state(CompletionState::default);
+        let hook = self.use_hook(|| UseCompletionImpl {
+            provider: CompletionProvider::default(),
+            state,
+            pending: None,
+        });
+        hook.provider = provider;
+        Completion { state }
+    }
+}
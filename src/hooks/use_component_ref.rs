@@ -0,0 +1,124 @@
+use std::{
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll, Waker},
+};
+
+use super::{Hook, Hooks};
+use crate::{
+    component::Component,
+    element::{Element, key::ElementKey},
+    render::updater::ComponentUpdater,
+};
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::hooks::Hooks<'_, '_> {}
+}
+
+type Command<T> = Box<dyn FnOnce(&mut T) + Send>;
+
+struct RefInner<T> {
+    /// 绑定的子组件
+    key: Option<ElementKey>,
+    commands: Vec<Command<T>>,
+    waker: Option<Waker>,
+}
+
+/// ComponentRef：指向某个子组件实例的句柄
+///
+/// 在 `element!` 中通过 `ref: handle` 绑定到子组件（可以是更深层的后代），
+/// 之后可以在事件回调中调用 [`ComponentRef::call`] 操作子组件实例，
+/// 例如 `list_ref.call(|list: &mut List| list.scroll_to(0))`。
+pub struct ComponentRef<T> {
+    inner: Arc<Mutex<RefInner<T>>>,
+}
+
+impl<T> Clone for ComponentRef<T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<T: Component> ComponentRef<T> {
+    /// 绑定到 element 对应的组件实例，由 `element!` 的 `ref:` 属性调用
+    pub fn attach(&self, element: &Element<'_, T>) {
+        self.inner.lock().unwrap().key = Some(element.key.clone());
+    }
+
+    /// 是否已经绑定到子组件
+    pub fn is_attached(&self) -> bool {
+        self.inner.lock().unwrap().key.is_some()
+    }
+
+    /// 在子组件实例上执行 f
+    ///
+    /// f 会在下一轮渲染中、子组件 update 之后执行。组件的字段在每次 update 时通常会重新计算，
+    /// 需要持久生效的修改应写入组件持有的状态。未绑定或找不到子组件时 f 会被丢弃。
+    pub fn call(&self, f: impl FnOnce(&mut T) + Send + 'static) {
+        let mut inner = self.inner.lock().unwrap();
+        inner.commands.push(Box::new(f));
+        if let Some(waker) = inner.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+pub trait UseComponentRef: private::Sealed {
+    /// 创建一个子组件引用，需要在 `element!` 中通过 `ref:` 绑定到类型为 T 的子组件
+    fn use_component_ref<T: Component>(&mut self) -> ComponentRef<T>;
+}
+
+struct UseComponentRefImpl<T> {
+    component_ref: ComponentRef<T>,
+}
+
+impl<T: Component> Hook for UseComponentRefImpl<T> {
+    fn poll_change(self: Pin<&mut Self>, cx: &mut Context) -> Poll<()> {
+        let mut inner = self.component_ref.inner.lock().unwrap();
+        if inner.commands.is_empty() {
+            inner.waker = Some(cx.waker().clone());
+            Poll::Pending
+        } else {
+            Poll::Ready(())
+        }
+    }
+
+    // 子组件在当前组件的 update 中完成更新，此时再执行排队的调用
+    fn post_component_update(&mut self, updater: &mut ComponentUpdater) {
+        let (key, commands) = {
+            let mut inner = self.component_ref.inner.lock().unwrap();
+            if inner.commands.is_empty() {
+                return;
+            }
+            (inner.key.clone(), std::mem::take(&mut inner.commands))
+        };
+        let Some(component) = key
+            .and_then(|key| updater.components_mut().find_mut(&key))
+            .and_then(|component| component.downcast_mut::<T>())
+        else {
+            return;
+        };
+        for command in commands {
+            command(component);
+        }
+    }
+}
+
+impl UseComponentRef for Hooks<'_, '_> {
+    fn use_component_ref<T: Component>(&mut self) -> ComponentRef<T> {
+        self.use_hook(|| UseComponentRefImpl {
+            component_ref: ComponentRef {
+                inner: Arc::new(Mutex::new(RefInner {
+                    key: None,
+                    commands: Vec::new(),
+                    waker: None,
+                })),
+            },
+        })
+        .component_ref
+        .clone()
+    }
+}
@@ -0,0 +1,101 @@
+use std::time::Duration;
+
+use super::{Hooks, use_future::UseFuture, use_state::UseState};
+use crate::clock;
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::hooks::Hooks<'_, '_> {}
+}
+
+/// 网络连通状态
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Connectivity {
+    /// 第一次探测尚未完成
+    #[default]
+    Unknown,
+    Online,
+    Offline,
+}
+
+impl Connectivity {
+    pub fn is_online(self) -> bool {
+        self == Connectivity::Online
+    }
+
+    /// 只有探测失败后才算离线，尚未探测完成时不算
+    pub fn is_offline(self) -> bool {
+        self == Connectivity::Offline
+    }
+}
+
+/// 连通性探测的参数
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConnectivityOptions {
+    /// 探测的地址，形如 `host:port`，能建立 TCP 连接即视为在线
+    pub endpoint: String,
+    /// 两次探测之间的间隔
+    pub interval: Duration,
+    /// 单次探测的超时时间
+    pub timeout: Duration,
+}
+
+impl Default for ConnectivityOptions {
+    fn default() -> Self {
+        Self {
+            endpoint: "1.1.1.1:443".to_string(),
+            interval: Duration::from_secs(10),
+            timeout: Duration::from_secs(3),
+        }
+    }
+}
+
+pub trait UseConnectivity: private::Sealed {
+    /// 定期探测网络是否可用，状态变化时重新渲染
+    ///
+    /// 离线时可以显示提示条，并暂停依赖网络的查询：
+    ///
+    /// ```ignore
+    /// let connectivity = hooks.use_connectivity();
+    /// let mut query = hooks.use_query("feed", fetch_feed);
+    /// let previous = hooks.use_previous(connectivity);
+    /// // 恢复连接时重新获取
+    /// if previous == Some(Connectivity::Offline) && connectivity.is_online() {
+    ///     query.refetch();
+    /// }
+    /// ```
+    fn use_connectivity(&mut self) -> Connectivity;
+
+    /// 与 [`UseConnectivity::use_connectivity`] 相同，但可以指定探测的地址与频率
+    fn use_connectivity_with(&mut self, options: ConnectivityOptions) -> Connectivity;
+}
+
+async fn probe(options: &ConnectivityOptions) -> Connectivity {
+    let connect = tokio::net::TcpStream::connect(options.endpoint.as_str());
+    match tokio::time::timeout(options.timeout, connect).await {
+        Ok(Ok(_)) => Connectivity::Online,
+        _ => Connectivity::Offline,
+    }
+}
+
+impl UseConnectivity for Hooks<'_, '_> {
+    fn use_connectivity(&mut self) -> Connectivity {
+        self.use_connectivity_with(ConnectivityOptions::default())
+    }
+
+    fn use_connectivity_with(&mut self, options: ConnectivityOptions) -> Connectivity {
+        let status = self.use_state(Connectivity::default);
+        self.use_future_with(options.clone(), move || async move {
+            let mut status = status;
+            loop {
+                let current = probe(&options).await;
+                // 只在变化时写入，避免多余的重新渲染
+                if status.get() != current {
+                    status.set(current);
+                }
+                clock::sleep(options.interval).await;
+            }
+        });
+        status.get()
+    }
+}
@@ -3,7 +3,7 @@ use std::{
     cell::{Ref, RefMut},
 };
 
-use super::Hooks;
+use super::{Hooks, use_store::UseStore};
 
 // 私有模块用于防止 trait 被外部实现，保证 API 封装性
 mod private {
@@ -24,6 +24,25 @@ pub trait UseContext<'a>: private::Sealed {
     fn try_use_context<T: Any>(&self) -> Option<Ref<'a, T>>;
     /// 尝试获取类型为 T 的可变上下文引用，找不到返回 None。
     fn try_use_context_mut<T: Any>(&self) -> Option<RefMut<'a, T>>;
+
+    /// 读取并订阅最近的上层以 [`Store<T>`](super::use_store::Store) 形式提供的上下文，
+    /// 提供方修改值后组件会重新渲染；找不到时使用根部安装的 store，都没有会 panic。
+    ///
+    /// 上面的方法只在 update 时读取一次，提供方在回调或后台任务中修改值不会通知使用方。
+    ///
+    /// ```ignore
+    /// // 提供方
+    /// let theme = hooks.use_memo((), || Store::new(Theme::dark()));
+    /// updater.update_children(children, Some(Context::owned(theme.clone())));
+    /// // 在回调中切换主题，所有使用方都会重新渲染
+    /// theme.update(|theme| *theme = Theme::light());
+    ///
+    /// // 使用方
+    /// let theme = hooks.use_context_value::<Theme>();
+    /// ```
+    fn use_context_value<T>(&mut self) -> T
+    where
+        T: Clone + PartialEq + Unpin + Send + 'static;
 }
 
 impl<'a> UseContext<'a> for Hooks<'a, '_> {
@@ -54,4 +73,11 @@ impl<'a> UseContext<'a> for Hooks<'a, '_> {
         self.context
             .and_then(|context_stack| context_stack.get_context_mut())
     }
+
+    fn use_context_value<T>(&mut self) -> T
+    where
+        T: Clone + PartialEq + Unpin + Send + 'static,
+    {
+        self.use_selector(|value: &T| value.clone())
+    }
 }
@@ -0,0 +1,362 @@
+use std::{
+    pin::{Pin, pin},
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicU64, Ordering},
+    },
+    task::{Context, Poll},
+};
+
+use crossterm::event::{Event, KeyCode, KeyEventKind, MouseButton, MouseEventKind};
+use futures::Stream;
+use ratatui::{
+    Frame,
+    layout::{Position, Rect},
+    style::{Modifier, Style},
+    text::Line,
+    widgets::{Block, Clear, Paragraph},
+};
+
+use super::{Hook, Hooks};
+use crate::{
+    context::SystemContext,
+    render::{drawer::ComponentDrawer, updater::ComponentUpdater},
+    terminal::{InputLayer, InputLayers, TerminalEvents},
+};
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::hooks::Hooks<'_, '_> {}
+}
+
+/// 右键菜单中的一项
+#[derive(Clone)]
+pub struct ContextMenuItem {
+    pub label: String,
+    action: Arc<dyn Fn() + Send + Sync>,
+}
+
+impl ContextMenuItem {
+    /// 创建菜单项，选中时调用 action
+    pub fn new<F>(label: impl Into<String>, action: F) -> Self
+    where
+        F: Fn() + Send + Sync + 'static,
+    {
+        Self {
+            label: label.into(),
+            action: Arc::new(action),
+        }
+    }
+}
+
+/// 当前弹出的菜单
+struct OpenMenu {
+    /// 注册该菜单的 Hook
+    owner: u64,
+    items: Vec<ContextMenuItem>,
+    position: Position,
+    /// 注册组件的区域，用于嵌套时选择最内层的菜单
+    area: Rect,
+    /// 菜单打开期间占据的输入层级
+    layer: InputLayer,
+    /// 注册组件所在的输入层级
+    base: InputLayer,
+    selected: usize,
+    /// 最近一次绘制的位置，用于鼠标命中判断
+    rect: Option<Rect>,
+}
+
+impl OpenMenu {
+    fn item_at(&self, position: Position) -> Option<usize> {
+        let rect = self.rect?;
+        let inner = Block::bordered().inner(rect);
+        inner
+            .contains(position)
+            .then(|| (position.y - inner.y) as usize)
+            .filter(|index| *index < self.items.len())
+    }
+}
+
+/// ContextMenus：全局唯一的右键菜单弹层
+///
+/// 由 [`SystemContext::context_menus`] 提供，渲染循环在整棵组件树绘制完成后绘制弹层。
+/// 菜单打开期间占据最上层的 [`InputLayer`]，↑/↓、Enter、Esc 等按键只由菜单处理，不会同时传给其他组件。
+#[derive(Clone, Default)]
+pub struct ContextMenus {
+    inner: Arc<Mutex<Option<OpenMenu>>>,
+    layers: InputLayers,
+}
+
+impl ContextMenus {
+    pub(crate) fn new(layers: InputLayers) -> Self {
+        Self {
+            inner: Arc::default(),
+            layers,
+        }
+    }
+
+    /// 是否有菜单处于弹出状态
+    pub fn is_open(&self) -> bool {
+        self.inner.lock().unwrap().is_some()
+    }
+
+    /// 收起菜单
+    pub fn dismiss(&self) {
+        self.close(&mut self.inner.lock().unwrap());
+    }
+
+    /// 收起菜单并释放它占据的输入层级
+    fn close(&self, menu: &mut Option<OpenMenu>) {
+        if let Some(menu) = menu.take() {
+            self.layers.remove(menu.layer);
+        }
+    }
+
+    /// layer 中的组件能否弹出菜单：layer 在最上层，或者正在显示的菜单是从 layer 中弹出的
+    fn accepts(&self, layer: InputLayer) -> bool {
+        match self.inner.lock().unwrap().as_ref() {
+            Some(menu) => menu.base == layer,
+            None => self.layers.is_active(layer),
+        }
+    }
+
+    fn open(
+        &self,
+        owner: u64,
+        items: Vec<ContextMenuItem>,
+        position: Position,
+        area: Rect,
+        base: InputLayer,
+    ) {
+        let mut menu = self.inner.lock().unwrap();
+        // 同一次点击会被所有包含该位置的组件收到，只保留区域最小（最内层）的菜单
+        if let Some(current) = menu.as_ref()
+            && current.rect.is_none()
+            && current.position == position
+            && current.area.area() <= area.area()
+        {
+            return;
+        }
+        self.close(&mut menu);
+        let layer = InputLayer::new();
+        self.layers.push(layer);
+        *menu = Some(OpenMenu {
+            owner,
+            items,
+            position,
+            area,
+            layer,
+            base,
+            selected: 0,
+            rect: None,
+        });
+    }
+
+    fn is_owner(&self, owner: u64) -> bool {
+        self.inner
+            .lock()
+            .unwrap()
+            .as_ref()
+            .is_some_and(|menu| menu.owner == owner)
+    }
+
+    fn dismiss_owned(&self, owner: u64) {
+        let mut menu = self.inner.lock().unwrap();
+        if menu.as_ref().is_some_and(|menu| menu.owner == owner) {
+            self.close(&mut menu);
+        }
+    }
+
+    /// 处理菜单拥有者收到的事件，返回是否需要重新渲染
+    fn handle_event(&self, event: &Event) -> bool {
+        let mut guard = self.inner.lock().unwrap();
+        let Some(menu) = guard.as_mut() else {
+            return false;
+        };
+        let len = menu.items.len();
+        // Some(None) 表示收起菜单，Some(Some(index)) 表示选中菜单项
+        let mut close = None;
+        match event {
+            Event::Key(key) if key.kind == KeyEventKind::Press => match key.code {
+                KeyCode::Up => menu.selected = (menu.selected + len - 1) % len,
+                KeyCode::Down => menu.selected = (menu.selected + 1) % len,
+                KeyCode::Enter => close = Some(Some(menu.selected)),
+                KeyCode::Esc => close = Some(None),
+                _ => return false,
+            },
+            Event::Mouse(mouse) => {
+                let position = Position::new(mouse.column, mouse.row);
+                match mouse.kind {
+                    MouseEventKind::Moved => match menu.item_at(position) {
+                        Some(index) if index != menu.selected => menu.selected = index,
+                        _ => return false,
+                    },
+                    MouseEventKind::Down(MouseButton::Left) => {
+                        close = Some(menu.item_at(position));
+                    }
+                    _ => return false,
+                }
+            }
+            _ => return false,
+        }
+
+        if let Some(chosen) = close {
+            let action = chosen.map(|index| menu.items[index].action.clone());
+            self.close(&mut guard);
+            // 先释放锁，允许回调中再次操作菜单
+            drop(guard);
+            if let Some(action) = action {
+                action();
+            }
+        }
+        true
+    }
+
+    /// 在整个画面之上绘制菜单弹层
+    pub fn draw(&self, frame: &mut Frame) {
+        let mut guard = self.inner.lock().unwrap();
+        let Some(menu) = guard.as_mut() else {
+            return;
+        };
+        let screen = frame.area();
+        let width = menu
+            .items
+            .iter()
+            .map(|item| Line::raw(item.label.as_str()).width() as u16)
+            .max()
+            .unwrap_or_default()
+            + 4;
+        let height = menu.items.len() as u16 + 2;
+        // 超出屏幕时向左、向上翻转
+        let x = if menu.position.x + width > screen.right() {
+            menu.position.x.saturating_sub(width)
+        } else {
+            menu.position.x
+        };
+        let y = if menu.position.y + height > screen.bottom() {
+            menu.position.y.saturating_sub(height)
+        } else {
+            menu.position.y
+        };
+        let rect = Rect::new(x, y, width, height).intersection(screen);
+        menu.rect = Some(rect);
+
+        let lines: Vec<Line> = menu
+            .items
+            .iter()
+            .enumerate()
+            .map(|(index, item)| {
+                let line = Line::raw(format!(" {} ", item.label));
+                if index == menu.selected {
+                    line.style(Style::default().add_modifier(Modifier::REVERSED))
+                } else {
+                    line
+                }
+            })
+            .collect();
+        frame.render_widget(Clear, rect);
+        frame.render_widget(Paragraph::new(lines).block(Block::bordered()), rect);
+    }
+}
+
+pub trait UseContextMenu: private::Sealed {
+    /// 为当前组件的区域声明右键菜单
+    ///
+    /// 在区域内右键点击时，框架会在点击位置弹出菜单；支持 ↑/↓、Enter、Esc 与鼠标操作，
+    /// 选中后调用对应菜单项的回调。嵌套组件都声明了菜单时，弹出最内层的菜单。
+    fn use_context_menu(&mut self, items: Vec<ContextMenuItem>);
+}
+
+static NEXT_MENU_ID: AtomicU64 = AtomicU64::new(0);
+
+struct UseContextMenuImpl {
+    id: u64,
+    items: Vec<ContextMenuItem>,
+    area: Rect,
+    /// 组件所在的输入层级
+    layer: InputLayer,
+    events: Option<TerminalEvents>,
+    menus: Option<ContextMenus>,
+}
+
+impl Hook for UseContextMenuImpl {
+    fn poll_change(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<()> {
+        let mut changed = false;
+        while let Some(Poll::Ready(Some(event))) = self
+            .events
+            .as_mut()
+            .map(|events| pin!(events).poll_next(cx))
+        {
+            let Some(menus) = self.menus.clone() else {
+                continue;
+            };
+            if let Event::Mouse(mouse) = &event
+                && mouse.kind == MouseEventKind::Down(MouseButton::Right)
+            {
+                let position = Position::new(mouse.column, mouse.row);
+                if self.area.contains(position)
+                    && !self.items.is_empty()
+                    && menus.accepts(self.layer)
+                {
+                    menus.open(self.id, self.items.clone(), position, self.area, self.layer);
+                    changed = true;
+                } else if menus.is_owner(self.id) {
+                    menus.dismiss_owned(self.id);
+                    changed = true;
+                }
+            } else if menus.is_owner(self.id) {
+                changed |= menus.handle_event(&event);
+            }
+        }
+        if changed {
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    }
+
+    fn post_component_update(&mut self, updater: &mut ComponentUpdater) {
+        if self.events.is_none() {
+            // 菜单打开后占据最上层，拥有者需要接收所有层级的事件
+            self.events = Some(updater.terminal().events());
+            self.layer = updater
+                .component_context_stack()
+                .get_context::<InputLayer>()
+                .map(|layer| *layer)
+                .unwrap_or(InputLayer::ROOT);
+        }
+        if self.menus.is_none() {
+            self.menus = updater
+                .component_context_stack()
+                .get_context::<SystemContext>()
+                .map(|system| system.context_menus());
+        }
+    }
+
+    fn pre_component_draw(&mut self, drawer: &mut ComponentDrawer) {
+        self.area = drawer.area;
+    }
+}
+
+impl Drop for UseContextMenuImpl {
+    fn drop(&mut self) {
+        // 组件卸载时收起它弹出的菜单
+        if let Some(menus) = &self.menus {
+            menus.dismiss_owned(self.id);
+        }
+    }
+}
+
+impl UseContextMenu for Hooks<'_, '_> {
+    fn use_context_menu(&mut self, items: Vec<ContextMenuItem>) {
+        let hook = self.use_hook(|| UseContextMenuImpl {
+            id: NEXT_MENU_ID.fetch_add(1, Ordering::Relaxed),
+            items: Vec::new(),
+            area: Rect::default(),
+            layer: InputLayer::ROOT,
+            events: None,
+            menus: None,
+        });
+        hook.items = items;
+    }
+}
@@ -0,0 +1,75 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use super::{Hook, Hooks};
+use crate::clock::{self, Sleep};
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::hooks::Hooks<'_, '_> {}
+}
+
+pub trait UseDebounce: private::Sealed {
+    /// 防抖：value 停止变化 delay 之后才返回新值，并重新渲染组件
+    ///
+    /// 在 delay 内持续变化时会不断重新计时，适合搜索框这类只关心最终输入的场景。
+    ///
+    /// ```ignore
+    /// let query = hooks.use_state(String::new);
+    /// let debounced = hooks.use_debounce(query.read().clone(), Duration::from_millis(300));
+    /// let results = hooks.use_memo(debounced.clone(), || search(&debounced));
+    /// ```
+    fn use_debounce<T>(&mut self, value: T, delay: Duration) -> T
+    where
+        T: Clone + PartialEq + Unpin + Send + 'static;
+}
+
+struct UseDebounceImpl<T> {
+    /// 最近一次传入的值
+    latest: T,
+    /// 对外返回的值
+    value: T,
+    timer: Option<Pin<Box<Sleep>>>,
+}
+
+impl<T> Hook for UseDebounceImpl<T>
+where
+    T: Clone + PartialEq + Unpin + Send,
+{
+    fn poll_change(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<()> {
+        let Some(timer) = &mut self.timer else {
+            return Poll::Pending;
+        };
+        if timer.as_mut().poll(cx).is_pending() {
+            return Poll::Pending;
+        }
+        self.timer = None;
+        if self.value == self.latest {
+            return Poll::Pending;
+        }
+        self.value = self.latest.clone();
+        Poll::Ready(())
+    }
+}
+
+impl UseDebounce for Hooks<'_, '_> {
+    fn use_debounce<T>(&mut self, value: T, delay: Duration) -> T
+    where
+        T: Clone + PartialEq + Unpin + Send + 'static,
+    {
+        let hook = self.use_hook(|| UseDebounceImpl {
+            latest: value.clone(),
+            value: value.clone(),
+            timer: None,
+        });
+        if hook.latest != value {
+            hook.latest = value;
+            hook.timer = Some(Box::pin(clock::sleep(delay)));
+        }
+        hook.value.clone()
+    }
+}
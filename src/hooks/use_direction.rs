@@ -0,0 +1,36 @@
+use super::Hooks;
+use crate::{bidi::TextDirection, context::SystemContext};
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::hooks::Hooks<'_, '_> {}
+}
+
+pub trait UseDirection: private::Sealed {
+    /// 读取最近的上层通过 `ContextProvider<TextDirection>` 提供的文字方向，没有时使用根部的方向
+    ///
+    /// 需要通过 `with_context_stack` 提供上下文，否则返回 [`TextDirection::Ltr`]。
+    ///
+    /// ```ignore
+    /// let direction = hooks.with_context_stack(updater.component_context_stack()).use_direction();
+    /// self.alignment = direction.align(props.alignment);
+    /// ```
+    fn use_direction(&self) -> TextDirection;
+}
+
+impl UseDirection for Hooks<'_, '_> {
+    fn use_direction(&self) -> TextDirection {
+        let Some(context) = self.context else {
+            return TextDirection::default();
+        };
+        context
+            .get_context::<TextDirection>()
+            .map(|direction| *direction)
+            .or_else(|| {
+                context
+                    .get_context::<SystemContext>()
+                    .map(|system| system.direction())
+            })
+            .unwrap_or_default()
+    }
+}
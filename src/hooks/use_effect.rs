@@ -0,0 +1,80 @@
+use super::{Hook, Hooks};
+use crate::render::updater::ComponentUpdater;
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::hooks::Hooks<'_, '_> {}
+}
+
+type Effect = Box<dyn FnOnce() -> Cleanup + Send>;
+type Cleanup = Box<dyn FnOnce() + Send>;
+
+pub trait UseEffect: private::Sealed {
+    /// 声明一个副作用，依赖变化时在组件 update 之后执行
+    ///
+    /// - deps 通常是元组，首次渲染以及与上一次不相等时才会执行 f
+    /// - f 返回清理函数，在下一次执行 f 之前以及组件卸载时调用
+    ///
+    /// ```ignore
+    /// hooks.use_effect(url.clone(), move || {
+    ///     let handle = tokio::spawn(watch(url));
+    ///     move || handle.abort()
+    /// });
+    /// ```
+    fn use_effect<D, F, C>(&mut self, deps: D, f: F)
+    where
+        D: PartialEq + Send + 'static,
+        F: FnOnce() -> C + Send + 'static,
+        C: FnOnce() + Send + 'static;
+}
+
+struct UseEffectImpl<D> {
+    deps: Option<Box<D>>,
+    /// 本轮 update 中需要执行的副作用
+    effect: Option<Effect>,
+    cleanup: Option<Cleanup>,
+}
+
+impl<D: Send> Hook for UseEffectImpl<D> {
+    fn post_component_update(&mut self, _updater: &mut ComponentUpdater) {
+        if let Some(effect) = self.effect.take() {
+            if let Some(cleanup) = self.cleanup.take() {
+                cleanup();
+            }
+            self.cleanup = Some(effect());
+        }
+    }
+
+    fn on_unmount(&mut self) {
+        if let Some(cleanup) = self.cleanup.take() {
+            cleanup();
+        }
+    }
+}
+
+impl<D> Drop for UseEffectImpl<D> {
+    fn drop(&mut self) {
+        if let Some(cleanup) = self.cleanup.take() {
+            cleanup();
+        }
+    }
+}
+
+impl UseEffect for Hooks<'_, '_> {
+    fn use_effect<D, F, C>(&mut self, deps: D, f: F)
+    where
+        D: PartialEq + Send + 'static,
+        F: FnOnce() -> C + Send + 'static,
+        C: FnOnce() + Send + 'static,
+    {
+        let hook = self.use_hook(|| UseEffectImpl::<D> {
+            deps: None,
+            effect: None,
+            cleanup: None,
+        });
+        if hook.deps.as_deref() != Some(&deps) {
+            hook.deps = Some(Box::new(deps));
+            hook.effect = Some(Box::new(move || Box::new(f()) as Cleanup));
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll, Waker},
+};
+
+use ratatui::layout::Rect;
+
+use super::{Hook, Hooks};
+use crate::render::drawer::ComponentDrawer;
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::hooks::Hooks<'_, '_> {}
+}
+
+pub trait UseElementSize: private::Sealed {
+    /// 当前组件上一帧实际绘制的区域，首次渲染时为空区域
+    ///
+    /// 区域在绘制阶段才能确定，变化后组件会再渲染一次，从而在 update 中按尺寸调整内容，
+    /// 例如空间不足时隐藏次要的列。
+    ///
+    /// ```ignore
+    /// let area = hooks.use_element_size();
+    /// let columns = if area.width < 60 { &columns[..2] } else { &columns[..] };
+    /// ```
+    fn use_element_size(&mut self) -> Rect;
+}
+
+struct UseElementSizeImpl {
+    /// 上一次 update 时返回给组件的区域
+    area: Rect,
+    /// 最近一次绘制的区域
+    drawn: Rect,
+    waker: Option<Waker>,
+}
+
+impl Hook for UseElementSizeImpl {
+    fn poll_change(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<()> {
+        if self.drawn != self.area {
+            Poll::Ready(())
+        } else {
+            self.waker = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+
+    fn pre_component_draw(&mut self, drawer: &mut ComponentDrawer) {
+        self.drawn = drawer.area;
+        if self.drawn != self.area
+            && let Some(waker) = self.waker.take()
+        {
+            waker.wake();
+        }
+    }
+}
+
+impl UseElementSize for Hooks<'_, '_> {
+    fn use_element_size(&mut self) -> Rect {
+        let hook = self.use_hook(|| UseElementSizeImpl {
+            area: Rect::default(),
+            drawn: Rect::default(),
+            waker: None,
+        });
+        hook.area = hook.drawn;
+        hook.area
+    }
+}
@@ -109,7 +109,7 @@ impl Hook for UseEventsImpl {
     fn post_component_update(&mut self, updater: &mut ComponentUpdater) {
         // 组件 update 后，首次初始化事件流
         if self.events.is_none() {
-            self.events = Some(updater.terminal().events());
+            self.events = Some(updater.terminal_events());
         }
     }
 
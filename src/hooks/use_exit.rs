@@ -0,0 +1,155 @@
+use std::{
+    any::Any,
+    future::poll_fn,
+    sync::{Arc, Mutex},
+    task::{Poll, Waker},
+};
+
+use super::{Hook, Hooks};
+use crate::{context::SystemContext, render::updater::ComponentUpdater};
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::hooks::Hooks<'_, '_> {}
+}
+
+#[derive(Default)]
+struct ExitState {
+    requested: bool,
+    code: Option<i32>,
+    /// 通过 finish 传出的结果
+    result: Option<Box<dyn Any + Send>>,
+    waker: Option<Waker>,
+}
+
+/// ExitSignal：请求结束渲染循环的信号
+///
+/// 由 [`SystemContext`] 持有，渲染循环在每一帧之后检查，并在等待事件时一同等待。
+#[derive(Clone, Default)]
+pub struct ExitSignal {
+    inner: Arc<Mutex<ExitState>>,
+}
+
+impl ExitSignal {
+    /// 请求结束渲染循环
+    pub fn exit(&self) {
+        let mut state = self.inner.lock().unwrap();
+        state.requested = true;
+        if let Some(waker) = state.waker.take() {
+            waker.wake();
+        }
+    }
+
+    /// 请求结束渲染循环并指定退出码，由 `render_loop_with_exit` 返回给调用方，是否结束进程由调用方决定
+    pub fn exit_with_code(&self, code: i32) {
+        self.inner.lock().unwrap().code = Some(code);
+        self.exit();
+    }
+
+    /// 以 value 作为结果结束渲染循环，由 `render_loop_with_result` 返回
+    ///
+    /// 适合 fzf 这类选择器程序：用户确认后把选中的值传给调用方。
+    pub fn finish<T: Send + 'static>(&self, value: T) {
+        self.inner.lock().unwrap().result = Some(Box::new(value));
+        self.exit();
+    }
+
+    /// 取出通过 finish 传出的结果，类型不一致时返回 None
+    pub(crate) fn take_result<T: 'static>(&self) -> Option<T> {
+        let result = self.inner.lock().unwrap().result.take()?;
+        result.downcast().ok().map(|value| *value)
+    }
+
+    pub fn is_requested(&self) -> bool {
+        self.inner.lock().unwrap().requested
+    }
+
+    /// 请求退出时指定的退出码
+    pub fn code(&self) -> Option<i32> {
+        self.inner.lock().unwrap().code
+    }
+
+    /// 等待退出请求
+    pub(crate) async fn wait(&self) {
+        poll_fn(|cx| {
+            let mut state = self.inner.lock().unwrap();
+            if state.requested {
+                Poll::Ready(())
+            } else {
+                state.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        })
+        .await
+    }
+}
+
+/// ExitHandle：组件中用于结束渲染循环的句柄，可以克隆到事件回调中
+///
+/// 组件首次 update 完成后才与渲染循环关联，在此之前调用不会有任何效果。
+#[derive(Clone, Default)]
+pub struct ExitHandle {
+    signal: Arc<Mutex<Option<ExitSignal>>>,
+}
+
+impl ExitHandle {
+    /// 结束渲染循环
+    pub fn exit(&self) {
+        if let Some(signal) = &*self.signal.lock().unwrap() {
+            signal.exit();
+        }
+    }
+
+    /// 结束渲染循环并指定退出码，见 [`ExitSignal::exit_with_code`]
+    pub fn exit_with_code(&self, code: i32) {
+        if let Some(signal) = &*self.signal.lock().unwrap() {
+            signal.exit_with_code(code);
+        }
+    }
+
+    /// 以 value 作为结果结束渲染循环，见 [`ExitSignal::finish`]
+    pub fn finish<T: Send + 'static>(&self, value: T) {
+        if let Some(signal) = &*self.signal.lock().unwrap() {
+            signal.finish(value);
+        }
+    }
+}
+
+pub trait UseExit: private::Sealed {
+    /// 获取结束渲染循环的句柄
+    ///
+    /// ```ignore
+    /// let exit = hooks.use_exit();
+    /// hooks.use_events(move |event| {
+    ///     if let Event::Key(KeyEvent { code: KeyCode::Char('q'), .. }) = event {
+    ///         exit.exit();
+    ///     }
+    /// });
+    /// ```
+    fn use_exit(&mut self) -> ExitHandle;
+}
+
+struct UseExitImpl {
+    handle: ExitHandle,
+}
+
+impl Hook for UseExitImpl {
+    fn post_component_update(&mut self, updater: &mut ComponentUpdater) {
+        let mut signal = self.handle.signal.lock().unwrap();
+        if signal.is_none() {
+            *signal = updater
+                .component_context_stack()
+                .get_context::<SystemContext>()
+                .map(|context| context.exit_signal());
+        }
+    }
+}
+
+impl UseExit for Hooks<'_, '_> {
+    fn use_exit(&mut self) -> ExitHandle {
+        let hook = self.use_hook(|| UseExitImpl {
+            handle: ExitHandle::default(),
+        });
+        hook.handle.clone()
+    }
+}
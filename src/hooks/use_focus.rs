@@ -0,0 +1,639 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    pin::{Pin, pin},
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicU64, Ordering},
+    },
+    task::{Context, Poll, Waker},
+};
+
+use crossterm::event::{Event, KeyCode, KeyEventKind, KeyModifiers};
+use futures::Stream;
+use ratatui::layout::Rect;
+
+use super::{Hook, Hooks};
+use crate::{
+    context::SystemContext,
+    render::{drawer::ComponentDrawer, updater::ComponentUpdater},
+    terminal::TerminalEvents,
+};
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::hooks::Hooks<'_, '_> {}
+}
+
+/// 焦点移动的方向
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FocusDirection {
+    Left,
+    Right,
+    Up,
+    Down,
+}
+
+/// 在指定方向上寻找离 from 最近的区域，距离相同时取靠前的候选项
+///
+/// 只考虑完全位于该方向一侧的区域，距离为主轴间距加上两倍的交叉轴偏移，
+/// 使同一行（列）上的区域优先于斜向的区域，行为与 tmux 的面板切换相近。
+pub(crate) fn nearest_in_direction<T>(
+    from: Rect,
+    candidates: impl IntoIterator<Item = (T, Rect)>,
+    direction: FocusDirection,
+) -> Option<T> {
+    // 中心点坐标放大两倍以避免取整，间距也按两倍计算
+    let center = |rect: Rect| {
+        (
+            rect.x as i32 * 2 + rect.width as i32,
+            rect.y as i32 * 2 + rect.height as i32,
+        )
+    };
+    let (cx, cy) = center(from);
+    candidates
+        .into_iter()
+        .enumerate()
+        .filter_map(|(index, (value, to))| {
+            let (tx, ty) = center(to);
+            let (gap, offset) = match direction {
+                FocusDirection::Left if to.right() <= from.left() => {
+                    (from.left() - to.right(), (ty - cy).abs())
+                }
+                FocusDirection::Right if to.left() >= from.right() => {
+                    (to.left() - from.right(), (ty - cy).abs())
+                }
+                FocusDirection::Up if to.bottom() <= from.top() => {
+                    (from.top() - to.bottom(), (tx - cx).abs())
+                }
+                FocusDirection::Down if to.top() >= from.bottom() => {
+                    (to.top() - from.bottom(), (tx - cx).abs())
+                }
+                _ => return None,
+            };
+            Some(((gap as i32 * 2 + offset * 2, index), value))
+        })
+        .min_by_key(|(score, _)| *score)
+        .map(|(_, value)| value)
+}
+
+/// 可获得焦点的组件在最近一次绘制时的信息
+#[derive(Clone, Copy, Debug, Default)]
+struct FocusEntry {
+    area: Rect,
+    /// 绘制顺序，即 Tab 顺序
+    order: usize,
+    /// 绘制时所在的焦点陷阱
+    trap: Option<u64>,
+    /// 最近一帧是否绘制，未绘制的组件不参与焦点切换
+    drawn: bool,
+}
+
+struct FocusInner {
+    entries: BTreeMap<u64, FocusEntry>,
+    /// 不参与 Tab 与方向切换、只能通过 [`FocusHandle::focus`] 获得焦点的组件
+    passive: BTreeSet<u64>,
+    focused: Option<u64>,
+    /// 当前帧已绘制的可聚焦组件数量
+    next_order: usize,
+    /// 是否由框架处理 Tab/Shift+Tab
+    tab_navigation: bool,
+    /// 焦点每次变化时递增
+    version: u64,
+    waker: Option<Waker>,
+    /// 由第一个注册的组件创建的事件订阅，所有组件共享，保证每个按键只处理一次
+    events: Option<TerminalEvents>,
+    /// 生效中的焦点陷阱，焦点只在其中的组件之间移动
+    trap: Option<u64>,
+    /// 当前帧绘制中的焦点陷阱，内层在后
+    trap_stack: Vec<u64>,
+    /// 当前帧最后绘制的焦点陷阱，帧结束时生效
+    frame_trap: Option<u64>,
+    /// 进入焦点陷阱之前的焦点，陷阱解除后恢复
+    before_trap: Option<u64>,
+    /// 组件通过 `use_claim_keys` 暂时占用的导航按键，占用期间不再用来切换焦点
+    claims: BTreeMap<u64, Vec<KeyCode>>,
+}
+
+impl Default for FocusInner {
+    fn default() -> Self {
+        Self {
+            entries: BTreeMap::new(),
+            passive: BTreeSet::new(),
+            focused: None,
+            next_order: 0,
+            tab_navigation: true,
+            version: 0,
+            waker: None,
+            events: None,
+            trap: None,
+            trap_stack: Vec::new(),
+            frame_trap: None,
+            before_trap: None,
+            claims: BTreeMap::new(),
+        }
+    }
+}
+
+impl FocusInner {
+    fn set_focused(&mut self, id: Option<u64>) {
+        if self.focused != id {
+            self.focused = id;
+            self.version += 1;
+            if let Some(waker) = self.waker.take() {
+                waker.wake();
+            }
+        }
+    }
+
+    /// 组件是否可以通过导航获得焦点：最近一帧绘制过、没有被跳过且位于生效的焦点陷阱内
+    fn reachable(&self, id: u64) -> bool {
+        self.entries.get(&id).is_some_and(|entry| {
+            entry.drawn
+                && !self.passive.contains(&id)
+                && (self.trap.is_none() || entry.trap == self.trap)
+        })
+    }
+
+    /// 按绘制顺序排列的可导航组件
+    fn ordered(&self) -> Vec<u64> {
+        let mut ids: Vec<_> = self
+            .entries
+            .keys()
+            .copied()
+            .filter(|id| self.reachable(*id))
+            .collect();
+        ids.sort_by_key(|id| self.entries[id].order);
+        ids
+    }
+
+    fn cycle(&mut self, forward: bool) {
+        let ids = self.ordered();
+        if ids.is_empty() {
+            return;
+        }
+        let current = self
+            .focused
+            .and_then(|focused| ids.iter().position(|id| *id == focused));
+        let next = match (current, forward) {
+            (Some(index), true) => (index + 1) % ids.len(),
+            (Some(index), false) => (index + ids.len() - 1) % ids.len(),
+            (None, true) => 0,
+            (None, false) => ids.len() - 1,
+        };
+        self.set_focused(Some(ids[next]));
+    }
+
+    fn navigate(&mut self, direction: FocusDirection) {
+        let Some(from) = self.focused.and_then(|id| self.entries.get(&id)) else {
+            self.cycle(true);
+            return;
+        };
+        let candidates = self
+            .ordered()
+            .into_iter()
+            .filter(|id| Some(*id) != self.focused)
+            .map(|id| (id, self.entries[&id].area));
+        if let Some(id) = nearest_in_direction(from.area, candidates, direction) {
+            self.set_focused(Some(id));
+        }
+    }
+
+    fn handle_event(&mut self, event: &Event) {
+        let Event::Key(key) = event else {
+            return;
+        };
+        if key.kind != KeyEventKind::Press
+            || self.claims.values().any(|keys| keys.contains(&key.code))
+        {
+            return;
+        }
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        let direction = match key.code {
+            KeyCode::Tab if self.tab_navigation => return self.cycle(true),
+            KeyCode::BackTab if self.tab_navigation => return self.cycle(false),
+            KeyCode::Left | KeyCode::Char('h') if ctrl => FocusDirection::Left,
+            KeyCode::Right | KeyCode::Char('l') if ctrl => FocusDirection::Right,
+            KeyCode::Up | KeyCode::Char('k') if ctrl => FocusDirection::Up,
+            KeyCode::Down | KeyCode::Char('j') if ctrl => FocusDirection::Down,
+            _ => return,
+        };
+        self.navigate(direction);
+    }
+}
+
+/// FocusManager：全局焦点管理
+///
+/// 由 [`SystemContext::focus`] 提供。通过 `use_focus` 注册的组件在绘制时记录自身区域，
+/// 框架据此处理 Tab/Shift+Tab（按绘制顺序）与 Ctrl+方向键、Ctrl+h/j/k/l（按几何位置）的焦点切换。
+#[derive(Clone, Default)]
+pub struct FocusManager {
+    inner: Arc<Mutex<FocusInner>>,
+}
+
+impl FocusManager {
+    /// 按 Tab 顺序聚焦下一个组件
+    pub fn focus_next(&self) {
+        self.inner.lock().unwrap().cycle(true);
+    }
+
+    /// 按 Tab 顺序聚焦上一个组件
+    pub fn focus_prev(&self) {
+        self.inner.lock().unwrap().cycle(false);
+    }
+
+    /// 聚焦指定方向上最近的组件
+    pub fn focus_direction(&self, direction: FocusDirection) {
+        self.inner.lock().unwrap().navigate(direction);
+    }
+
+    /// 是否由框架处理 Tab/Shift+Tab，自身需要使用 Tab 的应用可以关闭
+    ///
+    /// 只在部分时候需要 Tab 的组件应当使用 [`UseFocus::use_claim_keys`]。
+    pub fn set_tab_navigation(&self, enabled: bool) {
+        self.inner.lock().unwrap().tab_navigation = enabled;
+    }
+
+    fn claim(&self, id: u64, keys: &[KeyCode]) {
+        let mut inner = self.inner.lock().unwrap();
+        if keys.is_empty() {
+            inner.claims.remove(&id);
+        } else if inner.claims.get(&id).is_none_or(|claimed| claimed != keys) {
+            inner.claims.insert(id, keys.to_vec());
+        }
+    }
+
+    fn register(&self, id: u64) {
+        self.inner
+            .lock()
+            .unwrap()
+            .entries
+            .insert(id, FocusEntry::default());
+    }
+
+    fn unregister(&self, id: u64) {
+        let mut inner = self.inner.lock().unwrap();
+        inner.entries.remove(&id);
+        inner.passive.remove(&id);
+        if inner.focused == Some(id) {
+            inner.set_focused(None);
+        }
+    }
+
+    fn focus(&self, id: u64) {
+        self.inner.lock().unwrap().set_focused(Some(id));
+    }
+
+    fn is_focused(&self, id: u64) -> bool {
+        self.inner.lock().unwrap().focused == Some(id)
+    }
+
+    fn record(&self, id: u64, area: Rect) {
+        let mut inner = self.inner.lock().unwrap();
+        let order = inner.next_order;
+        inner.next_order += 1;
+        let trap = inner.trap_stack.last().copied();
+        inner.entries.insert(
+            id,
+            FocusEntry {
+                area,
+                order,
+                trap,
+                drawn: true,
+            },
+        );
+    }
+
+    fn begin_trap(&self, id: u64) {
+        let mut inner = self.inner.lock().unwrap();
+        inner.trap_stack.push(id);
+        inner.frame_trap = Some(id);
+    }
+
+    fn end_trap(&self) {
+        self.inner.lock().unwrap().trap_stack.pop();
+    }
+
+    /// 每帧绘制前调用，重新计算 Tab 顺序
+    pub(crate) fn begin_frame(&self) {
+        let mut inner = self.inner.lock().unwrap();
+        inner.next_order = 0;
+        inner
+            .entries
+            .values_mut()
+            .for_each(|entry| entry.drawn = false);
+        inner.trap_stack.clear();
+        inner.frame_trap = None;
+    }
+
+    /// 每帧绘制后调用，更新生效的焦点陷阱，没有组件获得焦点时聚焦第一个组件
+    pub(crate) fn end_frame(&self) {
+        let mut inner = self.inner.lock().unwrap();
+        let trap = inner.frame_trap.take();
+        if trap != inner.trap {
+            if inner.trap.is_none() {
+                inner.before_trap = inner.focused;
+            }
+            let restore = if trap.is_none() {
+                inner.before_trap.take()
+            } else {
+                None
+            };
+            inner.trap = trap;
+            // 陷阱解除后回到进入之前的组件
+            if let Some(id) = restore.filter(|id| inner.reachable(*id)) {
+                inner.set_focused(Some(id));
+            }
+        }
+        let valid = inner.focused.is_some_and(|id| {
+            inner.entries.get(&id).is_some_and(|entry| {
+                entry.drawn && (inner.trap.is_none() || entry.trap == inner.trap)
+            })
+        });
+        if !valid {
+            let first = inner.ordered().first().copied();
+            inner.set_focused(first);
+        }
+    }
+
+    /// 处理共享事件流中的导航按键，返回当前的焦点版本
+    fn poll(&self, cx: &mut Context) -> u64 {
+        let mut inner = self.inner.lock().unwrap();
+        inner.waker = Some(cx.waker().clone());
+        while let Some(Poll::Ready(Some(event))) = inner
+            .events
+            .as_mut()
+            .map(|events| pin!(events).poll_next(cx))
+        {
+            inner.handle_event(&event);
+        }
+        inner.version
+    }
+}
+
+/// 由 FocusRing 提供给子树的上下文，子树中通过 `use_focus` 注册的组件获得焦点时置为 true
+#[derive(Default)]
+pub(crate) struct FocusWithin {
+    pub(crate) focused: bool,
+    /// 子树中第一个注册的组件
+    pub(crate) first: Option<FocusHandle>,
+}
+
+/// FocusHandle：当前组件的焦点句柄，可以在事件回调中使用
+#[derive(Clone)]
+pub struct FocusHandle {
+    id: u64,
+    manager: Option<FocusManager>,
+    focused: bool,
+}
+
+impl FocusHandle {
+    /// 本轮 update 时组件是否拥有焦点
+    pub fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    /// 请求把焦点移到当前组件
+    pub fn focus(&self) {
+        if let Some(manager) = &self.manager {
+            manager.focus(self.id);
+        }
+    }
+
+    /// 是否跳过当前组件：跳过时 Tab 与方向切换不会经过它，只能通过 [`focus`](Self::focus) 获得焦点
+    pub(crate) fn set_passive(&self, passive: bool) {
+        if let Some(manager) = &self.manager {
+            let mut inner = manager.inner.lock().unwrap();
+            if passive {
+                inner.passive.insert(self.id);
+            } else {
+                inner.passive.remove(&self.id);
+            }
+        }
+    }
+}
+
+pub trait UseFocus: private::Sealed {
+    /// 把当前组件注册为可获得焦点的组件
+    ///
+    /// 焦点变化时组件会重新渲染。组件首次渲染时总是返回未聚焦；
+    /// 通过 `with_context_stack` 调用时首次渲染就完成注册，返回的句柄可以立即请求焦点。
+    fn use_focus(&mut self) -> FocusHandle;
+
+    /// active 时把焦点限制在当前组件的子树内，Tab/Shift+Tab 与方向切换只在子树中的组件之间移动
+    ///
+    /// 生效时焦点移入子树，解除后回到生效之前拥有焦点的组件；嵌套时最后绘制的陷阱生效，
+    /// 用于 [`Modal`](crate::components::Modal) 等对话框。
+    ///
+    /// ```ignore
+    /// hooks.use_focus_trap(props.open);
+    /// ```
+    fn use_focus_trap(&mut self, active: bool);
+
+    /// 暂时占用焦点切换使用的按键，占用期间框架不再用这些按键移动焦点，按键只由组件自己处理
+    ///
+    /// keys 为 `KeyCode::Tab`、`KeyCode::BackTab`，或者 Ctrl 组合中的方向键与 `h`/`j`/`k`/`l`，
+    /// 例如 `KeyCode::Char('k')` 表示占用 Ctrl+K。每次 update 传入当前需要的按键，传入空切片时释放，
+    /// 组件卸载时自动释放。只应在组件拥有焦点并且确实需要这些按键时占用，例如补全列表展开期间：
+    ///
+    /// ```ignore
+    /// let keys: &[KeyCode] = if focused && completion.is_open() { &[KeyCode::Tab, KeyCode::BackTab] } else { &[] };
+    /// hooks.use_claim_keys(keys);
+    /// ```
+    fn use_claim_keys(&mut self, keys: &[KeyCode]);
+}
+
+static NEXT_FOCUS_ID: AtomicU64 = AtomicU64::new(0);
+
+struct UseFocusImpl {
+    id: u64,
+    manager: Option<FocusManager>,
+    version: u64,
+}
+
+impl Hook for UseFocusImpl {
+    fn poll_change(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<()> {
+        let Some(manager) = &self.manager else {
+            return Poll::Pending;
+        };
+        let version = manager.poll(cx);
+        if version != self.version {
+            self.version = version;
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    }
+
+    fn post_component_update(&mut self, updater: &mut ComponentUpdater) {
+        if self.manager.is_none()
+            && let Some(system) = updater
+                .component_context_stack()
+                .get_context::<SystemContext>()
+        {
+            self.attach(system.focus());
+        }
+        if let Some(manager) = &self.manager {
+            let mut inner = manager.inner.lock().unwrap();
+            if inner.events.is_none() {
+                inner.events = Some(updater.terminal().events());
+            }
+        }
+        self.report_within(updater);
+    }
+
+    fn replay_component_update(&mut self, updater: &mut ComponentUpdater) {
+        self.report_within(updater);
+    }
+
+    fn pre_component_draw(&mut self, drawer: &mut ComponentDrawer) {
+        if let Some(manager) = &self.manager {
+            manager.record(self.id, drawer.area);
+            if manager.is_focused(self.id) {
+                drawer.mark_focused();
+            }
+        }
+    }
+}
+
+struct UseFocusTrapImpl {
+    id: u64,
+    active: bool,
+    manager: Option<FocusManager>,
+    /// 本次绘制是否进入了陷阱
+    entered: bool,
+}
+
+impl Hook for UseFocusTrapImpl {
+    fn post_component_update(&mut self, updater: &mut ComponentUpdater) {
+        if self.manager.is_none() {
+            self.manager = updater
+                .component_context_stack()
+                .get_context::<SystemContext>()
+                .map(|system| system.focus());
+        }
+    }
+
+    fn pre_component_draw(&mut self, _drawer: &mut ComponentDrawer) {
+        self.entered = false;
+        if self.active
+            && let Some(manager) = &self.manager
+        {
+            manager.begin_trap(self.id);
+            self.entered = true;
+        }
+    }
+
+    fn post_component_draw(&mut self, _drawer: &mut ComponentDrawer) {
+        if self.entered
+            && let Some(manager) = &self.manager
+        {
+            manager.end_trap();
+        }
+    }
+}
+
+struct UseClaimKeysImpl {
+    id: u64,
+    keys: Vec<KeyCode>,
+    manager: Option<FocusManager>,
+}
+
+impl Hook for UseClaimKeysImpl {
+    fn post_component_update(&mut self, updater: &mut ComponentUpdater) {
+        if self.manager.is_none() {
+            self.manager = updater
+                .component_context_stack()
+                .get_context::<SystemContext>()
+                .map(|system| system.focus());
+        }
+        if let Some(manager) = &self.manager {
+            manager.claim(self.id, &self.keys);
+        }
+    }
+}
+
+impl Drop for UseClaimKeysImpl {
+    fn drop(&mut self) {
+        if let Some(manager) = &self.manager {
+            manager.claim(self.id, &[]);
+        }
+    }
+}
+
+impl UseFocusImpl {
+    fn attach(&mut self, manager: FocusManager) {
+        manager.register(self.id);
+        self.manager = Some(manager);
+    }
+
+    /// 告知外层的 FocusRing 焦点在其子树内
+    fn report_within(&self, updater: &mut ComponentUpdater) {
+        if let Some(manager) = &self.manager
+            && let Some(mut within) = updater
+                .component_context_stack()
+                .get_context_mut::<FocusWithin>()
+        {
+            let focused = manager.is_focused(self.id);
+            within.focused |= focused;
+            within.first.get_or_insert_with(|| FocusHandle {
+                id: self.id,
+                manager: Some(manager.clone()),
+                focused,
+            });
+        }
+    }
+}
+
+impl Drop for UseFocusImpl {
+    fn drop(&mut self) {
+        if let Some(manager) = &self.manager {
+            manager.unregister(self.id);
+        }
+    }
+}
+
+impl UseFocus for Hooks<'_, '_> {
+    fn use_focus(&mut self) -> FocusHandle {
+        let context = self.context;
+        let hook = self.use_hook(|| UseFocusImpl {
+            id: NEXT_FOCUS_ID.fetch_add(1, Ordering::Relaxed),
+            manager: None,
+            version: 0,
+        });
+        // 能取得上下文时立即注册，首次 update 返回的句柄就可以请求焦点
+        if hook.manager.is_none()
+            && let Some(system) = context.and_then(|context| context.get_context::<SystemContext>())
+        {
+            hook.attach(system.focus());
+        }
+        FocusHandle {
+            id: hook.id,
+            focused: hook
+                .manager
+                .as_ref()
+                .is_some_and(|manager| manager.is_focused(hook.id)),
+            manager: hook.manager.clone(),
+        }
+    }
+
+    fn use_focus_trap(&mut self, active: bool) {
+        let hook = self.use_hook(|| UseFocusTrapImpl {
+            id: NEXT_FOCUS_ID.fetch_add(1, Ordering::Relaxed),
+            active,
+            manager: None,
+            entered: false,
+        });
+        hook.active = active;
+    }
+
+    fn use_claim_keys(&mut self, keys: &[KeyCode]) {
+        let hook = self.use_hook(|| UseClaimKeysImpl {
+            id: NEXT_FOCUS_ID.fetch_add(1, Ordering::Relaxed),
+            keys: Vec::new(),
+            manager: None,
+        });
+        if hook.keys != keys {
+            hook.keys = keys.to_vec();
+        }
+    }
+}
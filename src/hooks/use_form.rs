@@ -0,0 +1,292 @@
+use std::{collections::HashMap, sync::Arc};
+
+use super::{
+    Hooks,
+    use_state::{State, UseState},
+};
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::hooks::Hooks<'_, '_> {}
+}
+
+type Validator = Arc<dyn Fn(&str) -> Result<(), String> + Send + Sync>;
+
+/// Field：表单字段的声明
+///
+/// ```ignore
+/// Field::new("port")
+///     .initial("8080")
+///     .required("端口不能为空")
+///     .validate(|value| value.parse::<u16>().map(|_| ()).map_err(|_| "端口无效".into()))
+/// ```
+pub struct Field {
+    name: String,
+    initial: String,
+    required: bool,
+    validators: Vec<Validator>,
+}
+
+impl Field {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            initial: String::new(),
+            required: false,
+            validators: Vec::new(),
+        }
+    }
+
+    /// 初始值，也是 [`Form::reset`] 恢复到的值
+    pub fn initial(mut self, value: impl Into<String>) -> Self {
+        self.initial = value.into();
+        self
+    }
+
+    /// 值为空（去除首尾空白后）时报告 message
+    pub fn required(mut self, message: impl Into<String>) -> Self {
+        let message = message.into();
+        self.required = true;
+        self.validators.push(Arc::new(move |value: &str| {
+            if value.trim().is_empty() {
+                Err(message.clone())
+            } else {
+                Ok(())
+            }
+        }));
+        self
+    }
+
+    /// 添加校验函数，按添加顺序执行，报告第一个错误
+    pub fn validate<F>(mut self, validator: F) -> Self
+    where
+        F: Fn(&str) -> Result<(), String> + Send + Sync + 'static,
+    {
+        self.validators.push(Arc::new(validator));
+        self
+    }
+}
+
+struct FieldState {
+    field: Field,
+    value: String,
+    touched: bool,
+    error: Option<String>,
+}
+
+impl FieldState {
+    fn validate(&mut self) {
+        self.error = self
+            .field
+            .validators
+            .iter()
+            .find_map(|validator| validator(&self.value).err());
+    }
+}
+
+struct FormState {
+    fields: Vec<FieldState>,
+    submitted: bool,
+}
+
+impl FormState {
+    fn field(&self, name: &str) -> Option<&FieldState> {
+        self.fields.iter().find(|field| field.field.name == name)
+    }
+
+    fn field_mut(&mut self, name: &str) -> Option<&mut FieldState> {
+        self.fields
+            .iter_mut()
+            .find(|field| field.field.name == name)
+    }
+}
+
+/// Form：表单句柄，可以自由复制到事件回调与字段组件中
+///
+/// 字段的值均为字符串，修改后立即重新校验。错误信息在字段失去焦点（touched）或提交之后才对外显示，
+/// 避免用户还没输入完就看到报错。
+pub struct Form {
+    state: State<FormState>,
+}
+
+impl Clone for Form {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl Copy for Form {}
+
+impl Form {
+    /// 字段的当前值，字段不存在时为空字符串
+    pub fn value(&self, name: &str) -> String {
+        self.state
+            .read()
+            .field(name)
+            .map(|field| field.value.clone())
+            .unwrap_or_default()
+    }
+
+    /// 修改字段的值并重新校验
+    pub fn set_value(&mut self, name: &str, value: impl Into<String>) {
+        let value = value.into();
+        if self.value(name) == value {
+            return;
+        }
+        if let Some(field) = self.state.write().field_mut(name) {
+            field.value = value;
+            field.validate();
+        }
+    }
+
+    /// 所有字段的当前值
+    pub fn values(&self) -> HashMap<String, String> {
+        self.state
+            .read()
+            .fields
+            .iter()
+            .map(|field| (field.field.name.clone(), field.value.clone()))
+            .collect()
+    }
+
+    /// 标记字段已被访问过，之后会显示它的错误信息
+    pub fn touch(&mut self, name: &str) {
+        if self.is_touched(name) {
+            return;
+        }
+        if let Some(field) = self.state.write().field_mut(name) {
+            field.touched = true;
+        }
+    }
+
+    pub fn is_touched(&self, name: &str) -> bool {
+        self.state
+            .read()
+            .field(name)
+            .is_some_and(|field| field.touched)
+    }
+
+    /// 字段的值是否与初始值不同
+    pub fn is_field_dirty(&self, name: &str) -> bool {
+        self.state
+            .read()
+            .field(name)
+            .is_some_and(|field| field.value != field.field.initial)
+    }
+
+    /// 是否有任何字段被修改过
+    pub fn is_dirty(&self) -> bool {
+        self.state
+            .read()
+            .fields
+            .iter()
+            .any(|field| field.value != field.field.initial)
+    }
+
+    pub fn is_required(&self, name: &str) -> bool {
+        self.state
+            .read()
+            .field(name)
+            .is_some_and(|field| field.field.required)
+    }
+
+    /// 需要显示的错误信息：字段被访问过或者表单已提交时才返回
+    pub fn error(&self, name: &str) -> Option<String> {
+        let state = self.state.read();
+        let field = state.field(name)?;
+        if field.touched || state.submitted {
+            field.error.clone()
+        } else {
+            None
+        }
+    }
+
+    /// 所有字段是否都通过校验，不论是否显示
+    pub fn is_valid(&self) -> bool {
+        self.state
+            .read()
+            .fields
+            .iter()
+            .all(|field| field.error.is_none())
+    }
+
+    /// 提交表单：显示所有错误，全部通过校验时返回所有字段的值
+    pub fn submit(&mut self) -> Option<HashMap<String, String>> {
+        self.state.write().submitted = true;
+        self.is_valid().then(|| self.values())
+    }
+
+    /// 是否已经提交过
+    pub fn is_submitted(&self) -> bool {
+        self.state.read().submitted
+    }
+
+    /// 恢复所有字段的初始值，并清除访问与提交标记
+    pub fn reset(&mut self) {
+        let mut state = self.state.write();
+        state.submitted = false;
+        for field in state.fields.iter_mut() {
+            field.value = field.field.initial.clone();
+            field.touched = false;
+            field.validate();
+        }
+    }
+}
+
+pub trait UseForm: private::Sealed {
+    /// 声明一个表单，管理字段的值、修改与访问标记以及校验
+    ///
+    /// 字段只在首次渲染时声明，之后传入的 init 不会被调用。
+    /// 配合 [`FormField`](crate::components::FormField) 使用时，Tab 在字段之间切换焦点，离开字段后显示错误。
+    ///
+    /// ```ignore
+    /// let mut form = hooks.use_form(|| {
+    ///     vec![
+    ///         Field::new("name").required("请输入名称"),
+    ///         Field::new("email").validate(|value| {
+    ///             value.contains('@').then_some(()).ok_or_else(|| "邮箱格式不正确".into())
+    ///         }),
+    ///     ]
+    /// });
+    /// hooks.use_events(move |event| {
+    ///     if let Event::Key(key) = event && key.code == KeyCode::F(2) {
+    ///         if let Some(values) = form.submit() {
+    ///             save(values);
+    ///         }
+    ///     }
+    /// });
+    ///
+    /// element!(Stack {
+    ///     FormField(form: form, name: "name", label: "名称")
+    ///     FormField(form: form, name: "email", label: "邮箱")
+    /// })
+    /// ```
+    fn use_form<F>(&mut self, init: F) -> Form
+    where
+        F: FnOnce() -> Vec<Field>;
+}
+
+impl UseForm for Hooks<'_, '_> {
+    fn use_form<F>(&mut self, init: F) -> Form
+    where
+        F: FnOnce() -> Vec<Field>,
+    {
+        let state = self.use_state(move || FormState {
+            fields: init()
+                .into_iter()
+                .map(|field| {
+                    let mut state = FieldState {
+                        value: field.initial.clone(),
+                        field,
+                        touched: false,
+                        error: None,
+                    };
+                    state.validate();
+                    state
+                })
+                .collect(),
+            submitted: false,
+        });
+        Form { state }
+    }
+}
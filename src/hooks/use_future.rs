@@ -17,6 +17,22 @@ pub trait UseFuture: private::Sealed {
     fn use_future<F>(&mut self, f: F)
     where
         F: Future<Output = ()> + Send + 'static;
+
+    /// 注册一个依赖 deps 的异步 Future
+    ///
+    /// 首次渲染以及 deps 与上一次不相等时调用 f 创建新的 Future，尚未完成的旧 Future 会被直接丢弃。
+    ///
+    /// ```ignore
+    /// let id = selected.get();
+    /// hooks.use_future_with(id, move || async move {
+    ///     detail.set(fetch_detail(id).await);
+    /// });
+    /// ```
+    fn use_future_with<D, F, Fut>(&mut self, deps: D, f: F)
+    where
+        D: PartialEq + Send + 'static,
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = ()> + Send + 'static;
 }
 
 /// UseFutureImpl：包装 Future 的 Hook 实现
@@ -52,6 +68,21 @@ impl Hook for UseFutureImpl {
     }
 }
 
+/// UseFutureWithImpl：依赖变化时重新创建 Future 的 Hook 实现
+struct UseFutureWithImpl<D> {
+    deps: Option<Box<D>>,
+    future: UseFutureImpl,
+}
+
+impl<D: Send> Hook for UseFutureWithImpl<D> {
+    fn poll_change(
+        mut self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context,
+    ) -> std::task::Poll<()> {
+        std::pin::Pin::new(&mut self.future).poll_change(cx)
+    }
+}
+
 /// 为 Hooks 实现 UseFuture trait，便于在组件中直接调用 use_future
 impl UseFuture for Hooks<'_, '_> {
     fn use_future<F>(&mut self, f: F)
@@ -60,4 +91,20 @@ impl UseFuture for Hooks<'_, '_> {
     {
         self.use_hook(move || UseFutureImpl::new(f));
     }
+
+    fn use_future_with<D, F, Fut>(&mut self, deps: D, f: F)
+    where
+        D: PartialEq + Send + 'static,
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        let hook = self.use_hook(|| UseFutureWithImpl::<D> {
+            deps: None,
+            future: UseFutureImpl { f: None },
+        });
+        if hook.deps.as_deref() != Some(&deps) {
+            hook.deps = Some(Box::new(deps));
+            hook.future = UseFutureImpl::new(f());
+        }
+    }
 }
@@ -0,0 +1,224 @@
+use std::{
+    collections::VecDeque,
+    ops::{Deref, DerefMut},
+};
+
+use super::{
+    Hooks,
+    use_state::{State, StateMutRef, StateRef, UseState},
+};
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::hooks::Hooks<'_, '_> {}
+}
+
+/// 默认保留的撤销步数
+pub const DEFAULT_HISTORY_DEPTH: usize = 100;
+
+struct HistoryValue<T> {
+    present: T,
+    past: VecDeque<T>,
+    future: Vec<T>,
+    depth: usize,
+}
+
+/// History：带撤销/重做的状态句柄
+///
+/// 与 [`State`] 一样可以自由复制到事件回调中。`set`、`write` 只修改当前值，
+/// 调用 [`History::checkpoint`] 时才把当前值记录为一个可以撤销回去的步骤。
+pub struct History<T: Send + Sync + 'static> {
+    state: State<HistoryValue<T>>,
+}
+
+impl<T: Send + Sync + 'static> Clone for History<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Send + Sync + 'static> Copy for History<T> {}
+
+/// 当前值的只读引用
+pub struct HistoryRef<'a, T: 'static> {
+    inner: StateRef<'a, HistoryValue<T>>,
+}
+
+impl<T: 'static> Deref for HistoryRef<'_, T> {
+    type Target = T;
+    fn deref(&self) -> &Self::Target {
+        &self.inner.present
+    }
+}
+
+/// 当前值的可变引用，发生修改时触发重新渲染
+pub struct HistoryMutRef<'a, T: 'static> {
+    inner: StateMutRef<'a, HistoryValue<T>>,
+}
+
+impl<T: 'static> Deref for HistoryMutRef<'_, T> {
+    type Target = T;
+    fn deref(&self) -> &Self::Target {
+        &self.inner.present
+    }
+}
+
+impl<T: 'static> DerefMut for HistoryMutRef<'_, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.inner.present
+    }
+}
+
+impl<T: Send + Sync + 'static> History<T> {
+    /// 读取当前值
+    pub fn read(&self) -> HistoryRef<'_, T> {
+        HistoryRef {
+            inner: self.state.read(),
+        }
+    }
+
+    /// 修改当前值，不会记录撤销步骤
+    pub fn write(&self) -> HistoryMutRef<'_, T> {
+        HistoryMutRef {
+            inner: self.state.write(),
+        }
+    }
+
+    /// 替换当前值，不会记录撤销步骤
+    pub fn set(&mut self, value: T) {
+        *self.write() = value;
+    }
+
+    /// 撤销到上一个检查点，没有可撤销的步骤时返回 false
+    ///
+    /// 撤销前的当前值可以通过 [`History::redo`] 恢复。
+    pub fn undo(&mut self) -> bool {
+        // 先检查再写入，没有步骤时不触发重新渲染
+        if !self.can_undo() {
+            return false;
+        }
+        let mut history = self.state.write();
+        let Some(previous) = history.past.pop_back() else {
+            return false;
+        };
+        let present = std::mem::replace(&mut history.present, previous);
+        history.future.push(present);
+        true
+    }
+
+    /// 重做上一次撤销，没有可重做的步骤时返回 false
+    pub fn redo(&mut self) -> bool {
+        if !self.can_redo() {
+            return false;
+        }
+        let mut history = self.state.write();
+        let Some(next) = history.future.pop() else {
+            return false;
+        };
+        let present = std::mem::replace(&mut history.present, next);
+        history.past.push_back(present);
+        true
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.state.read().past.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.state.read().future.is_empty()
+    }
+
+    /// 清空所有撤销与重做步骤，保留当前值
+    pub fn clear(&mut self) {
+        if let Some(mut history) = self.state.try_write() {
+            history.past.clear();
+            history.future.clear();
+        }
+    }
+
+    /// 修改保留的撤销步数，超出的最早步骤会被丢弃
+    pub fn set_depth(&mut self, depth: usize) {
+        if let Some(mut history) = self.state.try_write() {
+            history.depth = depth;
+            let excess = history.past.len().saturating_sub(depth);
+            history.past.drain(..excess);
+        }
+    }
+}
+
+impl<T: Clone + Send + Sync + 'static> History<T> {
+    /// 获取当前值的副本
+    pub fn get(&self) -> T {
+        self.read().clone()
+    }
+
+    /// 把当前值记录为一个检查点，之后的 [`History::undo`] 会回到这里
+    ///
+    /// 通常在一次完整的编辑开始之前调用。记录后会清空重做的步骤，超过深度时丢弃最早的检查点。
+    pub fn checkpoint(&mut self) {
+        let Some(mut history) = self.state.try_write() else {
+            return;
+        };
+        if history.depth == 0 {
+            return;
+        }
+        let present = history.present.clone();
+        if history.past.len() == history.depth {
+            history.past.pop_front();
+        }
+        history.past.push_back(present);
+        history.future.clear();
+    }
+}
+
+pub trait UseHistory: private::Sealed {
+    /// 声明一个支持撤销/重做的本地状态，最多保留 [`DEFAULT_HISTORY_DEPTH`] 步
+    ///
+    /// ```ignore
+    /// let mut text = hooks.use_history(String::new);
+    /// hooks.use_events(move |event| match event {
+    ///     Event::Key(key) if key.code == KeyCode::Char('z') => {
+    ///         text.undo();
+    ///     }
+    ///     Event::Key(KeyEvent { code: KeyCode::Char(c), .. }) => {
+    ///         text.checkpoint();
+    ///         text.write().push(c);
+    ///     }
+    ///     _ => {}
+    /// });
+    /// ```
+    fn use_history<T, F>(&mut self, init: F) -> History<T>
+    where
+        F: FnOnce() -> T,
+        T: Clone + Unpin + Send + Sync + 'static;
+
+    /// 与 [`UseHistory::use_history`] 相同，但最多保留 depth 步
+    fn use_history_with<T, F>(&mut self, depth: usize, init: F) -> History<T>
+    where
+        F: FnOnce() -> T,
+        T: Clone + Unpin + Send + Sync + 'static;
+}
+
+impl UseHistory for Hooks<'_, '_> {
+    fn use_history<T, F>(&mut self, init: F) -> History<T>
+    where
+        F: FnOnce() -> T,
+        T: Clone + Unpin + Send + Sync + 'static,
+    {
+        self.use_history_with(DEFAULT_HISTORY_DEPTH, init)
+    }
+
+    fn use_history_with<T, F>(&mut self, depth: usize, init: F) -> History<T>
+    where
+        F: FnOnce() -> T,
+        T: Clone + Unpin + Send + Sync + 'static,
+    {
+        let state = self.use_state(move || HistoryValue {
+            present: init(),
+            past: VecDeque::new(),
+            future: Vec::new(),
+            depth,
+        });
+        History { state }
+    }
+}
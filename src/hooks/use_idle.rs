@@ -0,0 +1,111 @@
+use std::{
+    future::Future,
+    pin::{Pin, pin},
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use futures::Stream;
+
+use super::{Hook, Hooks};
+use crate::{
+    clock::{self, Sleep},
+    render::updater::ComponentUpdater,
+    terminal::TerminalEvents,
+};
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::hooks::Hooks<'_, '_> {}
+}
+
+pub trait UseIdle: private::Sealed {
+    /// 空闲回调：持续 timeout 没有终端事件、也没有重新渲染时调用一次 f
+    ///
+    /// - 任何终端事件（按键、鼠标、窗口尺寸变化）或状态变化引起的重新渲染都会重新计时
+    /// - 每个空闲期只调用一次，f 内修改状态引起的重新渲染不会再次触发，直到下一次终端事件
+    /// - 持续运行的动画会不断重新渲染，期间不会进入空闲
+    ///
+    /// ```ignore
+    /// let mut saved = hooks.use_state(|| true);
+    /// hooks.use_idle(Duration::from_secs(2), move || {
+    ///     save_draft();
+    ///     saved.set(true);
+    /// });
+    /// ```
+    fn use_idle<F>(&mut self, timeout: Duration, f: F)
+    where
+        F: FnMut() + Send + 'static;
+}
+
+struct UseIdleImpl {
+    timeout: Duration,
+    timer: Option<Pin<Box<Sleep>>>,
+    events: Option<TerminalEvents>,
+    /// 本轮空闲期是否已经调用过回调
+    idle: bool,
+    f: Option<Box<dyn FnMut() + Send>>,
+}
+
+impl UseIdleImpl {
+    fn restart(&mut self) {
+        self.idle = false;
+        self.timer = Some(Box::pin(clock::sleep(self.timeout)));
+    }
+}
+
+impl Hook for UseIdleImpl {
+    fn poll_change(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<()> {
+        let mut active = false;
+        while let Some(Poll::Ready(Some(_))) = self
+            .events
+            .as_mut()
+            .map(|events| pin!(events).poll_next(cx))
+        {
+            active = true;
+        }
+        if active {
+            self.restart();
+        }
+
+        let Some(timer) = &mut self.timer else {
+            return Poll::Pending;
+        };
+        if timer.as_mut().poll(cx).is_ready() {
+            self.timer = None;
+            self.idle = true;
+            if let Some(f) = &mut self.f {
+                f();
+            }
+        }
+        // 回调中修改的状态会自行触发重新渲染
+        Poll::Pending
+    }
+
+    fn post_component_update(&mut self, updater: &mut ComponentUpdater) {
+        if self.events.is_none() {
+            self.events = Some(updater.terminal().events());
+        }
+    }
+}
+
+impl UseIdle for Hooks<'_, '_> {
+    fn use_idle<F>(&mut self, timeout: Duration, f: F)
+    where
+        F: FnMut() + Send + 'static,
+    {
+        let hook = self.use_hook(|| UseIdleImpl {
+            timeout,
+            timer: None,
+            events: None,
+            idle: false,
+            f: None,
+        });
+        hook.timeout = timeout;
+        hook.f = Some(Box::new(f));
+        // 重新渲染说明有状态变化，重新计时
+        if !hook.idle {
+            hook.restart();
+        }
+    }
+}
@@ -0,0 +1,135 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use super::{Hook, Hooks};
+use crate::{
+    clock::{self, Interval},
+    context::SystemContext,
+    render::{activity::ActivityTracker, updater::ComponentUpdater},
+};
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::hooks::Hooks<'_, '_> {}
+}
+
+pub trait UseInterval: private::Sealed {
+    /// 每隔 period 调用一次 f，并在调用后重新渲染组件
+    ///
+    /// - 首次调用发生在一个 period 之后，错过的调用会被跳过而不是集中补齐
+    /// - f 每次 update 都会被替换，可以直接捕获本轮的状态
+    /// - period 变化时重新开始计时，period 为 0 时会 panic
+    /// - 应用失去焦点或空闲时按 [`ActivityTracker::scale`] 降低频率
+    ///
+    /// ```ignore
+    /// let mut now = hooks.use_state(Local::now);
+    /// hooks.use_interval(Duration::from_secs(1), move || now.set(Local::now()));
+    /// ```
+    fn use_interval<F>(&mut self, period: Duration, f: F)
+    where
+        F: FnMut() + Send + 'static;
+
+    /// 同 [`use_interval`](Self::use_interval)，但只在 active 为 true 时计时
+    ///
+    /// active 为 false 时不登记定时器，组件不会因此被唤醒；重新变为 true 后从头开始一个周期。
+    /// 适合只在加载中播放的旋转指示器等动画。
+    ///
+    /// ```ignore
+    /// let mut frame = hooks.use_state(|| 0usize);
+    /// hooks.use_interval_while(loading, Duration::from_millis(100), move || {
+    ///     frame.set(frame.get().wrapping_add(1))
+    /// });
+    /// ```
+    fn use_interval_while<F>(&mut self, active: bool, period: Duration, f: F)
+    where
+        F: FnMut() + Send + 'static;
+}
+
+struct UseIntervalImpl {
+    period: Duration,
+    /// 按活跃状态缩放后实际使用的周期
+    scaled: Duration,
+    interval: Interval,
+    /// 为 false 时暂停计时
+    active: bool,
+    activity: Option<ActivityTracker>,
+    f: Option<Box<dyn FnMut() + Send>>,
+}
+
+fn interval(period: Duration) -> Interval {
+    // 首次触发推迟到一个周期之后
+    clock::interval_at(clock::now() + period, period)
+}
+
+impl Hook for UseIntervalImpl {
+    fn poll_change(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<()> {
+        if !self.active {
+            return Poll::Pending;
+        }
+        if let Some(activity) = &self.activity {
+            let scaled = activity.scale(self.period);
+            if scaled != self.scaled {
+                self.scaled = scaled;
+                self.interval = interval(scaled);
+            }
+        }
+
+        let mut ticked = false;
+        while self.interval.poll_tick(cx).is_ready() {
+            ticked = true;
+        }
+        if !ticked {
+            return Poll::Pending;
+        }
+        if let Some(f) = &mut self.f {
+            f();
+        }
+        Poll::Ready(())
+    }
+
+    fn post_component_update(&mut self, updater: &mut ComponentUpdater) {
+        if self.activity.is_none() {
+            self.activity = updater
+                .component_context_stack()
+                .get_context::<SystemContext>()
+                .map(|context| context.activity());
+        }
+    }
+}
+
+impl UseInterval for Hooks<'_, '_> {
+    fn use_interval<F>(&mut self, period: Duration, f: F)
+    where
+        F: FnMut() + Send + 'static,
+    {
+        self.use_interval_while(true, period, f);
+    }
+
+    fn use_interval_while<F>(&mut self, active: bool, period: Duration, f: F)
+    where
+        F: FnMut() + Send + 'static,
+    {
+        let hook = self.use_hook(|| UseIntervalImpl {
+            period,
+            scaled: period,
+            interval: interval(period),
+            active,
+            activity: None,
+            f: None,
+        });
+        if hook.period != period || (active && !hook.active) {
+            let scaled = hook
+                .activity
+                .as_ref()
+                .map_or(period, |activity| activity.scale(period));
+            hook.period = period;
+            hook.scaled = scaled;
+            hook.interval = interval(scaled);
+        }
+        hook.active = active;
+        hook.f = Some(Box::new(f));
+    }
+}
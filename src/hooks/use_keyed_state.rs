@@ -0,0 +1,161 @@
+use std::{
+    any::Any,
+    collections::HashMap,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll},
+};
+
+use super::{
+    Hook, Hooks,
+    use_state::{State, UseStateImpl},
+};
+use crate::context::SystemContext;
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::hooks::Hooks<'_, '_> {}
+}
+
+/// KeyedStates：以全局 key 保存的组件状态，组件卸载后依然保留
+///
+/// 由 [`SystemContext::keyed_states`] 提供，只在本次运行期间保留，需要跨启动保存时使用 `use_persisted_state`。
+#[derive(Clone, Default)]
+pub struct KeyedStates {
+    states: Arc<Mutex<HashMap<String, Box<dyn Any + Send>>>>,
+}
+
+impl KeyedStates {
+    /// 是否保存着 key 对应的状态，使用中的状态不计入
+    pub fn contains(&self, key: &str) -> bool {
+        self.states.lock().unwrap().contains_key(key)
+    }
+
+    /// 丢弃 key 对应的状态，之后重新挂载的组件从初始值开始
+    pub fn remove(&self, key: &str) {
+        self.states.lock().unwrap().remove(key);
+    }
+
+    /// 丢弃所有保存的状态
+    pub fn clear(&self) {
+        self.states.lock().unwrap().clear();
+    }
+
+    fn take<T>(&self, key: &str) -> Option<UseStateImpl<T>>
+    where
+        T: Unpin + Send + Sync + 'static,
+    {
+        let state = self.states.lock().unwrap().remove(key)?;
+        // 同一个 key 被用于不同类型的状态时丢弃旧值
+        state.downcast().ok().map(|state| *state)
+    }
+
+    fn put<T>(&self, key: String, state: UseStateImpl<T>)
+    where
+        T: Unpin + Send + Sync + 'static,
+    {
+        self.states.lock().unwrap().insert(key, Box::new(state));
+    }
+}
+
+pub trait UseKeyedState: private::Sealed {
+    /// 声明一个以全局 key 标识的本地状态，组件卸载后重新挂载时恢复到卸载前的值
+    ///
+    /// 适合会被卸载又重新挂载、但需要保留状态的组件，例如切换标签页时卸载的页面。
+    /// 状态保存在 [`KeyedStates`] 中，保留的是同一个状态，卸载期间其他任务持有的 `State` 依然有效。
+    /// key 需要在整个应用内唯一，同一时间只应被一个组件使用。
+    /// key 变化时当前状态以旧 key 保存起来，改用新 key 对应的状态，没有时以 init 创建，
+    /// 例如同一个详情页组件切换展示的条目时各条目的滚动位置互不影响。
+    ///
+    /// 需要通过 `with_context_stack` 提供上下文，否则等同于 `use_state`。
+    ///
+    /// ```ignore
+    /// let mut hooks = hooks.with_context_stack(updater.component_context_stack());
+    /// let scroll = hooks.use_keyed_state(format!("tab/{}/scroll", props.id), || 0usize);
+    /// ```
+    fn use_keyed_state<T, F>(&mut self, key: impl Into<String>, init: F) -> State<T>
+    where
+        F: FnOnce() -> T,
+        T: Unpin + Send + Sync + 'static;
+}
+
+struct UseKeyedStateImpl<T>
+where
+    T: Unpin + Send + Sync + 'static,
+{
+    state: State<T>,
+    /// 卸载时移交给 KeyedStates
+    inner: Option<UseStateImpl<T>>,
+    key: String,
+    registry: Option<KeyedStates>,
+}
+
+impl<T> Hook for UseKeyedStateImpl<T>
+where
+    T: Unpin + Send + Sync + 'static,
+{
+    fn poll_change(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<()> {
+        match &mut self.inner {
+            Some(inner) => Pin::new(inner).poll_change(cx),
+            None => Poll::Pending,
+        }
+    }
+
+    fn on_unmount(&mut self) {
+        if let (Some(registry), Some(inner)) = (&self.registry, self.inner.take()) {
+            registry.put(std::mem::take(&mut self.key), inner);
+        }
+    }
+}
+
+impl UseKeyedState for Hooks<'_, '_> {
+    fn use_keyed_state<T, F>(&mut self, key: impl Into<String>, init: F) -> State<T>
+    where
+        F: FnOnce() -> T,
+        T: Unpin + Send + Sync + 'static,
+    {
+        let registry = self
+            .context
+            .and_then(|context| context.get_context::<SystemContext>())
+            .map(|system| system.keyed_states());
+        let key = key.into();
+        let mut init = Some(init);
+        let hook = self.use_hook(|| {
+            let inner = acquire(registry.as_ref(), &key, &mut init);
+            UseKeyedStateImpl {
+                state: inner.state,
+                inner: Some(inner),
+                key: key.clone(),
+                registry: registry.clone(),
+            }
+        });
+        if hook.registry.is_none() {
+            hook.registry = registry;
+        }
+        if hook.key != key {
+            // 旧 key 的状态保存起来，切换回来时恢复
+            if let (Some(registry), Some(inner)) = (&hook.registry, hook.inner.take()) {
+                registry.put(std::mem::take(&mut hook.key), inner);
+            }
+            let inner = acquire(hook.registry.as_ref(), &key, &mut init);
+            hook.state = inner.state;
+            hook.inner = Some(inner);
+            hook.key = key;
+        }
+        hook.state
+    }
+}
+
+/// 取出 key 对应的状态，没有时以 init 创建
+fn acquire<T, F>(registry: Option<&KeyedStates>, key: &str, init: &mut Option<F>) -> UseStateImpl<T>
+where
+    F: FnOnce() -> T,
+    T: Unpin + Send + Sync + 'static,
+{
+    registry
+        .and_then(|registry| registry.take(key))
+        .unwrap_or_else(|| {
+            let init = init.take().expect("init is only used once per update");
+            UseStateImpl::new(init())
+        })
+}
@@ -0,0 +1,318 @@
+use std::{
+    collections::BTreeMap,
+    fmt,
+    pin::{Pin, pin},
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicU64, Ordering},
+    },
+    task::{Context, Poll},
+    time::{Duration, Instant},
+};
+
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use futures::Stream;
+
+use super::{Hook, Hooks, use_mode::KeyBinding};
+use crate::{
+    clock, context::SystemContext, render::updater::ComponentUpdater, terminal::TerminalEvents,
+};
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::hooks::Hooks<'_, '_> {}
+}
+
+/// KeyCombo：一个按键或依次按下的多个按键（和弦），例如 `g g`
+///
+/// ```ignore
+/// KeyCombo::from('j');
+/// KeyCombo::from((KeyCode::Char('s'), KeyModifiers::CONTROL));
+/// KeyCombo::from(['g', 'g']);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct KeyCombo {
+    keys: Vec<KeyBinding>,
+}
+
+impl KeyCombo {
+    pub fn new(keys: impl IntoIterator<Item = impl Into<KeyBinding>>) -> Self {
+        Self {
+            keys: keys.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    pub fn keys(&self) -> &[KeyBinding] {
+        &self.keys
+    }
+
+    /// 按下的按键是否与整个组合完全一致
+    fn matches(&self, pressed: &[KeyEvent]) -> bool {
+        self.keys.len() == pressed.len() && self.starts_with(pressed)
+    }
+
+    /// 按下的按键是否是组合的前缀
+    fn starts_with(&self, pressed: &[KeyEvent]) -> bool {
+        self.keys.len() >= pressed.len()
+            && self
+                .keys
+                .iter()
+                .zip(pressed)
+                .all(|(binding, key)| binding.matches(key))
+    }
+}
+
+impl From<KeyBinding> for KeyCombo {
+    fn from(binding: KeyBinding) -> Self {
+        Self {
+            keys: vec![binding],
+        }
+    }
+}
+
+impl From<KeyCode> for KeyCombo {
+    fn from(code: KeyCode) -> Self {
+        KeyBinding::from(code).into()
+    }
+}
+
+impl From<char> for KeyCombo {
+    fn from(c: char) -> Self {
+        KeyBinding::from(c).into()
+    }
+}
+
+impl From<(KeyCode, KeyModifiers)> for KeyCombo {
+    fn from(key: (KeyCode, KeyModifiers)) -> Self {
+        KeyBinding::from(key).into()
+    }
+}
+
+impl<K: Into<KeyBinding>, const N: usize> From<[K; N]> for KeyCombo {
+    fn from(keys: [K; N]) -> Self {
+        Self::new(keys)
+    }
+}
+
+impl fmt::Display for KeyCombo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, key) in self.keys.iter().enumerate() {
+            if index > 0 {
+                f.write_str(" ")?;
+            }
+            for (modifier, name) in [
+                (KeyModifiers::CONTROL, "Ctrl+"),
+                (KeyModifiers::ALT, "Alt+"),
+                (KeyModifiers::SHIFT, "Shift+"),
+            ] {
+                if key.modifiers.contains(modifier) {
+                    f.write_str(name)?;
+                }
+            }
+            match key.code {
+                KeyCode::Char(' ') => f.write_str("Space")?,
+                KeyCode::Char(c) => write!(f, "{c}")?,
+                code => write!(f, "{code}")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+struct KeymapRegistryInner {
+    /// 各个 use_keymap 当前注册的按键组合
+    entries: BTreeMap<u64, Vec<KeyCombo>>,
+    /// 和弦中相邻两个按键的最大间隔
+    chord_timeout: Duration,
+}
+
+impl Default for KeymapRegistryInner {
+    fn default() -> Self {
+        Self {
+            entries: BTreeMap::new(),
+            chord_timeout: Duration::from_secs(1),
+        }
+    }
+}
+
+/// KeymapRegistry：全局的按键绑定登记表
+///
+/// 由 [`SystemContext::keymaps`] 提供，记录所有已挂载的 `use_keymap` 绑定的按键组合，
+/// 可以用来渲染快捷键帮助，或者检查按键冲突。
+#[derive(Clone, Default)]
+pub struct KeymapRegistry {
+    inner: Arc<Mutex<KeymapRegistryInner>>,
+}
+
+impl KeymapRegistry {
+    /// 所有已注册的按键组合，按注册顺序排列
+    pub fn combos(&self) -> Vec<KeyCombo> {
+        let inner = self.inner.lock().unwrap();
+        inner.entries.values().flatten().cloned().collect()
+    }
+
+    /// 设置和弦中相邻两个按键的最大间隔，默认 1 秒
+    pub fn set_chord_timeout(&self, timeout: Duration) {
+        self.inner.lock().unwrap().chord_timeout = timeout;
+    }
+
+    pub fn chord_timeout(&self) -> Duration {
+        self.inner.lock().unwrap().chord_timeout
+    }
+
+    fn register(&self, id: u64, combos: Vec<KeyCombo>) {
+        self.inner.lock().unwrap().entries.insert(id, combos);
+    }
+
+    fn unregister(&self, id: u64) {
+        self.inner.lock().unwrap().entries.remove(&id);
+    }
+}
+
+static NEXT_KEYMAP_ID: AtomicU64 = AtomicU64::new(0);
+
+pub trait UseKeymap: private::Sealed {
+    /// 声明按键绑定，按下绑定的按键或和弦时以对应的动作调用 handler
+    ///
+    /// - 修饰键需要完全一致，字符按键忽略 Shift
+    /// - 和弦中相邻按键的间隔超过 [`KeymapRegistry::chord_timeout`] 时重新开始匹配
+    /// - 按键同时完整匹配一个组合、又是另一个组合的前缀时，优先触发完整匹配
+    /// - bindings 与 handler 每次 update 都会被替换
+    ///
+    /// ```ignore
+    /// hooks.use_keymap(
+    ///     [
+    ///         (KeyCombo::from('j'), Action::Down),
+    ///         (KeyCombo::from('k'), Action::Up),
+    ///         (KeyCombo::from(['g', 'g']), Action::Top),
+    ///         (KeyCombo::from('G'), Action::Bottom),
+    ///     ],
+    ///     move |action| match action { ... },
+    /// );
+    /// ```
+    fn use_keymap<A, K, F>(&mut self, bindings: impl IntoIterator<Item = (K, A)>, handler: F)
+    where
+        A: Clone + Send + 'static,
+        K: Into<KeyCombo>,
+        F: FnMut(A) + Send + 'static;
+}
+
+struct UseKeymapImpl<A> {
+    id: u64,
+    bindings: Vec<(KeyCombo, A)>,
+    handler: Option<Box<dyn FnMut(A) + Send>>,
+    events: Option<TerminalEvents>,
+    registry: Option<KeymapRegistry>,
+    /// 已按下、尚未完成的和弦
+    pending: Vec<KeyEvent>,
+    last_key: Option<Instant>,
+}
+
+// 不会对字段做 pin 投影，无需要求 A: Unpin
+impl<A> Unpin for UseKeymapImpl<A> {}
+
+impl<A: Clone> UseKeymapImpl<A> {
+    fn handle_key(&mut self, key: KeyEvent) -> Option<A> {
+        let timeout = self
+            .registry
+            .as_ref()
+            .map_or(Duration::from_secs(1), KeymapRegistry::chord_timeout);
+        if self
+            .last_key
+            .is_some_and(|last| clock::elapsed(last) > timeout)
+        {
+            self.pending.clear();
+        }
+        self.last_key = Some(clock::now());
+        self.pending.push(key);
+
+        // 当前序列无法继续匹配时，丢弃最早的按键再试，保证新按键本身仍然可以触发绑定
+        while !self.pending.is_empty() {
+            if let Some((_, action)) = self
+                .bindings
+                .iter()
+                .find(|(combo, _)| combo.matches(&self.pending))
+            {
+                self.pending.clear();
+                return Some(action.clone());
+            }
+            if self
+                .bindings
+                .iter()
+                .any(|(combo, _)| combo.starts_with(&self.pending))
+            {
+                return None;
+            }
+            self.pending.remove(0);
+        }
+        None
+    }
+}
+
+impl<A: Clone + Send> Hook for UseKeymapImpl<A> {
+    fn poll_change(self: Pin<&mut Self>, cx: &mut Context) -> Poll<()> {
+        let this = self.get_mut();
+        while let Some(Poll::Ready(Some(event))) = this
+            .events
+            .as_mut()
+            .map(|events| pin!(events).poll_next(cx))
+        {
+            if let Event::Key(key) = event
+                && key.kind == KeyEventKind::Press
+                && let Some(action) = this.handle_key(key)
+                && let Some(handler) = &mut this.handler
+            {
+                handler(action);
+            }
+        }
+        Poll::Pending
+    }
+
+    fn post_component_update(&mut self, updater: &mut ComponentUpdater) {
+        if self.events.is_none() {
+            self.events = Some(updater.terminal_events());
+        }
+        if self.registry.is_none() {
+            self.registry = updater
+                .component_context_stack()
+                .get_context::<SystemContext>()
+                .map(|context| context.keymaps());
+        }
+        if let Some(registry) = &self.registry {
+            let combos = self.bindings.iter().map(|(combo, _)| combo.clone());
+            registry.register(self.id, combos.collect());
+        }
+    }
+}
+
+impl<A> Drop for UseKeymapImpl<A> {
+    fn drop(&mut self) {
+        if let Some(registry) = &self.registry {
+            registry.unregister(self.id);
+        }
+    }
+}
+
+impl UseKeymap for Hooks<'_, '_> {
+    fn use_keymap<A, K, F>(&mut self, bindings: impl IntoIterator<Item = (K, A)>, handler: F)
+    where
+        A: Clone + Send + 'static,
+        K: Into<KeyCombo>,
+        F: FnMut(A) + Send + 'static,
+    {
+        let hook = self.use_hook(|| UseKeymapImpl {
+            id: NEXT_KEYMAP_ID.fetch_add(1, Ordering::Relaxed),
+            bindings: Vec::new(),
+            handler: None,
+            events: None,
+            registry: None,
+            pending: Vec::new(),
+            last_key: None,
+        });
+        hook.bindings = bindings
+            .into_iter()
+            .map(|(combo, action)| (combo.into(), action))
+            .collect();
+        hook.handler = Some(Box::new(handler));
+    }
+}
@@ -0,0 +1,40 @@
+use std::time::Duration;
+
+use super::{Hook, Hooks};
+use crate::render::updater::ComponentUpdater;
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::hooks::Hooks<'_, '_> {}
+}
+
+pub trait UseMaxRefreshRate: private::Sealed {
+    /// 限制当前组件自身引起重新渲染的频率，两次之间至少间隔 interval
+    ///
+    /// 高频更新的数据源（例如每毫秒推送一次的采样）只需要以较低的帧率显示，
+    /// 间隔内的状态变化会合并，在间隔结束时刷新一次。其它组件照常渲染，
+    /// 期间因其它组件而重新渲染时当前组件也会读取到最新的状态。
+    ///
+    /// ```ignore
+    /// // 最多 10 FPS
+    /// hooks.use_max_refresh_rate(Duration::from_millis(100));
+    /// ```
+    fn use_max_refresh_rate(&mut self, interval: Duration);
+}
+
+struct UseMaxRefreshRateImpl {
+    interval: Duration,
+}
+
+impl Hook for UseMaxRefreshRateImpl {
+    fn post_component_update(&mut self, updater: &mut ComponentUpdater) {
+        updater.set_max_refresh_rate(Some(self.interval));
+    }
+}
+
+impl UseMaxRefreshRate for Hooks<'_, '_> {
+    fn use_max_refresh_rate(&mut self, interval: Duration) {
+        let hook = self.use_hook(|| UseMaxRefreshRateImpl { interval });
+        hook.interval = interval;
+    }
+}
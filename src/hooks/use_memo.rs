@@ -0,0 +1,56 @@
+use super::{Hook, Hooks};
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::hooks::Hooks<'_, '_> {}
+}
+
+pub trait UseMemo: private::Sealed {
+    /// 缓存派生值，只有依赖变化时才重新计算
+    ///
+    /// - deps 通常是元组，首次渲染以及与上一次不相等时才会调用 compute
+    /// - 返回缓存值的克隆，计算结果较大时可以返回 `Arc<T>` 降低克隆开销
+    ///
+    /// ```ignore
+    /// let keyword = filter.read().clone();
+    /// let visible = hooks.use_memo((props.items.clone(), keyword.clone()), || {
+    ///     let mut items: Vec<_> = props.items.iter().filter(|item| item.contains(&keyword)).cloned().collect();
+    ///     items.sort();
+    ///     Arc::new(items)
+    /// });
+    /// ```
+    fn use_memo<D, T, F>(&mut self, deps: D, compute: F) -> T
+    where
+        D: PartialEq + Send + 'static,
+        T: Clone + Send + 'static,
+        F: FnOnce() -> T;
+}
+
+struct UseMemoImpl<D, T> {
+    deps: Option<Box<D>>,
+    value: Option<Box<T>>,
+}
+
+impl<D: Send, T: Send> Hook for UseMemoImpl<D, T> {}
+
+impl UseMemo for Hooks<'_, '_> {
+    fn use_memo<D, T, F>(&mut self, deps: D, compute: F) -> T
+    where
+        D: PartialEq + Send + 'static,
+        T: Clone + Send + 'static,
+        F: FnOnce() -> T,
+    {
+        let hook = self.use_hook(|| UseMemoImpl::<D, T> {
+            deps: None,
+            value: None,
+        });
+        if hook.deps.as_deref() != Some(&deps) {
+            hook.deps = Some(Box::new(deps));
+            hook.value = Some(Box::new(compute()));
+        }
+        hook.value
+            .as_deref()
+            .cloned()
+            .expect("memo value is computed above")
+    }
+}
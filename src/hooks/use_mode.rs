@@ -0,0 +1,324 @@
+use std::{
+    collections::HashMap,
+    fmt,
+    pin::{Pin, pin},
+    sync::{Arc, Mutex},
+    task::{Context, Poll, Waker},
+};
+
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use futures::Stream;
+
+use super::{Hook, Hooks};
+use crate::{context::SystemContext, render::updater::ComponentUpdater, terminal::TerminalEvents};
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::hooks::Hooks<'_, '_> {}
+}
+
+/// 输入模式
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Mode {
+    /// 普通模式：按键作为命令，不输入文本
+    Normal,
+    /// 插入模式：按键输入文本
+    Insert,
+    /// 可视模式：移动光标时扩展选择范围
+    Visual,
+}
+
+impl fmt::Display for Mode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Mode::Normal => "NORMAL",
+            Mode::Insert => "INSERT",
+            Mode::Visual => "VISUAL",
+        };
+        f.write_str(name)
+    }
+}
+
+/// 一个按键绑定，修饰键需要完全一致
+///
+/// 字符按键忽略 Shift，大小写已经体现在字符本身。
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct KeyBinding {
+    pub code: KeyCode,
+    pub modifiers: KeyModifiers,
+}
+
+impl KeyBinding {
+    pub fn new(code: KeyCode, modifiers: KeyModifiers) -> Self {
+        Self { code, modifiers }
+    }
+
+    pub fn matches(&self, key: &KeyEvent) -> bool {
+        let mut modifiers = key.modifiers;
+        if let KeyCode::Char(_) = key.code {
+            modifiers.remove(KeyModifiers::SHIFT);
+        }
+        self.code == key.code && self.modifiers == modifiers
+    }
+}
+
+impl From<KeyCode> for KeyBinding {
+    fn from(code: KeyCode) -> Self {
+        Self::new(code, KeyModifiers::NONE)
+    }
+}
+
+impl From<char> for KeyBinding {
+    fn from(c: char) -> Self {
+        Self::new(KeyCode::Char(c), KeyModifiers::NONE)
+    }
+}
+
+impl From<(KeyCode, KeyModifiers)> for KeyBinding {
+    fn from((code, modifiers): (KeyCode, KeyModifiers)) -> Self {
+        Self::new(code, modifiers)
+    }
+}
+
+/// Keymap：按模式划分的按键绑定表
+///
+/// ```ignore
+/// let keymap = Keymap::new()
+///     .bind(Mode::Normal, 'd', Action::Delete)
+///     .bind(Mode::Normal, (KeyCode::Char('s'), KeyModifiers::CONTROL), Action::Save);
+/// if let Some(action) = keymap.lookup(mode, &key) { ... }
+/// ```
+#[derive(Clone, Debug)]
+pub struct Keymap<A> {
+    bindings: HashMap<Mode, Vec<(KeyBinding, A)>>,
+}
+
+impl<A> Default for Keymap<A> {
+    fn default() -> Self {
+        Self {
+            bindings: HashMap::new(),
+        }
+    }
+}
+
+impl<A> Keymap<A> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 在 mode 下绑定按键，同一按键重复绑定时以后者为准
+    pub fn bind(mut self, mode: Mode, key: impl Into<KeyBinding>, action: A) -> Self {
+        let key = key.into();
+        let bindings = self.bindings.entry(mode).or_default();
+        bindings.retain(|(binding, _)| *binding != key);
+        bindings.push((key, action));
+        self
+    }
+
+    /// 查找 mode 下按键对应的动作
+    pub fn lookup(&self, mode: Mode, key: &KeyEvent) -> Option<&A> {
+        self.bindings
+            .get(&mode)?
+            .iter()
+            .find(|(binding, _)| binding.matches(key))
+            .map(|(_, action)| action)
+    }
+}
+
+impl Keymap<Mode> {
+    /// vim 风格的模式切换：Normal 下 i/a 进入 Insert、v 进入 Visual，Esc 回到 Normal
+    pub fn vim() -> Self {
+        Self::new()
+            .bind(Mode::Normal, 'i', Mode::Insert)
+            .bind(Mode::Normal, 'a', Mode::Insert)
+            .bind(Mode::Normal, 'v', Mode::Visual)
+            .bind(Mode::Insert, KeyCode::Esc, Mode::Normal)
+            .bind(Mode::Visual, KeyCode::Esc, Mode::Normal)
+            .bind(Mode::Visual, 'v', Mode::Normal)
+    }
+}
+
+struct ModesInner {
+    /// 为 None 时未启用模式输入
+    mode: Option<Mode>,
+    switches: Keymap<Mode>,
+    /// 模式每次变化时递增
+    version: u64,
+    waker: Option<Waker>,
+    /// 由第一个 use_mode 创建的事件订阅，保证每个切换按键只处理一次
+    events: Option<TerminalEvents>,
+}
+
+impl Default for ModesInner {
+    fn default() -> Self {
+        Self {
+            mode: None,
+            switches: Keymap::vim(),
+            version: 0,
+            waker: None,
+            events: None,
+        }
+    }
+}
+
+impl ModesInner {
+    fn set(&mut self, mode: Option<Mode>) {
+        if self.mode != mode {
+            self.mode = mode;
+            self.version += 1;
+            if let Some(waker) = self.waker.take() {
+                waker.wake();
+            }
+        }
+    }
+
+    fn handle_event(&mut self, event: &Event) {
+        if let Event::Key(key) = event
+            && key.kind == KeyEventKind::Press
+            && let Some(mode) = self.mode
+            && let Some(next) = self.switches.lookup(mode, key).copied()
+        {
+            self.set(Some(next));
+        }
+    }
+}
+
+/// Modes：全局的模式输入状态
+///
+/// 由 [`SystemContext::modes`] 提供，默认不启用，此时内置组件保持原有的按键行为。
+/// 启用后框架按切换表（默认为 [`Keymap::vim`]）处理模式切换按键，
+/// TextInput 只在 Insert 模式下输入文本，List 在 Insert 模式下不响应导航、在 Visual 模式下扩展选择范围。
+#[derive(Clone, Default)]
+pub struct Modes {
+    inner: Arc<Mutex<ModesInner>>,
+}
+
+impl Modes {
+    /// 以 mode 作为初始模式启用模式输入
+    pub fn enable(&self, mode: Mode) {
+        self.inner.lock().unwrap().set(Some(mode));
+    }
+
+    pub fn disable(&self) {
+        self.inner.lock().unwrap().set(None);
+    }
+
+    /// 当前模式，未启用时为 None
+    pub fn mode(&self) -> Option<Mode> {
+        self.inner.lock().unwrap().mode
+    }
+
+    /// 切换模式，未启用时不做任何事
+    pub fn set(&self, mode: Mode) {
+        let mut inner = self.inner.lock().unwrap();
+        if inner.mode.is_some() {
+            inner.set(Some(mode));
+        }
+    }
+
+    /// 替换模式切换表
+    pub fn set_switches(&self, switches: Keymap<Mode>) {
+        self.inner.lock().unwrap().switches = switches;
+    }
+
+    /// 处理共享事件流中的切换按键，返回当前的模式版本
+    fn poll(&self, cx: &mut Context) -> u64 {
+        let mut inner = self.inner.lock().unwrap();
+        inner.waker = Some(cx.waker().clone());
+        while let Some(Poll::Ready(Some(event))) = inner
+            .events
+            .as_mut()
+            .map(|events| pin!(events).poll_next(cx))
+        {
+            inner.handle_event(&event);
+        }
+        inner.version
+    }
+}
+
+/// ModeHandle：当前组件看到的模式
+#[derive(Clone)]
+pub struct ModeHandle {
+    mode: Option<Mode>,
+    modes: Option<Modes>,
+}
+
+impl ModeHandle {
+    /// 本轮 update 时的模式，未启用模式输入时为 None
+    pub fn mode(&self) -> Option<Mode> {
+        self.mode
+    }
+
+    /// 是否处于 mode；未启用模式输入时总是返回 false
+    pub fn is(&self, mode: Mode) -> bool {
+        self.mode == Some(mode)
+    }
+
+    /// 切换模式，可以在事件回调中调用
+    pub fn set(&self, mode: Mode) {
+        if let Some(modes) = &self.modes {
+            modes.set(mode);
+        }
+    }
+}
+
+pub trait UseMode: private::Sealed {
+    /// 读取并订阅当前的输入模式，模式变化时组件会重新渲染
+    ///
+    /// 组件首次渲染时尚未订阅，此时总是返回未启用。
+    fn use_mode(&mut self) -> ModeHandle;
+}
+
+struct UseModeImpl {
+    modes: Option<Modes>,
+    version: u64,
+}
+
+impl Hook for UseModeImpl {
+    fn poll_change(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<()> {
+        let Some(modes) = &self.modes else {
+            return Poll::Pending;
+        };
+        let version = modes.poll(cx);
+        if version != self.version {
+            self.version = version;
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    }
+
+    // Hook 在首次 update 中创建，此时才能拿到上下文；已启用模式输入时会触发一次重新渲染
+    fn post_component_update(&mut self, updater: &mut ComponentUpdater) {
+        if self.modes.is_some() {
+            return;
+        }
+        let Some(modes) = updater
+            .component_context_stack()
+            .get_context::<SystemContext>()
+            .map(|system| system.modes())
+        else {
+            return;
+        };
+        {
+            let mut inner = modes.inner.lock().unwrap();
+            if inner.events.is_none() {
+                inner.events = Some(updater.terminal().events());
+            }
+        }
+        self.modes = Some(modes);
+    }
+}
+
+impl UseMode for Hooks<'_, '_> {
+    fn use_mode(&mut self) -> ModeHandle {
+        let hook = self.use_hook(|| UseModeImpl {
+            modes: None,
+            version: 0,
+        });
+        ModeHandle {
+            mode: hook.modes.as_ref().and_then(Modes::mode),
+            modes: hook.modes.clone(),
+        }
+    }
+}
@@ -0,0 +1,158 @@
+use std::{
+    pin::{Pin, pin},
+    task::{Context, Poll},
+};
+
+use crossterm::event::{Event, MouseButton, MouseEvent, MouseEventKind};
+use futures::Stream;
+use ratatui::layout::{Position, Rect};
+
+use super::{Hook, Hooks};
+use crate::{
+    render::{drawer::ComponentDrawer, updater::ComponentUpdater},
+    terminal::TerminalEvents,
+};
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::hooks::Hooks<'_, '_> {}
+}
+
+/// 鼠标按键的状态变化，坐标相对组件左上角
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MouseTransition {
+    /// 在组件内按下
+    Pressed(MouseButton, Position),
+    /// 在组件内按下后松开，松开的位置可能在组件外，此时坐标被限制在组件边缘
+    Released(MouseButton, Position),
+    /// 在组件内按下后拖动
+    Dragged(MouseButton, Position),
+}
+
+/// 组件看到的鼠标状态
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MouseState {
+    /// 鼠标是否悬停在组件上
+    pub hovered: bool,
+    /// 悬停时鼠标相对组件的位置
+    pub position: Option<Position>,
+    /// 在组件内按下、尚未松开的按键
+    pub pressed: Option<MouseButton>,
+    /// 最近一次在组件内点击的按键与位置
+    pub last_click: Option<(MouseButton, Position)>,
+    /// 自上一次 update 以来最近的一次按键变化，只在紧随其后的一次 update 中出现
+    pub transition: Option<MouseTransition>,
+}
+
+impl MouseState {
+    /// 是否正在拖动
+    pub fn is_dragging(&self) -> bool {
+        matches!(self.transition, Some(MouseTransition::Dragged(..)))
+    }
+
+    /// 本轮是否刚刚点击了 button
+    pub fn clicked(&self, button: MouseButton) -> bool {
+        matches!(self.transition, Some(MouseTransition::Pressed(pressed, _)) if pressed == button)
+    }
+}
+
+pub trait UseMouse: private::Sealed {
+    /// 读取组件范围内的鼠标状态，状态变化时组件会重新渲染
+    ///
+    /// 组件区域在绘制阶段才能确定，首次绘制之前不会响应任何鼠标事件。
+    ///
+    /// ```ignore
+    /// let mouse = hooks.use_mouse();
+    /// let style = if mouse.hovered { Style::default().reversed() } else { Style::default() };
+    /// if mouse.clicked(MouseButton::Left) {
+    ///     // ...
+    /// }
+    /// ```
+    fn use_mouse(&mut self) -> MouseState;
+}
+
+struct UseMouseImpl {
+    state: MouseState,
+    area: Rect,
+    events: Option<TerminalEvents>,
+}
+
+impl UseMouseImpl {
+    /// 将终端坐标转换为相对组件的坐标，超出组件时限制在边缘
+    fn relative(&self, column: u16, row: u16) -> Position {
+        let area = self.area;
+        Position::new(
+            column
+                .saturating_sub(area.x)
+                .min(area.width.saturating_sub(1)),
+            row.saturating_sub(area.y)
+                .min(area.height.saturating_sub(1)),
+        )
+    }
+
+    fn handle(&mut self, event: MouseEvent) {
+        let inside = self.area.contains(Position::new(event.column, event.row));
+        let position = self.relative(event.column, event.row);
+        let state = &mut self.state;
+        state.hovered = inside;
+        state.position = inside.then_some(position);
+        match event.kind {
+            MouseEventKind::Down(button) if inside => {
+                state.pressed = Some(button);
+                state.last_click = Some((button, position));
+                state.transition = Some(MouseTransition::Pressed(button, position));
+            }
+            MouseEventKind::Up(button) if state.pressed == Some(button) => {
+                state.pressed = None;
+                state.transition = Some(MouseTransition::Released(button, position));
+            }
+            MouseEventKind::Drag(button) if state.pressed == Some(button) => {
+                state.transition = Some(MouseTransition::Dragged(button, position));
+            }
+            _ => {}
+        }
+    }
+}
+
+impl Hook for UseMouseImpl {
+    fn poll_change(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<()> {
+        let previous = self.state;
+        while let Some(Poll::Ready(Some(event))) = self
+            .events
+            .as_mut()
+            .map(|events| pin!(events).poll_next(cx))
+        {
+            if let Event::Mouse(event) = event {
+                self.handle(event);
+            }
+        }
+        if self.state != previous {
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    }
+
+    fn post_component_update(&mut self, updater: &mut ComponentUpdater) {
+        if self.events.is_none() {
+            self.events = Some(updater.terminal_events());
+        }
+    }
+
+    fn pre_component_draw(&mut self, drawer: &mut ComponentDrawer) {
+        self.area = drawer.area;
+    }
+}
+
+impl UseMouse for Hooks<'_, '_> {
+    fn use_mouse(&mut self) -> MouseState {
+        let hook = self.use_hook(|| UseMouseImpl {
+            state: MouseState::default(),
+            area: Rect::default(),
+            events: None,
+        });
+        let state = hook.state;
+        hook.state.transition = None;
+        state
+    }
+}
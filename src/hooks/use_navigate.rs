@@ -0,0 +1,291 @@
+use std::{
+    collections::HashMap,
+    pin::Pin,
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicU64, Ordering},
+    },
+    task::{Context, Poll, Waker},
+};
+
+use super::{Hook, Hooks};
+use crate::{context::SystemContext, render::updater::ComponentUpdater};
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::hooks::Hooks<'_, '_> {}
+}
+
+struct NavigatorInner {
+    /// 导航栈，栈顶为当前位置，至少保留一项
+    history: Vec<String>,
+    /// 位置每次变化时递增
+    version: u64,
+    /// 订阅了位置变化的 Routes，按 hook id 记录
+    subscribers: HashMap<u64, Waker>,
+}
+
+/// Navigator：全局的导航栈
+///
+/// 由 [`SystemContext::navigator`] 提供，初始位置为 `/`。位置变化时 `Routes` 会重新选择要显示的 `Route`。
+#[derive(Clone)]
+pub struct Navigator {
+    inner: Arc<Mutex<NavigatorInner>>,
+}
+
+impl Default for Navigator {
+    fn default() -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(NavigatorInner {
+                history: vec!["/".to_string()],
+                version: 0,
+                subscribers: HashMap::new(),
+            })),
+        }
+    }
+}
+
+impl Navigator {
+    fn navigate(&self, f: impl FnOnce(&mut Vec<String>) -> bool) -> bool {
+        let mut inner = self.inner.lock().unwrap();
+        if !f(&mut inner.history) {
+            return false;
+        }
+        inner.version += 1;
+        for (_, waker) in inner.subscribers.drain() {
+            waker.wake();
+        }
+        true
+    }
+
+    /// 进入 path，原位置保留在导航栈中
+    pub fn push(&self, path: impl Into<String>) {
+        let path = path.into();
+        self.navigate(|history| {
+            history.push(path);
+            true
+        });
+    }
+
+    /// 以 path 替换当前位置
+    pub fn replace(&self, path: impl Into<String>) {
+        let path = path.into();
+        self.navigate(|history| {
+            *history.last_mut().unwrap() = path;
+            true
+        });
+    }
+
+    /// 返回上一个位置，已经在栈底时返回 false
+    pub fn pop(&self) -> bool {
+        self.navigate(|history| {
+            if history.len() > 1 {
+                history.pop();
+                true
+            } else {
+                false
+            }
+        })
+    }
+
+    /// 清空导航栈并以 path 作为唯一的位置
+    pub fn reset(&self, path: impl Into<String>) {
+        let path = path.into();
+        self.navigate(|history| {
+            *history = vec![path];
+            true
+        });
+    }
+
+    /// 当前位置
+    pub fn location(&self) -> String {
+        self.inner.lock().unwrap().history.last().unwrap().clone()
+    }
+
+    pub fn can_go_back(&self) -> bool {
+        self.inner.lock().unwrap().history.len() > 1
+    }
+
+    /// 从栈底到栈顶的全部位置
+    pub fn history(&self) -> Vec<String> {
+        self.inner.lock().unwrap().history.clone()
+    }
+
+    pub(crate) fn version(&self) -> u64 {
+        self.inner.lock().unwrap().version
+    }
+
+    /// 返回当前版本，并登记在位置变化时唤醒
+    fn poll(&self, id: u64, cx: &mut Context) -> u64 {
+        let mut inner = self.inner.lock().unwrap();
+        inner.subscribers.insert(id, cx.waker().clone());
+        inner.version
+    }
+
+    fn unsubscribe(&self, id: u64) {
+        if let Ok(mut inner) = self.inner.lock() {
+            inner.subscribers.remove(&id);
+        }
+    }
+}
+
+static NEXT_SUBSCRIBER_ID: AtomicU64 = AtomicU64::new(0);
+
+/// 订阅导航栈，位置变化时重新渲染组件
+pub(crate) struct UseLocationImpl {
+    id: u64,
+    navigator: Option<Navigator>,
+    version: u64,
+}
+
+impl UseLocationImpl {
+    pub(crate) fn new() -> Self {
+        Self {
+            id: NEXT_SUBSCRIBER_ID.fetch_add(1, Ordering::Relaxed),
+            navigator: None,
+            version: 0,
+        }
+    }
+
+    pub(crate) fn subscribe(&mut self, navigator: &Navigator) {
+        self.version = navigator.version();
+        self.navigator = Some(navigator.clone());
+    }
+}
+
+impl Hook for UseLocationImpl {
+    fn poll_change(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<()> {
+        let Some(navigator) = &self.navigator else {
+            return Poll::Pending;
+        };
+        let version = navigator.poll(self.id, cx);
+        if version != self.version {
+            self.version = version;
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+impl Drop for UseLocationImpl {
+    fn drop(&mut self) {
+        if let Some(navigator) = &self.navigator {
+            navigator.unsubscribe(self.id);
+        }
+    }
+}
+
+/// RouteParams：当前匹配的路由参数
+///
+/// 由 `Routes` 作为上下文提供给匹配的 `Route` 子树，路由 `/users/:id` 匹配 `/users/42` 时
+/// `get("id")` 返回 `"42"`，`*` 匹配到的剩余部分通过 `get("*")` 获取。
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RouteParams {
+    pub(crate) location: String,
+    pub(crate) params: HashMap<String, String>,
+}
+
+impl RouteParams {
+    /// 匹配时的完整位置
+    pub fn location(&self) -> &str {
+        &self.location
+    }
+
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.params.get(name).map(String::as_str)
+    }
+}
+
+/// Navigate：当前组件使用的导航句柄，可以克隆到事件回调中
+///
+/// 组件首次 update 结束后才会连接到 [`Navigator`]，在此之前的导航操作会被忽略。
+#[derive(Clone, Default)]
+pub struct Navigate {
+    navigator: Arc<Mutex<Option<Navigator>>>,
+}
+
+impl Navigate {
+    fn with(&self, f: impl FnOnce(&Navigator)) {
+        if let Some(navigator) = &*self.navigator.lock().unwrap() {
+            f(navigator);
+        }
+    }
+
+    /// 见 [`Navigator::push`]
+    pub fn push(&self, path: impl Into<String>) {
+        self.with(|navigator| navigator.push(path));
+    }
+
+    /// 见 [`Navigator::replace`]
+    pub fn replace(&self, path: impl Into<String>) {
+        self.with(|navigator| navigator.replace(path));
+    }
+
+    /// 见 [`Navigator::pop`]
+    pub fn pop(&self) -> bool {
+        let mut popped = false;
+        self.with(|navigator| popped = navigator.pop());
+        popped
+    }
+
+    /// 当前位置，尚未连接时返回 None
+    pub fn location(&self) -> Option<String> {
+        self.navigator
+            .lock()
+            .unwrap()
+            .as_ref()
+            .map(Navigator::location)
+    }
+}
+
+pub trait UseNavigate: private::Sealed {
+    /// 获取导航句柄
+    ///
+    /// ```ignore
+    /// let navigate = hooks.use_navigate();
+    /// hooks.use_events(move |event| match event {
+    ///     Event::Key(key) if key.code == KeyCode::Char('s') => navigate.push("/settings"),
+    ///     Event::Key(key) if key.code == KeyCode::Esc => { navigate.pop(); }
+    ///     _ => {}
+    /// });
+    /// ```
+    fn use_navigate(&mut self) -> Navigate;
+
+    /// 读取最近的上层 `Route` 匹配到的参数，不在 `Route` 中或者没有提供上下文时返回空参数
+    ///
+    /// 需要通过 `with_context_stack` 提供上下文。
+    fn use_params(&self) -> RouteParams;
+}
+
+struct UseNavigateImpl {
+    navigate: Navigate,
+}
+
+impl Hook for UseNavigateImpl {
+    fn post_component_update(&mut self, updater: &mut ComponentUpdater) {
+        let mut navigator = self.navigate.navigator.lock().unwrap();
+        if navigator.is_none() {
+            *navigator = updater
+                .component_context_stack()
+                .get_context::<SystemContext>()
+                .map(|context| context.navigator());
+        }
+    }
+}
+
+impl UseNavigate for Hooks<'_, '_> {
+    fn use_navigate(&mut self) -> Navigate {
+        let hook = self.use_hook(|| UseNavigateImpl {
+            navigate: Navigate::default(),
+        });
+        hook.navigate.clone()
+    }
+
+    fn use_params(&self) -> RouteParams {
+        self.context
+            .and_then(|context| context.get_context::<RouteParams>())
+            .map(|params| params.clone())
+            .unwrap_or_default()
+    }
+}
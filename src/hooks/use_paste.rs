@@ -0,0 +1,35 @@
+use crossterm::event::Event;
+
+use super::{Hooks, use_events::UseEvents};
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::hooks::Hooks<'_, '_> {}
+}
+
+pub trait UsePaste: private::Sealed {
+    /// 订阅粘贴事件，粘贴的全部文本一次性回调给 f
+    ///
+    /// 终端已开启 bracketed paste，粘贴不会再被拆成一连串按键事件。
+    ///
+    /// ```ignore
+    /// let mut text = hooks.use_state(String::new);
+    /// hooks.use_paste(move |pasted| text.write().push_str(&pasted));
+    /// ```
+    fn use_paste<F>(&mut self, f: F)
+    where
+        F: FnMut(String) + Send + 'static;
+}
+
+impl UsePaste for Hooks<'_, '_> {
+    fn use_paste<F>(&mut self, mut f: F)
+    where
+        F: FnMut(String) + Send + 'static,
+    {
+        self.use_events(move |event| {
+            if let Event::Paste(text) = event {
+                f(text);
+            }
+        });
+    }
+}
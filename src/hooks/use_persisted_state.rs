@@ -0,0 +1,334 @@
+use std::{
+    collections::HashMap,
+    fs, io,
+    path::{Path, PathBuf},
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use serde::{Serialize, de::DeserializeOwned};
+use serde_json::{Map, Value};
+
+use super::{
+    Hook, Hooks, PersistableHook,
+    use_state::{State, UseStateImpl},
+};
+use crate::{clock, context::SystemContext, render::updater::ComponentUpdater};
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::hooks::Hooks<'_, '_> {}
+}
+
+type Snapshot = Box<dyn Fn() -> Option<Value> + Send>;
+
+struct SessionInner {
+    path: Option<PathBuf>,
+    values: Map<String, Value>,
+    /// 仍在使用中的持久化状态，保存时读取其最新值
+    sources: HashMap<String, Snapshot>,
+    /// 状态变化后等待多久写入文件，None 表示只在退出时写入
+    debounce: Option<Duration>,
+    /// 每次安排写入时递增，延迟结束时与之比较以合并连续的变化
+    generation: u64,
+    /// 每次保存取快照时递增，用来丢弃比已写入内容更旧的快照
+    revision: u64,
+}
+
+impl Default for SessionInner {
+    fn default() -> Self {
+        Self {
+            path: None,
+            values: Map::new(),
+            sources: HashMap::new(),
+            debounce: Some(Duration::from_millis(500)),
+            generation: 0,
+            revision: 0,
+        }
+    }
+}
+
+/// SessionStore：跨启动保存的界面状态
+///
+/// 由 [`SystemContext::session`] 提供，默认不启用。调用 [`SessionStore::open`] 指定文件，
+/// 或者 [`SessionStore::open_app`] 使用系统的应用数据目录后，`use_persisted_state` 声明的状态会在启动时从文件恢复，
+/// 状态变化后经过一段延迟写回文件，退出渲染循环时再写入一次。
+#[derive(Clone, Default)]
+pub struct SessionStore {
+    inner: Arc<Mutex<SessionInner>>,
+    /// 串行化文件写入，保存最近一次写入的快照版本
+    written: Arc<Mutex<u64>>,
+}
+
+impl SessionStore {
+    /// 启用持久化并从 path 读取上次保存的状态，文件不存在或无法解析时从空白开始
+    ///
+    /// 需要在声明持久化状态的组件首次渲染之前调用，通常放在根组件的 update 中，重复调用同一路径不会重新读取。
+    pub fn open(&self, path: impl AsRef<Path>) {
+        let path = path.as_ref();
+        let mut inner = self.inner.lock().unwrap();
+        if inner.path.as_deref() == Some(path) {
+            return;
+        }
+        inner.values = fs::read_to_string(path)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default();
+        inner.path = Some(path.to_path_buf());
+    }
+
+    /// 在系统的应用数据目录下为 app 启用持久化，返回使用的文件路径
+    ///
+    /// 文件位于 [`SessionStore::app_data_dir`] 下的 `state.json`，找不到数据目录时不启用并返回 None。
+    pub fn open_app(&self, app: &str) -> Option<PathBuf> {
+        let path = Self::app_data_dir(app)?.join("state.json");
+        self.open(&path);
+        Some(path)
+    }
+
+    /// app 的数据目录
+    ///
+    /// Linux 上为 `$XDG_DATA_HOME/<app>`（默认 `~/.local/share/<app>`），
+    /// macOS 上为 `~/Library/Application Support/<app>`，Windows 上为 `%APPDATA%\<app>`。
+    pub fn app_data_dir(app: &str) -> Option<PathBuf> {
+        let home = || {
+            std::env::var_os("HOME")
+                .filter(|home| !home.is_empty())
+                .map(PathBuf::from)
+        };
+        let base = if cfg!(windows) {
+            std::env::var_os("APPDATA").map(PathBuf::from)
+        } else if cfg!(target_os = "macos") {
+            home().map(|home| home.join("Library/Application Support"))
+        } else {
+            std::env::var_os("XDG_DATA_HOME")
+                .filter(|dir| !dir.is_empty())
+                .map(PathBuf::from)
+                .or_else(|| home().map(|home| home.join(".local/share")))
+        };
+        Some(base?.join(app))
+    }
+
+    /// 设置状态变化后延迟多久写入文件，连续的变化只写入一次，默认 500ms
+    ///
+    /// 传入 None 时只在退出渲染循环时写入。
+    pub fn set_debounce(&self, debounce: Option<Duration>) {
+        self.inner.lock().unwrap().debounce = debounce;
+    }
+
+    /// 是否已经启用持久化
+    pub fn is_enabled(&self) -> bool {
+        self.inner.lock().unwrap().path.is_some()
+    }
+
+    /// 读取 key 对应的已保存值
+    pub fn get<T: DeserializeOwned>(&self, key: &str) -> Option<T> {
+        let inner = self.inner.lock().unwrap();
+        let value = inner.values.get(key)?.clone();
+        serde_json::from_value(value).ok()
+    }
+
+    /// 手动写入 key 对应的值，保存时一并写入文件
+    pub fn set<T: Serialize>(&self, key: impl Into<String>, value: &T) {
+        if let Ok(value) = serde_json::to_value(value) {
+            self.inner.lock().unwrap().values.insert(key.into(), value);
+            self.schedule_save();
+        }
+    }
+
+    /// 把所有状态写入文件，未启用时不做任何事
+    ///
+    /// 先写入同目录下的临时文件再重命名，写到一半退出也不会损坏已有的文件；文件读写不持有状态锁。
+    pub fn save(&self) -> io::Result<()> {
+        let (path, content, revision) = {
+            let mut inner = self.inner.lock().unwrap();
+            let Some(path) = inner.path.clone() else {
+                return Ok(());
+            };
+            let snapshots: Vec<_> = inner
+                .sources
+                .iter()
+                .filter_map(|(key, snapshot)| Some((key.clone(), snapshot()?)))
+                .collect();
+            inner.values.extend(snapshots);
+            inner.revision += 1;
+            let content = serde_json::to_string_pretty(&inner.values)?;
+            (path, content, inner.revision)
+        };
+
+        let mut written = self.written.lock().unwrap();
+        // 后台写入与退出时的写入可能交错，已经写入更新的快照时跳过
+        if *written > revision {
+            return Ok(());
+        }
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut temp = path.clone().into_os_string();
+        temp.push(".tmp");
+        fs::write(&temp, content)?;
+        fs::rename(&temp, &path)?;
+        *written = revision;
+        Ok(())
+    }
+
+    /// 延迟一段时间后在后台写入文件，期间再次调用会重新计时
+    fn schedule_save(&self) {
+        let (debounce, generation) = {
+            let mut inner = self.inner.lock().unwrap();
+            let Some(debounce) = inner.debounce.filter(|_| inner.path.is_some()) else {
+                return;
+            };
+            inner.generation = inner.generation.wrapping_add(1);
+            (debounce, inner.generation)
+        };
+        // 不在 tokio 运行时中时只能等退出时写入
+        let Ok(runtime) = tokio::runtime::Handle::try_current() else {
+            return;
+        };
+        let store = self.clone();
+        runtime.spawn(async move {
+            clock::sleep(debounce).await;
+            if store.inner.lock().unwrap().generation == generation {
+                let _ = tokio::task::spawn_blocking(move || store.save()).await;
+            }
+        });
+    }
+
+    fn register(&self, key: &str, snapshot: Snapshot) {
+        self.inner
+            .lock()
+            .unwrap()
+            .sources
+            .insert(key.to_string(), snapshot);
+    }
+
+    /// 组件卸载时记录最终值，之后重新挂载的组件可以恢复到相同状态
+    fn unregister(&self, key: &str) {
+        let mut inner = self.inner.lock().unwrap();
+        if let Some(value) = inner.sources.remove(key).and_then(|snapshot| snapshot()) {
+            inner.values.insert(key.to_string(), value);
+        }
+    }
+}
+
+pub trait UsePersistedState: private::Sealed {
+    /// 声明一个跨启动保存的本地状态
+    ///
+    /// 行为与 `use_state` 相同，额外以 key 为标识写入 [`SessionStore`]，值变化后由框架延迟写入文件。
+    /// key 需要在整个应用内唯一，通常使用组件路径，例如 `"sidebar/tab"`。
+    /// 未启用持久化时等同于 `use_state`。
+    ///
+    /// ```ignore
+    /// let tab = hooks.use_persisted_state("main/tab", || 0usize);
+    /// ```
+    fn use_persisted_state<T, F>(&mut self, key: impl Into<String>, init: F) -> State<T>
+    where
+        F: FnOnce() -> T,
+        T: Serialize + DeserializeOwned + Unpin + Send + Sync + 'static;
+}
+
+struct UsePersistedStateImpl<T>
+where
+    T: Unpin + Send + Sync + 'static,
+{
+    inner: UseStateImpl<T>,
+    key: String,
+    store: Option<SessionStore>,
+    /// 是否已经尝试从 SessionStore 恢复
+    restored: bool,
+}
+
+impl<T> Hook for UsePersistedStateImpl<T>
+where
+    T: Serialize + DeserializeOwned + Unpin + Send + Sync + 'static,
+{
+    fn poll_change(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<()> {
+        let poll = Pin::new(&mut self.inner).poll_change(cx);
+        if poll.is_ready()
+            && let Some(store) = &self.store
+        {
+            store.schedule_save();
+        }
+        poll
+    }
+
+    // Hook 在首次 update 中创建，此时才能拿到上下文，恢复的值会触发一次重新渲染
+    fn post_component_update(&mut self, updater: &mut ComponentUpdater) {
+        if self.restored {
+            return;
+        }
+        self.restored = true;
+        let Some(store) = updater
+            .component_context_stack()
+            .get_context::<SystemContext>()
+            .map(|system| system.session())
+            .filter(SessionStore::is_enabled)
+        else {
+            return;
+        };
+        let mut state = self.inner.state;
+        if let Some(value) = store.get::<T>(&self.key) {
+            state.set(value);
+        }
+        store.register(
+            &self.key,
+            Box::new(move || {
+                let value = state.try_read()?;
+                serde_json::to_value(&*value).ok()
+            }),
+        );
+        self.store = Some(store);
+    }
+
+    fn persistable(&mut self) -> Option<&mut dyn PersistableHook> {
+        Some(self)
+    }
+}
+
+impl<T> PersistableHook for UsePersistedStateImpl<T>
+where
+    T: Serialize + DeserializeOwned + Unpin + Send + Sync + 'static,
+{
+    fn snapshot(&self) -> Option<Value> {
+        let value = self.inner.state.try_read()?;
+        serde_json::to_value(&*value).ok()
+    }
+
+    fn restore(&mut self, value: Value) {
+        if let Ok(value) = serde_json::from_value(value) {
+            self.inner.state.set(value);
+        }
+    }
+}
+
+impl<T> Drop for UsePersistedStateImpl<T>
+where
+    T: Unpin + Send + Sync + 'static,
+{
+    // 在状态存储释放之前执行
+    fn drop(&mut self) {
+        if let Some(store) = &self.store {
+            store.unregister(&self.key);
+        }
+    }
+}
+
+impl UsePersistedState for Hooks<'_, '_> {
+    fn use_persisted_state<T, F>(&mut self, key: impl Into<String>, init: F) -> State<T>
+    where
+        F: FnOnce() -> T,
+        T: Serialize + DeserializeOwned + Unpin + Send + Sync + 'static,
+    {
+        self.use_hook(move || UsePersistedStateImpl {
+            inner: UseStateImpl::new(init()),
+            key: key.into(),
+            store: None,
+            restored: false,
+        })
+        .inner
+        .state
+    }
+}
@@ -0,0 +1,163 @@
+use std::{
+    panic::{self, AssertUnwindSafe},
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+};
+
+use ratatui::{layout::Rect, style::Style, text::Line};
+use tokio::sync::mpsc::{self, UnboundedReceiver, UnboundedSender};
+
+use super::{Hook, Hooks};
+use crate::render::drawer::ComponentDrawer;
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::hooks::Hooks<'_, '_> {}
+}
+
+/// 准备中时显示的提示
+const INDICATOR: &str = " ⟳ updating… ";
+
+/// Prepared：在后台准备的渲染数据
+///
+/// 依赖变化后、新数据准备好之前仍然保留上一次的结果，此时 [`Prepared::is_stale`] 为 true。
+pub struct Prepared<T> {
+    value: Option<Arc<T>>,
+    preparing: bool,
+}
+
+impl<T> Clone for Prepared<T> {
+    fn clone(&self) -> Self {
+        Self {
+            value: self.value.clone(),
+            preparing: self.preparing,
+        }
+    }
+}
+
+impl<T> Default for Prepared<T> {
+    fn default() -> Self {
+        Self {
+            value: None,
+            preparing: false,
+        }
+    }
+}
+
+impl<T> Prepared<T> {
+    /// 最近一次准备好的数据，首次准备完成之前为 None
+    pub fn value(&self) -> Option<&T> {
+        self.value.as_deref()
+    }
+
+    /// 共享最近一次准备好的数据，可以直接保存在组件中供 draw 使用
+    pub fn get(&self) -> Option<Arc<T>> {
+        self.value.clone()
+    }
+
+    /// 是否正在后台准备
+    pub fn is_preparing(&self) -> bool {
+        self.preparing
+    }
+
+    /// 正在准备新的数据，当前显示的是旧数据
+    pub fn is_stale(&self) -> bool {
+        self.preparing && self.value.is_some()
+    }
+
+    /// 准备期间在区域右上角绘制提示，没有在准备时不绘制任何内容
+    pub fn draw_indicator(&self, drawer: &mut ComponentDrawer<'_, '_>, style: Style) {
+        if !self.preparing {
+            return;
+        }
+        let area = drawer.area;
+        let width = (Line::raw(INDICATOR).width() as u16).min(area.width);
+        let corner = Rect::new(area.right() - width, area.y, width, area.height.min(1));
+        drawer.render_widget(Line::styled(INDICATOR, style), corner);
+    }
+}
+
+pub trait UsePrepared: private::Sealed {
+    /// 在阻塞线程池中准备渲染数据，只有依赖变化时才重新准备
+    ///
+    /// 用法与 `use_memo` 相似，适合对非常大的内容做格式化（例如对 10MB 的文件做语法高亮）。
+    /// 准备完成后组件重新渲染；准备期间继续返回上一次的结果，可以用 [`Prepared::draw_indicator`] 提示内容已过期。
+    /// 依赖再次变化时，尚未完成的旧任务的结果会被丢弃。
+    ///
+    /// ```ignore
+    /// let prepared = hooks.use_prepared((path.clone(), source.len()), move || highlight(&source));
+    /// self.lines = prepared.get();
+    /// self.prepared = prepared;
+    /// // draw 中
+    /// self.prepared.draw_indicator(drawer, theme.muted());
+    /// ```
+    fn use_prepared<D, T, F>(&mut self, deps: D, prepare: F) -> Prepared<T>
+    where
+        D: PartialEq + Send + 'static,
+        T: Send + Sync + 'static,
+        F: FnOnce() -> T + Send + 'static;
+}
+
+struct UsePreparedImpl<D, T> {
+    deps: Option<D>,
+    generation: u64,
+    sender: UnboundedSender<(u64, Option<T>)>,
+    receiver: UnboundedReceiver<(u64, Option<T>)>,
+    prepared: Prepared<T>,
+}
+
+impl<D: Unpin + Send, T: Send + Sync + 'static> Hook for UsePreparedImpl<D, T> {
+    fn poll_change(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<()> {
+        let mut changed = false;
+        while let Poll::Ready(Some((generation, value))) = self.receiver.poll_recv(cx) {
+            // 只接受最新一次依赖对应的结果
+            if generation != self.generation {
+                continue;
+            }
+            // 任务 panic 时保留旧数据
+            if let Some(value) = value {
+                self.prepared.value = Some(Arc::new(value));
+            }
+            self.prepared.preparing = false;
+            changed = true;
+        }
+        if changed {
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+impl UsePrepared for Hooks<'_, '_> {
+    fn use_prepared<D, T, F>(&mut self, deps: D, prepare: F) -> Prepared<T>
+    where
+        D: PartialEq + Send + 'static,
+        T: Send + Sync + 'static,
+        F: FnOnce() -> T + Send + 'static,
+    {
+        let hook = self.use_hook(|| {
+            let (sender, receiver) = mpsc::unbounded_channel();
+            UsePreparedImpl::<Box<D>, T> {
+                deps: None,
+                generation: 0,
+                sender,
+                receiver,
+                prepared: Prepared::default(),
+            }
+        });
+        if hook.deps.as_deref() != Some(&deps) {
+            hook.deps = Some(Box::new(deps));
+            hook.generation += 1;
+            hook.prepared.preparing = true;
+            let generation = hook.generation;
+            let sender = hook.sender.clone();
+            tokio::task::spawn_blocking(move || {
+                let value = panic::catch_unwind(AssertUnwindSafe(prepare)).ok();
+                let _ = sender.send((generation, value));
+            });
+        }
+        hook.prepared.clone()
+    }
+}
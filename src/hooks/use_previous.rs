@@ -0,0 +1,39 @@
+use super::{Hook, Hooks};
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::hooks::Hooks<'_, '_> {}
+}
+
+pub trait UsePrevious: private::Sealed {
+    /// 返回上一次渲染时传入的值，首次渲染返回 None
+    ///
+    /// 常用于变化检测，例如只在计数真正变化时才触发动画。
+    ///
+    /// ```ignore
+    /// let current = *count.read();
+    /// let previous = hooks.use_previous(current);
+    /// let changed = previous.is_some_and(|previous| previous != current);
+    /// ```
+    fn use_previous<T>(&mut self, value: T) -> Option<T>
+    where
+        T: Send + 'static;
+}
+
+struct UsePreviousImpl<T> {
+    value: Option<Box<T>>,
+}
+
+impl<T: Send> Hook for UsePreviousImpl<T> {}
+
+impl UsePrevious for Hooks<'_, '_> {
+    fn use_previous<T>(&mut self, value: T) -> Option<T>
+    where
+        T: Send + 'static,
+    {
+        let hook = self.use_hook(|| UsePreviousImpl::<T> { value: None });
+        hook.value
+            .replace(Box::new(value))
+            .map(|previous| *previous)
+    }
+}
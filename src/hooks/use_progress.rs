@@ -0,0 +1,179 @@
+use std::{
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll, Waker},
+};
+
+use super::{Hook, Hooks};
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::hooks::Hooks<'_, '_> {}
+}
+
+/// 单个任务的进度快照
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProgressJob {
+    pub id: u64,
+    pub name: String,
+    /// 完成比例（0.0 ~ 1.0），None 表示进度未知
+    pub fraction: Option<f64>,
+    pub message: String,
+}
+
+#[derive(Default)]
+struct ProgressInner {
+    jobs: Vec<ProgressJob>,
+    next_id: u64,
+    /// 每次变更递增，用于判断是否需要重新渲染
+    version: u64,
+    waker: Option<Waker>,
+}
+
+impl ProgressInner {
+    fn job_mut(&mut self, id: u64) -> Option<&mut ProgressJob> {
+        self.jobs.iter_mut().find(|job| job.id == id)
+    }
+
+    fn notify(&mut self) {
+        self.version += 1;
+        if let Some(waker) = self.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+/// ProgressManager：后台任务的进度登记表
+///
+/// 可以自由克隆并发送到其他线程或异步任务中，通过 [`ProgressManager::start`] 登记任务。
+#[derive(Clone, Default)]
+pub struct ProgressManager {
+    inner: Arc<Mutex<ProgressInner>>,
+}
+
+impl ProgressManager {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 登记一个新任务，返回的句柄被 drop 时任务自动移除
+    pub fn start(&self, name: impl Into<String>) -> ProgressHandle {
+        let mut inner = self.inner.lock().unwrap();
+        let id = inner.next_id;
+        inner.next_id += 1;
+        inner.jobs.push(ProgressJob {
+            id,
+            name: name.into(),
+            fraction: None,
+            message: String::new(),
+        });
+        inner.notify();
+        ProgressHandle {
+            id,
+            manager: self.clone(),
+        }
+    }
+
+    /// 当前所有进行中的任务
+    pub fn jobs(&self) -> Vec<ProgressJob> {
+        self.inner.lock().unwrap().jobs.clone()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.inner.lock().unwrap().jobs.is_empty()
+    }
+
+    fn version(&self) -> u64 {
+        self.inner.lock().unwrap().version
+    }
+
+    /// 是否与 other 是同一个登记表
+    pub(crate) fn same(&self, other: &ProgressManager) -> bool {
+        Arc::ptr_eq(&self.inner, &other.inner)
+    }
+
+    fn update(&self, id: u64, f: impl FnOnce(&mut ProgressJob)) {
+        let mut inner = self.inner.lock().unwrap();
+        if let Some(job) = inner.job_mut(id) {
+            f(job);
+            inner.notify();
+        }
+    }
+}
+
+/// ProgressHandle：单个任务的进度句柄
+pub struct ProgressHandle {
+    id: u64,
+    manager: ProgressManager,
+}
+
+impl ProgressHandle {
+    /// 设置完成比例，超出范围时会被截断到 0.0 ~ 1.0
+    pub fn set_fraction(&self, fraction: f64) {
+        self.manager
+            .update(self.id, |job| job.fraction = Some(fraction.clamp(0.0, 1.0)));
+    }
+
+    /// 设置状态描述
+    pub fn set_message(&self, message: impl Into<String>) {
+        let message = message.into();
+        self.manager.update(self.id, |job| job.message = message);
+    }
+
+    /// 结束任务，等同于 drop 句柄
+    pub fn finish(self) {}
+}
+
+impl Drop for ProgressHandle {
+    fn drop(&mut self) {
+        let mut inner = self.manager.inner.lock().unwrap();
+        inner.jobs.retain(|job| job.id != self.id);
+        inner.notify();
+    }
+}
+
+pub trait UseProgress: private::Sealed {
+    /// 创建组件级的进度登记表
+    ///
+    /// 任何任务的进度变化（登记、更新、结束）都会触发组件重新渲染，
+    /// 通常与 [`ProgressList`](crate::components::ProgressList) 组件一起使用。
+    fn use_progress(&mut self) -> ProgressManager;
+}
+
+/// 在登记表变化时触发组件重新渲染
+pub(crate) struct ProgressWatcher {
+    pub(crate) manager: ProgressManager,
+    /// 上次渲染时看到的版本
+    version: u64,
+}
+
+impl ProgressWatcher {
+    pub(crate) fn new(manager: ProgressManager) -> Self {
+        Self {
+            version: manager.version(),
+            manager,
+        }
+    }
+}
+
+impl Hook for ProgressWatcher {
+    fn poll_change(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<()> {
+        let mut inner = self.manager.inner.lock().unwrap();
+        if inner.version != self.version {
+            let version = inner.version;
+            drop(inner);
+            self.version = version;
+            Poll::Ready(())
+        } else {
+            inner.waker = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
+impl UseProgress for Hooks<'_, '_> {
+    fn use_progress(&mut self) -> ProgressManager {
+        let hook = self.use_hook(|| ProgressWatcher::new(ProgressManager::new()));
+        hook.manager.clone()
+    }
+}
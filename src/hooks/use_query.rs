@@ -0,0 +1,306 @@
+use std::{
+    any::Any,
+    collections::HashMap,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll},
+    time::{Duration, Instant},
+};
+
+use futures::{FutureExt, future::BoxFuture};
+
+use super::{
+    Hook, Hooks,
+    use_resource::{LoadingGuard, begin_loading},
+    use_state::{State, StateRef, UseState},
+};
+use crate::{clock, context::SystemContext, render::updater::ComponentUpdater};
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::hooks::Hooks<'_, '_> {}
+}
+
+/// 查询的状态
+#[derive(Clone, Debug)]
+pub enum QueryState<T, E> {
+    /// 首次加载中，还没有任何数据
+    Loading,
+    Ready(T),
+    Error(E),
+}
+
+struct CacheEntry {
+    value: Arc<dyn Any + Send + Sync>,
+    fetched_at: Instant,
+}
+
+struct QueryCacheInner {
+    entries: HashMap<String, CacheEntry>,
+    stale_time: Duration,
+}
+
+/// QueryCache：按 key 缓存 `use_query` 的结果
+///
+/// 由 [`SystemContext::queries`] 提供，同一 key 的查询在组件之间、以及组件重新挂载后共享结果。
+/// 有缓存时先返回缓存的数据，缓存过期（超过 stale_time，默认为 0）时再在后台重新获取。
+#[derive(Clone)]
+pub struct QueryCache {
+    inner: Arc<Mutex<QueryCacheInner>>,
+}
+
+impl Default for QueryCache {
+    fn default() -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(QueryCacheInner {
+                entries: HashMap::new(),
+                stale_time: Duration::ZERO,
+            })),
+        }
+    }
+}
+
+impl QueryCache {
+    /// 设置缓存的保鲜时间，保鲜期内挂载的查询直接使用缓存，不再重新获取
+    pub fn set_stale_time(&self, stale_time: Duration) {
+        self.inner.lock().unwrap().stale_time = stale_time;
+    }
+
+    /// 移除 key 的缓存，之后挂载的查询会重新获取
+    pub fn invalidate(&self, key: &str) {
+        self.inner.lock().unwrap().entries.remove(key);
+    }
+
+    pub fn clear(&self) {
+        self.inner.lock().unwrap().entries.clear();
+    }
+
+    /// 读取缓存，返回数据以及是否仍在保鲜期内
+    fn get<T: Clone + 'static>(&self, key: &str) -> Option<(T, bool)> {
+        let inner = self.inner.lock().unwrap();
+        let entry = inner.entries.get(key)?;
+        let value = entry.value.downcast_ref::<T>()?.clone();
+        Some((value, clock::elapsed(entry.fetched_at) < inner.stale_time))
+    }
+
+    fn set<T: Send + Sync + 'static>(&self, key: String, value: T) {
+        self.inner.lock().unwrap().entries.insert(
+            key,
+            CacheEntry {
+                value: Arc::new(value),
+                fetched_at: clock::now(),
+            },
+        );
+    }
+}
+
+/// Query：查询句柄，可以在事件回调中自由复制使用
+pub struct Query<T, E>
+where
+    T: Send + Sync + 'static,
+    E: Send + Sync + 'static,
+{
+    state: State<QueryState<T, E>>,
+    fetching: State<bool>,
+    refetch: State<u64>,
+}
+
+impl<T: Send + Sync + 'static, E: Send + Sync + 'static> Clone for Query<T, E> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Send + Sync + 'static, E: Send + Sync + 'static> Copy for Query<T, E> {}
+
+impl<T: Send + Sync + 'static, E: Send + Sync + 'static> Query<T, E> {
+    /// 读取查询状态
+    pub fn read(&self) -> StateRef<'_, QueryState<T, E>> {
+        self.state.read()
+    }
+
+    /// 是否处于首次加载中
+    pub fn is_loading(&self) -> bool {
+        matches!(*self.state.read(), QueryState::Loading)
+    }
+
+    /// 是否正在获取数据，包括已有数据时在后台重新获取
+    pub fn is_fetching(&self) -> bool {
+        self.fetching.get()
+    }
+
+    /// 手动重新获取，获取期间保留当前数据
+    pub fn refetch(&mut self) {
+        let next = self.refetch.get() + 1;
+        self.refetch.set(next);
+    }
+}
+
+impl<T: Clone + Send + Sync + 'static, E: Clone + Send + Sync + 'static> Query<T, E> {
+    /// 获取数据的副本
+    pub fn value(&self) -> Option<T> {
+        match &*self.state.read() {
+            QueryState::Ready(value) => Some(value.clone()),
+            _ => None,
+        }
+    }
+
+    /// 获取错误的副本
+    pub fn error(&self) -> Option<E> {
+        match &*self.state.read() {
+            QueryState::Error(error) => Some(error.clone()),
+            _ => None,
+        }
+    }
+}
+
+pub trait UseQuery: private::Sealed {
+    /// 按 key 获取并缓存异步数据
+    ///
+    /// - 首次渲染、key 变化或调用 [`Query::refetch`] 时调用 fetcher 获取数据
+    /// - 同一 key 有缓存时先显示缓存的数据，过期时在后台重新获取（stale-while-revalidate）
+    /// - 获取期间计入全局的 [`LoadingTracker`](super::use_resource::LoadingTracker) 与最近的加载边界
+    ///
+    /// ```ignore
+    /// let query = hooks.use_query(format!("user/{id}"), move || fetch_user(id));
+    /// match &*query.read() {
+    ///     QueryState::Loading => ...,
+    ///     QueryState::Ready(user) => ...,
+    ///     QueryState::Error(error) => ...,
+    /// }
+    /// ```
+    fn use_query<T, E, F, Fut>(&mut self, key: impl Into<String>, fetcher: F) -> Query<T, E>
+    where
+        T: Clone + Unpin + Send + Sync + 'static,
+        E: Clone + Unpin + Send + Sync + 'static,
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<T, E>> + Send + 'static;
+}
+
+struct UseQueryImpl<T, E>
+where
+    T: Send + Sync + 'static,
+    E: Send + Sync + 'static,
+{
+    key: Option<String>,
+    refetch: u64,
+    /// 本次 update 创建、尚未根据缓存决定是否执行的请求
+    pending: Option<(BoxFuture<'static, Result<T, E>>, bool)>,
+    future: Option<BoxFuture<'static, Result<T, E>>>,
+    query: Query<T, E>,
+    cache: Option<QueryCache>,
+    guards: Vec<LoadingGuard>,
+}
+
+impl<T, E> Hook for UseQueryImpl<T, E>
+where
+    T: Clone + Unpin + Send + Sync + 'static,
+    E: Clone + Unpin + Send + Sync + 'static,
+{
+    fn poll_change(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<()> {
+        if let Some(future) = &mut self.future
+            && let Poll::Ready(result) = future.as_mut().poll(cx)
+        {
+            self.future = None;
+            self.guards.clear();
+            let state = match result {
+                Ok(value) => {
+                    if let (Some(cache), Some(key)) = (&self.cache, &self.key) {
+                        cache.set(key.clone(), value.clone());
+                    }
+                    QueryState::Ready(value)
+                }
+                Err(error) => QueryState::Error(error),
+            };
+            self.query.state.set(state);
+            self.set_fetching(false);
+        }
+        // 状态的变化由 State 自身负责唤醒
+        Poll::Pending
+    }
+
+    fn post_component_update(&mut self, updater: &mut ComponentUpdater) {
+        let Some((future, forced)) = self.pending.take() else {
+            return;
+        };
+        let system = updater
+            .component_context_stack()
+            .get_context::<SystemContext>();
+        if self.cache.is_none() {
+            self.cache = system.as_ref().map(|system| system.queries());
+        }
+
+        // 有缓存时先显示缓存的数据，保鲜期内且不是手动重新获取时直接使用缓存
+        let cached = match (&self.cache, &self.key) {
+            (Some(cache), Some(key)) => cache.get::<T>(key),
+            _ => None,
+        };
+        if let Some((value, fresh)) = cached {
+            self.query.state.set(QueryState::Ready(value));
+            if fresh && !forced {
+                self.future = None;
+                self.guards.clear();
+                self.set_fetching(false);
+                return;
+            }
+        } else if !forced && !self.query.is_loading() {
+            self.query.state.set(QueryState::Loading);
+        }
+
+        self.future = Some(future);
+        drop(system);
+        self.guards = begin_loading(updater);
+        self.set_fetching(true);
+    }
+}
+
+impl<T, E> UseQueryImpl<T, E>
+where
+    T: Send + Sync + 'static,
+    E: Send + Sync + 'static,
+{
+    // 只在变化时写入，避免多余的重新渲染
+    fn set_fetching(&mut self, fetching: bool) {
+        if self.query.fetching.get() != fetching {
+            self.query.fetching.set(fetching);
+        }
+    }
+}
+
+impl UseQuery for Hooks<'_, '_> {
+    fn use_query<T, E, F, Fut>(&mut self, key: impl Into<String>, fetcher: F) -> Query<T, E>
+    where
+        T: Clone + Unpin + Send + Sync + 'static,
+        E: Clone + Unpin + Send + Sync + 'static,
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<T, E>> + Send + 'static,
+    {
+        let query = Query {
+            state: self.use_state(|| QueryState::Loading),
+            // 首次渲染总会发起请求
+            fetching: self.use_state(|| true),
+            refetch: self.use_state(|| 0),
+        };
+        let hook = self.use_hook(|| UseQueryImpl {
+            key: None,
+            refetch: 0,
+            pending: None,
+            future: None,
+            query,
+            cache: None,
+            guards: Vec::new(),
+        });
+
+        let key = key.into();
+        let refetch = query.refetch.get();
+        if hook.key.as_ref() != Some(&key) {
+            hook.key = Some(key);
+            hook.refetch = refetch;
+            hook.pending = Some((fetcher().boxed(), false));
+        } else if hook.refetch != refetch {
+            hook.refetch = refetch;
+            hook.pending = Some((fetcher().boxed(), true));
+        }
+        query
+    }
+}
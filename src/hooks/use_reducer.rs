@@ -0,0 +1,89 @@
+use std::sync::Arc;
+
+use super::{
+    Hook, Hooks,
+    use_state::{State, UseState},
+};
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::hooks::Hooks<'_, '_> {}
+}
+
+/// Dispatcher：向 reducer 派发动作的句柄
+///
+/// 可以克隆并移动到 `use_events`、`use_future` 等回调中，派发后组件会重新渲染。
+pub struct Dispatcher<A> {
+    dispatch: Arc<dyn Fn(A) + Send + Sync>,
+}
+
+impl<A> Clone for Dispatcher<A> {
+    fn clone(&self) -> Self {
+        Self {
+            dispatch: self.dispatch.clone(),
+        }
+    }
+}
+
+impl<A> Dispatcher<A> {
+    /// 派发一个动作，由 reducer 原地更新状态
+    pub fn dispatch(&self, action: A) {
+        (self.dispatch)(action)
+    }
+}
+
+pub trait UseReducer: private::Sealed {
+    /// 以 reducer 的方式管理状态，适合由多种事件驱动的复杂状态转换
+    ///
+    /// - reducer 只在首次渲染时保存，之后传入的 reducer 会被忽略
+    /// - 返回状态句柄与派发器，状态仍然可以直接读取
+    ///
+    /// ```ignore
+    /// let (state, dispatch) = hooks.use_reducer(
+    ///     |count: &mut i32, action: Action| match action {
+    ///         Action::Increment => *count += 1,
+    ///         Action::Reset => *count = 0,
+    ///     },
+    ///     || 0,
+    /// );
+    /// hooks.use_events(move |event| {
+    ///     if let Event::Key(key) = event && key.code == KeyCode::Char('+') {
+    ///         dispatch.dispatch(Action::Increment);
+    ///     }
+    /// });
+    /// ```
+    fn use_reducer<T, A, R, F>(&mut self, reducer: R, init: F) -> (State<T>, Dispatcher<A>)
+    where
+        T: Unpin + Send + Sync + 'static,
+        A: 'static,
+        R: Fn(&mut T, A) + Send + Sync + 'static,
+        F: FnOnce() -> T;
+}
+
+struct UseReducerImpl<A> {
+    dispatcher: Dispatcher<A>,
+}
+
+impl<A> Hook for UseReducerImpl<A> {}
+
+impl UseReducer for Hooks<'_, '_> {
+    fn use_reducer<T, A, R, F>(&mut self, reducer: R, init: F) -> (State<T>, Dispatcher<A>)
+    where
+        T: Unpin + Send + Sync + 'static,
+        A: 'static,
+        R: Fn(&mut T, A) + Send + Sync + 'static,
+        F: FnOnce() -> T,
+    {
+        let state = self.use_state(init);
+        let hook = self.use_hook(move || UseReducerImpl {
+            dispatcher: Dispatcher {
+                dispatch: Arc::new(move |action| {
+                    if let Some(mut value) = state.try_write() {
+                        reducer(&mut value, action);
+                    }
+                }),
+            },
+        });
+        (state, hook.dispatcher.clone())
+    }
+}
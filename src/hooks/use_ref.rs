@@ -0,0 +1,75 @@
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use super::{Hook, Hooks};
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::hooks::Hooks<'_, '_> {}
+}
+
+/// Ref：不触发重新渲染的可变存储
+///
+/// 与 [`State`](super::use_state::State) 不同，修改 Ref 不会唤醒渲染循环，
+/// 适合缓存布局计算结果、保存子进程句柄等与界面显示无关的数据。
+pub struct Ref<T> {
+    inner: Arc<Mutex<T>>,
+}
+
+impl<T> Clone for Ref<T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<T> Ref<T> {
+    /// 获取内部数据的可变引用，持有期间其他地方的访问会阻塞
+    pub fn lock(&self) -> MutexGuard<'_, T> {
+        self.inner.lock().unwrap()
+    }
+
+    /// 替换内部数据
+    pub fn set(&self, value: T) {
+        *self.lock() = value;
+    }
+}
+
+impl<T: Clone> Ref<T> {
+    /// 返回内部数据的克隆
+    pub fn get(&self) -> T {
+        self.lock().clone()
+    }
+}
+
+pub trait UseRef: private::Sealed {
+    /// 声明一个不触发重新渲染的可变存储，init 只在首次渲染时调用
+    ///
+    /// 返回的 Ref 可以克隆并移动到事件回调或异步任务中。
+    fn use_ref<T, F>(&mut self, init: F) -> Ref<T>
+    where
+        F: FnOnce() -> T,
+        T: Send + 'static;
+}
+
+struct UseRefImpl<T> {
+    value: Ref<T>,
+}
+
+impl<T: Send> Hook for UseRefImpl<T> {}
+
+impl UseRef for Hooks<'_, '_> {
+    fn use_ref<T, F>(&mut self, init: F) -> Ref<T>
+    where
+        F: FnOnce() -> T,
+        T: Send + 'static,
+    {
+        self.use_hook(move || UseRefImpl {
+            value: Ref {
+                inner: Arc::new(Mutex::new(init())),
+            },
+        })
+        .value
+        .clone()
+    }
+}
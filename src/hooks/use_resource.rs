@@ -0,0 +1,220 @@
+use std::{
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll, Waker},
+};
+
+use futures::{FutureExt, future::BoxFuture};
+
+use super::{
+    Hook, Hooks,
+    use_state::{State, StateRef, UseState},
+};
+use crate::{context::SystemContext, render::updater::ComponentUpdater};
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::hooks::Hooks<'_, '_> {}
+}
+
+#[derive(Default)]
+struct LoadingInner {
+    count: usize,
+    /// 计数每次变化时递增
+    version: u64,
+    waker: Option<Waker>,
+}
+
+impl LoadingInner {
+    fn changed(&mut self) {
+        self.version += 1;
+        if let Some(waker) = self.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+/// LoadingTracker：全局进行中异步操作的计数器
+///
+/// 由 [`SystemContext::loading`] 提供，`use_resource` 会自动登记，
+/// 自定义的异步 Hook 也可以通过 [`LoadingTracker::begin`] 接入。计数变化时唤醒渲染循环，空闲时不产生刷新。
+#[derive(Clone, Default)]
+pub struct LoadingTracker {
+    inner: Arc<Mutex<LoadingInner>>,
+}
+
+impl LoadingTracker {
+    /// 登记一个进行中的操作，返回的守卫被 drop 时计数减一
+    pub fn begin(&self) -> LoadingGuard {
+        let mut inner = self.inner.lock().unwrap();
+        inner.count += 1;
+        inner.changed();
+        LoadingGuard {
+            tracker: self.clone(),
+        }
+    }
+
+    /// 当前进行中的操作数量
+    pub fn count(&self) -> usize {
+        self.inner.lock().unwrap().count
+    }
+
+    pub fn is_loading(&self) -> bool {
+        self.count() > 0
+    }
+
+    /// 登记唤醒并返回当前的版本号
+    fn poll_version(&self, cx: &mut Context) -> u64 {
+        let mut inner = self.inner.lock().unwrap();
+        inner.waker = Some(cx.waker().clone());
+        inner.version
+    }
+}
+
+/// LoadingGuard：进行中操作的登记凭证
+pub struct LoadingGuard {
+    tracker: LoadingTracker,
+}
+
+impl Drop for LoadingGuard {
+    fn drop(&mut self) {
+        let mut inner = self.tracker.inner.lock().unwrap();
+        inner.count -= 1;
+        inner.changed();
+    }
+}
+
+/// 在全局计数器与最近的加载边界中登记进行中的操作
+///
+/// 加载边界是组件通过上下文提供给子树的 [`LoadingTracker`]，
+/// 例如开启 suspense 的 [`Skeleton`](crate::components::Skeleton)。
+pub(crate) fn begin_loading(updater: &ComponentUpdater) -> Vec<LoadingGuard> {
+    let stack = updater.component_context_stack();
+    let global = stack
+        .get_context::<SystemContext>()
+        .map(|system| system.loading().begin());
+    let boundary = stack
+        .get_context::<LoadingTracker>()
+        .map(|tracker| tracker.begin());
+    global.into_iter().chain(boundary).collect()
+}
+
+/// 在 tracker 的计数变化时触发组件重新渲染
+pub(crate) struct LoadingWatcher {
+    pub(crate) tracker: LoadingTracker,
+    /// 上次渲染时的版本号
+    seen: u64,
+}
+
+impl LoadingWatcher {
+    pub(crate) fn new(tracker: LoadingTracker) -> Self {
+        let seen = tracker.inner.lock().unwrap().version;
+        Self { tracker, seen }
+    }
+}
+
+impl Hook for LoadingWatcher {
+    fn poll_change(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<()> {
+        let version = self.tracker.poll_version(cx);
+        if version != self.seen {
+            self.seen = version;
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+/// Resource：异步资源句柄，可以在事件回调中自由复制使用
+pub struct Resource<T: Unpin + Send + Sync + 'static> {
+    state: State<Option<T>>,
+}
+
+impl<T: Unpin + Send + Sync + 'static> Clone for Resource<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Unpin + Send + Sync + 'static> Copy for Resource<T> {}
+
+impl<T: Unpin + Send + Sync + 'static> Resource<T> {
+    /// 是否仍在加载
+    pub fn is_loading(&self) -> bool {
+        self.state.read().is_none()
+    }
+
+    /// 读取加载结果，加载中时为 None
+    pub fn read(&self) -> StateRef<'_, Option<T>> {
+        self.state.read()
+    }
+}
+
+impl<T: Clone + Unpin + Send + Sync + 'static> Resource<T> {
+    /// 获取加载结果的副本
+    pub fn value(&self) -> Option<T> {
+        self.state.read().clone()
+    }
+}
+
+pub trait UseResource: private::Sealed {
+    /// 加载异步资源
+    ///
+    /// 组件首次渲染时调用 f 创建 Future，结果就绪后写入状态并触发重新渲染。
+    /// 加载期间会计入全局的 [`LoadingTracker`] 与最近的加载边界，组件卸载时自动撤销。
+    fn use_resource<T, F, Fut>(&mut self, f: F) -> Resource<T>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = T> + Send + 'static,
+        T: Unpin + Send + Sync + 'static;
+}
+
+struct UseResourceImpl<T: Unpin + Send + Sync + 'static> {
+    future: Option<BoxFuture<'static, T>>,
+    state: State<Option<T>>,
+    guards: Vec<LoadingGuard>,
+    registered: bool,
+}
+
+impl<T: Unpin + Send + Sync + 'static> Hook for UseResourceImpl<T> {
+    fn poll_change(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<()> {
+        if let Some(future) = &mut self.future
+            && let Poll::Ready(value) = future.as_mut().poll(cx)
+        {
+            self.future = None;
+            self.guards.clear();
+            self.state.set(Some(value));
+        }
+        // 状态的变化由 State 自身负责唤醒
+        Poll::Pending
+    }
+
+    fn post_component_update(&mut self, updater: &mut ComponentUpdater) {
+        // Hook 在首次 update 中创建，此时才能拿到上下文
+        if self.registered {
+            return;
+        }
+        self.registered = true;
+        if self.future.is_some() {
+            self.guards = begin_loading(updater);
+        }
+    }
+}
+
+impl UseResource for Hooks<'_, '_> {
+    fn use_resource<T, F, Fut>(&mut self, f: F) -> Resource<T>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = T> + Send + 'static,
+        T: Unpin + Send + Sync + 'static,
+    {
+        let state = self.use_state(|| None);
+        self.use_hook(move || UseResourceImpl {
+            future: Some(f().boxed()),
+            state,
+            guards: Vec::new(),
+            registered: false,
+        });
+        Resource { state }
+    }
+}
@@ -0,0 +1,238 @@
+use std::time::{Duration, Instant};
+
+use crossterm::event::{KeyCode, KeyEvent};
+
+use super::{
+    Hook, Hooks,
+    use_state::{State, UseState},
+};
+use crate::{
+    animation::{AnimationClock, Easing},
+    clock,
+    context::SystemContext,
+    render::updater::ComponentUpdater,
+};
+
+/// 越界提示的持续时间
+const OVERSCROLL_DURATION: Duration = Duration::from_millis(400);
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::hooks::Hooks<'_, '_> {}
+}
+
+/// ScrollState：可滚动内容的位置，在滚动的组件与 [`Scrollbar`](crate::components::Scrollbar) 之间共享
+///
+/// content 与 viewport 由绘制内容的组件在绘制时写入，offset 由键盘、鼠标或 Scrollbar 修改，
+/// 始终被限制在 `0..=max_offset()` 之内。
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ScrollState {
+    offset: usize,
+    content: usize,
+    viewport: usize,
+}
+
+impl ScrollState {
+    /// 第一行可见内容的下标
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// 内容的总行数
+    pub fn content(&self) -> usize {
+        self.content
+    }
+
+    /// 可见区域的行数
+    pub fn viewport(&self) -> usize {
+        self.viewport
+    }
+
+    pub fn max_offset(&self) -> usize {
+        self.content.saturating_sub(self.viewport)
+    }
+
+    /// 内容是否超出可见区域
+    pub fn is_scrollable(&self) -> bool {
+        self.content > self.viewport
+    }
+
+    pub fn set_content(&mut self, content: usize) {
+        self.content = content;
+        self.offset = self.offset.min(self.max_offset());
+    }
+
+    pub fn set_viewport(&mut self, viewport: usize) {
+        self.viewport = viewport;
+        self.offset = self.offset.min(self.max_offset());
+    }
+
+    pub fn scroll_to(&mut self, offset: usize) {
+        self.offset = offset.min(self.max_offset());
+    }
+
+    pub fn scroll_by(&mut self, delta: isize) {
+        self.scroll_to(self.offset.saturating_add_signed(delta));
+    }
+
+    pub fn page_up(&mut self) {
+        self.scroll_by(-(self.viewport.max(1) as isize));
+    }
+
+    pub fn page_down(&mut self) {
+        self.scroll_by(self.viewport.max(1) as isize);
+    }
+
+    /// 以最小的滚动距离让第 index 行可见，可见区域尚未确定时不做任何事
+    pub fn scroll_into_view(&mut self, index: usize) {
+        if self.viewport == 0 {
+            return;
+        }
+        if index < self.offset {
+            self.scroll_to(index);
+        } else if index >= self.offset + self.viewport {
+            self.scroll_to(index + 1 - self.viewport);
+        }
+    }
+
+    /// 处理 ↑/↓、PageUp/PageDown、Home/End，返回按键是否被消费
+    pub fn handle_key(&mut self, key: KeyEvent) -> bool {
+        let previous = self.offset;
+        match key.code {
+            KeyCode::Up => self.scroll_by(-1),
+            KeyCode::Down => self.scroll_by(1),
+            KeyCode::PageUp => self.page_up(),
+            KeyCode::PageDown => self.page_down(),
+            KeyCode::Home => self.scroll_to(0),
+            KeyCode::End => self.scroll_to(self.max_offset()),
+            _ => return false,
+        }
+        self.offset != previous
+    }
+}
+
+/// 滚动到达的边界
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ScrollEdge {
+    Top,
+    Bottom,
+}
+
+/// Overscroll：滚动到边界后继续滚动时的提示，由 `use_overscroll` 创建
+#[derive(Clone, Copy)]
+pub struct Overscroll {
+    hit: State<Option<(ScrollEdge, Instant)>>,
+    current: Option<(ScrollEdge, f64)>,
+}
+
+impl Overscroll {
+    /// 记录一次越界，可以在事件回调中调用
+    pub fn hit(&mut self, edge: ScrollEdge) {
+        self.hit.set(Some((edge, clock::now())));
+    }
+
+    /// 当前正在提示的边界与强度，强度从 1 逐渐减弱到 0，提示结束后返回 None
+    pub fn current(&self) -> Option<(ScrollEdge, f64)> {
+        self.current
+    }
+}
+
+pub trait UseScroll: private::Sealed {
+    /// 创建一个滚动状态，传给可滚动的组件与 Scrollbar 使两者保持同步
+    ///
+    /// ```ignore
+    /// let scroll = hooks.use_scroll();
+    /// element!(View(flex_direction: Direction::Horizontal) {
+    ///     List(items: items, scroll: scroll)
+    ///     Scrollbar(state: scroll)
+    /// })
+    /// ```
+    fn use_scroll(&mut self) -> State<ScrollState>;
+
+    /// 滚动到边界后继续按键时给出短暂的视觉提示，而不是静默忽略按键
+    ///
+    /// 在事件回调中调用 [`Overscroll::hit`]，绘制时根据 [`Overscroll::current`] 的强度绘制提示；
+    /// 提示期间由动画时钟驱动刷新，开启 reduced_motion 时不渐隐。
+    ///
+    /// ```ignore
+    /// let mut overscroll = hooks.use_overscroll();
+    /// hooks.use_events(move |event| {
+    ///     // 已经在第一行时继续向上
+    ///     overscroll.hit(ScrollEdge::Top);
+    /// });
+    /// self.overscroll = overscroll.current();
+    /// ```
+    fn use_overscroll(&mut self) -> Overscroll;
+}
+
+struct UseOverscrollImpl {
+    clock: Option<AnimationClock>,
+    reduced_motion: bool,
+    /// 是否已在时钟上登记为进行中
+    running: bool,
+    active: bool,
+}
+
+impl Hook for UseOverscrollImpl {
+    fn post_component_update(&mut self, updater: &mut ComponentUpdater) {
+        if self.clock.is_none()
+            && let Some(system) = updater
+                .component_context_stack()
+                .get_context::<SystemContext>()
+        {
+            self.clock = Some(system.animations());
+            self.reduced_motion = system.reduced_motion();
+        }
+        let Some(clock) = &self.clock else {
+            return;
+        };
+        if self.running != self.active {
+            if self.active {
+                clock.start();
+            } else {
+                clock.stop();
+            }
+            self.running = self.active;
+        }
+    }
+}
+
+impl Drop for UseOverscrollImpl {
+    fn drop(&mut self) {
+        if self.running
+            && let Some(clock) = &self.clock
+        {
+            clock.stop();
+        }
+    }
+}
+
+impl UseScroll for Hooks<'_, '_> {
+    fn use_scroll(&mut self) -> State<ScrollState> {
+        self.use_state(ScrollState::default)
+    }
+
+    fn use_overscroll(&mut self) -> Overscroll {
+        let hit: State<Option<(ScrollEdge, Instant)>> = self.use_state(|| None);
+        let hook = self.use_hook(|| UseOverscrollImpl {
+            clock: None,
+            reduced_motion: false,
+            running: false,
+            active: false,
+        });
+        let current = hit.get().and_then(|(edge, at)| {
+            let t = clock::elapsed(at).as_secs_f64() / OVERSCROLL_DURATION.as_secs_f64();
+            if t >= 1.0 {
+                return None;
+            }
+            let intensity = if hook.reduced_motion {
+                1.0
+            } else {
+                1.0 - Easing::EaseIn.apply(t)
+            };
+            Some((edge, intensity))
+        });
+        hook.active = current.is_some();
+        Overscroll { hit, current }
+    }
+}
@@ -0,0 +1,122 @@
+use std::sync::{Arc, Mutex, Weak};
+
+use super::{Hook, Hooks};
+use crate::{context::SystemContext, render::updater::ComponentUpdater};
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::hooks::Hooks<'_, '_> {}
+}
+
+/// Signal：进程收到的终止类信号
+///
+/// Unix 上对应 SIGINT、SIGTERM 与 SIGHUP；Windows 上 Ctrl+C / Ctrl+Break 对应 Interrupt，
+/// 关闭控制台窗口对应 Hangup，注销与关机对应 Terminate。
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Signal {
+    Interrupt,
+    Terminate,
+    Hangup,
+}
+
+impl Signal {
+    /// 按 shell 的惯例，因信号结束时的退出码（128 + 信号编号）
+    pub fn exit_code(self) -> i32 {
+        match self {
+            Signal::Interrupt => 130,
+            Signal::Terminate => 143,
+            Signal::Hangup => 129,
+        }
+    }
+}
+
+type Handler = Box<dyn FnMut(Signal) -> bool + Send>;
+type HandlerCell = Mutex<Option<Handler>>;
+type HandlerSlot = Arc<HandlerCell>;
+
+/// SignalHandlers：通过 `use_signal_handler` 注册的信号回调
+///
+/// 由 [`SystemContext`] 持有，渲染循环收到信号后依次调用仍然挂载的组件注册的回调。
+#[derive(Clone, Default)]
+pub struct SignalHandlers {
+    handlers: Arc<Mutex<Vec<Weak<HandlerCell>>>>,
+}
+
+impl SignalHandlers {
+    fn register(&self, slot: &HandlerSlot) {
+        self.handlers.lock().unwrap().push(Arc::downgrade(slot));
+    }
+
+    /// 把 signal 分发给所有回调，任一回调返回 true 时表示信号已被处理，不再执行默认的退出
+    pub(crate) fn dispatch(&self, signal: Signal) -> bool {
+        // 先取出回调再调用，回调中可以安全地注册新的回调
+        let slots: Vec<HandlerSlot> = {
+            let mut handlers = self.handlers.lock().unwrap();
+            handlers.retain(|slot| slot.strong_count() > 0);
+            handlers.iter().filter_map(Weak::upgrade).collect()
+        };
+        let mut handled = false;
+        for slot in slots {
+            if let Some(handler) = &mut *slot.lock().unwrap() {
+                handled |= handler(signal);
+            }
+        }
+        handled
+    }
+}
+
+pub trait UseSignalHandler: private::Sealed {
+    /// 订阅进程收到的终止类信号（SIGINT、SIGTERM、SIGHUP 以及 Windows 的控制台事件）
+    ///
+    /// 默认情况下收到信号后结束渲染循环、恢复终端，并把 [`Signal::exit_code`] 作为退出码返回给调用方。
+    /// 回调在渲染循环中执行，返回 true 表示信号已被处理、不要退出，例如先弹出确认对话框。
+    /// 退出过程中再次收到信号时会立即恢复终端并结束进程，避免渲染循环卡住时终端停留在 raw 模式。
+    ///
+    /// ```ignore
+    /// let mut editor = hooks.use_state(Editor::default);
+    /// let exit = hooks.use_exit();
+    /// hooks.use_signal_handler(move |signal| {
+    ///     editor.read().save_backup();
+    ///     if signal == Signal::Hangup {
+    ///         exit.exit_with_code(signal.exit_code());
+    ///     }
+    ///     false
+    /// });
+    /// ```
+    fn use_signal_handler<F>(&mut self, f: F)
+    where
+        F: FnMut(Signal) -> bool + Send + 'static;
+}
+
+struct UseSignalHandlerImpl {
+    slot: HandlerSlot,
+    registered: bool,
+}
+
+impl Hook for UseSignalHandlerImpl {
+    fn post_component_update(&mut self, updater: &mut ComponentUpdater) {
+        if self.registered {
+            return;
+        }
+        if let Some(system) = updater
+            .component_context_stack()
+            .get_context::<SystemContext>()
+        {
+            system.signals().register(&self.slot);
+            self.registered = true;
+        }
+    }
+}
+
+impl UseSignalHandler for Hooks<'_, '_> {
+    fn use_signal_handler<F>(&mut self, f: F)
+    where
+        F: FnMut(Signal) -> bool + Send + 'static,
+    {
+        let hook = self.use_hook(|| UseSignalHandlerImpl {
+            slot: Arc::default(),
+            registered: false,
+        });
+        *hook.slot.lock().unwrap() = Some(Box::new(f));
+    }
+}
@@ -27,11 +27,11 @@ pub trait UseState: private::Sealed {
 }
 
 // use_state 的 Hook 实现，负责状态的生命周期和变更检测
-struct UseStateImpl<T>
+pub(crate) struct UseStateImpl<T>
 where
     T: Unpin + Send + Sync + 'static,
 {
-    state: State<T>,              // 状态句柄
+    pub(crate) state: State<T>,   // 状态句柄
     _storage: Owner<SyncStorage>, // 状态存储的所有权，保证生命周期
 }
 
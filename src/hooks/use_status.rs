@@ -0,0 +1,97 @@
+use std::io::{self, Write};
+
+use crossterm::{execute, terminal::SetTitle};
+
+use super::{Hook, Hooks};
+use crate::render::updater::ComponentUpdater;
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::hooks::Hooks<'_, '_> {}
+}
+
+/// 状态文本的输出目标
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct StatusOptions {
+    /// 写入终端标题（OSC 0），在 tmux 中即为当前面板的标题
+    pub title: bool,
+    /// 写入 tmux 窗口名，默认在检测到 `TMUX` 环境变量时开启
+    pub tmux_window: bool,
+}
+
+impl Default for StatusOptions {
+    fn default() -> Self {
+        Self {
+            title: true,
+            tmux_window: std::env::var_os("TMUX").is_some(),
+        }
+    }
+}
+
+pub trait UseStatus: private::Sealed {
+    /// 把简短的状态文本同步到终端标题或 tmux 窗口名
+    ///
+    /// 即使面板处于后台，也能在标签栏或状态栏中看到进度，例如 `use_status("building… 42%")`。
+    /// 只在文本变化时输出转义序列，组件卸载时清空标题。
+    fn use_status(&mut self, status: impl Into<String>);
+
+    /// 与 [`UseStatus::use_status`] 相同，但可以指定输出目标
+    fn use_status_with(&mut self, status: impl Into<String>, options: StatusOptions);
+}
+
+struct UseStatusImpl {
+    status: String,
+    options: StatusOptions,
+    /// 上次输出的内容
+    emitted: Option<String>,
+}
+
+impl UseStatusImpl {
+    fn emit(options: StatusOptions, status: &str) -> io::Result<()> {
+        let mut stdout = io::stdout();
+        if options.title {
+            execute!(stdout, SetTitle(status))?;
+        }
+        if options.tmux_window {
+            write!(stdout, "\x1bk{status}\x1b\\")?;
+            stdout.flush()?;
+        }
+        Ok(())
+    }
+}
+
+impl Hook for UseStatusImpl {
+    fn post_component_update(&mut self, _updater: &mut ComponentUpdater) {
+        if self.emitted.as_deref() != Some(self.status.as_str()) {
+            // 输出失败时不影响渲染
+            let _ = Self::emit(self.options, &self.status);
+            self.emitted = Some(self.status.clone());
+        }
+    }
+}
+
+impl Drop for UseStatusImpl {
+    fn drop(&mut self) {
+        if self.emitted.is_some() {
+            let _ = Self::emit(self.options, "");
+        }
+    }
+}
+
+impl UseStatus for Hooks<'_, '_> {
+    fn use_status(&mut self, status: impl Into<String>) {
+        self.use_status_with(status, StatusOptions::default());
+    }
+
+    fn use_status_with(&mut self, status: impl Into<String>, options: StatusOptions) {
+        // 去掉控制字符，避免提前结束转义序列
+        let status: String = status.into().chars().filter(|c| !c.is_control()).collect();
+        let hook = self.use_hook(|| UseStatusImpl {
+            status: String::new(),
+            options,
+            emitted: None,
+        });
+        hook.status = status;
+        hook.options = options;
+    }
+}
@@ -0,0 +1,245 @@
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+    pin::Pin,
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicU64, Ordering},
+    },
+    task::{Context, Poll, Waker},
+};
+
+use super::{Hook, Hooks};
+use crate::context::{ContextStack, SystemContext};
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::hooks::Hooks<'_, '_> {}
+}
+
+/// Reducer：描述全局状态如何响应动作
+///
+/// ```ignore
+/// impl Reducer for AppState {
+///     type Action = Action;
+///     fn reduce(&mut self, action: Action) {
+///         match action {
+///             Action::Increment => self.count += 1,
+///             Action::Rename(name) => self.name = name,
+///         }
+///     }
+/// }
+/// ```
+pub trait Reducer {
+    type Action;
+
+    fn reduce(&mut self, action: Self::Action);
+}
+
+struct StoreInner<S> {
+    state: S,
+    /// 状态每次修改时递增
+    version: u64,
+    /// 订阅了该 store 的 use_selector，按 hook id 记录
+    subscribers: HashMap<u64, Waker>,
+}
+
+/// Store：应用级的共享状态
+///
+/// 通过 [`Stores::provide`] 安装在根部，或者以 `Context::owned(store)` 提供给某个子树；
+/// 组件用 `use_selector` 订阅其中的一部分，只有选中的部分变化时才会重新渲染。
+/// 可以克隆并移动到事件回调或后台任务中修改状态。
+pub struct Store<S> {
+    inner: Arc<Mutex<StoreInner<S>>>,
+}
+
+impl<S> Clone for Store<S> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<S> Store<S> {
+    pub fn new(state: S) -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(StoreInner {
+                state,
+                version: 0,
+                subscribers: HashMap::new(),
+            })),
+        }
+    }
+
+    /// 读取当前状态
+    pub fn read<T>(&self, f: impl FnOnce(&S) -> T) -> T {
+        f(&self.inner.lock().unwrap().state)
+    }
+
+    /// 直接修改状态，并通知所有订阅者检查各自选中的部分
+    pub fn update(&self, f: impl FnOnce(&mut S)) {
+        let mut inner = self.inner.lock().unwrap();
+        f(&mut inner.state);
+        inner.version += 1;
+        for (_, waker) in inner.subscribers.drain() {
+            waker.wake();
+        }
+    }
+
+    /// 派发一个动作，由 [`Reducer::reduce`] 更新状态
+    pub fn dispatch(&self, action: S::Action)
+    where
+        S: Reducer,
+    {
+        self.update(|state| state.reduce(action));
+    }
+}
+
+/// Stores：根部安装的 store，每种状态类型一个
+///
+/// 由 [`SystemContext::stores`] 提供，也可以在渲染前通过 `Tree::provide_store` 安装。
+#[derive(Clone, Default)]
+pub struct Stores {
+    inner: Arc<Mutex<HashMap<TypeId, Box<dyn Any + Send>>>>,
+}
+
+impl Stores {
+    /// 安装 store，同一状态类型重复安装时以后者为准
+    pub fn provide<S: Send + 'static>(&self, store: Store<S>) {
+        self.inner
+            .lock()
+            .unwrap()
+            .insert(TypeId::of::<S>(), Box::new(store));
+    }
+
+    pub fn get<S: Send + 'static>(&self) -> Option<Store<S>> {
+        self.inner
+            .lock()
+            .unwrap()
+            .get(&TypeId::of::<S>())
+            .and_then(|store| store.downcast_ref::<Store<S>>())
+            .cloned()
+    }
+}
+
+/// 先在上下文栈中查找子树提供的 store，找不到再使用根部安装的 store
+fn find_store<S: Send + 'static>(context: Option<&ContextStack>) -> Store<S> {
+    let context = context.expect("context not available");
+    if let Some(store) = context.get_context::<Store<S>>() {
+        return store.clone();
+    }
+    context
+        .get_context::<SystemContext>()
+        .and_then(|system| system.stores().get::<S>())
+        .expect("store not provided")
+}
+
+pub trait UseStore: private::Sealed {
+    /// 获取类型为 S 的 store，可以用来派发动作，但不会订阅变化
+    ///
+    /// 需要通过 `with_context_stack` 提供上下文，store 不存在时会 panic。
+    fn use_store<S: Send + 'static>(&mut self) -> Store<S>;
+
+    /// 从类型为 S 的 store 中选取一部分状态，只有选中的值变化时组件才会重新渲染
+    ///
+    /// - selector 每次渲染都会更新，可以捕获渲染时的变量
+    /// - 需要通过 `with_context_stack` 提供上下文，store 不存在时会 panic
+    ///
+    /// ```ignore
+    /// let mut hooks = hooks.with_context_stack(updater.component_context_stack());
+    /// let count = hooks.use_selector(|state: &AppState| state.count);
+    /// let store = hooks.use_store::<AppState>();
+    /// hooks.use_events(move |event| {
+    ///     if let Event::Key(key) = event && key.code == KeyCode::Char('+') {
+    ///         store.dispatch(Action::Increment);
+    ///     }
+    /// });
+    /// ```
+    fn use_selector<S, T, F>(&mut self, selector: F) -> T
+    where
+        S: Send + 'static,
+        T: Clone + PartialEq + Unpin + Send + 'static,
+        F: Fn(&S) -> T + Send + 'static;
+}
+
+type Selector<S, T> = Box<dyn Fn(&S) -> T + Send>;
+
+static NEXT_SUBSCRIBER_ID: AtomicU64 = AtomicU64::new(0);
+
+struct UseSelectorImpl<S, T> {
+    id: u64,
+    store: Store<S>,
+    selector: Option<Selector<S, T>>,
+    selected: T,
+    version: u64,
+}
+
+impl<S: Send + 'static, T: PartialEq + Unpin + Send> Hook for UseSelectorImpl<S, T> {
+    fn poll_change(self: Pin<&mut Self>, cx: &mut Context) -> Poll<()> {
+        let this = self.get_mut();
+        let Some(selector) = &this.selector else {
+            return Poll::Pending;
+        };
+        let mut inner = this.store.inner.lock().unwrap();
+        if inner.version != this.version {
+            this.version = inner.version;
+            let selected = selector(&inner.state);
+            if selected != this.selected {
+                this.selected = selected;
+                return Poll::Ready(());
+            }
+        }
+        inner.subscribers.insert(this.id, cx.waker().clone());
+        Poll::Pending
+    }
+}
+
+impl<S, T> Drop for UseSelectorImpl<S, T> {
+    fn drop(&mut self) {
+        if let Ok(mut inner) = self.store.inner.lock() {
+            inner.subscribers.remove(&self.id);
+        }
+    }
+}
+
+impl UseStore for Hooks<'_, '_> {
+    fn use_store<S: Send + 'static>(&mut self) -> Store<S> {
+        find_store(self.context)
+    }
+
+    fn use_selector<S, T, F>(&mut self, selector: F) -> T
+    where
+        S: Send + 'static,
+        T: Clone + PartialEq + Unpin + Send + 'static,
+        F: Fn(&S) -> T + Send + 'static,
+    {
+        let store = find_store::<S>(self.context);
+        // 每次渲染都以最新的 selector 重新选取
+        let (selected, version) = {
+            let inner = store.inner.lock().unwrap();
+            (selector(&inner.state), inner.version)
+        };
+        let hook = self.use_hook(|| UseSelectorImpl {
+            id: NEXT_SUBSCRIBER_ID.fetch_add(1, Ordering::Relaxed),
+            store: store.clone(),
+            selector: None,
+            selected: selected.clone(),
+            version,
+        });
+        // 提供 store 的位置可能发生变化
+        if !Arc::ptr_eq(&hook.store.inner, &store.inner) {
+            hook.store
+                .inner
+                .lock()
+                .unwrap()
+                .subscribers
+                .remove(&hook.id);
+            hook.store = store;
+        }
+        hook.selector = Some(Box::new(selector));
+        hook.selected = selected.clone();
+        hook.version = version;
+        selected
+    }
+}
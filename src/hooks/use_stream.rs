@@ -0,0 +1,76 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::{Stream, StreamExt, stream::BoxStream};
+
+use super::{Hook, Hooks};
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::hooks::Hooks<'_, '_> {}
+}
+
+pub trait UseStream: private::Sealed {
+    /// 订阅一个异步流，每收到一项就调用 f 并重新渲染组件
+    ///
+    /// - stream 只在首次渲染时注册，流结束后不再轮询
+    /// - f 每次 update 都会被替换，可以直接捕获本轮的状态
+    ///
+    /// ```ignore
+    /// let mut lines = hooks.use_state(Vec::new);
+    /// hooks.use_stream(LinesStream::new(reader.lines()), move |line| {
+    ///     if let Ok(line) = line {
+    ///         lines.write().push(line);
+    ///     }
+    /// });
+    /// ```
+    fn use_stream<S, F>(&mut self, stream: S, f: F)
+    where
+        S: Stream + Send + 'static,
+        F: FnMut(S::Item) + Send + 'static;
+}
+
+struct UseStreamImpl<T> {
+    stream: Option<BoxStream<'static, T>>,
+    f: Option<Box<dyn FnMut(T) + Send>>,
+}
+
+impl<T> Hook for UseStreamImpl<T> {
+    fn poll_change(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<()> {
+        let this = &mut *self;
+        let mut received = false;
+        while let Some(stream) = &mut this.stream {
+            match stream.poll_next_unpin(cx) {
+                Poll::Ready(Some(item)) => {
+                    received = true;
+                    if let Some(f) = &mut this.f {
+                        f(item);
+                    }
+                }
+                Poll::Ready(None) => this.stream = None,
+                Poll::Pending => break,
+            }
+        }
+        if received {
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+impl UseStream for Hooks<'_, '_> {
+    fn use_stream<S, F>(&mut self, stream: S, f: F)
+    where
+        S: Stream + Send + 'static,
+        F: FnMut(S::Item) + Send + 'static,
+    {
+        let hook = self.use_hook(move || UseStreamImpl {
+            stream: Some(stream.boxed()),
+            f: None,
+        });
+        hook.f = Some(Box::new(f));
+    }
+}
@@ -0,0 +1,75 @@
+use std::{
+    pin::{Pin, pin},
+    task::{Context, Poll},
+};
+
+use crossterm::event::Event;
+use futures::Stream;
+use ratatui::layout::Size;
+
+use super::{Hook, Hooks};
+use crate::{render::updater::ComponentUpdater, terminal::TerminalEvents};
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::hooks::Hooks<'_, '_> {}
+}
+
+pub trait UseTerminalSize: private::Sealed {
+    /// 当前终端的尺寸，终端大小变化时组件会重新渲染
+    ///
+    /// 用于在 update 阶段按尺寸调整布局，例如窄屏时隐藏侧边栏。
+    ///
+    /// ```ignore
+    /// let size = hooks.use_terminal_size();
+    /// let show_sidebar = size.width >= 100;
+    /// ```
+    fn use_terminal_size(&mut self) -> Size;
+}
+
+struct UseTerminalSizeImpl {
+    size: Size,
+    events: Option<TerminalEvents>,
+}
+
+impl Hook for UseTerminalSizeImpl {
+    fn poll_change(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<()> {
+        let mut resized = false;
+        while let Some(Poll::Ready(Some(event))) = self
+            .events
+            .as_mut()
+            .map(|events| pin!(events).poll_next(cx))
+        {
+            if let Event::Resize(width, height) = event {
+                let size = Size::new(width, height);
+                if size != self.size {
+                    self.size = size;
+                    resized = true;
+                }
+            }
+        }
+        if resized {
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    }
+
+    fn post_component_update(&mut self, updater: &mut ComponentUpdater) {
+        if self.events.is_none() {
+            self.events = Some(updater.terminal().events());
+        }
+    }
+}
+
+impl UseTerminalSize for Hooks<'_, '_> {
+    fn use_terminal_size(&mut self) -> Size {
+        let hook = self.use_hook(|| UseTerminalSizeImpl {
+            size: crossterm::terminal::size()
+                .map(|(width, height)| Size::new(width, height))
+                .unwrap_or_default(),
+            events: None,
+        });
+        hook.size
+    }
+}
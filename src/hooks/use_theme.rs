@@ -0,0 +1,36 @@
+use super::Hooks;
+use crate::{context::SystemContext, theme::Theme};
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::hooks::Hooks<'_, '_> {}
+}
+
+pub trait UseTheme: private::Sealed {
+    /// 读取最近的上层通过 `ContextProvider<Theme>` 提供的主题，没有时使用根部的主题
+    ///
+    /// 需要通过 `with_context_stack` 提供上下文，否则返回默认主题。
+    ///
+    /// ```ignore
+    /// let theme = hooks.with_context_stack(updater.component_context_stack()).use_theme();
+    /// self.style = theme.primary().patch(props.style);
+    /// ```
+    fn use_theme(&self) -> Theme;
+}
+
+impl UseTheme for Hooks<'_, '_> {
+    fn use_theme(&self) -> Theme {
+        let Some(context) = self.context else {
+            return Theme::default();
+        };
+        context
+            .get_context::<Theme>()
+            .map(|theme| *theme)
+            .or_else(|| {
+                context
+                    .get_context::<SystemContext>()
+                    .map(|system| system.theme())
+            })
+            .unwrap_or_default()
+    }
+}
@@ -0,0 +1,91 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+    time::{Duration, Instant},
+};
+
+use super::{Hook, Hooks};
+use crate::clock::{self, Sleep};
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::hooks::Hooks<'_, '_> {}
+}
+
+pub trait UseThrottle: private::Sealed {
+    /// 节流：返回的值每个 interval 内最多变化一次
+    ///
+    /// 距离上次变化已超过 interval 时立即返回新值，否则等到本周期结束再返回期间的最后一个值，
+    /// 保证最终值不会丢失。适合滚动位置、窗口尺寸这类高频变化但需要及时反馈的场景。
+    ///
+    /// ```ignore
+    /// let offset = hooks.use_throttle(*scroll.read(), Duration::from_millis(50));
+    /// ```
+    fn use_throttle<T>(&mut self, value: T, interval: Duration) -> T
+    where
+        T: Clone + PartialEq + Unpin + Send + 'static;
+}
+
+struct UseThrottleImpl<T> {
+    /// 最近一次传入的值
+    latest: T,
+    /// 对外返回的值
+    value: T,
+    /// 上次对外返回值变化的时间
+    last: Option<Instant>,
+    timer: Option<Pin<Box<Sleep>>>,
+}
+
+impl<T> Hook for UseThrottleImpl<T>
+where
+    T: Clone + PartialEq + Unpin + Send,
+{
+    fn poll_change(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<()> {
+        let Some(timer) = &mut self.timer else {
+            return Poll::Pending;
+        };
+        if timer.as_mut().poll(cx).is_pending() {
+            return Poll::Pending;
+        }
+        self.timer = None;
+        if self.value == self.latest {
+            return Poll::Pending;
+        }
+        self.value = self.latest.clone();
+        self.last = Some(clock::now());
+        Poll::Ready(())
+    }
+}
+
+impl UseThrottle for Hooks<'_, '_> {
+    fn use_throttle<T>(&mut self, value: T, interval: Duration) -> T
+    where
+        T: Clone + PartialEq + Unpin + Send + 'static,
+    {
+        let hook = self.use_hook(|| UseThrottleImpl {
+            latest: value.clone(),
+            value: value.clone(),
+            last: None,
+            timer: None,
+        });
+        if hook.latest != value {
+            hook.latest = value.clone();
+            let now = clock::now();
+            match hook.last {
+                // 仍在本周期内，推迟到周期结束
+                Some(last) if now < last + interval => {
+                    if hook.timer.is_none() {
+                        hook.timer = Some(Box::pin(clock::sleep_until(last + interval)));
+                    }
+                }
+                _ => {
+                    hook.value = value;
+                    hook.last = Some(now);
+                    hook.timer = None;
+                }
+            }
+        }
+        hook.value.clone()
+    }
+}
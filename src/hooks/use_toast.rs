@@ -0,0 +1,206 @@
+use std::{
+    sync::{Arc, Mutex},
+    task::{Context, Waker},
+    time::{Duration, Instant},
+};
+
+use super::Hooks;
+use crate::{clock, context::SystemContext};
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::hooks::Hooks<'_, '_> {}
+}
+
+/// 通知的级别，决定显示的颜色与默认的停留时间
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ToastLevel {
+    #[default]
+    Info,
+    Warn,
+    Error,
+}
+
+impl ToastLevel {
+    /// 默认的停留时间，错误停留得更久
+    pub fn default_timeout(self) -> Duration {
+        match self {
+            ToastLevel::Info | ToastLevel::Warn => Duration::from_secs(4),
+            ToastLevel::Error => Duration::from_secs(8),
+        }
+    }
+}
+
+/// Toast：一条短暂显示的通知
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Toast {
+    pub level: ToastLevel,
+    pub message: String,
+    /// 显示多久后自动消失，为 None 时一直显示到被关闭
+    pub timeout: Option<Duration>,
+}
+
+impl Toast {
+    pub fn new(level: ToastLevel, message: impl Into<String>) -> Self {
+        Self {
+            level,
+            message: message.into(),
+            timeout: Some(level.default_timeout()),
+        }
+    }
+
+    pub fn info(message: impl Into<String>) -> Self {
+        Self::new(ToastLevel::Info, message)
+    }
+
+    pub fn warn(message: impl Into<String>) -> Self {
+        Self::new(ToastLevel::Warn, message)
+    }
+
+    pub fn error(message: impl Into<String>) -> Self {
+        Self::new(ToastLevel::Error, message)
+    }
+
+    pub fn with_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.timeout = timeout;
+        self
+    }
+}
+
+/// 通知的标识，用于提前关闭
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ToastId(u64);
+
+struct ActiveToast {
+    id: ToastId,
+    toast: Toast,
+    expires_at: Option<Instant>,
+}
+
+#[derive(Default)]
+struct ToastsInner {
+    items: Vec<ActiveToast>,
+    next_id: u64,
+    /// 通知列表每次变化时递增
+    version: u64,
+    waker: Option<Waker>,
+}
+
+impl ToastsInner {
+    fn changed(&mut self) {
+        self.version += 1;
+        if let Some(waker) = self.waker.take() {
+            waker.wake();
+        }
+    }
+
+    /// 移除已经到期的通知
+    fn prune(&mut self, now: Instant) {
+        let len = self.items.len();
+        self.items
+            .retain(|item| item.expires_at.is_none_or(|expires_at| expires_at > now));
+        if self.items.len() != len {
+            self.changed();
+        }
+    }
+}
+
+/// Toasts：全局的通知队列，由 [`SystemContext::toasts`] 提供
+///
+/// 任何组件都可以通过 `use_toast` 发出通知，由组件树中的 [`ToastManager`](crate::components::ToastManager) 显示，
+/// 到期后自动移除。没有 ToastManager 时通知照常排队与到期，只是不会显示。
+#[derive(Clone, Default)]
+pub struct Toasts {
+    inner: Arc<Mutex<ToastsInner>>,
+}
+
+impl Toasts {
+    /// 发出一条通知，返回用于提前关闭的标识
+    pub fn show(&self, toast: Toast) -> ToastId {
+        let mut inner = self.inner.lock().unwrap();
+        let id = ToastId(inner.next_id);
+        inner.next_id += 1;
+        let expires_at = toast.timeout.map(|timeout| clock::now() + timeout);
+        inner.items.push(ActiveToast {
+            id,
+            toast,
+            expires_at,
+        });
+        inner.changed();
+        id
+    }
+
+    pub fn info(&self, message: impl Into<String>) -> ToastId {
+        self.show(Toast::info(message))
+    }
+
+    pub fn warn(&self, message: impl Into<String>) -> ToastId {
+        self.show(Toast::warn(message))
+    }
+
+    pub fn error(&self, message: impl Into<String>) -> ToastId {
+        self.show(Toast::error(message))
+    }
+
+    /// 关闭一条通知，通知已经消失时不做任何事
+    pub fn dismiss(&self, id: ToastId) {
+        let mut inner = self.inner.lock().unwrap();
+        let len = inner.items.len();
+        inner.items.retain(|item| item.id != id);
+        if inner.items.len() != len {
+            inner.changed();
+        }
+    }
+
+    /// 关闭所有通知
+    pub fn clear(&self) {
+        let mut inner = self.inner.lock().unwrap();
+        if !inner.items.is_empty() {
+            inner.items.clear();
+            inner.changed();
+        }
+    }
+
+    /// 正在显示的通知，按发出的先后排列
+    pub fn active(&self) -> Vec<(ToastId, Toast)> {
+        let mut inner = self.inner.lock().unwrap();
+        inner.prune(clock::now());
+        inner
+            .items
+            .iter()
+            .map(|item| (item.id, item.toast.clone()))
+            .collect()
+    }
+
+    /// 移除到期的通知并登记唤醒，返回当前的版本号与最近一条通知的到期时间
+    pub(crate) fn poll_state(&self, cx: &mut Context) -> (u64, Option<Instant>) {
+        let mut inner = self.inner.lock().unwrap();
+        inner.prune(clock::now());
+        inner.waker = Some(cx.waker().clone());
+        let next_expiry = inner.items.iter().filter_map(|item| item.expires_at).min();
+        (inner.version, next_expiry)
+    }
+}
+
+pub trait UseToast: private::Sealed {
+    /// 获取全局的通知队列，用于发出通知
+    ///
+    /// ```ignore
+    /// let toasts = hooks.use_toast();
+    /// hooks.use_events(move |event| {
+    ///     if is_save(&event) {
+    ///         toasts.info("Saved");
+    ///     }
+    /// });
+    /// ```
+    fn use_toast(&self) -> Toasts;
+}
+
+impl UseToast for Hooks<'_, '_> {
+    fn use_toast(&self) -> Toasts {
+        self.context
+            .and_then(|context| context.get_context::<SystemContext>())
+            .map(|system| system.toasts())
+            .unwrap_or_default()
+    }
+}
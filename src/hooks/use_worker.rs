@@ -0,0 +1,135 @@
+use std::{
+    panic::{self, AssertUnwindSafe},
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use tokio::sync::mpsc::{self, UnboundedReceiver, UnboundedSender};
+
+use super::{
+    Hook, Hooks,
+    use_state::{State, StateRef, UseState},
+};
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::hooks::Hooks<'_, '_> {}
+}
+
+/// Worker：在 tokio 的阻塞线程池中执行任务的句柄，可以克隆到事件回调中
+pub struct Worker<T: Send + Sync + 'static> {
+    sender: UnboundedSender<Option<T>>,
+    result: State<Option<T>>,
+    running: State<usize>,
+}
+
+impl<T: Send + Sync + 'static> Clone for Worker<T> {
+    fn clone(&self) -> Self {
+        Self {
+            sender: self.sender.clone(),
+            result: self.result,
+            running: self.running,
+        }
+    }
+}
+
+impl<T: Send + Sync + 'static> Worker<T> {
+    /// 在阻塞线程池中执行 f，完成后结果写入状态并重新渲染组件
+    ///
+    /// 多个任务同时执行时按完成顺序写入，最后完成的结果保留下来。
+    /// 组件卸载后完成的任务结果会被丢弃，但无法中断已经开始执行的 f。
+    pub fn run<F>(&self, f: F)
+    where
+        F: FnOnce() -> T + Send + 'static,
+    {
+        *self.running.write() += 1;
+        let sender = self.sender.clone();
+        tokio::task::spawn_blocking(move || {
+            // 任务 panic 时也要通知 Hook，保证运行计数能够归零
+            let value = panic::catch_unwind(AssertUnwindSafe(f)).ok();
+            let _ = sender.send(value);
+        });
+    }
+
+    /// 是否有任务正在执行
+    pub fn is_running(&self) -> bool {
+        self.running.get() > 0
+    }
+
+    /// 读取最近一次完成的结果，尚未完成过任何任务时为 None
+    pub fn read(&self) -> StateRef<'_, Option<T>> {
+        self.result.read()
+    }
+}
+
+impl<T: Clone + Send + Sync + 'static> Worker<T> {
+    /// 获取最近一次完成的结果的副本
+    pub fn value(&self) -> Option<T> {
+        self.result.read().clone()
+    }
+}
+
+pub trait UseWorker: private::Sealed {
+    /// 创建在阻塞线程池中执行耗时任务的 Worker
+    ///
+    /// 用于 CPU 密集或调用阻塞接口（`std::fs`、同步网络请求等）的任务，避免卡住渲染循环。
+    ///
+    /// ```ignore
+    /// let worker = hooks.use_worker::<Vec<String>>();
+    /// let runner = worker.clone();
+    /// hooks.use_events(move |event| {
+    ///     if is_refresh(&event) {
+    ///         runner.run(|| read_lines("big.log"));
+    ///     }
+    /// });
+    /// let lines = worker.value().unwrap_or_default();
+    /// ```
+    fn use_worker<T>(&mut self) -> Worker<T>
+    where
+        T: Unpin + Send + Sync + 'static;
+}
+
+struct UseWorkerImpl<T: Send + Sync + 'static> {
+    sender: UnboundedSender<Option<T>>,
+    receiver: UnboundedReceiver<Option<T>>,
+    result: State<Option<T>>,
+    running: State<usize>,
+}
+
+impl<T: Unpin + Send + Sync + 'static> Hook for UseWorkerImpl<T> {
+    fn poll_change(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<()> {
+        while let Poll::Ready(Some(value)) = self.receiver.poll_recv(cx) {
+            if let Some(value) = value {
+                self.result.set(Some(value));
+            }
+            let running = self.running.get();
+            self.running.set(running.saturating_sub(1));
+        }
+        // 状态的变化由 State 自身负责唤醒
+        Poll::Pending
+    }
+}
+
+impl UseWorker for Hooks<'_, '_> {
+    fn use_worker<T>(&mut self) -> Worker<T>
+    where
+        T: Unpin + Send + Sync + 'static,
+    {
+        let result = self.use_state(|| None);
+        let running = self.use_state(|| 0);
+        let hook = self.use_hook(|| {
+            let (sender, receiver) = mpsc::unbounded_channel();
+            UseWorkerImpl {
+                sender,
+                receiver,
+                result,
+                running,
+            }
+        });
+        Worker {
+            sender: hook.sender.clone(),
+            result,
+            running,
+        }
+    }
+}
@@ -1,4 +1,10 @@
+pub mod animation;
+pub mod assets;
+pub mod bidi;
+pub mod capabilities;
+pub mod clock;
 pub mod component;
+pub mod components;
 pub mod context;
 pub mod element;
 pub mod hooks;
@@ -6,6 +12,10 @@ mod multimap;
 pub mod props;
 pub mod render;
 pub mod terminal;
+pub mod testing;
+pub mod theme;
+pub mod width;
+pub mod wrap;
 pub use ratatui_kit_macros;
 
 extern crate self as ratatui_kit_principle;
@@ -1,82 +1,50 @@
-use crossterm::event::{Event, KeyCode, KeyEvent, KeyEventKind};
+use crossterm::event::KeyCode;
 // 引入 ratatui 相关模块
 use ratatui::{
     layout::{Constraint, Direction, Flex, Margin, Offset},
     style::{Style, Stylize},
-    widgets::{Block, Paragraph},
+    widgets::Block,
 };
 use ratatui_kit_macros::element;
 // 引入 ratatui-kit-principle 组件系统相关模块
 use ratatui_kit_principle::{
     component::Component,
+    components::Text,
     element::{AnyElement, Element, ElementExt, key::ElementKey},
-    hooks::{self, use_events::UseEvents, use_state::UseState},
-    render::{drawer::ComponentDrawer, layout_style::LayoutStyle},
+    hooks::{
+        self,
+        use_direction::UseDirection,
+        use_exit::UseExit,
+        use_keymap::{KeyCombo, UseKeymap},
+        use_state::UseState,
+    },
+    props::Props,
+    render::{
+        drawer::ComponentDrawer,
+        layout_style::{AlignItems, LayoutStyle},
+    },
 };
 
 use std::io;
 
-// 文本组件，负责渲染一段文本
-pub struct Text {
-    pub text: String,
-    pub style: Style,
-    pub alignment: ratatui::layout::Alignment,
-}
-
-// 文本组件的 Props
-#[derive(Default)]
-pub struct TextProps<'a> {
-    pub text: &'a str,
-    pub style: Style,
-    pub alignment: ratatui::layout::Alignment,
-}
-
-// Text 组件实现 Component 协议
-impl Component for Text {
-    type Props<'a> = TextProps<'a>;
-
-    fn new(props: &Self::Props<'_>) -> Self {
-        Self {
-            text: props.text.to_string(),
-            style: props.style,
-            alignment: props.alignment,
-        }
-    }
-
-    fn update(
-        &mut self,
-        props: &mut Self::Props<'_>,
-        _hooks: hooks::Hooks,
-        _updater: &mut ratatui_kit_principle::render::updater::ComponentUpdater<'_, '_>,
-    ) {
-        *self = Self {
-            text: props.text.to_string(),
-            style: props.style,
-            alignment: props.alignment,
-        };
-    }
-
-    fn draw(&self, drawer: &mut ComponentDrawer<'_, '_>) {
-        // 渲染段落文本
-        let paragraph = Paragraph::new(self.text.clone())
-            .style(self.style)
-            .alignment(self.alignment);
-        drawer.render_widget(paragraph, drawer.area);
-    }
-}
-
 // 边框组件，负责为内容添加边框
 pub struct Border {
     pub border_style: Style,
 }
 
+// 边框组件的 Props
+#[derive(Default, Props)]
+pub struct BorderProps {
+    pub border_style: Style,
+}
+
 // Border 组件实现 Component 协议
 impl Component for Border {
-    type Props<'a> = Style;
+    type Props<'a> = BorderProps;
 
     fn new(props: &Self::Props<'_>) -> Self {
         Self {
-            border_style: props.clone(),
+            border_style: props.border_style,
         }
     }
 
@@ -86,7 +54,7 @@ impl Component for Border {
         _hooks: hooks::Hooks,
         _updater: &mut ratatui_kit_principle::render::updater::ComponentUpdater<'_, '_>,
     ) {
-        self.border_style = props.clone();
+        self.border_style = props.border_style;
     }
 
     fn draw(&self, drawer: &mut ComponentDrawer<'_, '_>) {
@@ -101,12 +69,14 @@ impl Component for Border {
     }
 }
 
-#[derive(Default)]
+#[derive(Default, Props)]
 pub struct ViewProps<'a> {
     /// 主轴方向（横向/纵向）
     pub flex_direction: Direction,
     /// 主轴对齐方式（如 Start, End, Center, SpaceBetween 等）
     pub justify_content: Flex,
+    /// 交叉轴对齐方式（如 Start, Center, End, Baseline）
+    pub align_items: AlignItems,
     /// 子项间距
     pub gap: i32,
     /// 外边距
@@ -133,17 +103,23 @@ impl Component for View {
     fn update(
         &mut self,
         props: &mut Self::Props<'_>,
-        _hooks: hooks::Hooks,
+        mut hooks: hooks::Hooks,
         updater: &mut ratatui_kit_principle::render::updater::ComponentUpdater<'_, '_>,
     ) {
+        // 从右向左时镜像子组件的布局
+        let direction = hooks
+            .with_context_stack(updater.component_context_stack())
+            .use_direction();
         updater.set_layout_style(LayoutStyle {
             flex_direction: props.flex_direction,
             justify_content: props.justify_content,
+            align_items: props.align_items,
             gap: props.gap,
             margin: props.margin,
             offset: props.offset,
             width: props.width,
             height: props.height,
+            mirrored: direction.is_rtl(),
         });
 
         updater.update_children(props.children.iter_mut(), None);
@@ -158,10 +134,21 @@ async fn main() -> io::Result<()> {
         props: (),
     };
 
-    element.render_loop().await?;
+    // 组件或信号指定了退出码时，在终端恢复之后再结束进程
+    if let Some(code) = element.render_loop_with_exit::<()>().await?.code {
+        std::process::exit(code);
+    }
     Ok(())
 }
 
+#[derive(Clone, Copy)]
+enum CounterAction {
+    Increase,
+    Decrease,
+    Reset,
+    Quit,
+}
+
 pub struct Counter;
 
 impl Component for Counter {
@@ -177,23 +164,22 @@ impl Component for Counter {
         updater: &mut ratatui_kit_principle::render::updater::ComponentUpdater<'_, '_>,
     ) {
         let mut state = hooks.use_state(|| 0);
+        let exit = hooks.use_exit();
 
-        hooks.use_events(move |event| match event {
-            Event::Key(KeyEvent {
-                kind: KeyEventKind::Press,
-                code,
-                ..
-            }) => match code {
-                KeyCode::Up => {
-                    state.set(state.get() + 1);
-                }
-                KeyCode::Down => {
-                    state.set(state.get() - 1);
-                }
-                _ => {}
+        hooks.use_keymap(
+            [
+                (KeyCombo::from(KeyCode::Up), CounterAction::Increase),
+                (KeyCombo::from(KeyCode::Down), CounterAction::Decrease),
+                (KeyCombo::from(['g', 'g']), CounterAction::Reset),
+                (KeyCombo::from('q'), CounterAction::Quit),
+            ],
+            move |action| match action {
+                CounterAction::Increase => state.set(state.get() + 1),
+                CounterAction::Decrease => state.set(state.get() - 1),
+                CounterAction::Reset => state.set(0),
+                CounterAction::Quit => exit.exit(),
             },
-            _ => {}
-        });
+        );
 
         let counter_text = format!("Count: {}", state.get());
 
@@ -207,7 +193,7 @@ impl Component for Counter {
                     Text(text: counter_text.as_str(), style: Style::default().light_green(), alignment: ratatui::layout::Alignment::Center)
                 }
                 View(height: Constraint::Length(1),){
-                    Text(text: "Press q or Ctrl+C to quit, + to increase, - to decrease", style: Style::default().yellow(), alignment: ratatui::layout::Alignment::Center)
+                    Text(text: "Press q or Ctrl+C to quit, Up/Down to change, g g to reset", style: Style::default().yellow(), alignment: ratatui::layout::Alignment::Center)
                 }
             }
         };
@@ -74,6 +74,14 @@ where
         self.items.iter().filter_map(|item| item.as_ref())
     }
 
+    /// 取出所有尚未被移除的值及其原来的位置。
+    pub fn into_remaining(self) -> impl Iterator<Item = (usize, V)> {
+        self.items
+            .into_iter()
+            .enumerate()
+            .filter_map(|(index, item)| item.map(|item| (index, item)))
+    }
+
     /// 遍历 multimap 中的所有值（可变引用）。
     pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut V> {
         self.items.iter_mut().filter_map(|item| item.as_mut())
@@ -1,3 +1,18 @@
+pub use ratatui_kit_macros::Props;
+
+/// Props：可以作为组件属性的类型
+///
+/// 属性经过 [`AnyProps`] 类型擦除后，生命周期信息只由 `AnyProps<'a>` 记录，
+/// 因此属性类型必须对其生命周期参数协变，否则可能借出比实际更长的引用。
+/// 请使用 `#[derive(Props)]` 实现，派生宏会在编译期检查协变性。
+///
+/// # Safety
+///
+/// 手动实现时必须保证类型对其所有生命周期参数协变。
+pub unsafe trait Props {}
+
+unsafe impl Props for () {}
+
 // 定义一个用于释放原始指针内存的 trait
 trait DropRaw {
     fn drop_raw(&self, raw: *mut ());
@@ -26,7 +41,7 @@ pub struct AnyProps<'a> {
 }
 
 impl<'a> AnyProps<'a> {
-    pub fn owned<T: 'a>(props: T) -> Self {
+    pub fn owned<T: Props + 'a>(props: T) -> Self {
         // 将堆分配的值转换为原始指针，用于手动内存管理
         let raw = Box::into_raw(Box::new(props));
 
@@ -41,7 +56,7 @@ impl<'a> AnyProps<'a> {
         }
     }
 
-    pub fn borrowed<T>(props: &'a mut T) -> Self {
+    pub fn borrowed<T: Props>(props: &'a mut T) -> Self {
         // 创建一个不负责内存释放的 AnyProps 实例
         // 用于持有对 T 类型数据的引用
         Self {
@@ -87,3 +102,42 @@ impl Drop for AnyProps<'_> {
         }
     }
 }
+
+/// Handler：组件的事件回调属性
+///
+/// 默认不做任何事，可以直接由闭包转换而来，因此在 `element!` 中可以省略，也可以写成
+/// `on_submit: move |text: String| { ... }`。多个参数以元组传入。
+pub struct Handler<'a, Args> {
+    f: Option<Box<dyn FnMut(Args) + Send + 'a>>,
+}
+
+impl<Args> Default for Handler<'_, Args> {
+    fn default() -> Self {
+        Self { f: None }
+    }
+}
+
+impl<'a, Args, F> From<F> for Handler<'a, Args>
+where
+    F: FnMut(Args) + Send + 'a,
+{
+    fn from(f: F) -> Self {
+        Self {
+            f: Some(Box::new(f)),
+        }
+    }
+}
+
+impl<Args> Handler<'_, Args> {
+    /// 是否设置了回调
+    pub fn is_set(&self) -> bool {
+        self.f.is_some()
+    }
+
+    /// 调用回调，未设置时不做任何事
+    pub fn invoke(&mut self, args: Args) {
+        if let Some(f) = &mut self.f {
+            f(args);
+        }
+    }
+}
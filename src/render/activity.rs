@@ -0,0 +1,155 @@
+use std::{
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use crossterm::event::Event;
+use futures::StreamExt;
+use tokio::sync::watch;
+
+use crate::{clock, terminal::TerminalEvents};
+
+/// 应用的活跃状态
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Activity {
+    /// 终端有焦点，且最近有输入
+    #[default]
+    Active,
+    /// 终端有焦点，但一段时间内没有任何输入
+    Idle,
+    /// 终端失去焦点
+    Unfocused,
+}
+
+struct ActivityConfig {
+    last_input: Instant,
+    idle_after: Duration,
+    idle_factor: u32,
+    unfocused_factor: u32,
+}
+
+/// ActivityTracker：跟踪应用的活跃状态
+///
+/// 由渲染循环根据终端事件更新，状态变化时整棵组件树会重新渲染。
+/// 定时刷新的 Hook 和动画组件通过 [`ActivityTracker::scale`] 在非活跃时降低频率，
+/// 后台任务可以在刷新前等待 [`ActivityTracker::until_active`]。
+#[derive(Clone)]
+pub struct ActivityTracker {
+    state: Arc<watch::Sender<Activity>>,
+    config: Arc<Mutex<ActivityConfig>>,
+}
+
+impl Default for ActivityTracker {
+    fn default() -> Self {
+        Self {
+            state: Arc::new(watch::Sender::new(Activity::Active)),
+            config: Arc::new(Mutex::new(ActivityConfig {
+                last_input: clock::now(),
+                idle_after: Duration::from_secs(30),
+                idle_factor: 4,
+                unfocused_factor: 8,
+            })),
+        }
+    }
+}
+
+impl ActivityTracker {
+    /// 当前的活跃状态
+    pub fn activity(&self) -> Activity {
+        *self.state.borrow()
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.activity() == Activity::Active
+    }
+
+    /// 多久没有输入后进入 Idle，默认 30 秒
+    pub fn set_idle_after(&self, idle_after: Duration) {
+        self.config.lock().unwrap().idle_after = idle_after;
+    }
+
+    /// 设置非活跃状态下定时任务的减速倍数，默认 Idle 为 4 倍、Unfocused 为 8 倍
+    ///
+    /// Active 状态始终不减速，传入 Active 会被忽略。
+    pub fn set_slowdown(&self, activity: Activity, factor: u32) {
+        let mut config = self.config.lock().unwrap();
+        match activity {
+            Activity::Active => {}
+            Activity::Idle => config.idle_factor = factor.max(1),
+            Activity::Unfocused => config.unfocused_factor = factor.max(1),
+        }
+    }
+
+    /// 按当前状态放大定时任务的周期
+    pub fn scale(&self, period: Duration) -> Duration {
+        let config = self.config.lock().unwrap();
+        match self.activity() {
+            Activity::Active => period,
+            Activity::Idle => period * config.idle_factor,
+            Activity::Unfocused => period * config.unfocused_factor,
+        }
+    }
+
+    /// 等待应用回到 Active 状态，已经是 Active 时立即返回
+    pub async fn until_active(&self) {
+        let mut receiver = self.state.subscribe();
+        let _ = receiver
+            .wait_for(|activity| *activity == Activity::Active)
+            .await;
+    }
+
+    fn set(&self, activity: Activity) -> bool {
+        self.state.send_if_modified(|current| {
+            let changed = *current != activity;
+            *current = activity;
+            changed
+        })
+    }
+
+    /// 根据终端事件更新状态，返回状态是否变化
+    fn record(&self, event: &Event) -> bool {
+        match event {
+            Event::FocusLost => self.set(Activity::Unfocused),
+            _ => {
+                self.config.lock().unwrap().last_input = clock::now();
+                self.set(Activity::Active)
+            }
+        }
+    }
+
+    /// 消费终端事件并计时，直到活跃状态发生变化
+    pub(crate) async fn wait(&self, events: &mut TerminalEvents) {
+        loop {
+            let deadline = match self.activity() {
+                Activity::Active => {
+                    let config = self.config.lock().unwrap();
+                    Some(config.last_input + config.idle_after)
+                }
+                // 失去焦点时不再计时，Idle 时已经无需计时
+                Activity::Idle | Activity::Unfocused => None,
+            };
+            let timeout = async {
+                match deadline {
+                    Some(deadline) => clock::sleep_until(deadline).await,
+                    None => futures::future::pending().await,
+                }
+            };
+
+            tokio::select! {
+                event = events.next() => match event {
+                    Some(event) => {
+                        if self.record(&event) {
+                            return;
+                        }
+                    }
+                    None => futures::future::pending::<()>().await,
+                },
+                _ = timeout => {
+                    if self.set(Activity::Idle) {
+                        return;
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,153 @@
+//! 批量更新：把多次状态修改合并为一次渲染
+//!
+//! 渲染循环被唤醒后，会先等待进行中的 [`batch`] 结束，并让出一次执行权让其他已就绪的任务完成修改，
+//! 再统一 update 与绘制。同一个事件回调中的多次修改本来就只触发一次渲染；
+//! 在异步任务中分几步修改多个状态时，用 `batch` 包裹可以避免界面显示中间状态。
+
+use std::{
+    pin::pin,
+    sync::{
+        LazyLock,
+        atomic::{AtomicUsize, Ordering},
+    },
+};
+
+use tokio::sync::Notify;
+
+/// 进行中的 batch 数量，嵌套与多个线程同时进行时累加
+static DEPTH: AtomicUsize = AtomicUsize::new(0);
+static SETTLED: LazyLock<Notify> = LazyLock::new(Notify::new);
+
+struct BatchGuard;
+
+impl BatchGuard {
+    fn enter() -> Self {
+        DEPTH.fetch_add(1, Ordering::SeqCst);
+        BatchGuard
+    }
+}
+
+impl Drop for BatchGuard {
+    fn drop(&mut self) {
+        if DEPTH.fetch_sub(1, Ordering::SeqCst) == 1 {
+            SETTLED.notify_waiters();
+        }
+    }
+}
+
+/// 执行 f，其中的所有状态修改在 f 返回之后才会被渲染
+///
+/// 可以嵌套；f 发生 panic 时同样会结束批量。
+///
+/// ```ignore
+/// tokio::spawn(async move {
+///     let user = fetch_user().await;
+///     batch(|| {
+///         name.set(user.name);
+///         email.set(user.email);
+///         loading.set(false);
+///     });
+/// });
+/// ```
+pub fn batch<R>(f: impl FnOnce() -> R) -> R {
+    let _guard = BatchGuard::enter();
+    f()
+}
+
+/// 当前是否有进行中的 batch
+pub fn is_batching() -> bool {
+    DEPTH.load(Ordering::SeqCst) > 0
+}
+
+/// 等待所有进行中的 batch 结束
+pub(crate) async fn settled() {
+    loop {
+        let mut notified = pin!(SETTLED.notified());
+        // 先登记等待再检查，避免错过检查之后、等待之前结束的 batch
+        notified.as_mut().enable();
+        if !is_batching() {
+            return;
+        }
+        notified.await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::{Arc, atomic::AtomicBool},
+        thread,
+        time::Duration,
+    };
+
+    use tokio::sync::Mutex;
+
+    use super::*;
+
+    /// DEPTH 为全局计数，测试之间需要串行执行
+    static SERIAL: Mutex<()> = Mutex::const_new(());
+
+    #[test]
+    fn nested_batches_end_with_the_outermost() {
+        let _lock = SERIAL.blocking_lock();
+        assert!(!is_batching());
+        batch(|| {
+            batch(|| assert!(is_batching()));
+            assert!(is_batching());
+        });
+        assert!(!is_batching());
+
+        let result = std::panic::catch_unwind(|| batch(|| batch(|| panic!("boom"))));
+        assert!(result.is_err());
+        assert!(!is_batching());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn nested_batches_render_once() {
+        let _lock = SERIAL.lock().await;
+        let changed = Arc::new(Notify::new());
+        let dirty = Arc::new(AtomicBool::new(false));
+        let value = Arc::new(AtomicUsize::new(0));
+
+        // 模拟状态修改：写入新值、标记需要渲染并唤醒渲染循环
+        let set = {
+            let (changed, dirty, value) = (changed.clone(), dirty.clone(), value.clone());
+            move |v| {
+                value.store(v, Ordering::SeqCst);
+                dirty.store(true, Ordering::SeqCst);
+                changed.notify_one();
+            }
+        };
+        let writer = thread::spawn(move || {
+            batch(|| {
+                set(1);
+                batch(|| {
+                    set(2);
+                    thread::sleep(Duration::from_millis(20));
+                });
+                thread::sleep(Duration::from_millis(20));
+                set(3);
+            })
+        });
+
+        // 模拟渲染循环：被唤醒后等待 batch 结束，有修改时渲染一次
+        let mut renders = Vec::new();
+        let mut render = async || {
+            settled().await;
+            if dirty.swap(false, Ordering::SeqCst) {
+                renders.push(value.load(Ordering::SeqCst));
+            }
+        };
+        changed.notified().await;
+        render().await;
+        writer.join().unwrap();
+        while tokio::time::timeout(Duration::from_millis(20), changed.notified())
+            .await
+            .is_ok()
+        {
+            render().await;
+        }
+
+        assert_eq!(renders, [3]);
+    }
+}
@@ -0,0 +1,126 @@
+//! 阻塞检测：在后台线程中监视组件的 update、绘制以及 Hook 轮询，
+//! 发现长时间没有让出执行权的组件时记录它在组件树中的路径。
+//!
+//! 常见原因是在 update 或 `use_future` 的异步块中调用了 `std::fs`、`reqwest::blocking`
+//! 等阻塞接口，这会卡住整个渲染循环。检测默认关闭，通过 `Tree::detect_blocking` 开启。
+
+use std::{
+    sync::{
+        Mutex,
+        atomic::{AtomicBool, Ordering},
+    },
+    thread::{self, JoinHandle},
+    time::{Duration, Instant},
+};
+
+use crate::element::key::ElementKey;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static STATE: Mutex<MonitorState> = Mutex::new(MonitorState {
+    stack: Vec::new(),
+    reports: Vec::new(),
+});
+
+struct MonitorState {
+    /// 正在执行的组件，栈顶为最内层
+    stack: Vec<Section>,
+    reports: Vec<String>,
+}
+
+struct Section {
+    label: String,
+    phase: &'static str,
+    /// 上一次取得进展的时间，子组件返回时刷新，避免把子组件的耗时算在父组件头上
+    resumed_at: Instant,
+    reported: bool,
+}
+
+/// 组件执行区段的守卫，离开作用域时出栈
+pub(crate) struct SectionGuard {
+    active: bool,
+}
+
+/// 进入一个组件的执行区段，未开启检测时不做任何事
+pub(crate) fn enter(phase: &'static str, name: &'static str, key: &ElementKey) -> SectionGuard {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return SectionGuard { active: false };
+    }
+    STATE.lock().unwrap().stack.push(Section {
+        label: format!("{name}({key:?})"),
+        phase,
+        resumed_at: Instant::now(),
+        reported: false,
+    });
+    SectionGuard { active: true }
+}
+
+impl Drop for SectionGuard {
+    fn drop(&mut self) {
+        if !self.active {
+            return;
+        }
+        let mut state = STATE.lock().unwrap();
+        state.stack.pop();
+        if let Some(parent) = state.stack.last_mut() {
+            parent.resumed_at = Instant::now();
+        }
+    }
+}
+
+/// 后台监视线程
+pub(crate) struct BlockingMonitor {
+    handle: Option<JoinHandle<()>>,
+}
+
+impl BlockingMonitor {
+    /// 开启检测，最内层组件超过 threshold 没有进展时记录一条报告
+    pub(crate) fn start(threshold: Duration) -> Self {
+        ENABLED.store(true, Ordering::Relaxed);
+        let handle = thread::spawn(move || {
+            let interval = (threshold / 4).max(Duration::from_millis(1));
+            while ENABLED.load(Ordering::Relaxed) {
+                thread::sleep(interval);
+                let mut state = STATE.lock().unwrap();
+                let path = state
+                    .stack
+                    .iter()
+                    .map(|section| section.label.as_str())
+                    .collect::<Vec<_>>()
+                    .join(" > ");
+                let Some(section) = state.stack.last_mut() else {
+                    continue;
+                };
+                if section.reported || section.resumed_at.elapsed() < threshold {
+                    continue;
+                }
+                section.reported = true;
+                let report = format!(
+                    "{} blocked the executor for more than {threshold:?} during {}",
+                    path, section.phase
+                );
+                state.reports.push(report);
+            }
+        });
+        Self {
+            handle: Some(handle),
+        }
+    }
+
+    /// 停止检测并返回所有报告
+    pub(crate) fn stop(mut self) -> Vec<String> {
+        ENABLED.store(false, Ordering::Relaxed);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+        let mut state = STATE.lock().unwrap();
+        state.stack.clear();
+        std::mem::take(&mut state.reports)
+    }
+}
+
+// 渲染循环因错误提前返回时也要关闭检测，监视线程会在下一次检查时退出
+impl Drop for BlockingMonitor {
+    fn drop(&mut self) {
+        ENABLED.store(false, Ordering::Relaxed);
+    }
+}
@@ -0,0 +1,328 @@
+//! 控制服务：通过 TCP 或 Unix socket 从外部驱动正在运行的界面，供脚本和集成测试使用
+//!
+//! 需要开启 `control` feature。协议按行传输文本命令，每条命令的响应以 `ok` 或
+//! `error: <原因>` 结尾的一行结束：
+//!
+//! - `key <按键>`：注入按键事件，例如 `key q`、`key ctrl+s`、`key enter`
+//! - `text <文本>`：依次注入文本中每个字符的按键事件
+//! - `resize <宽> <高>`：注入终端尺寸变化事件
+//! - `command <内容>`：交给 `Tree::on_control_command` 注册的处理函数，响应其返回值
+//! - `screenshot`：按行输出最近一帧的画面
+//! - `quit`：结束渲染循环
+//!
+//! 注入的事件在下一轮渲染中处理，截图反映的是处理前的画面，必要时需要稍等再截图。
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+#[cfg(feature = "control")]
+pub(crate) use imp::Control;
+#[cfg(feature = "control")]
+pub use imp::ControlAddr;
+#[cfg(not(feature = "control"))]
+pub(crate) use stub::Control;
+
+/// 解析控制命令与 [`TestRunner::press`](crate::testing::TestRunner::press) 中 `ctrl+s`、`enter`、`q` 这样的按键描述
+pub(crate) fn parse_key(spec: &str) -> Result<KeyEvent, String> {
+    let spec = spec.trim();
+    let mut modifiers = KeyModifiers::NONE;
+    let mut parts: Vec<&str> = spec.split('+').collect();
+    // `ctrl++` 这类按键本身就是加号的情况
+    let key = match parts.pop() {
+        Some("") if spec.ends_with('+') => {
+            parts.pop();
+            "+"
+        }
+        Some(key) => key,
+        None => return Err("missing key".to_string()),
+    };
+    for modifier in parts {
+        modifiers |= match modifier.to_ascii_lowercase().as_str() {
+            "ctrl" | "control" => KeyModifiers::CONTROL,
+            "alt" => KeyModifiers::ALT,
+            "shift" => KeyModifiers::SHIFT,
+            _ => return Err(format!("unknown modifier `{modifier}`")),
+        };
+    }
+    let mut chars = key.chars();
+    let code = match (chars.next(), chars.next()) {
+        (Some(c), None) => KeyCode::Char(c),
+        _ => match key.to_ascii_lowercase().as_str() {
+            "enter" => KeyCode::Enter,
+            "esc" | "escape" => KeyCode::Esc,
+            "tab" => KeyCode::Tab,
+            "backtab" => KeyCode::BackTab,
+            "backspace" => KeyCode::Backspace,
+            "delete" | "del" => KeyCode::Delete,
+            "space" => KeyCode::Char(' '),
+            "up" => KeyCode::Up,
+            "down" => KeyCode::Down,
+            "left" => KeyCode::Left,
+            "right" => KeyCode::Right,
+            "home" => KeyCode::Home,
+            "end" => KeyCode::End,
+            "pageup" => KeyCode::PageUp,
+            "pagedown" => KeyCode::PageDown,
+            name => match name.strip_prefix('f').and_then(|n| n.parse().ok()) {
+                Some(n) => KeyCode::F(n),
+                None => return Err(format!("unknown key `{key}`")),
+            },
+        },
+    };
+    Ok(KeyEvent::new(code, modifiers))
+}
+
+#[cfg(feature = "control")]
+mod imp {
+    use std::{io, net::SocketAddr};
+
+    use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+
+    use super::parse_key;
+    use ratatui::buffer::Buffer;
+    use tokio::{
+        io::{AsyncBufReadExt, AsyncRead, AsyncWrite, AsyncWriteExt, BufReader},
+        sync::{
+            mpsc::{self, UnboundedReceiver, UnboundedSender},
+            oneshot,
+        },
+    };
+
+    use crate::{context::SystemContext, terminal::Terminal};
+
+    /// 控制服务的监听地址
+    #[derive(Clone, Debug)]
+    pub enum ControlAddr {
+        Tcp(SocketAddr),
+        #[cfg(unix)]
+        Unix(std::path::PathBuf),
+    }
+
+    impl From<SocketAddr> for ControlAddr {
+        fn from(addr: SocketAddr) -> Self {
+            ControlAddr::Tcp(addr)
+        }
+    }
+
+    enum ControlCommand {
+        Event(Vec<Event>),
+        Command(String),
+        Screenshot,
+        Quit,
+    }
+
+    pub(crate) struct ControlRequest {
+        command: ControlCommand,
+        reply: oneshot::Sender<String>,
+    }
+
+    type CommandHandler<'a> = Box<dyn FnMut(&str, &mut SystemContext) -> String + 'a>;
+
+    #[derive(Default)]
+    pub(crate) struct Control<'a> {
+        addr: Option<ControlAddr>,
+        receiver: Option<UnboundedReceiver<ControlRequest>>,
+        handler: Option<CommandHandler<'a>>,
+        last_frame: Option<Buffer>,
+    }
+
+    impl<'a> Control<'a> {
+        pub(crate) fn set_addr(&mut self, addr: ControlAddr) {
+            self.addr = Some(addr);
+        }
+
+        pub(crate) fn set_handler(
+            &mut self,
+            handler: impl FnMut(&str, &mut SystemContext) -> String + 'a,
+        ) {
+            self.handler = Some(Box::new(handler));
+        }
+
+        /// 绑定监听地址并在后台接受连接
+        pub(crate) async fn start(&mut self) -> io::Result<()> {
+            let Some(addr) = self.addr.take() else {
+                return Ok(());
+            };
+            let (sender, receiver) = mpsc::unbounded_channel();
+            match addr {
+                ControlAddr::Tcp(addr) => {
+                    let listener = tokio::net::TcpListener::bind(addr).await?;
+                    tokio::spawn(async move {
+                        while let Ok((stream, _)) = listener.accept().await {
+                            tokio::spawn(serve(stream, sender.clone()));
+                        }
+                    });
+                }
+                #[cfg(unix)]
+                ControlAddr::Unix(path) => {
+                    let _ = std::fs::remove_file(&path);
+                    let listener = tokio::net::UnixListener::bind(path)?;
+                    tokio::spawn(async move {
+                        while let Ok((stream, _)) = listener.accept().await {
+                            tokio::spawn(serve(stream, sender.clone()));
+                        }
+                    });
+                }
+            }
+            self.receiver = Some(receiver);
+            Ok(())
+        }
+
+        /// 等待下一条命令，未开启控制服务时永远不会返回
+        pub(crate) async fn next(&mut self) -> ControlRequest {
+            if let Some(receiver) = &mut self.receiver
+                && let Some(request) = receiver.recv().await
+            {
+                return request;
+            }
+            futures::future::pending().await
+        }
+
+        /// 记录最近一帧的画面，供截图使用
+        pub(crate) fn record_frame(&mut self, buffer: &Buffer) {
+            if self.receiver.is_some() {
+                self.last_frame = Some(buffer.clone());
+            }
+        }
+
+        pub(crate) fn handle(
+            &mut self,
+            request: ControlRequest,
+            terminal: &mut Terminal,
+            context: &mut SystemContext,
+        ) {
+            let response = match request.command {
+                ControlCommand::Event(events) => {
+                    for event in events {
+                        terminal.inject(event);
+                    }
+                    "ok".to_string()
+                }
+                ControlCommand::Command(command) => match &mut self.handler {
+                    Some(handler) => {
+                        let output = handler(&command, context);
+                        if output.is_empty() {
+                            "ok".to_string()
+                        } else {
+                            format!("{output}\nok")
+                        }
+                    }
+                    None => "error: no command handler registered".to_string(),
+                },
+                ControlCommand::Screenshot => match &self.last_frame {
+                    Some(buffer) => format!("{}\nok", screenshot(buffer)),
+                    None => "error: nothing has been drawn yet".to_string(),
+                },
+                ControlCommand::Quit => {
+                    context.exit();
+                    "ok".to_string()
+                }
+            };
+            let _ = request.reply.send(response);
+        }
+    }
+
+    async fn serve<S>(stream: S, sender: UnboundedSender<ControlRequest>)
+    where
+        S: AsyncRead + AsyncWrite + Send + 'static,
+    {
+        let (reader, mut writer) = tokio::io::split(stream);
+        let mut lines = BufReader::new(reader).lines();
+        while let Ok(Some(line)) = lines.next_line().await {
+            let response = match parse_command(&line) {
+                Ok(command) => {
+                    let (reply, response) = oneshot::channel();
+                    if sender.send(ControlRequest { command, reply }).is_err() {
+                        break;
+                    }
+                    response
+                        .await
+                        .unwrap_or_else(|_| "error: render loop stopped".to_string())
+                }
+                Err(error) => format!("error: {error}"),
+            };
+            if writer.write_all(response.as_bytes()).await.is_err()
+                || writer.write_all(b"\n").await.is_err()
+            {
+                break;
+            }
+        }
+    }
+
+    fn parse_command(line: &str) -> Result<ControlCommand, String> {
+        let line = line.trim();
+        let (name, rest) = line.split_once(' ').unwrap_or((line, ""));
+        match name {
+            "key" => Ok(ControlCommand::Event(vec![Event::Key(parse_key(rest)?)])),
+            "text" => Ok(ControlCommand::Event(
+                rest.chars()
+                    .map(|c| Event::Key(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE)))
+                    .collect(),
+            )),
+            "resize" => {
+                let size = rest
+                    .split_once(' ')
+                    .and_then(|(width, height)| {
+                        Some((width.trim().parse().ok()?, height.trim().parse().ok()?))
+                    })
+                    .ok_or_else(|| format!("invalid size `{rest}`"))?;
+                Ok(ControlCommand::Event(vec![Event::Resize(size.0, size.1)]))
+            }
+            "command" => Ok(ControlCommand::Command(rest.to_string())),
+            "screenshot" => Ok(ControlCommand::Screenshot),
+            "quit" => Ok(ControlCommand::Quit),
+            _ => Err(format!("unknown command `{name}`")),
+        }
+    }
+
+    /// 把画面转换为纯文本，每行去掉末尾空白
+    fn screenshot(buffer: &Buffer) -> String {
+        let area = buffer.area;
+        (area.top()..area.bottom())
+            .map(|y| {
+                let line: String = (area.left()..area.right())
+                    .map(|x| buffer[(x, y)].symbol())
+                    .collect();
+                line.trim_end().to_string()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// 未开启 `control` feature 时的空实现，命令类型无法构造，渲染循环中的分支永远不会就绪
+#[cfg(not(feature = "control"))]
+mod stub {
+    use std::{io, marker::PhantomData};
+
+    use ratatui::buffer::Buffer;
+
+    use crate::{context::SystemContext, terminal::Terminal};
+
+    pub(crate) enum ControlRequest {}
+
+    #[derive(Default)]
+    pub(crate) struct Control<'a> {
+        _marker: PhantomData<&'a ()>,
+    }
+
+    impl Control<'_> {
+        pub(crate) async fn start(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+
+        pub(crate) async fn next(&mut self) -> ControlRequest {
+            futures::future::pending().await
+        }
+
+        pub(crate) fn record_frame(&mut self, _buffer: &Buffer) {}
+
+        pub(crate) fn handle(
+            &mut self,
+            request: ControlRequest,
+            _terminal: &mut Terminal,
+            _context: &mut SystemContext,
+        ) {
+            match request {}
+        }
+    }
+}
@@ -1,17 +1,61 @@
 use ratatui::{layout::Rect, widgets::Widget};
 
+use crate::render::transition::Presence;
+
+/// 延迟到整棵组件树绘制完成后执行的绘制操作
+type Overlay = Box<dyn FnOnce(&mut ComponentDrawer<'_, '_>)>;
+
 /// 用于封装组件绘制上下文，便于在组件内部安全地操作 frame 和区域
 pub struct ComponentDrawer<'a, 'b: 'a> {
     /// 当前组件的绘制区域
     pub area: ratatui::layout::Rect,
     /// 指向全局 frame 的可变引用
     pub frame: &'a mut ratatui::Frame<'b>,
+    /// 已登记的覆盖层及其层级
+    overlays: Vec<(i32, Overlay)>,
+    presence: Presence,
+    /// 正在绘制的组件的类型名
+    component: &'static str,
+    /// 本帧声明持有焦点的组件的类型名
+    focused: Vec<&'static str>,
 }
 
 impl<'a, 'b> ComponentDrawer<'a, 'b> {
     /// 创建新的 ComponentDrawer
     pub fn new(frame: &'a mut ratatui::Frame<'b>, area: ratatui::layout::Rect) -> Self {
-        Self { area, frame }
+        Self {
+            area,
+            frame,
+            overlays: Vec::new(),
+            presence: Presence::Present,
+            component: "",
+            focused: Vec::new(),
+        }
+    }
+
+    /// 正在绘制的组件所处的挂载阶段，祖先组件离开中时子组件也视为离开中，见 [`crate::render::transition`]
+    pub fn presence(&self) -> Presence {
+        self.presence
+    }
+
+    pub(crate) fn set_presence(&mut self, presence: Presence) {
+        self.presence = presence;
+    }
+
+    pub(crate) fn set_component(&mut self, component: &'static str) -> &'static str {
+        std::mem::replace(&mut self.component, component)
+    }
+
+    /// 声明正在绘制的组件持有键盘焦点
+    ///
+    /// 通过 `use_focus` 获得焦点的组件会自动声明；用 `focused` 之类的属性自行管理焦点的组件应当在绘制时调用，
+    /// 以便 [`TestRunner::expect_focused`](crate::testing::TestRunner::expect_focused) 等工具判断焦点所在的组件。
+    pub fn mark_focused(&mut self) {
+        self.focused.push(self.component);
+    }
+
+    pub(crate) fn take_focused(&mut self) -> Vec<&'static str> {
+        std::mem::take(&mut self.focused)
     }
 
     /// 获取底层 buffer 的可变引用
@@ -22,4 +66,55 @@ impl<'a, 'b> ComponentDrawer<'a, 'b> {
     pub fn render_widget<W: Widget>(&mut self, widget: W, area: Rect) {
         widget.render(area, self.buffer_mut());
     }
+
+    /// 整个 frame 的区域，与当前组件的 area 无关
+    pub fn frame_area(&self) -> Rect {
+        self.frame.area()
+    }
+
+    /// 登记一个覆盖层绘制操作
+    ///
+    /// 供快捷键栏、水印等“外框”类组件使用：操作会在整棵组件树绘制完成后按登记顺序执行，
+    /// 执行时 `drawer.area` 为整个 frame 的区域，因此可以绘制在任意位置并覆盖其他组件，
+    /// 但仍位于右键菜单等弹层之下。普通组件应当只在自己的 area 内绘制。
+    ///
+    /// ```ignore
+    /// fn draw(&self, drawer: &mut ComponentDrawer<'_, '_>) {
+    ///     let hint = self.hint.clone();
+    ///     drawer.overlay(move |drawer| {
+    ///         let area = drawer.area;
+    ///         let bottom = Rect::new(area.x, area.bottom().saturating_sub(1), area.width, 1);
+    ///         drawer.render_widget(Paragraph::new(hint), bottom);
+    ///     });
+    /// }
+    /// ```
+    pub fn overlay(&mut self, draw: impl FnOnce(&mut ComponentDrawer<'_, '_>) + 'static) {
+        self.overlay_at(0, draw);
+    }
+
+    /// 以指定层级登记覆盖层绘制操作，层级大的后执行、绘制在上面，层级相同时按登记顺序执行
+    ///
+    /// 所有覆盖层都在整棵组件树之上，`overlay` 登记的层级为 0。
+    /// 下拉框、提示消息等需要盖住其他弹层的内容可以使用更大的层级，或者直接使用
+    /// [`Layer`](crate::components::Layer) 组件。
+    pub fn overlay_at(
+        &mut self,
+        z_index: i32,
+        draw: impl FnOnce(&mut ComponentDrawer<'_, '_>) + 'static,
+    ) {
+        self.overlays.push((z_index, Box::new(draw)));
+    }
+
+    /// 按层级执行所有已登记的覆盖层，覆盖层中再登记的操作也会在本轮执行
+    pub(crate) fn flush_overlays(&mut self) {
+        while !self.overlays.is_empty() {
+            let mut overlays = std::mem::take(&mut self.overlays);
+            // 稳定排序，层级相同的保持登记顺序
+            overlays.sort_by_key(|(z_index, _)| *z_index);
+            for (_, draw) in overlays {
+                self.area = self.frame.area();
+                draw(self);
+            }
+        }
+    }
 }
@@ -1,5 +1,19 @@
 use ratatui::layout::{Constraint, Direction, Flex, Layout, Margin, Offset};
 
+/// 交叉轴对齐方式，类似于 Web 的 align-items
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AlignItems {
+    /// 对齐到交叉轴起点
+    #[default]
+    Start,
+    /// 交叉轴居中
+    Center,
+    /// 对齐到交叉轴终点
+    End,
+    /// 按子组件的文本基线对齐，仅在横向布局中生效，纵向布局时等同于 Start
+    Baseline,
+}
+
 /// 用于描述组件布局样式的结构体，类似于 Web 的 Flex 布局属性
 #[derive(Default)]
 pub struct LayoutStyle {
@@ -7,6 +21,8 @@ pub struct LayoutStyle {
     pub flex_direction: Direction,
     /// 主轴对齐方式（如 Start, End, Center, SpaceBetween 等）
     pub justify_content: Flex,
+    /// 交叉轴对齐方式
+    pub align_items: AlignItems,
     /// 子项间距
     pub gap: i32,
     /// 外边距
@@ -17,6 +33,8 @@ pub struct LayoutStyle {
     pub width: Constraint,
     /// 高度约束
     pub height: Constraint,
+    /// 是否左右镜像子组件的布局，用于从右向左的界面：横向布局从右向左排列，纵向布局的交叉轴对齐左右互换
+    pub mirrored: bool,
 }
 
 impl LayoutStyle {
@@ -1,4 +1,13 @@
+pub mod activity;
+pub mod batch;
+pub mod blocking;
+pub mod control;
 pub mod drawer;
 pub mod layout_style;
+mod scheduler;
+mod signals;
+pub mod snapshot;
+pub mod transition;
 pub mod tree;
 pub mod updater;
+pub mod watchdog;
@@ -0,0 +1,110 @@
+//! 更新调度：按来源的优先级决定渲染循环先处理哪一次唤醒
+//!
+//! 多个来源同时就绪时，终端输入最先处理，其次是组件状态变化，外部数据源与动画帧最后处理。
+//! 输入分发之后紧接着的状态变化视为输入触发，渲染时不再让出执行权等待其他任务，
+//! 后台任务频繁完成时键入依然能立即得到响应。
+
+/// 唤醒渲染循环的来源的优先级，越靠前越先处理
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum Priority {
+    /// 终端输入、信号、退出请求与控制命令
+    Input,
+    /// 组件状态变化与活跃状态变化，例如 `use_future` 完成或定时器触发
+    Update,
+    /// 外部数据源与动画帧
+    Background,
+}
+
+/// 后台来源连续被推迟的帧数上限，超过后先处理一次后台来源
+const MAX_DEFERRED: u32 = 8;
+
+#[derive(Default)]
+pub(crate) struct Scheduler {
+    /// 已分发输入、尚未渲染
+    input_pending: bool,
+    /// 后台来源连续被推迟的帧数
+    deferred: u32,
+}
+
+impl Scheduler {
+    /// 记录终端输入已分发给组件，下一次状态变化按输入优先级处理
+    pub(crate) fn input_dispatched(&mut self) {
+        self.input_pending = true;
+    }
+
+    /// 记录即将渲染的一帧，返回调整后的优先级
+    pub(crate) fn begin_frame(&mut self, priority: Priority) -> Priority {
+        let priority = if self.input_pending {
+            Priority::Input
+        } else {
+            priority
+        };
+        self.input_pending = false;
+        if priority == Priority::Background {
+            self.deferred = 0;
+        } else {
+            self.deferred += 1;
+        }
+        priority
+    }
+
+    /// 后台来源是否已被推迟太久，需要在等待其他来源之前先处理一次，返回 true 时重新计数
+    pub(crate) fn take_starved(&mut self) -> bool {
+        let starved = self.deferred >= MAX_DEFERRED;
+        if starved {
+            self.deferred = 0;
+        }
+        starved
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn input_takes_priority_over_the_next_frame_only() {
+        let mut scheduler = Scheduler::default();
+        scheduler.input_dispatched();
+        assert_eq!(scheduler.begin_frame(Priority::Background), Priority::Input);
+        assert_eq!(
+            scheduler.begin_frame(Priority::Background),
+            Priority::Background
+        );
+
+        scheduler.input_dispatched();
+        assert_eq!(scheduler.begin_frame(Priority::Update), Priority::Input);
+        assert_eq!(scheduler.begin_frame(Priority::Update), Priority::Update);
+    }
+
+    #[test]
+    fn background_is_served_after_max_deferred_frames() {
+        let mut scheduler = Scheduler::default();
+        for _ in 1..MAX_DEFERRED {
+            scheduler.begin_frame(Priority::Update);
+            assert!(!scheduler.take_starved());
+        }
+        scheduler.begin_frame(Priority::Update);
+        assert!(scheduler.take_starved());
+        // 触发后重新计数
+        assert!(!scheduler.take_starved());
+
+        // 输入触发的帧同样推迟后台来源
+        for _ in 0..MAX_DEFERRED {
+            scheduler.input_dispatched();
+            scheduler.begin_frame(Priority::Background);
+        }
+        assert!(scheduler.take_starved());
+    }
+
+    #[test]
+    fn background_frame_resets_the_counter() {
+        let mut scheduler = Scheduler::default();
+        for _ in 1..MAX_DEFERRED {
+            scheduler.begin_frame(Priority::Input);
+        }
+        scheduler.begin_frame(Priority::Background);
+        scheduler.begin_frame(Priority::Update);
+        assert!(!scheduler.take_starved());
+    }
+}
@@ -0,0 +1,108 @@
+//! 信号监听：把 SIGINT / SIGTERM / SIGHUP（Windows 上为控制台事件）转交给渲染循环，
+//! 由渲染循环分发给 `use_signal_handler` 注册的回调，默认结束渲染循环并恢复终端。
+//!
+//! raw 模式下按 Ctrl+C 产生的是按键事件而不是 SIGINT，这里处理的是 `kill`、关闭终端窗口等外部信号。
+
+use std::sync::{
+    Arc,
+    atomic::{AtomicBool, Ordering},
+};
+
+use tokio::sync::mpsc::{self, UnboundedReceiver, UnboundedSender};
+
+use crate::hooks::use_signal_handler::Signal;
+
+pub(crate) struct SignalListener {
+    receiver: UnboundedReceiver<Signal>,
+    shutting_down: Arc<AtomicBool>,
+}
+
+impl SignalListener {
+    /// 开始监听信号，无法注册的信号会被忽略
+    pub(crate) fn start() -> Self {
+        let (sender, receiver) = mpsc::unbounded_channel();
+        let shutting_down = Arc::new(AtomicBool::new(false));
+        listen(sender, shutting_down.clone());
+        Self {
+            receiver,
+            shutting_down,
+        }
+    }
+
+    /// 等待下一个信号
+    pub(crate) async fn recv(&mut self) -> Signal {
+        match self.receiver.recv().await {
+            Some(signal) => signal,
+            None => std::future::pending().await,
+        }
+    }
+
+    /// 标记渲染循环正在退出，此后再收到信号时立即恢复终端并结束进程
+    pub(crate) fn shutting_down(&self) {
+        self.shutting_down.store(true, Ordering::Relaxed);
+    }
+}
+
+/// 把信号转发给渲染循环；渲染循环已在退出却迟迟没有结束时，第二次信号直接结束进程
+fn forward(signal: Signal, sender: &UnboundedSender<Signal>, shutting_down: &AtomicBool) -> bool {
+    if shutting_down.load(Ordering::Relaxed) {
+        ratatui::restore();
+        std::process::exit(signal.exit_code());
+    }
+    sender.send(signal).is_ok()
+}
+
+#[cfg(unix)]
+fn listen(sender: UnboundedSender<Signal>, shutting_down: Arc<AtomicBool>) {
+    use tokio::signal::unix::{SignalKind, signal};
+
+    for (kind, mapped) in [
+        (SignalKind::interrupt(), Signal::Interrupt),
+        (SignalKind::terminate(), Signal::Terminate),
+        (SignalKind::hangup(), Signal::Hangup),
+    ] {
+        let Ok(mut stream) = signal(kind) else {
+            continue;
+        };
+        let sender = sender.clone();
+        let shutting_down = shutting_down.clone();
+        tokio::spawn(async move {
+            while stream.recv().await.is_some() {
+                if !forward(mapped, &sender, &shutting_down) {
+                    break;
+                }
+            }
+        });
+    }
+}
+
+#[cfg(windows)]
+fn listen(sender: UnboundedSender<Signal>, shutting_down: Arc<AtomicBool>) {
+    use tokio::signal::windows;
+
+    // 各个控制台事件的监听器类型不同，用宏展开相同的转发逻辑
+    macro_rules! listen {
+        ($listener:expr, $mapped:expr) => {
+            if let Ok(mut listener) = $listener {
+                let sender = sender.clone();
+                let shutting_down = shutting_down.clone();
+                tokio::spawn(async move {
+                    while listener.recv().await.is_some() {
+                        if !forward($mapped, &sender, &shutting_down) {
+                            break;
+                        }
+                    }
+                });
+            }
+        };
+    }
+
+    listen!(windows::ctrl_c(), Signal::Interrupt);
+    listen!(windows::ctrl_break(), Signal::Interrupt);
+    listen!(windows::ctrl_close(), Signal::Hangup);
+    listen!(windows::ctrl_logoff(), Signal::Terminate);
+    listen!(windows::ctrl_shutdown(), Signal::Terminate);
+}
+
+#[cfg(not(any(unix, windows)))]
+fn listen(_sender: UnboundedSender<Signal>, _shutting_down: Arc<AtomicBool>) {}
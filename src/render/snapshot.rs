@@ -0,0 +1,72 @@
+//! 状态快照：保存整棵组件树中可序列化的 Hook 状态，用于崩溃恢复与开发时的热重启
+//!
+//! 通过 [`Tree::snapshot_state`](crate::render::tree::Tree::snapshot_state) 生成、
+//! [`Tree::restore_state`](crate::render::tree::Tree::restore_state) 恢复，参与的 Hook 需要实现
+//! [`PersistableHook`](crate::hooks::PersistableHook)，内置的 `use_persisted_state` 已经实现。
+//!
+//! 每个状态以组件路径与 Hook 的序号标识，例如 `App/View[1]/Sidebar[0]#2`，
+//! 路径由组件类型名与在兄弟组件中的位置组成，重新编译后依然有效；组件树结构变化后对不上的条目被忽略。
+
+use std::{fs, io, path::Path};
+
+use serde_json::{Map, Value};
+
+/// StateSnapshot：组件树中可序列化的 Hook 状态
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct StateSnapshot {
+    states: Map<String, Value>,
+}
+
+impl StateSnapshot {
+    /// 从 [`StateSnapshot::to_value`] 生成的 JSON 恢复，不是对象时返回空快照
+    pub fn from_value(value: Value) -> Self {
+        match value {
+            Value::Object(states) => Self { states },
+            _ => Self::default(),
+        }
+    }
+
+    pub fn to_value(&self) -> Value {
+        Value::Object(self.states.clone())
+    }
+
+    /// 从 path 读取快照，文件无法解析时返回空快照
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        let content = fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&content)
+            .map(Self::from_value)
+            .unwrap_or_default())
+    }
+
+    /// 把快照写入 path
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let path = path.as_ref();
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, serde_json::to_string_pretty(&self.states)?)
+    }
+
+    /// 保存的状态数量
+    pub fn len(&self) -> usize {
+        self.states.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.states.is_empty()
+    }
+
+    pub(crate) fn insert(&mut self, key: String, value: Value) {
+        self.states.insert(key, value);
+    }
+
+    pub(crate) fn take(&mut self, key: &str) -> Option<Value> {
+        self.states.remove(key)
+    }
+}
+
+/// 组件在快照路径中的名称：去掉模块路径与泛型参数的类型名
+pub(crate) fn segment(component_name: &str) -> &str {
+    let name = component_name.split('<').next().unwrap_or(component_name);
+    name.rsplit("::").next().unwrap_or(name)
+}
@@ -0,0 +1,154 @@
+//! 挂载与卸载过渡：组件进入时经历一段 Entering，离开时在原位置保留一段 Exiting 再销毁
+//!
+//! 组件在 update 中通过 [`ComponentUpdater::set_transition`](crate::render::updater::ComponentUpdater::set_transition)
+//! 声明过渡时长，在 update 中用 [`ComponentUpdater::presence`](crate::render::updater::ComponentUpdater::presence)、
+//! 在 draw 中用 [`ComponentDrawer::presence`](crate::render::drawer::ComponentDrawer::presence) 读取当前阶段。
+//! 离开中的组件不再收到新的 props、也不再执行 update，只按保存的状态继续绘制。
+
+use std::time::{Duration, Instant};
+
+use crate::{
+    animation::{AnimationClock, Easing},
+    clock,
+};
+
+/// Transition：组件进入与离开的过渡时长
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Transition {
+    pub enter: Duration,
+    pub exit: Duration,
+    /// 阶段进度使用的缓动函数
+    pub easing: Easing,
+}
+
+impl Transition {
+    /// 进入与离开使用相同的时长
+    pub fn new(duration: Duration) -> Self {
+        Self {
+            enter: duration,
+            exit: duration,
+            easing: Easing::default(),
+        }
+    }
+
+    pub fn easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+}
+
+/// Presence：组件所处的挂载阶段
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Presence {
+    /// 刚挂载，进入过渡中，进度从 0 到 1（已应用缓动）
+    Entering(f64),
+    /// 已完全显示
+    #[default]
+    Present,
+    /// 已从 children 中移除，离开过渡中，进度从 0 到 1（已应用缓动）
+    Exiting(f64),
+}
+
+impl Presence {
+    /// 可见程度：进入时从 0 增加到 1，离开时从 1 减少到 0，适合直接用于插值
+    pub fn visibility(self) -> f64 {
+        match self {
+            Presence::Entering(progress) => progress,
+            Presence::Present => 1.0,
+            Presence::Exiting(progress) => 1.0 - progress,
+        }
+    }
+
+    pub fn is_exiting(self) -> bool {
+        matches!(self, Presence::Exiting(_))
+    }
+}
+
+/// Lifecycle：组件实例的挂载时间与过渡状态，由组件实例持有
+pub struct Lifecycle {
+    transition: Transition,
+    mounted_at: Instant,
+    exiting_since: Option<Instant>,
+    /// 过渡进行时让渲染循环持续刷新
+    clock: Option<AnimationClock>,
+    animating: bool,
+}
+
+impl Default for Lifecycle {
+    fn default() -> Self {
+        Self {
+            transition: Transition::default(),
+            mounted_at: clock::now(),
+            exiting_since: None,
+            clock: None,
+            animating: false,
+        }
+    }
+}
+
+impl Lifecycle {
+    pub(crate) fn set_transition(&mut self, transition: Transition) {
+        self.transition = transition;
+    }
+
+    /// 当前阶段
+    pub fn presence(&self) -> Presence {
+        let progress = |since: Instant, duration: Duration| {
+            let t = clock::elapsed(since).as_secs_f64() / duration.as_secs_f64();
+            self.transition.easing.apply(t)
+        };
+        match self.exiting_since {
+            Some(since) if clock::elapsed(since) < self.transition.exit => {
+                Presence::Exiting(progress(since, self.transition.exit))
+            }
+            Some(_) => Presence::Exiting(1.0),
+            None if clock::elapsed(self.mounted_at) < self.transition.enter => {
+                Presence::Entering(progress(self.mounted_at, self.transition.enter))
+            }
+            None => Presence::Present,
+        }
+    }
+
+    /// 被移出 children 时调用，返回是否需要保留以播放离开过渡
+    pub(crate) fn begin_exit(&mut self) -> bool {
+        if self.transition.exit.is_zero() {
+            return false;
+        }
+        let since = *self.exiting_since.get_or_insert_with(clock::now);
+        clock::elapsed(since) < self.transition.exit
+    }
+
+    /// 重新出现在 children 中时取消离开过渡
+    pub(crate) fn cancel_exit(&mut self) {
+        self.exiting_since = None;
+    }
+
+    /// 根据当前阶段登记或注销动画时钟
+    pub(crate) fn sync_clock(&mut self, clock: Option<AnimationClock>) {
+        if self.clock.is_none() {
+            self.clock = clock;
+        }
+        let Some(clock) = &self.clock else {
+            return;
+        };
+        let animating = self.presence() != Presence::Present;
+        if animating != self.animating {
+            if animating {
+                clock.start();
+            } else {
+                clock.stop();
+            }
+            self.animating = animating;
+        }
+    }
+}
+
+impl Drop for Lifecycle {
+    fn drop(&mut self) {
+        if self.animating
+            && let Some(clock) = &self.clock
+        {
+            clock.stop();
+        }
+    }
+}
@@ -1,21 +1,170 @@
-use futures::{FutureExt, future::select};
-use std::io;
+use futures::{FutureExt, Stream, future::poll_fn, stream};
+use ratatui::{
+    layout::{Alignment, Constraint, Flex, Layout, Rect, Size},
+    style::{Style, Stylize},
+    widgets::{Paragraph, Wrap},
+};
+use serde::de::DeserializeOwned;
+use std::{
+    io,
+    pin::Pin,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+    task::{Context, Poll, Wake, Waker},
+    time::{Duration, Instant},
+};
 
 use crate::{
+    clock,
     component::{
-        component_helper::ComponentHelperExt, instantiated_component::InstantiatedComponent,
+        Component, component_helper::ComponentHelperExt,
+        instantiated_component::InstantiatedComponent,
     },
     context::{ContextStack, SystemContext},
     element::{ElementExt, key::ElementKey},
+    hooks::use_store::Store,
     props::AnyProps,
-    render::drawer::ComponentDrawer,
+    render::{
+        batch,
+        blocking::BlockingMonitor,
+        control::Control,
+        drawer::ComponentDrawer,
+        scheduler::{Priority, Scheduler},
+        signals::SignalListener,
+        snapshot::StateSnapshot,
+        watchdog::{SlowFrame, Watchdog},
+    },
     terminal::Terminal,
+    width,
 };
 
+/// 每一帧渲染前后的回调，用于把外部系统接入渲染循环
+///
+/// 例如同步音频时钟、驱动 FFI 事件循环或上报指标，无需改写 render_loop。
+pub trait RenderHook {
+    /// 在组件树 update 之前调用，frame 为从 0 开始的帧序号
+    fn before_render(&mut self, _frame: u64) {}
+
+    /// 在本帧绘制到终端之后调用，elapsed 为 update 与绘制的总耗时
+    fn after_render(&mut self, _frame: u64, _elapsed: Duration) {}
+}
+
+struct BeforeRender<F>(F);
+
+impl<F: FnMut(u64)> RenderHook for BeforeRender<F> {
+    fn before_render(&mut self, frame: u64) {
+        (self.0)(frame);
+    }
+}
+
+struct AfterRender<F>(F);
+
+impl<F: FnMut(u64, Duration)> RenderHook for AfterRender<F> {
+    fn after_render(&mut self, frame: u64, elapsed: Duration) {
+        (self.0)(frame, elapsed);
+    }
+}
+
+/// 接入渲染循环的外部数据源
+trait Source {
+    /// 轮询一条数据并交给回调处理，数据源结束时返回 Ready(None)
+    fn poll_deliver(&mut self, cx: &mut Context, context: &mut SystemContext) -> Poll<Option<()>>;
+}
+
+struct StreamSource<'a, T, F> {
+    stream: Pin<Box<dyn Stream<Item = T> + 'a>>,
+    f: F,
+}
+
+impl<T, F: FnMut(T, &mut SystemContext)> Source for StreamSource<'_, T, F> {
+    fn poll_deliver(&mut self, cx: &mut Context, context: &mut SystemContext) -> Poll<Option<()>> {
+        self.stream.as_mut().poll_next(cx).map(|item| {
+            item.map(|item| {
+                (self.f)(item, context);
+            })
+        })
+    }
+}
+
+/// 等待任意外部数据源交付数据，交付后返回以便重新渲染
+async fn poll_sources(sources: &mut Vec<Box<dyn Source + '_>>, context: &mut SystemContext) {
+    poll_fn(|cx| {
+        let mut delivered = false;
+        sources.retain_mut(|source| {
+            loop {
+                match source.poll_deliver(cx, context) {
+                    Poll::Ready(Some(())) => delivered = true,
+                    Poll::Ready(None) => return false,
+                    Poll::Pending => return true,
+                }
+            }
+        });
+        if delivered {
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    })
+    .await
+}
+
+/// 渲染循环结束时交给调用方的结果与退出码
+///
+/// 框架不会自行结束进程，终端恢复之后由调用方决定是否以 code 退出：
+///
+/// ```ignore
+/// let exit = element.render_loop_with_exit::<String>().await?;
+/// if let Some(choice) = exit.result {
+///     println!("{choice}");
+/// }
+/// if let Some(code) = exit.code {
+///     std::process::exit(code);
+/// }
+/// ```
+#[derive(Debug)]
+pub struct RenderExit<T> {
+    /// 组件通过 `finish` 传出的结果，结果类型与 T 不一致时为 None
+    pub result: Option<T>,
+    /// 组件通过 `exit_with_code` 指定的退出码，因收到 SIGTERM 等信号而退出时为信号对应的退出码
+    pub code: Option<i32>,
+}
+
+/// take_change 在一次检查中最多轮询的次数，避免不断唤醒自身的 Hook 让检查无法结束
+const MAX_CHANGE_POLLS: usize = 16;
+
+/// 记录是否被唤醒的 Waker
+#[derive(Default)]
+struct WakeFlag(AtomicBool);
+
+impl Wake for WakeFlag {
+    fn wake(self: Arc<Self>) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+}
+
 pub struct Tree<'a> {
     root_component: InstantiatedComponent,
     props: AnyProps<'a>,
     system_context: SystemContext,
+    render_hooks: Vec<Box<dyn RenderHook + 'a>>,
+    sources: Vec<Box<dyn Source + 'a>>,
+    control: Control<'a>,
+    watchdog: Watchdog<'a>,
+    blocking_threshold: Option<Duration>,
+    min_size: Option<Size>,
+    /// 为外部界面保留的顶部与底部行数
+    reserved_rows: (u16, u16),
+    /// 两次渲染之间的最小间隔，由 set_max_fps 设置
+    min_frame_interval: Option<Duration>,
+    /// 等待在下一帧 update 之后恢复的状态
+    pending_restore: Option<StateSnapshot>,
+    /// 最近一帧声明持有焦点的组件的类型名
+    focused: Vec<&'static str>,
+    /// 按下 Ctrl+C 时是否直接结束渲染循环
+    exit_on_ctrl_c: bool,
+    frame: u64,
 }
 
 impl<'a> Tree<'a> {
@@ -28,51 +177,468 @@ impl<'a> Tree<'a> {
             ),
             props,
             system_context: SystemContext::new(),
+            render_hooks: Vec::new(),
+            sources: Vec::new(),
+            control: Control::default(),
+            watchdog: Watchdog::default(),
+            blocking_threshold: None,
+            min_size: None,
+            reserved_rows: (0, 0),
+            min_frame_interval: None,
+            pending_restore: None,
+            focused: Vec::new(),
+            exit_on_ctrl_c: true,
+            frame: 0,
         }
     }
 
+    /// 注册每帧渲染前后的回调，按注册顺序调用
+    pub fn add_render_hook(&mut self, hook: impl RenderHook + 'a) -> &mut Self {
+        self.render_hooks.push(Box::new(hook));
+        self
+    }
+
+    /// 注册在每帧 update 之前调用的回调
+    pub fn on_before_render(&mut self, f: impl FnMut(u64) + 'a) -> &mut Self {
+        self.add_render_hook(BeforeRender(f))
+    }
+
+    /// 注册在每帧绘制完成之后调用的回调
+    pub fn on_after_render(&mut self, f: impl FnMut(u64, Duration) + 'a) -> &mut Self {
+        self.add_render_hook(AfterRender(f))
+    }
+
+    /// 把外部的数据流接入渲染循环，例如 IPC 监听
+    ///
+    /// 渲染循环在等待组件变化和终端事件的同时轮询 stream，每收到一条数据就调用 f，
+    /// f 可以把数据写入上下文或共享状态，随后组件树会重新渲染。
+    pub fn add_stream<T>(
+        &mut self,
+        stream: impl Stream<Item = T> + 'a,
+        f: impl FnMut(T, &mut SystemContext) + 'a,
+    ) -> &mut Self
+    where
+        T: 'a,
+    {
+        self.sources.push(Box::new(StreamSource {
+            stream: Box::pin(stream),
+            f,
+        }));
+        self
+    }
+
+    /// 把外部的 Future 接入渲染循环，完成时调用 f，随后组件树会重新渲染
+    pub fn add_future<T>(
+        &mut self,
+        future: impl Future<Output = T> + 'a,
+        f: impl FnOnce(T, &mut SystemContext) + 'a,
+    ) -> &mut Self
+    where
+        T: 'a,
+    {
+        let mut f = Some(f);
+        self.add_stream(stream::once(future), move |value, context| {
+            if let Some(f) = f.take() {
+                f(value, context);
+            }
+        })
+    }
+
+    /// 设置单帧 update 与绘制的耗时预算，None 表示关闭检查
+    ///
+    /// 调试构建下默认为 50ms，发布构建下默认关闭。
+    pub fn set_frame_budget(&mut self, budget: Option<Duration>) -> &mut Self {
+        self.watchdog.set_budget(budget);
+        self
+    }
+
+    /// 开启阻塞检测，组件的 update、绘制或 Hook 轮询超过 threshold 没有让出执行权时，
+    /// 在退出渲染循环后打印该组件在组件树中的路径，默认关闭
+    ///
+    /// 检测状态是全局的，同一时间只应有一个开启检测的 Tree。
+    pub fn detect_blocking(&mut self, threshold: Option<Duration>) -> &mut Self {
+        self.blocking_threshold = threshold;
+        self
+    }
+
+    /// 设置终端的最小可用尺寸，终端小于该尺寸时不绘制组件树，改为显示提示画面
+    ///
+    /// 组件树仍然正常 update，终端恢复足够大小后立即重新显示。
+    pub fn set_min_size(&mut self, min_size: Option<Size>) -> &mut Self {
+        self.min_size = min_size;
+        self
+    }
+
+    /// 在屏幕顶部和底部各保留若干行，组件树只在剩余区域内布局和绘制
+    ///
+    /// 保留的行留给外部的状态栏等界面，框架不会写入；覆盖层与右键菜单仍然可以使用整个屏幕。
+    pub fn reserve_rows(&mut self, top: u16, bottom: u16) -> &mut Self {
+        self.reserved_rows = (top, bottom);
+        self
+    }
+
+    /// 设置按下 Ctrl+C 时是否直接结束渲染循环，默认开启
+    ///
+    /// 关闭后 Ctrl+C 与其他按键一样分发给组件，由组件自行处理，例如先确认再调用 `use_exit`。
+    /// 终端处于 raw 模式，Ctrl+C 不会产生 SIGINT，关闭后需要组件提供其他退出方式。
+    pub fn set_exit_on_ctrl_c(&mut self, enabled: bool) -> &mut Self {
+        self.exit_on_ctrl_c = enabled;
+        self
+    }
+
+    /// 在根部安装一个全局 store，组件通过 `use_selector` 订阅
+    pub fn provide_store<S: Send + 'static>(&mut self, store: Store<S>) -> &mut Self {
+        self.system_context.stores().provide(store);
+        self
+    }
+
+    /// 在系统的应用数据目录下为 app 启用 `use_persisted_state` 的持久化，
+    /// 见 [`SessionStore::open_app`](crate::hooks::use_persisted_state::SessionStore::open_app)
+    pub fn persist_state(&mut self, app: &str) -> &mut Self {
+        self.system_context.session().open_app(app);
+        self
+    }
+
+    /// 设置根部的文字方向，开启从右向左的布局与文本重排，子树可以用 `ContextProvider<TextDirection>` 覆盖
+    ///
+    /// 默认为从左向右；按 locale 自动选择可以传入 [`TextDirection::from_env`](crate::bidi::TextDirection::from_env)。
+    pub fn set_direction(&mut self, direction: crate::bidi::TextDirection) -> &mut Self {
+        self.system_context.set_direction(direction);
+        self
+    }
+
+    /// 限制渲染循环每秒最多渲染的帧数，None 表示不限制（默认）
+    ///
+    /// 距离上一帧不足间隔时，渲染循环等到间隔结束再渲染，期间到达的事件与状态变化合并到同一帧中，
+    /// 避免密集的事件或高帧率的动画让 update 与 `terminal.draw` 占满 CPU。
+    /// 动画帧率高于该值时同样被限制。
+    pub fn set_max_fps(&mut self, fps: Option<u32>) -> &mut Self {
+        self.min_frame_interval = fps.map(|fps| Duration::from_secs(1) / fps.max(1));
+        self
+    }
+
+    /// 保存整棵组件树中实现了 [`PersistableHook`](crate::hooks::PersistableHook) 的 Hook 状态，
+    /// 见 [`crate::render::snapshot`]
+    ///
+    /// ```ignore
+    /// tree.render_loop().await?;
+    /// tree.snapshot_state().save("session.json")?;
+    /// ```
+    pub fn snapshot_state(&mut self) -> StateSnapshot {
+        let mut snapshot = StateSnapshot::default();
+        let path = self.root_component.snapshot_path();
+        self.root_component.snapshot_states(&path, &mut snapshot);
+        snapshot
+    }
+
+    /// 恢复 [`Tree::snapshot_state`] 保存的状态
+    ///
+    /// 在下一帧 update 之后写入路径一致的 Hook，恢复的值会触发一次重新渲染；
+    /// 届时组件树中不存在的条目被丢弃。通常在首次渲染之前调用。
+    ///
+    /// ```ignore
+    /// if let Ok(snapshot) = StateSnapshot::load("session.json") {
+    ///     tree.restore_state(snapshot);
+    /// }
+    /// tree.render_loop().await?;
+    /// ```
+    pub fn restore_state(&mut self, snapshot: StateSnapshot) -> &mut Self {
+        self.pending_restore = Some(snapshot);
+        self
+    }
+
+    /// 设置动画进行时的刷新帧率，默认 60，见 [`AnimationClock`](crate::animation::AnimationClock)
+    pub fn set_animation_frame_rate(&mut self, fps: u32) -> &mut Self {
+        self.system_context.animations().set_frame_rate(fps);
+        self
+    }
+
+    /// 设置根部的主题，子树可以用 `ContextProvider<Theme>` 覆盖
+    pub fn set_theme(&mut self, theme: crate::theme::Theme) -> &mut Self {
+        self.system_context.set_theme(theme);
+        self
+    }
+
+    /// 以 name 注册组件 C，供 [`DynamicElement`](crate::components::DynamicElement) 按名称实例化
+    pub fn register_component<C>(&mut self, name: impl Into<String>) -> &mut Self
+    where
+        C: Component,
+        C::Props<'static>: DeserializeOwned,
+    {
+        self.system_context.components().register::<C>(name);
+        self
+    }
+
+    /// 在 addr 上开启控制服务，供外部脚本和集成测试驱动界面，协议见 [`crate::render::control`]
+    #[cfg(feature = "control")]
+    pub fn enable_control(
+        &mut self,
+        addr: impl Into<crate::render::control::ControlAddr>,
+    ) -> &mut Self {
+        self.control.set_addr(addr.into());
+        self
+    }
+
+    /// 处理控制服务收到的 `command` 命令，返回值作为响应输出
+    #[cfg(feature = "control")]
+    pub fn on_control_command(
+        &mut self,
+        f: impl FnMut(&str, &mut SystemContext) -> String + 'a,
+    ) -> &mut Self {
+        self.control.set_handler(f);
+        self
+    }
+
+    /// 自定义超出预算时的处理方式，默认在退出渲染循环后打印到 stderr
+    pub fn on_slow_frame(&mut self, f: impl FnMut(&SlowFrame) + 'a) -> &mut Self {
+        self.watchdog.set_handler(f);
+        self
+    }
+
     pub fn render(&mut self, terminal: &mut Terminal) -> io::Result<()> {
+        terminal.set_exit_on_ctrl_c(self.exit_on_ctrl_c);
+        terminal.set_input_layers(self.system_context.input_layers());
+        let frame = self.frame;
+        for hook in &mut self.render_hooks {
+            hook.before_render(frame);
+        }
+        let started_at = Instant::now();
+
         // 创建上下文栈
         let mut context_stack = ContextStack::root(&mut self.system_context);
-        
+
         self.root_component
             .update(self.props.borrow(), terminal, &mut context_stack);
+        // 本帧没有被接管的可迁移组件随即卸载
+        self.system_context.reparenting().flush();
+        if let Some(mut snapshot) = self.pending_restore.take() {
+            let path = self.root_component.snapshot_path();
+            self.root_component.restore_states(&path, &mut snapshot);
+        }
 
-        terminal.draw(|frame| {
-            let area = frame.area();
+        let context_menus = self.system_context.context_menus();
+        let focus = self.system_context.focus();
+        focus.begin_frame();
+        let min_size = self.min_size;
+        let (top, bottom) = self.reserved_rows;
+        let mut focused = Vec::new();
+        let completed = terminal.draw(|frame| {
+            let mut area = frame.area();
+            area.y = area.y.saturating_add(top).min(area.bottom());
+            area.height = area.height.saturating_sub(top.saturating_add(bottom));
+            if let Some(min_size) = min_size
+                && (area.width < min_size.width || area.height < min_size.height)
+            {
+                draw_too_small(frame, area, min_size);
+                return;
+            }
             let mut drawer = ComponentDrawer::new(frame, area);
             self.root_component.draw(&mut drawer);
+            drawer.flush_overlays();
+            focused = drawer.take_focused();
+            // 弹层绘制在整棵组件树之上
+            context_menus.draw(frame);
         })?;
+        self.control.record_frame(completed.buffer);
+        focus.end_frame();
+        self.focused = focused;
+
+        let elapsed = started_at.elapsed();
+        if let Some(budget) = self.watchdog.exceeded(elapsed) {
+            let mut timings = Vec::new();
+            self.root_component.collect_timings(&mut timings);
+            self.watchdog.report(frame, elapsed, budget, timings);
+        }
+        for hook in &mut self.render_hooks {
+            hook.after_render(frame, elapsed);
+        }
+        self.frame += 1;
 
         Ok(())
     }
 
+    /// 组件树是否有等待渲染的状态变化，不等待
+    ///
+    /// 事件回调在轮询过程中修改的状态，要到下一次轮询时才会就绪，因此轮询期间被唤醒时继续轮询。
+    pub(crate) fn take_change(&mut self) -> bool {
+        for _ in 0..MAX_CHANGE_POLLS {
+            let woken = Arc::new(WakeFlag::default());
+            let waker = Waker::from(woken.clone());
+            let mut cx = Context::from_waker(&waker);
+            if Pin::new(&mut self.root_component)
+                .poll_change(&mut cx)
+                .is_ready()
+            {
+                return true;
+            }
+            if !woken.0.load(Ordering::Relaxed) {
+                return false;
+            }
+        }
+        false
+    }
+
+    /// 最近一帧声明持有焦点的组件的类型名，见 [`ComponentDrawer::mark_focused`]
+    pub(crate) fn focused_components(&self) -> &[&'static str] {
+        &self.focused
+    }
+
+    pub(crate) fn system_context(&self) -> &SystemContext {
+        &self.system_context
+    }
+
+    pub(crate) fn unmount(&mut self) {
+        self.root_component.unmount();
+    }
+
     pub async fn render_loop(&mut self) -> io::Result<()> {
         let mut terminal = Terminal::new();
+        let capabilities = terminal.capabilities();
+        width::set_ambiguous_wide(capabilities.ambiguous_wide);
+        self.system_context.set_capabilities(capabilities);
+        let activity = self.system_context.activity();
+        let mut activity_events = terminal.events();
+        let exit = self.system_context.exit_signal();
+        let mut signals = SignalListener::start();
+        let animations = self.system_context.animations();
+        let monitor = self.blocking_threshold.map(BlockingMonitor::start);
+        self.control.start().await?;
+
+        let mut last_render: Option<Instant> = None;
+        let mut scheduler = Scheduler::default();
 
-        loop {
+        'render: loop {
+            // 限制了帧率时，等到距离上一帧足够久再渲染
+            if let (Some(interval), Some(last)) = (self.min_frame_interval, last_render) {
+                clock::sleep_until(last + interval).await;
+            }
+            last_render = Some(clock::now());
             // 渲染 UI
             self.render(&mut terminal)?;
 
-            if terminal.received_ctrl_c() {
+            if terminal.received_ctrl_c() || self.system_context.should_exit() {
                 break;
             }
 
-            select(self.root_component.wait().boxed(), terminal.wait().boxed()).await;
+            // 后台来源被连续推迟太久时先处理一次已就绪的数据，避免被频繁的状态变化饿死
+            let starved = scheduler.take_starved()
+                && poll_sources(&mut self.sources, &mut self.system_context)
+                    .now_or_never()
+                    .is_some();
 
-            if terminal.received_ctrl_c() {
+            // 任一来源就绪都会重新渲染：组件状态、终端事件、活跃状态变化、退出请求、动画帧以及外部数据源；
+            // 同时就绪时按 Priority 的顺序处理
+            let priority = if starved {
+                Priority::Background
+            } else {
+                loop {
+                    tokio::select! {
+                        biased;
+                        signal = signals.recv() => {
+                            // 没有回调处理时按信号退出，之后由循环结束后的代码恢复终端
+                            if !self.system_context.signals().dispatch(signal) {
+                                signals.shutting_down();
+                                exit.exit_with_code(signal.exit_code());
+                            }
+                            break Priority::Input;
+                        }
+                        _ = exit.wait() => break Priority::Input,
+                        _ = terminal.wait() => {
+                            if terminal.received_ctrl_c() {
+                                break 'render;
+                            }
+                            // 事件由组件的 Hook 在下一次轮询时处理，处理后的状态变化按输入优先级渲染
+                            scheduler.input_dispatched();
+                        }
+                        request = self.control.next() => {
+                            self.control.handle(request, &mut terminal, &mut self.system_context);
+                            break Priority::Input;
+                        }
+                        _ = self.root_component.wait() => break Priority::Update,
+                        _ = activity.wait(&mut activity_events) => break Priority::Update,
+                        _ = poll_sources(&mut self.sources, &mut self.system_context) => {
+                            break Priority::Background;
+                        }
+                        _ = animations.next_frame() => break Priority::Background,
+                    }
+                }
+            };
+
+            if terminal.received_ctrl_c() || self.system_context.should_exit() {
                 break;
             }
+
+            // 合并同一时刻的多次状态修改：等待进行中的 batch 结束，
+            // 非输入触发时再让出一次执行权让其他已经就绪的任务先完成修改，之后只渲染一次
+            batch::settled().await;
+            if scheduler.begin_frame(priority) != Priority::Input {
+                tokio::task::yield_now().await;
+            }
         }
         ratatui::restore();
+        // 终端恢复后再卸载组件树，清理过程中的输出不会被界面覆盖
+        self.root_component.unmount();
+        self.watchdog.flush();
+        for report in monitor.map(BlockingMonitor::stop).unwrap_or_default() {
+            eprintln!("[ratatui-kit] {report}");
+        }
+        // 退出时保存持久化的界面状态
+        self.system_context.session().save()?;
         Ok(())
     }
+
+    /// 运行渲染循环，并返回组件通过 `finish` 传出的结果
+    ///
+    /// 通过 Ctrl+C 或 `exit` 结束、或者结果类型与 T 不一致时返回 None。
+    pub async fn render_loop_with_result<T: 'static>(&mut self) -> io::Result<Option<T>> {
+        self.render_loop().await?;
+        Ok(self.system_context.exit_signal().take_result())
+    }
+
+    /// 通过 [`ExitSignal::exit_with_code`](crate::hooks::use_exit::ExitSignal::exit_with_code) 结束渲染循环时指定的退出码
+    ///
+    /// 因收到 SIGTERM 等信号而退出时为 [`Signal::exit_code`](crate::hooks::use_signal_handler::Signal::exit_code)。
+    pub fn exit_code(&self) -> Option<i32> {
+        self.system_context.exit_signal().code()
+    }
+}
+
+/// 终端过小时代替组件树绘制的提示画面
+fn draw_too_small(frame: &mut ratatui::Frame, area: Rect, min_size: Size) {
+    let text = format!(
+        "Please enlarge your terminal to at least {}x{} (current: {}x{})",
+        min_size.width, min_size.height, area.width, area.height
+    );
+    // 提示文本只包含 ASCII，按长度估算折行后的行数，用于垂直居中
+    let height = (text.len() as u16)
+        .div_ceil(area.width.max(1))
+        .min(area.height);
+    let paragraph = Paragraph::new(text)
+        .style(Style::default().yellow())
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true });
+    let [area] = Layout::vertical([Constraint::Length(height)])
+        .flex(Flex::Center)
+        .areas(area);
+    frame.render_widget(paragraph, area);
 }
 
 pub(crate) async fn render_loop<E: ElementExt>(mut element: E) -> io::Result<()> {
+    render_loop_with_exit::<(), _>(&mut element).await?;
+    Ok(())
+}
+
+pub(crate) async fn render_loop_with_exit<T, E>(element: &mut E) -> io::Result<RenderExit<T>>
+where
+    T: Send + 'static,
+    E: ElementExt,
+{
     let helper = element.helper();
     let mut tree = Tree::new(element.props_mut(), helper);
 
-    tree.render_loop().await?;
-    Ok(())
+    let result = tree.render_loop_with_result().await?;
+    Ok(RenderExit {
+        result,
+        code: tree.exit_code(),
+    })
 }
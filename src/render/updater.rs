@@ -1,16 +1,31 @@
+use std::{
+    any::TypeId,
+    ops::{Deref, DerefMut},
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use ratatui::layout::Size;
+
 use crate::{
     component::instantiated_component::{Components, InstantiatedComponent},
+    context::SystemContext,
     context::{Context, ContextStack},
     element::{ElementExt, key::ElementKey},
     multimap::AppendOnlyMultimap,
-    render::layout_style::LayoutStyle,
-    terminal::Terminal,
+    render::{
+        layout_style::LayoutStyle,
+        transition::{Lifecycle, Presence, Transition},
+    },
+    terminal::{InputLayer, Terminal, TerminalEvents},
 };
 
 pub struct ComponentUpdater<'a, 'b: 'a> {
     key: ElementKey,
     components: &'a mut Components,
     layout_style: &'a mut LayoutStyle,
+    max_refresh_rate: &'a mut Option<Duration>,
+    lifecycle: &'a mut Lifecycle,
     terminal: &'a mut Terminal,
     component_context_stack: &'a mut ContextStack<'b>,
 }
@@ -20,6 +35,8 @@ impl<'a, 'b: 'a> ComponentUpdater<'a, 'b> {
         key: ElementKey,
         components: &'a mut Components,
         layout_style: &'a mut LayoutStyle,
+        max_refresh_rate: &'a mut Option<Duration>,
+        lifecycle: &'a mut Lifecycle,
         terminal: &'a mut Terminal,
         component_context_stack: &'a mut ContextStack<'b>,
     ) -> Self {
@@ -27,11 +44,13 @@ impl<'a, 'b: 'a> ComponentUpdater<'a, 'b> {
             key,
             components,
             layout_style,
+            max_refresh_rate,
+            lifecycle,
             terminal,
             component_context_stack,
         }
     }
-    
+
     pub fn component_context_stack(&self) -> &ContextStack<'b> {
         self.component_context_stack
     }
@@ -40,6 +59,18 @@ impl<'a, 'b: 'a> ComponentUpdater<'a, 'b> {
         self.terminal
     }
 
+    /// 订阅终端事件，加入当前组件所在的输入层级，见 [`InputLayer`]
+    ///
+    /// 所在的层级之上打开了模态对话框或右键菜单时，不会收到按键、鼠标与粘贴事件。
+    pub fn terminal_events(&mut self) -> TerminalEvents {
+        let layer = self
+            .component_context_stack
+            .get_context::<InputLayer>()
+            .map(|layer| *layer)
+            .unwrap_or(InputLayer::ROOT);
+        self.terminal.layer_events(layer)
+    }
+
     /// 获取当前组件的唯一标识 key。
     pub fn key(&self) -> &ElementKey {
         &self.key
@@ -49,6 +80,49 @@ impl<'a, 'b: 'a> ComponentUpdater<'a, 'b> {
         *self.layout_style = layout_style;
     }
 
+    /// 限制当前组件自身状态变化引起重新渲染的频率，两次之间至少间隔 interval
+    ///
+    /// 间隔内的变化会合并为一次，延迟到间隔结束时刷新；其它组件引起的渲染不受影响。传入 None 取消限制。
+    pub fn set_max_refresh_rate(&mut self, interval: Option<Duration>) {
+        *self.max_refresh_rate = interval;
+    }
+
+    /// 声明当前组件挂载与卸载时的过渡时长
+    ///
+    /// 设置了离开时长的组件从父组件的 children 中移除后，会在原来的位置保留 exit 时长再销毁，
+    /// 期间不再执行 update，只用保存的状态继续绘制，绘制时通过 [`ComponentDrawer::presence`] 读取离开进度。
+    ///
+    /// [`ComponentDrawer::presence`]: crate::render::drawer::ComponentDrawer::presence
+    ///
+    /// ```ignore
+    /// updater.set_transition(Transition::new(Duration::from_millis(150)).easing(Easing::CubicOut));
+    /// // draw 中按可见程度收起高度
+    /// let height = (self.height as f64 * drawer.presence().visibility()).round() as u16;
+    /// ```
+    pub fn set_transition(&mut self, transition: Transition) {
+        self.lifecycle.set_transition(transition);
+    }
+
+    /// 当前组件所处的挂载阶段，update 中只会是 Entering 或 Present
+    pub fn presence(&self) -> Presence {
+        self.lifecycle.presence()
+    }
+
+    /// 获取当前组件已实例化的子组件集合
+    pub fn components(&self) -> &Components {
+        self.components
+    }
+
+    /// 获取当前组件已实例化的子组件集合（可变引用）
+    pub fn components_mut(&mut self) -> &mut Components {
+        self.components
+    }
+
+    /// 测量所有子组件的固有尺寸，需在 update_children 之后调用才能得到最新结果
+    pub fn measure_children(&self) -> Vec<Size> {
+        self.components.measure()
+    }
+
     /// 根据传入的 children 列表，更新当前组件的所有子组件。
     ///
     /// 算法说明：
@@ -56,48 +130,202 @@ impl<'a, 'b: 'a> ComponentUpdater<'a, 'b> {
     /// 2. 如果 key 匹配且类型一致，则复用旧组件实例，否则新建一个组件实例。
     /// 3. 对每个组件实例调用 update，传入新的 props。
     /// 4. 将本轮用到的组件按顺序插入新的 multimap，最后整体替换原有的 components。
+    /// 5. 未被复用但声明了离开过渡的旧组件保留在原来的位置，过渡结束后才会被丢弃。
     ///
     /// 这样可以保证：
     /// - 组件 key 不变且类型一致时，组件实例被复用，保留内部状态。
     /// - key 变更或类型不一致时，自动销毁旧实例并新建，保证类型安全。
-    /// - 未被复用的旧组件会被丢弃，实现“最小化重建”。
+    /// - 未被复用的旧组件会被卸载（调用 unmount）并丢弃，实现“最小化重建”。
+    ///
+    /// 需要在实例化之前检查、排序或裁剪子元素时，使用 [`propose_children`](Self::propose_children)。
     pub fn update_children<T, E>(&mut self, children: T, context: Option<Context>)
     where
         T: IntoIterator<Item = E>,
         E: ElementExt,
     {
+        self.propose_children(children).commit(context);
+    }
+
+    /// 两阶段协调的第一步：收集子元素但暂不实例化
+    ///
+    /// 返回的 [`ProposedChildren`] 可以像 `Vec` 一样检查、排序、过滤或截断子元素，
+    /// 例如虚拟列表只保留可见范围内的元素，再调用 [`ProposedChildren::commit`] 完成协调。
+    ///
+    /// ```ignore
+    /// let mut children = updater.propose_children(props.children.iter_mut());
+    /// // 只实例化可见范围内的子元素
+    /// let end = (offset + visible).min(children.len());
+    /// children.truncate(end);
+    /// children.drain(..offset.min(end));
+    /// children.commit(None);
+    /// ```
+    pub fn propose_children<T, E>(&mut self, children: T) -> ProposedChildren<'_, 'a, 'b, E>
+    where
+        T: IntoIterator<Item = E>,
+        E: ElementExt,
+    {
+        ProposedChildren {
+            elements: children.into_iter().collect(),
+            updater: self,
+        }
+    }
+
+    /// 按顺序协调 children 与已实例化的子组件
+    fn reconcile<E: ElementExt>(&mut self, children: Vec<E>, context: Option<Context>) {
+        self.components.context_type = context.as_ref().map(Context::value_type_id);
         // 通过 with_context 方法将 context 传递给组件上下文栈
         self.component_context_stack
             .with_context(context, |context_stack| {
-                // 新建一个 multimap，用于存放本轮更新后实际用到的组件实例
-                let mut used_compoent = AppendOnlyMultimap::default();
-
-                // 遍历新的 children 列表
-                for mut child in children {
-                    // 尝试用 key 从旧组件集合中取出一个实例
-                    let mut component = match self.components.pop_front(&child.key()) {
-                        // 如果 key 匹配且类型一致，则复用旧组件实例
-                        Some(component)
+                let (clock, pool) = context_stack
+                    .get_context::<SystemContext>()
+                    .map(|system| (system.animations(), system.reparenting()))
+                    .unzip();
+
+                // 1. 尝试用 key 从旧组件集合中为每个 child 取出可以复用的实例
+                let mut matched = Vec::with_capacity(children.len());
+                let mut discarded = Vec::new();
+                for child in children {
+                    let reused = match self.components.pop_front(child.key()) {
+                        // 如果 key 匹配且类型一致，则复用旧组件实例，离开中的组件重新出现时取消离开
+                        Some(mut component)
                             if component.component().type_id()
                                 == child.helper().component_type_id() =>
                         {
-                            component
+                            component.lifecycle_mut().cancel_exit();
+                            Some(component)
                         }
-                        // 否则新建一个组件实例
-                        _ => {
-                            let h = child.helper().copy();
-                            InstantiatedComponent::new(child.key().clone(), child.props_mut(), h)
+                        // 类型不一致的旧实例需要丢弃
+                        Some(previous) => {
+                            discarded.push(previous);
+                            None
                         }
+                        None => None,
                     };
+                    matched.push((child, reused));
+                }
+
+                // 2. 声明了离开过渡的旧组件保留，过渡结束或没有声明过渡的丢弃。
+                // 丢弃在实例化新组件之前进行，子树中可迁移的组件可以被本轮新建的组件接管
+                let mut exiting = Vec::new();
+                let previous = std::mem::take(&mut self.components.components);
+                for (index, mut component) in previous.into_remaining() {
+                    if component.lifecycle_mut().begin_exit() {
+                        component.lifecycle_mut().sync_clock(clock.clone());
+                        exiting.push((index, component));
+                    } else {
+                        discarded.push(component);
+                    }
+                }
+                for component in discarded {
+                    component.discard(pool.as_ref());
+                }
+
+                // 3. 按顺序 update，没有可复用的实例时先尝试接管可迁移的实例，否则新建
+                let mut used_compoent = Vec::with_capacity(matched.len() + exiting.len());
+                for (mut child, reused) in matched {
+                    let mut component = reused
+                        .or_else(|| {
+                            let pool = pool.as_ref()?;
+                            let mut adopted =
+                                pool.take(child.key(), child.helper().component_type_id())?;
+                            adopted.lifecycle_mut().cancel_exit();
+                            Some(adopted)
+                        })
+                        .unwrap_or_else(|| {
+                            let h = child.helper().copy();
+                            InstantiatedComponent::new(child.key().clone(), child.props_mut(), h)
+                        });
 
                     // 用新的 props和context 更新组件实例
                     component.update(child.props_mut(), self.terminal, context_stack);
-                    // 将本轮用到的组件实例插入 multimap
-                    used_compoent.push_back(child.key().clone(), component);
+                    component.lifecycle_mut().sync_clock(clock.clone());
+                    // 本轮用到的组件实例按顺序记录下来
+                    used_compoent.push((child.key().clone(), component));
+                }
+
+                // 离开中的组件插回原来的位置
+                for (index, component) in exiting {
+                    let index = index.min(used_compoent.len());
+                    used_compoent.insert(index, (component.key().clone(), component));
                 }
 
                 // 用新的 multimap 替换原有的 components，实现“最小化重建”
-                self.components.components = used_compoent.into();
+                let mut components = AppendOnlyMultimap::default();
+                for (key, component) in used_compoent {
+                    components.push_back(key, component);
+                }
+                self.components.components = components.into();
             });
     }
 }
+
+/// ReparentPool：本帧中被丢弃、等待以相同的可迁移 key 被接管的组件实例
+///
+/// 由 [`SystemContext`] 持有，每帧 update 结束后仍未被接管的实例会被卸载。
+#[derive(Clone, Default)]
+pub(crate) struct ReparentPool {
+    components: Arc<Mutex<Vec<InstantiatedComponent>>>,
+}
+
+impl ReparentPool {
+    pub(crate) fn put(&self, component: InstantiatedComponent) {
+        self.components.lock().unwrap().push(component);
+    }
+
+    /// 取出 key 与类型都一致的实例
+    fn take(&self, key: &ElementKey, type_id: TypeId) -> Option<InstantiatedComponent> {
+        if !key.is_portable() {
+            return None;
+        }
+        let mut components = self.components.lock().unwrap();
+        let index = components.iter().position(|component| {
+            component.key() == key && component.component().type_id() == type_id
+        })?;
+        Some(components.swap_remove(index))
+    }
+
+    /// 卸载所有未被接管的实例
+    pub(crate) fn flush(&self) {
+        let components = std::mem::take(&mut *self.components.lock().unwrap());
+        for mut component in components {
+            component.unmount();
+        }
+    }
+}
+
+/// ProposedChildren：已收集、尚未实例化的子元素，由 [`ComponentUpdater::propose_children`] 创建
+///
+/// 通过 `Deref` 以 `Vec<E>` 的方式访问；没有调用 `commit` 就被丢弃时，子组件保持不变。
+pub struct ProposedChildren<'u, 'a, 'b: 'a, E> {
+    updater: &'u mut ComponentUpdater<'a, 'b>,
+    elements: Vec<E>,
+}
+
+impl<E: ElementExt> ProposedChildren<'_, '_, '_, E> {
+    /// key 对应的子组件是否已经实例化，提交时会被复用
+    pub fn is_mounted(&self, key: &ElementKey) -> bool {
+        self.updater
+            .components
+            .iter()
+            .any(|component| component.key() == key)
+    }
+
+    /// 两阶段协调的第二步：按当前的顺序实例化或复用子组件并 update，与 `update_children` 相同
+    pub fn commit(self, context: Option<Context>) {
+        self.updater.reconcile(self.elements, context);
+    }
+}
+
+impl<E> Deref for ProposedChildren<'_, '_, '_, E> {
+    type Target = Vec<E>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.elements
+    }
+}
+
+impl<E> DerefMut for ProposedChildren<'_, '_, '_, E> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.elements
+    }
+}
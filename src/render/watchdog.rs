@@ -0,0 +1,115 @@
+use std::{cmp::Reverse, fmt, time::Duration};
+
+/// 单个组件在一帧中的耗时，不包含子组件
+#[derive(Clone, Debug)]
+pub struct ComponentTiming {
+    /// 组件类型名
+    pub name: &'static str,
+    pub update: Duration,
+    pub draw: Duration,
+}
+
+impl ComponentTiming {
+    pub fn total(&self) -> Duration {
+        self.update + self.draw
+    }
+}
+
+/// 超出预算的一帧
+#[derive(Clone, Debug)]
+pub struct SlowFrame {
+    /// 帧序号
+    pub frame: u64,
+    /// update 与绘制的总耗时
+    pub elapsed: Duration,
+    pub budget: Duration,
+    /// 耗时最多的几个组件，按耗时降序排列
+    pub slowest: Vec<ComponentTiming>,
+}
+
+impl fmt::Display for SlowFrame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "frame #{} took {:?}, exceeding the {:?} budget",
+            self.frame, self.elapsed, self.budget
+        )?;
+        for timing in &self.slowest {
+            write!(
+                f,
+                "\n  {}: update {:?}, draw {:?}",
+                timing.name, timing.update, timing.draw
+            )?;
+        }
+        Ok(())
+    }
+}
+
+type SlowFrameHandler<'a> = Box<dyn FnMut(&SlowFrame) + 'a>;
+
+/// 报告中列出的组件数量
+const SLOWEST_COUNT: usize = 5;
+
+/// Watchdog：检查每一帧 update 与绘制的耗时，帮助发现在 update 中阻塞的 IO
+///
+/// 调试构建下默认预算为 50ms，发布构建下默认关闭。超出预算的帧默认在退出渲染循环、
+/// 恢复终端之后打印到 stderr，避免破坏界面；可以通过 `Tree::on_slow_frame` 自定义处理方式。
+pub(crate) struct Watchdog<'a> {
+    budget: Option<Duration>,
+    handler: Option<SlowFrameHandler<'a>>,
+    /// 未设置 handler 时暂存的报告
+    reports: Vec<SlowFrame>,
+}
+
+impl Default for Watchdog<'_> {
+    fn default() -> Self {
+        Self {
+            budget: cfg!(debug_assertions).then(|| Duration::from_millis(50)),
+            handler: None,
+            reports: Vec::new(),
+        }
+    }
+}
+
+impl<'a> Watchdog<'a> {
+    pub(crate) fn set_budget(&mut self, budget: Option<Duration>) {
+        self.budget = budget;
+    }
+
+    pub(crate) fn set_handler(&mut self, handler: impl FnMut(&SlowFrame) + 'a) {
+        self.handler = Some(Box::new(handler));
+    }
+
+    /// 超出预算时返回预算，此时调用方再收集组件耗时
+    pub(crate) fn exceeded(&self, elapsed: Duration) -> Option<Duration> {
+        self.budget.filter(|budget| elapsed > *budget)
+    }
+
+    pub(crate) fn report(
+        &mut self,
+        frame: u64,
+        elapsed: Duration,
+        budget: Duration,
+        mut timings: Vec<ComponentTiming>,
+    ) {
+        timings.sort_by_key(|timing| Reverse(timing.total()));
+        timings.truncate(SLOWEST_COUNT);
+        let slow_frame = SlowFrame {
+            frame,
+            elapsed,
+            budget,
+            slowest: timings,
+        };
+        match &mut self.handler {
+            Some(handler) => handler(&slow_frame),
+            None => self.reports.push(slow_frame),
+        }
+    }
+
+    /// 打印暂存的报告，需要在恢复终端之后调用
+    pub(crate) fn flush(&mut self) {
+        for report in self.reports.drain(..) {
+            eprintln!("[ratatui-kit] slow {report}");
+        }
+    }
+}
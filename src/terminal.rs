@@ -1,17 +1,97 @@
 // 引入终端事件相关依赖
-use crossterm::event::{Event, EventStream, KeyCode, KeyModifiers};
-use futures::{Stream, StreamExt};
+use crossterm::{
+    event::{
+        DisableBracketedPaste, DisableFocusChange, DisableMouseCapture, EnableBracketedPaste,
+        EnableFocusChange, EnableMouseCapture, Event, EventStream, KeyCode, KeyModifiers,
+    },
+    execute,
+};
+use futures::{Stream, StreamExt, future::poll_fn};
+use ratatui::{
+    backend::{Backend, ClearType, CrosstermBackend, TestBackend, WindowSize},
+    buffer::Cell,
+    layout::{Position, Size},
+};
+
+use crate::capabilities::{self, Capabilities};
 use std::{
     collections::VecDeque, // 用于存储事件队列
+    io::{self, Stdout},
     ops::{Deref, DerefMut},
-    sync::{Arc, Mutex, Weak},
+    sync::{
+        Arc, Mutex, Weak,
+        atomic::{AtomicU64, Ordering},
+    },
     task::{Poll, Waker},
 };
 
 // 事件队列和唤醒器的内部结构
 pub struct TerminalEventsInner {
-    pending: VecDeque<Event>, // 待处理的事件队列
-    waker: Option<Waker>,     // 用于异步唤醒的 Waker
+    pending: VecDeque<Event>,  // 待处理的事件队列
+    waker: Option<Waker>,      // 用于异步唤醒的 Waker
+    layer: Option<InputLayer>, // 所在的输入层级，为 None 时接收所有层级的事件
+}
+
+/// InputLayer：输入事件的层级，由模态对话框等覆盖层创建，作为 Context 提供给覆盖层内的组件
+///
+/// 组件通过 [`ComponentUpdater::terminal_events`](crate::render::updater::ComponentUpdater::terminal_events)
+/// 订阅事件时加入所在的层级，不在任何覆盖层内的组件位于 [`InputLayer::ROOT`]。
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct InputLayer(u64);
+
+impl InputLayer {
+    /// 根层级
+    pub const ROOT: InputLayer = InputLayer(0);
+
+    /// 创建一个新的层级
+    pub fn new() -> Self {
+        static NEXT: AtomicU64 = AtomicU64::new(1);
+        InputLayer(NEXT.fetch_add(1, Ordering::Relaxed))
+    }
+}
+
+impl Default for InputLayer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// InputLayers：当前打开的输入层级，由 [`SystemContext::input_layers`](crate::context::SystemContext::input_layers) 提供
+///
+/// 按键、鼠标与粘贴事件只分发给最上层的订阅者，覆盖层之下的组件不会同时响应；
+/// 没有打开的层级时所有订阅者都在根层级。终端尺寸、焦点变化等事件仍然分发给所有订阅者。
+#[derive(Clone, Default)]
+pub struct InputLayers {
+    inner: Arc<Mutex<Vec<InputLayer>>>,
+}
+
+impl InputLayers {
+    /// 把 layer 放到最上层，已经打开时移动到最上层
+    pub fn push(&self, layer: InputLayer) {
+        let mut layers = self.inner.lock().unwrap();
+        layers.retain(|open| *open != layer);
+        layers.push(layer);
+    }
+
+    /// 关闭 layer，未打开时不做任何事
+    pub fn remove(&self, layer: InputLayer) {
+        self.inner.lock().unwrap().retain(|open| *open != layer);
+    }
+
+    /// 最上层的层级
+    pub fn top(&self) -> InputLayer {
+        self.inner
+            .lock()
+            .unwrap()
+            .last()
+            .copied()
+            .unwrap_or(InputLayer::ROOT)
+    }
+
+    /// layer 是否为最上层，即其中的组件是否接收输入
+    pub fn is_active(&self, layer: InputLayer) -> bool {
+        self.top() == layer
+    }
 }
 
 // 终端事件流，支持异步 Stream 读取事件
@@ -40,17 +120,108 @@ impl Stream for TerminalEvents {
     }
 }
 
+/// 终端的绘制后端：真实终端，或者测试中使用的内存缓冲区
+pub enum TerminalBackend {
+    Crossterm(CrosstermBackend<Stdout>),
+    Headless(TestBackend),
+}
+
+impl Backend for TerminalBackend {
+    fn draw<'a, I>(&mut self, content: I) -> io::Result<()>
+    where
+        I: Iterator<Item = (u16, u16, &'a Cell)>,
+    {
+        match self {
+            TerminalBackend::Crossterm(backend) => backend.draw(content),
+            TerminalBackend::Headless(backend) => backend.draw(content),
+        }
+    }
+
+    fn append_lines(&mut self, n: u16) -> io::Result<()> {
+        match self {
+            TerminalBackend::Crossterm(backend) => backend.append_lines(n),
+            TerminalBackend::Headless(backend) => backend.append_lines(n),
+        }
+    }
+
+    fn hide_cursor(&mut self) -> io::Result<()> {
+        match self {
+            TerminalBackend::Crossterm(backend) => backend.hide_cursor(),
+            TerminalBackend::Headless(backend) => backend.hide_cursor(),
+        }
+    }
+
+    fn show_cursor(&mut self) -> io::Result<()> {
+        match self {
+            TerminalBackend::Crossterm(backend) => backend.show_cursor(),
+            TerminalBackend::Headless(backend) => backend.show_cursor(),
+        }
+    }
+
+    fn get_cursor_position(&mut self) -> io::Result<Position> {
+        match self {
+            TerminalBackend::Crossterm(backend) => backend.get_cursor_position(),
+            TerminalBackend::Headless(backend) => backend.get_cursor_position(),
+        }
+    }
+
+    fn set_cursor_position<P: Into<Position>>(&mut self, position: P) -> io::Result<()> {
+        match self {
+            TerminalBackend::Crossterm(backend) => backend.set_cursor_position(position),
+            TerminalBackend::Headless(backend) => backend.set_cursor_position(position),
+        }
+    }
+
+    fn clear(&mut self) -> io::Result<()> {
+        match self {
+            TerminalBackend::Crossterm(backend) => backend.clear(),
+            TerminalBackend::Headless(backend) => backend.clear(),
+        }
+    }
+
+    fn clear_region(&mut self, clear_type: ClearType) -> io::Result<()> {
+        match self {
+            TerminalBackend::Crossterm(backend) => backend.clear_region(clear_type),
+            TerminalBackend::Headless(backend) => backend.clear_region(clear_type),
+        }
+    }
+
+    fn size(&self) -> io::Result<Size> {
+        match self {
+            TerminalBackend::Crossterm(backend) => backend.size(),
+            TerminalBackend::Headless(backend) => backend.size(),
+        }
+    }
+
+    fn window_size(&mut self) -> io::Result<WindowSize> {
+        match self {
+            TerminalBackend::Crossterm(backend) => backend.window_size(),
+            TerminalBackend::Headless(backend) => backend.window_size(),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match self {
+            TerminalBackend::Crossterm(backend) => backend.flush(),
+            TerminalBackend::Headless(backend) => backend.flush(),
+        }
+    }
+}
+
 // 封装终端，负责事件分发和订阅
 pub struct Terminal {
-    inner: ratatui::DefaultTerminal,                    // 终端渲染对象
-    event_stream: EventStream,                          // crossterm 事件流
+    inner: ratatui::Terminal<TerminalBackend>, // 终端渲染对象
+    event_stream: Option<EventStream>,         // crossterm 事件流，无界面终端没有
     subscribers: Vec<Weak<Mutex<TerminalEventsInner>>>, // 事件订阅者列表
-    received_ctrl_c: bool,                              // 是否收到 Ctrl+C
+    received_ctrl_c: bool,                     // 是否收到 Ctrl+C
+    exit_on_ctrl_c: bool,                      // 是否拦截 Ctrl+C 并结束渲染循环
+    input_layers: InputLayers,                 // 当前打开的输入层级
+    mouse_capture: bool,                       // 是否开启了鼠标捕获
 }
 
-// 允许像操作 ratatui::DefaultTerminal 一样操作 Terminal
+// 允许像操作 ratatui::Terminal 一样操作 Terminal
 impl Deref for Terminal {
-    type Target = ratatui::DefaultTerminal;
+    type Target = ratatui::Terminal<TerminalBackend>;
 
     fn deref(&self) -> &Self::Target {
         &self.inner
@@ -66,11 +237,52 @@ impl DerefMut for Terminal {
 impl Terminal {
     // 创建新的终端对象
     pub fn new() -> Self {
+        // 开启鼠标捕获，右键菜单等交互依赖鼠标事件；开启焦点事件，用于跟踪活跃状态
+        let mouse_capture =
+            capabilities::wants_mouse() && execute!(std::io::stdout(), EnableMouseCapture).is_ok();
+        let _ = execute!(std::io::stdout(), EnableFocusChange);
+        // 开启 bracketed paste，粘贴的文本作为一个 Event::Paste 分发，而不是一连串按键
+        let _ = execute!(std::io::stdout(), EnableBracketedPaste);
+        // 借用 ratatui::init 进入原始模式与备用屏幕，并在 panic 时恢复终端
+        let _ = ratatui::init();
+        let backend = TerminalBackend::Crossterm(CrosstermBackend::new(std::io::stdout()));
+        Terminal {
+            inner: ratatui::Terminal::new(backend).expect("failed to initialize terminal"),
+            event_stream: Some(EventStream::new()),
+            subscribers: Vec::new(),
+            received_ctrl_c: false,
+            exit_on_ctrl_c: true,
+            input_layers: InputLayers::default(),
+            mouse_capture,
+        }
+    }
+
+    /// 创建一个不连接真实终端的无界面终端，画面绘制到 width x height 的内存缓冲区中
+    ///
+    /// 不读取终端输入，事件只能通过 [`Terminal::inject`] 注入，供 [`crate::testing`] 使用。
+    pub fn headless(width: u16, height: u16) -> Self {
+        let backend = TerminalBackend::Headless(TestBackend::new(width, height));
         Terminal {
-            inner: ratatui::init(),
-            event_stream: EventStream::new(),
+            inner: ratatui::Terminal::new(backend).expect("headless terminal never fails"),
+            event_stream: None,
             subscribers: Vec::new(),
             received_ctrl_c: false,
+            exit_on_ctrl_c: true,
+            input_layers: InputLayers::default(),
+            mouse_capture: false,
+        }
+    }
+
+    /// 是否为 [`Terminal::headless`] 创建的无界面终端
+    pub fn is_headless(&self) -> bool {
+        matches!(self.inner.backend(), TerminalBackend::Headless(_))
+    }
+
+    // 检测到的终端能力
+    pub fn capabilities(&self) -> Capabilities {
+        Capabilities {
+            mouse: self.mouse_capture,
+            ..Default::default()
         }
     }
 
@@ -79,11 +291,31 @@ impl Terminal {
         self.received_ctrl_c
     }
 
-    // 创建一个事件订阅流，供组件异步消费事件
+    /// 设置是否拦截 Ctrl+C，关闭后 Ctrl+C 与其他按键一样分发给订阅者
+    pub fn set_exit_on_ctrl_c(&mut self, enabled: bool) {
+        self.exit_on_ctrl_c = enabled;
+    }
+
+    /// 设置决定输入事件分发范围的层级，由组件树在渲染时设置
+    pub fn set_input_layers(&mut self, input_layers: InputLayers) {
+        self.input_layers = input_layers;
+    }
+
+    // 创建一个事件订阅流，接收所有层级的事件，供焦点管理等全局服务使用
     pub fn events(&mut self) -> TerminalEvents {
+        self.subscribe(None)
+    }
+
+    /// 创建一个只在 layer 为最上层时接收输入事件的订阅流，供组件使用
+    pub fn layer_events(&mut self, layer: InputLayer) -> TerminalEvents {
+        self.subscribe(Some(layer))
+    }
+
+    fn subscribe(&mut self, layer: Option<InputLayer>) -> TerminalEvents {
         let inner = Arc::new(Mutex::new(TerminalEventsInner {
             pending: VecDeque::new(),
             waker: None,
+            layer,
         }));
 
         // 订阅者弱引用加入列表
@@ -92,43 +324,99 @@ impl Terminal {
         TerminalEvents { inner }
     }
 
-    // 异步事件主循环，将事件分发给所有订阅者
+    // 等待终端事件并分发给所有订阅者，分发完所有已到达的事件后返回；输入流结束时返回一次，之后不再就绪
     pub async fn wait(&mut self) {
-        while let Some(Ok(event)) = self.event_stream.next().await {
-            // 检测 Ctrl+C 事件
-            if let Event::Key(key) = event {
-                if matches!(key.code, KeyCode::Char('c'))
-                    && key.modifiers.contains(KeyModifiers::CONTROL)
-                {
-                    self.received_ctrl_c = true;
-                    return;
+        poll_fn(|cx| {
+            let mut dispatched = false;
+            loop {
+                let Some(event_stream) = &mut self.event_stream else {
+                    // 无界面终端没有输入，事件都是注入的
+                    return Poll::Pending;
+                };
+                match event_stream.poll_next_unpin(cx) {
+                    Poll::Ready(Some(Ok(event))) => {
+                        self.dispatch(event);
+                        dispatched = true;
+                        if self.received_ctrl_c {
+                            return Poll::Ready(());
+                        }
+                    }
+                    // 事件流出错或结束：此后不再有输入，与无界面终端一样一直等待，避免渲染循环反复轮询
+                    Poll::Ready(_) => {
+                        self.event_stream = None;
+                        return Poll::Ready(());
+                    }
+                    Poll::Pending if dispatched => return Poll::Ready(()),
+                    Poll::Pending => return Poll::Pending,
                 }
             }
+        })
+        .await
+    }
 
-            // 分发事件到所有订阅者
-            self.subscribers.retain(|subscriber| {
-                if let Some(inner) = subscriber.upgrade() {
-                    let mut subscriber = inner.lock().unwrap();
-                    subscriber.pending.push_back(event.clone());
+    /// 注入一个事件，与真实的终端事件一样分发给所有订阅者
+    ///
+    /// 无界面终端收到 [`Event::Resize`] 时同时调整缓冲区的尺寸。
+    pub fn inject(&mut self, event: Event) {
+        if let (Event::Resize(width, height), TerminalBackend::Headless(backend)) =
+            (&event, self.inner.backend_mut())
+        {
+            backend.resize(*width, *height);
+        }
+        self.dispatch(event);
+    }
 
-                    // 唤醒等待事件的 waker
-                    if let Some(waker) = subscriber.waker.take() {
-                        waker.wake();
-                    }
+    fn dispatch(&mut self, event: Event) {
+        // 检测 Ctrl+C 事件
+        if let Event::Key(key) = event
+            && self.exit_on_ctrl_c
+            && matches!(key.code, KeyCode::Char('c'))
+            && key.modifiers.contains(KeyModifiers::CONTROL)
+        {
+            self.received_ctrl_c = true;
+            return;
+        }
+
+        // 输入事件只分发给最上层的订阅者
+        let top = matches!(event, Event::Key(_) | Event::Mouse(_) | Event::Paste(_))
+            .then(|| self.input_layers.top());
 
-                    true
-                } else {
-                    // 订阅者已被释放则移除
-                    false
+        // 分发事件到所有订阅者
+        self.subscribers.retain(|subscriber| {
+            if let Some(inner) = subscriber.upgrade() {
+                let mut subscriber = inner.lock().unwrap();
+                if let (Some(top), Some(layer)) = (top, subscriber.layer)
+                    && layer != top
+                {
+                    return true;
                 }
-            });
-        }
+                subscriber.pending.push_back(event.clone());
+
+                // 唤醒等待事件的 waker
+                if let Some(waker) = subscriber.waker.take() {
+                    waker.wake();
+                }
+
+                true
+            } else {
+                // 订阅者已被释放则移除
+                false
+            }
+        });
     }
 }
 
 impl Drop for Terminal {
     fn drop(&mut self) {
+        if self.is_headless() {
+            return;
+        }
         // 在终端被销毁时恢复原始终端状态
+        if self.mouse_capture {
+            let _ = execute!(std::io::stdout(), DisableMouseCapture);
+        }
+        let _ = execute!(std::io::stdout(), DisableFocusChange);
+        let _ = execute!(std::io::stdout(), DisableBracketedPaste);
         ratatui::restore();
     }
 }
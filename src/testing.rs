@@ -0,0 +1,621 @@
+//! 测试工具：在无界面终端中运行组件树，模拟按键并检查画面，用于编写端到端的界面测试
+//!
+//! [`TestRunner`] 把组件树绘制到内存缓冲区中，按键在调用时立即处理并重新渲染，
+//! 检查失败时 panic，信息中附带当时的画面与持有焦点的组件：
+//!
+//! ```ignore
+//! #[test]
+//! fn open_details() {
+//!     let mut app = element!(App);
+//!     TestRunner::new(&mut app)
+//!         .press("j")
+//!         .press("enter")
+//!         .expect_text("Details for item 2")
+//!         .expect_focused::<TextInput>();
+//! }
+//! ```
+//!
+//! 运行期间安装了 [`VirtualClock`]，计时只在 [`TestRunner::advance`] 时前进，同样的操作总是得到同样的画面。
+//! 虚拟时钟是全局的，因此同一进程中的 TestRunner 依次运行，并行的测试会等待前一个结束。
+//! 组件通过 `tokio::spawn` 执行的任务需要在 tokio 运行时中才能完成，按键处理本身不依赖运行时。
+
+use std::{
+    any::type_name,
+    sync::{Mutex, MutexGuard},
+    time::Duration,
+};
+
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+use ratatui::buffer::Buffer;
+
+use crate::{
+    clock::VirtualClock,
+    component::Component,
+    element::ElementExt,
+    render::{control::parse_key, snapshot, tree::Tree},
+    terminal::{Terminal, TerminalBackend},
+};
+
+/// 默认的画面尺寸
+const DEFAULT_SIZE: (u16, u16) = (80, 24);
+
+/// 一次操作之后最多连续渲染的帧数，超过时认为组件树无法稳定
+const MAX_SETTLE_FRAMES: usize = 100;
+
+/// 保证同一时间只有一个 TestRunner 使用虚拟时钟
+static RUNNING: Mutex<()> = Mutex::new(());
+
+/// TestRunner：在无界面终端中驱动组件树的测试工具
+///
+/// 首次操作或检查之前渲染第一帧，此前可以通过 [`TestRunner::tree`] 设置主题、store 等。
+/// 每次操作之后重复渲染，直到组件树不再有状态变化。
+pub struct TestRunner<'a> {
+    tree: Tree<'a>,
+    terminal: Terminal,
+    started: bool,
+    clock: VirtualClock,
+    _running: MutexGuard<'static, ()>,
+}
+
+impl<'a> TestRunner<'a> {
+    /// 在 80x24 的画面中运行 element
+    pub fn new<E: ElementExt>(element: &'a mut E) -> Self {
+        Self::with_size(element, DEFAULT_SIZE.0, DEFAULT_SIZE.1)
+    }
+
+    /// 在 width x height 的画面中运行 element
+    pub fn with_size<E: ElementExt>(element: &'a mut E, width: u16, height: u16) -> Self {
+        // 其他测试 panic 时锁会中毒，但虚拟时钟已经随之恢复，可以继续使用
+        let running = RUNNING.lock().unwrap_or_else(|error| error.into_inner());
+        let helper = element.helper();
+        Self {
+            tree: Tree::new(element.props_mut(), helper),
+            terminal: Terminal::headless(width, height),
+            started: false,
+            clock: VirtualClock::install(),
+            _running: running,
+        }
+    }
+
+    /// 被测的组件树，用于在首次渲染之前进行设置
+    pub fn tree(&mut self) -> &mut Tree<'a> {
+        &mut self.tree
+    }
+
+    /// 运行期间使用的虚拟时钟
+    pub fn clock(&self) -> &VirtualClock {
+        &self.clock
+    }
+
+    /// 按下一个按键，按键描述与控制服务相同，例如 `j`、`enter`、`ctrl+s`、`shift+tab`
+    #[track_caller]
+    pub fn press(&mut self, key: &str) -> &mut Self {
+        let event = parse_key(key).unwrap_or_else(|error| panic!("invalid key `{key}`: {error}"));
+        self.send(Event::Key(event))
+    }
+
+    /// 依次输入 text 中的每个字符
+    pub fn type_text(&mut self, text: &str) -> &mut Self {
+        for c in text.chars() {
+            self.send(Event::Key(KeyEvent::new(
+                KeyCode::Char(c),
+                KeyModifiers::NONE,
+            )));
+        }
+        self
+    }
+
+    /// 分发一个终端事件，例如鼠标或粘贴事件
+    pub fn send(&mut self, event: Event) -> &mut Self {
+        self.start();
+        self.terminal.inject(event);
+        self.settle();
+        self
+    }
+
+    /// 改变画面尺寸
+    pub fn resize(&mut self, width: u16, height: u16) -> &mut Self {
+        self.send(Event::Resize(width, height))
+    }
+
+    /// 把虚拟时间推进 duration 并重新渲染，用于触发计时器、动画与防抖
+    pub fn advance(&mut self, duration: Duration) -> &mut Self {
+        self.start();
+        self.clock.advance(duration);
+        self.render();
+        self.settle();
+        self
+    }
+
+    /// 检查画面中包含 text，text 可以跨越多行
+    #[track_caller]
+    pub fn expect_text(&mut self, text: &str) -> &mut Self {
+        self.start();
+        if !self.screen().contains(text) {
+            self.fail(format!(
+                "expected text `{text}` on screen, but it was not found"
+            ));
+        }
+        self
+    }
+
+    /// 检查画面中不包含 text
+    #[track_caller]
+    pub fn expect_no_text(&mut self, text: &str) -> &mut Self {
+        self.start();
+        if self.screen().contains(text) {
+            self.fail(format!("expected text `{text}` not to be on screen"));
+        }
+        self
+    }
+
+    /// 检查持有焦点的组件的类型为 C，见 [`ComponentDrawer::mark_focused`](crate::render::drawer::ComponentDrawer::mark_focused)
+    #[track_caller]
+    pub fn expect_focused<C: Component>(&mut self) -> &mut Self {
+        self.start();
+        let expected = type_name::<C>();
+        if !self.tree.focused_components().contains(&expected) {
+            self.fail(format!(
+                "expected `{}` to be focused",
+                snapshot::segment(expected)
+            ));
+        }
+        self
+    }
+
+    /// 检查组件树请求了退出，例如调用了 `use_exit`
+    #[track_caller]
+    pub fn expect_exited(&mut self) -> &mut Self {
+        self.start();
+        if !self.tree.system_context().should_exit() {
+            self.fail("expected the app to exit".to_string());
+        }
+        self
+    }
+
+    /// 当前画面的纯文本，每行去掉末尾空白
+    ///
+    /// 与 ratatui 的 TestBackend 一致，宽字符之后被其占据的格子不再输出。
+    pub fn screen(&self) -> String {
+        let buffer = self.buffer();
+        let area = buffer.area;
+        (area.top()..area.bottom())
+            .map(|y| {
+                let mut line = String::new();
+                let mut skip = 0;
+                for x in area.left()..area.right() {
+                    if skip > 0 {
+                        skip -= 1;
+                        continue;
+                    }
+                    let symbol = buffer[(x, y)].symbol();
+                    line.push_str(symbol);
+                    skip = crate::width::str_width(symbol).saturating_sub(1);
+                }
+                line.trim_end().to_string()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// 当前画面，包含每个格子的样式
+    pub fn buffer(&self) -> &Buffer {
+        match self.terminal.backend() {
+            TerminalBackend::Headless(backend) => backend.buffer(),
+            TerminalBackend::Crossterm(_) => {
+                unreachable!("TestRunner always uses a headless terminal")
+            }
+        }
+    }
+
+    /// 首次使用时渲染第一帧
+    fn start(&mut self) {
+        if !self.started {
+            self.started = true;
+            self.render();
+            self.settle();
+        }
+    }
+
+    fn render(&mut self) {
+        self.tree
+            .render(&mut self.terminal)
+            .expect("rendering to a headless terminal never fails");
+    }
+
+    /// 重复渲染直到组件树不再有状态变化
+    #[track_caller]
+    fn settle(&mut self) {
+        for _ in 0..MAX_SETTLE_FRAMES {
+            if !self.tree.take_change() {
+                return;
+            }
+            self.render();
+        }
+        self.fail(format!(
+            "the component tree kept changing after {MAX_SETTLE_FRAMES} frames"
+        ));
+    }
+
+    #[track_caller]
+    fn fail(&self, message: String) -> ! {
+        let focused = match self.tree.focused_components() {
+            [] => "none".to_string(),
+            focused => focused
+                .iter()
+                .map(|name| snapshot::segment(name))
+                .collect::<Vec<_>>()
+                .join(", "),
+        };
+        let area = self.buffer().area;
+        let rule = "─".repeat(area.width as usize);
+        panic!(
+            "{message}\nfocused: {focused}\nframe ({}x{}):\n┌{rule}┐\n{}\n└{rule}┘",
+            area.width,
+            area.height,
+            self.screen()
+                .lines()
+                .map(|line| {
+                    let padding =
+                        (area.width as usize).saturating_sub(crate::width::str_width(line));
+                    format!("│{line}{}│", " ".repeat(padding))
+                })
+                .collect::<Vec<_>>()
+                .join("\n"),
+        );
+    }
+}
+
+impl Drop for TestRunner<'_> {
+    fn drop(&mut self) {
+        self.tree.unmount();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::{layout::Constraint, style::Modifier, text::Line};
+    use ratatui_kit_macros::{Props, element};
+
+    use super::*;
+    use crate::{
+        animation::Easing,
+        components::{ColumnGroup, FocusRing, FormField, List, Row, Stack, Text, TextInput},
+        element::AnyElement,
+        hooks::{
+            Hooks,
+            use_animation::UseAnimation,
+            use_completion::CompletionProvider,
+            use_events::UseEvents,
+            use_focus::UseFocus,
+            use_form::{Field, UseForm},
+            use_interval::UseInterval,
+            use_state::UseState,
+        },
+        render::{layout_style::LayoutStyle, updater::ComponentUpdater},
+    };
+
+    /// 光标所在行：List 默认以反色高亮光标行
+    fn cursor_row(runner: &TestRunner) -> Option<u16> {
+        let buffer = runner.buffer();
+        (0..buffer.area.height).find(|&y| buffer[(0, y)].modifier.contains(Modifier::REVERSED))
+    }
+
+    fn items() -> Vec<Line<'static>> {
+        ["alpha", "beta", "gamma"].map(Line::raw).into()
+    }
+
+    #[test]
+    fn keys_move_the_list_cursor() {
+        let mut list = element!(List(items: items()));
+        let mut runner = TestRunner::with_size(&mut list, 20, 5);
+        runner
+            .expect_text("alpha\nbeta\ngamma")
+            .expect_no_text("delta")
+            .expect_focused::<List>();
+        assert_eq!(cursor_row(&runner), Some(0));
+
+        runner.press("j").press("j");
+        assert_eq!(cursor_row(&runner), Some(2));
+        runner.press("k");
+        assert_eq!(cursor_row(&runner), Some(1));
+        runner.press("down").press("down");
+        assert_eq!(cursor_row(&runner), Some(2));
+    }
+
+    #[test]
+    fn unfocused_list_ignores_keys() {
+        let mut list = element!(List(items: items(), focused: false));
+        let mut runner = TestRunner::with_size(&mut list, 20, 5);
+        runner.press("j");
+        assert_eq!(cursor_row(&runner), Some(0));
+        assert!(runner.tree().focused_components().is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "expected text `delta` on screen, but it was not found\n\
+                               focused: List\n\
+                               frame (8x3):\n\
+                               ┌────────┐\n\
+                               │alpha   │\n\
+                               │beta    │\n\
+                               │gamma   │\n\
+                               └────────┘")]
+    fn failed_expectation_reports_the_frame() {
+        let mut list = element!(List(items: items()));
+        TestRunner::with_size(&mut list, 8, 3).expect_text("delta");
+    }
+
+    #[test]
+    #[should_panic(expected = "expected `TextInput` to be focused")]
+    fn failed_focus_expectation() {
+        let mut list = element!(List(items: items()));
+        TestRunner::new(&mut list).expect_focused::<crate::components::TextInput>();
+    }
+
+    /// 通过 use_focus 参与焦点切换的补全输入框
+    struct CompletingInput;
+
+    impl Component for CompletingInput {
+        type Props<'a> = ();
+
+        fn new(_props: &Self::Props<'_>) -> Self {
+            CompletingInput
+        }
+
+        fn update(
+            &mut self,
+            _props: &mut Self::Props<'_>,
+            mut hooks: Hooks,
+            updater: &mut ComponentUpdater<'_, '_>,
+        ) {
+            let focused = hooks.use_focus().is_focused();
+            let completion = CompletionProvider::new(|input| {
+                ["apple", "apricot"]
+                    .into_iter()
+                    .filter(|candidate| candidate.starts_with(input))
+                    .map(str::to_string)
+                    .collect()
+            });
+            updater.set_layout_style(LayoutStyle {
+                height: Constraint::Length(1),
+                ..Default::default()
+            });
+            updater.update_children(
+                [element!(TextInput(focused: focused, completion: completion))],
+                None,
+            );
+        }
+    }
+
+    #[test]
+    fn completion_keeps_tab_from_moving_focus() {
+        let mut app = element!(Stack {
+            CompletingInput
+            FormField(name: "other", label: "Other")
+        });
+        let mut runner = TestRunner::with_size(&mut app, 30, 12);
+        // 输入时展开候选列表，Tab/Shift+Tab 切换候选项并填入输入框，焦点不动
+        runner.type_text("a").expect_text("apricot");
+        runner
+            .press("tab")
+            .press("tab")
+            .press("backtab")
+            .expect_focused::<TextInput>();
+        runner
+            .press("esc")
+            .expect_no_text("apricot")
+            .expect_text("apple");
+        // 列表收起后 Shift+Tab 重新用于切换焦点
+        runner.press("backtab").expect_focused::<FormField>();
+    }
+
+    /// 上下两个字段的表单
+    struct TwoFields;
+
+    impl Component for TwoFields {
+        type Props<'a> = ();
+
+        fn new(_props: &Self::Props<'_>) -> Self {
+            TwoFields
+        }
+
+        fn update(
+            &mut self,
+            _props: &mut Self::Props<'_>,
+            mut hooks: Hooks,
+            updater: &mut ComponentUpdater<'_, '_>,
+        ) {
+            let form = hooks.use_form(|| {
+                vec![
+                    Field::new("host").initial("localhost"),
+                    Field::new("port").initial("8080"),
+                ]
+            });
+            updater.update_children(
+                [element!(Stack {
+                    FormField(form: form, name: "host", label: "Host")
+                    FormField(form: form, name: "port", label: "Port")
+                })],
+                None,
+            );
+        }
+    }
+
+    #[test]
+    fn ctrl_k_in_a_form_field_does_not_move_focus() {
+        let mut app = element!(TwoFields);
+        let mut runner = TestRunner::with_size(&mut app, 20, 6);
+        runner
+            .press("tab")
+            .press("left")
+            .press("left")
+            .press("ctrl+k")
+            .expect_text("80")
+            .expect_no_text("8080");
+        // 焦点仍在 port 字段，继续输入的内容进入 port 而不是 host
+        runner
+            .type_text("1")
+            .expect_text("801")
+            .expect_text("localhost")
+            .expect_no_text("localhost1");
+    }
+
+    #[derive(Default, Props)]
+    struct FrozenProps<'a> {
+        children: Vec<AnyElement<'a>>,
+    }
+
+    /// 首次之后总是跳过 update 的容器
+    struct Frozen;
+
+    impl Component for Frozen {
+        type Props<'a> = FrozenProps<'a>;
+
+        fn new(_props: &Self::Props<'_>) -> Self {
+            Frozen
+        }
+
+        fn update(
+            &mut self,
+            props: &mut Self::Props<'_>,
+            _hooks: Hooks,
+            updater: &mut ComponentUpdater<'_, '_>,
+        ) {
+            updater.update_children(props.children.iter_mut(), None);
+        }
+
+        fn should_update(&self, _props: &Self::Props<'_>) -> bool {
+            false
+        }
+    }
+
+    /// 按 r 重新 update，子树中的 Frozen 被跳过
+    struct SkipApp;
+
+    impl Component for SkipApp {
+        type Props<'a> = ();
+
+        fn new(_props: &Self::Props<'_>) -> Self {
+            SkipApp
+        }
+
+        fn update(
+            &mut self,
+            _props: &mut Self::Props<'_>,
+            mut hooks: Hooks,
+            updater: &mut ComponentUpdater<'_, '_>,
+        ) {
+            let mut renders = hooks.use_state(|| 0);
+            hooks.use_events(move |event| {
+                if let Event::Key(key) = event
+                    && key.code == KeyCode::Char('r')
+                {
+                    renders.set(renders.get() + 1);
+                }
+            });
+            updater.update_children(
+                [element!(Stack {
+                    Text(text: format!("renders {}", renders.get()))
+                    FocusRing {
+                        Frozen {
+                            FormField(name: "name", label: "Name")
+                        }
+                    }
+                    ColumnGroup {
+                        Row(gap: 1) {
+                            Text(text: "a")
+                            Text(text: "b")
+                        }
+                        Frozen {
+                            Row(gap: 1) {
+                                Text(text: "long")
+                                Text(text: "c")
+                            }
+                        }
+                    }
+                })],
+                None,
+            );
+        }
+    }
+
+    #[test]
+    fn skipped_subtree_still_reports_to_ancestors() {
+        let mut app = element!(SkipApp);
+        let mut runner = TestRunner::with_size(&mut app, 20, 10);
+        runner.expect_text("a    b").expect_text("long c");
+        // FocusRing 边框左上角的颜色，子树中的字段获得焦点时使用焦点色
+        let ring = |runner: &TestRunner| {
+            let buffer = runner.buffer();
+            (0..buffer.area.height)
+                .map(|y| &buffer[(0, y)])
+                .find(|cell| cell.symbol() == "┌")
+                .map(|cell| cell.fg)
+        };
+        let focused = ring(&runner);
+
+        // SkipApp、FocusRing 与 ColumnGroup 重新 update，Frozen 跳过
+        runner
+            .press("r")
+            .expect_text("renders 1")
+            .expect_focused::<FormField>()
+            .expect_text("a    b")
+            .expect_text("long c");
+        assert_eq!(ring(&runner), focused);
+    }
+
+    /// 每秒计数一次，同时在一秒内把进度从 0 过渡到 100
+    struct Ticker;
+
+    impl Component for Ticker {
+        type Props<'a> = ();
+
+        fn new(_props: &Self::Props<'_>) -> Self {
+            Ticker
+        }
+
+        fn update(
+            &mut self,
+            _props: &mut Self::Props<'_>,
+            mut hooks: Hooks,
+            updater: &mut ComponentUpdater<'_, '_>,
+        ) {
+            let mut hooks = hooks.with_context_stack(updater.component_context_stack());
+            let mut ticks = hooks.use_state(|| 0);
+            hooks.use_interval(Duration::from_secs(1), move || ticks.set(ticks.get() + 1));
+            let progress = hooks.use_animation(0u16, 100, Duration::from_secs(1), Easing::Linear);
+            updater.update_children(
+                [element!(Text(text: format!("ticks {} progress {progress}", ticks.get())))],
+                None,
+            );
+        }
+    }
+
+    #[test]
+    fn advancing_the_clock_drives_intervals_and_animations() {
+        let mut ticker = element!(Ticker);
+        let mut runner = TestRunner::with_size(&mut ticker, 30, 1);
+        runner
+            .expect_text("ticks 0 progress 0")
+            .advance(Duration::from_millis(250))
+            .expect_text("ticks 0 progress 25")
+            .advance(Duration::from_millis(500))
+            .expect_text("ticks 0 progress 75")
+            .advance(Duration::from_millis(250))
+            .expect_text("ticks 1 progress 100")
+            // 动画结束后保持在终点，计时器继续每秒触发，错过的周期不补齐
+            .advance(Duration::from_secs(1))
+            .expect_text("ticks 2 progress 100")
+            .advance(Duration::from_millis(2500))
+            .expect_text("ticks 3 progress 100");
+    }
+
+    #[test]
+    fn wide_characters_occupy_two_cells() {
+        let mut text = element!(Text(text: "中文 ok"));
+        let mut runner = TestRunner::with_size(&mut text, 10, 1);
+        runner.expect_text("中文 ok");
+        assert_eq!(runner.screen(), "中文 ok");
+    }
+}
@@ -0,0 +1,276 @@
+//! 主题：内置组件使用的颜色与边框样式，以及颜色插值、色阶与按终端能力量化等工具
+
+use ratatui::{
+    style::{Color, Style},
+    widgets::BorderType,
+};
+
+use crate::capabilities::ColorDepth;
+
+/// Theme：调色板与边框样式
+///
+/// 根部的主题由 [`SystemContext::theme`](crate::context::SystemContext::theme) 提供，
+/// 子树可以用 `ContextProvider<Theme>` 覆盖，组件通过 `use_theme` 读取最近的主题。
+/// 内置组件的样式 props 默认为空，绘制时以主题为底再叠加 props 中设置的部分。
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Theme {
+    /// 主色：选中项、进度条
+    pub primary: Color,
+    /// 辅助色：键名、标识符
+    pub accent: Color,
+    /// 次要内容：占位符、分隔线、未聚焦的边框
+    pub muted: Color,
+    pub success: Color,
+    pub warning: Color,
+    pub error: Color,
+    /// 焦点指示：获得焦点的组件的边框与标签，见 [`Theme::focus_ring`]
+    pub focus: Color,
+    /// 正文样式
+    pub text: Style,
+    pub border_type: BorderType,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::dark()
+    }
+}
+
+impl Theme {
+    /// 深色背景的预设，与内置组件原有的配色一致
+    pub fn dark() -> Self {
+        Self {
+            primary: Color::Cyan,
+            accent: Color::Blue,
+            muted: Color::DarkGray,
+            success: Color::Green,
+            warning: Color::Yellow,
+            error: Color::Red,
+            focus: Color::Cyan,
+            text: Style::new(),
+            border_type: BorderType::Plain,
+        }
+    }
+
+    /// 浅色背景的预设
+    pub fn light() -> Self {
+        Self {
+            primary: Color::Blue,
+            accent: Color::Magenta,
+            muted: Color::Gray,
+            success: Color::Green,
+            warning: Color::Rgb(176, 112, 0),
+            error: Color::Red,
+            focus: Color::Blue,
+            text: Style::new().fg(Color::Black),
+            border_type: BorderType::Rounded,
+        }
+    }
+
+    /// 获得焦点时的样式，内置组件与 `FocusRing` 统一使用它标识焦点所在
+    pub fn focus_ring(&self) -> Style {
+        self.text.fg(self.focus)
+    }
+
+    pub fn primary(&self) -> Style {
+        self.text.fg(self.primary)
+    }
+
+    pub fn accent(&self) -> Style {
+        self.text.fg(self.accent)
+    }
+
+    pub fn muted(&self) -> Style {
+        self.text.fg(self.muted)
+    }
+
+    pub fn success(&self) -> Style {
+        self.text.fg(self.success)
+    }
+
+    pub fn warning(&self) -> Style {
+        self.text.fg(self.warning)
+    }
+
+    pub fn error(&self) -> Style {
+        self.text.fg(self.error)
+    }
+
+    /// 从成功色经警告色到错误色的色阶，适合表示负载、延迟等越高越糟的数值
+    pub fn severity_scale(&self) -> ColorScale {
+        ColorScale::new([self.success, self.warning, self.error])
+    }
+}
+
+/// xterm 默认调色板中 16 个基本色的 RGB 值，按索引排列
+const ANSI16: [(Color, (u8, u8, u8)); 16] = [
+    (Color::Black, (0, 0, 0)),
+    (Color::Red, (205, 0, 0)),
+    (Color::Green, (0, 205, 0)),
+    (Color::Yellow, (205, 205, 0)),
+    (Color::Blue, (0, 0, 238)),
+    (Color::Magenta, (205, 0, 205)),
+    (Color::Cyan, (0, 205, 205)),
+    (Color::Gray, (229, 229, 229)),
+    (Color::DarkGray, (127, 127, 127)),
+    (Color::LightRed, (255, 0, 0)),
+    (Color::LightGreen, (0, 255, 0)),
+    (Color::LightYellow, (255, 255, 0)),
+    (Color::LightBlue, (92, 92, 255)),
+    (Color::LightMagenta, (255, 0, 255)),
+    (Color::LightCyan, (0, 255, 255)),
+    (Color::White, (255, 255, 255)),
+];
+
+/// 256 色调色板中 6×6×6 色块每个分量的取值
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// 颜色的 RGB 值，命名色与索引色按 xterm 默认调色板换算，[`Color::Reset`] 没有确定的值
+pub fn rgb(color: Color) -> Option<(u8, u8, u8)> {
+    match color {
+        Color::Reset => None,
+        Color::Rgb(r, g, b) => Some((r, g, b)),
+        Color::Indexed(index @ 0..=15) => Some(ANSI16[index as usize].1),
+        Color::Indexed(index @ 16..=231) => {
+            let index = index - 16;
+            Some((
+                CUBE_LEVELS[(index / 36) as usize],
+                CUBE_LEVELS[(index / 6 % 6) as usize],
+                CUBE_LEVELS[(index % 6) as usize],
+            ))
+        }
+        Color::Indexed(index) => {
+            let level = 8 + 10 * (index - 232);
+            Some((level, level, level))
+        }
+        named => ANSI16
+            .iter()
+            .find(|(color, _)| *color == named)
+            .map(|(_, rgb)| *rgb),
+    }
+}
+
+/// 按 t（0..=1）在 from 与 to 之间插值，任一端没有确定的 RGB 值时在中点切换
+pub fn mix(from: Color, to: Color, t: f64) -> Color {
+    let t = t.clamp(0.0, 1.0);
+    match (rgb(from), rgb(to)) {
+        (Some((r1, g1, b1)), Some((r2, g2, b2))) => {
+            let channel = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * t).round() as u8;
+            Color::Rgb(channel(r1, r2), channel(g1, g2), channel(b1, b2))
+        }
+        _ if t < 0.5 => from,
+        _ => to,
+    }
+}
+
+/// 以 base 为中点生成 count 级由暗到亮的色阶
+pub fn shades(base: Color, count: usize) -> Vec<Color> {
+    match count {
+        0 => Vec::new(),
+        1 => vec![base],
+        _ => (0..count)
+            .map(|i| {
+                // -0.6..=0.6：负数混入黑色，正数混入白色
+                let t = i as f64 / (count - 1) as f64 * 1.2 - 0.6;
+                if t < 0.0 {
+                    mix(base, Color::Black, -t)
+                } else {
+                    mix(base, Color::White, t)
+                }
+            })
+            .collect(),
+    }
+}
+
+/// 把颜色换算为终端能显示的最接近的颜色
+///
+/// 终端的颜色数量见 [`Capabilities::colors`](crate::capabilities::Capabilities::colors)。
+/// 命名色与 Reset 在任何终端中都可用，原样返回。
+pub fn quantize(color: Color, depth: ColorDepth) -> Color {
+    let Color::Rgb(r, g, b) = color else {
+        return match (color, depth) {
+            (Color::Indexed(_), ColorDepth::Ansi16) => {
+                rgb(color).map_or(color, |(r, g, b)| quantize(Color::Rgb(r, g, b), depth))
+            }
+            _ => color,
+        };
+    };
+    let distance = |(r2, g2, b2): (u8, u8, u8)| {
+        let d = |a: u8, b: u8| (a as i32 - b as i32).pow(2);
+        d(r, r2) + d(g, g2) + d(b, b2)
+    };
+    match depth {
+        ColorDepth::TrueColor => color,
+        ColorDepth::Ansi256 => {
+            let nearest_level = |value: u8| {
+                (0..6)
+                    .min_by_key(|&i| (CUBE_LEVELS[i] as i32 - value as i32).abs())
+                    .unwrap_or_default() as u8
+            };
+            let cube = 16 + 36 * nearest_level(r) + 6 * nearest_level(g) + nearest_level(b);
+            let average = (r as u32 + g as u32 + b as u32) / 3;
+            let gray = 232 + ((average.saturating_sub(8) + 5) / 10).min(23) as u8;
+            [cube, gray]
+                .into_iter()
+                .min_by_key(|&index| rgb(Color::Indexed(index)).map_or(i32::MAX, distance))
+                .map_or(color, Color::Indexed)
+        }
+        ColorDepth::Ansi16 => ANSI16
+            .iter()
+            .min_by_key(|(_, rgb)| distance(*rgb))
+            .map_or(color, |(named, _)| *named),
+    }
+}
+
+/// ColorScale：把 0..=1 的数值映射为颜色的连续色阶，相邻的颜色之间线性插值
+///
+/// ```ignore
+/// let scale = ColorScale::new([Color::Blue, Color::Green, Color::Yellow, Color::Red]);
+/// let color = quantize(scale.map(latency_ms, 0.0, 500.0), capabilities.colors);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ColorScale {
+    stops: Vec<Color>,
+}
+
+impl ColorScale {
+    /// 由均匀分布的颜色节点构成色阶，没有节点时始终返回 [`Color::Reset`]
+    pub fn new(stops: impl IntoIterator<Item = Color>) -> Self {
+        Self {
+            stops: stops.into_iter().collect(),
+        }
+    }
+
+    /// 冷色到暖色的热力图色阶
+    pub fn heat() -> Self {
+        Self::new([
+            Color::Rgb(49, 54, 149),
+            Color::Rgb(69, 117, 180),
+            Color::Rgb(116, 173, 209),
+            Color::Rgb(254, 224, 144),
+            Color::Rgb(244, 109, 67),
+            Color::Rgb(165, 0, 38),
+        ])
+    }
+
+    /// t（会被限制在 0..=1）处的颜色
+    pub fn color_at(&self, t: f64) -> Color {
+        match self.stops.as_slice() {
+            [] => Color::Reset,
+            [only] => *only,
+            stops => {
+                let position = t.clamp(0.0, 1.0) * (stops.len() - 1) as f64;
+                let index = (position.floor() as usize).min(stops.len() - 2);
+                mix(stops[index], stops[index + 1], position - index as f64)
+            }
+        }
+    }
+
+    /// 把 min..=max 范围内的 value 映射为颜色
+    pub fn map(&self, value: f64, min: f64, max: f64) -> Color {
+        if max <= min {
+            return self.color_at(0.0);
+        }
+        self.color_at((value - min) / (max - min))
+    }
+}
@@ -0,0 +1,190 @@
+//! 显示宽度：按终端实际占用的列数测量文本
+//!
+//! `chars().count()` 对中日韩文字、emoji 与组合字符都会算错列数，内置组件统一通过这里测量。
+//! 以字素簇（grapheme cluster）为单位计算：组合字符不占列，emoji ZWJ 序列与带 VS16 的 emoji 占两列，
+//! East Asian Ambiguous 字符的宽度由 [`Capabilities::ambiguous_wide`](crate::capabilities::Capabilities::ambiguous_wide) 决定。
+
+use std::{
+    borrow::Cow,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+/// 截断时使用的省略号
+pub const ELLIPSIS: &str = "…";
+
+static AMBIGUOUS_WIDE: AtomicBool = AtomicBool::new(false);
+
+/// 设置 East Asian Ambiguous 字符（例如 `±`、`○`、希腊字母）是否按两列计算
+///
+/// 渲染循环启动时根据检测到的终端能力自动设置。
+pub fn set_ambiguous_wide(wide: bool) {
+    AMBIGUOUS_WIDE.store(wide, Ordering::Relaxed);
+}
+
+pub fn ambiguous_wide() -> bool {
+    AMBIGUOUS_WIDE.load(Ordering::Relaxed)
+}
+
+/// 单个字素簇占用的列数，结果为 0、1 或 2
+pub fn grapheme_width(grapheme: &str) -> usize {
+    measure_grapheme(grapheme, ambiguous_wide())
+}
+
+fn measure_grapheme(grapheme: &str, ambiguous_wide: bool) -> usize {
+    // ZWJ 连接的 emoji 序列与 VS16 表情变体在终端中显示为一个两列宽的字形
+    if grapheme.chars().count() > 1 && grapheme.contains(['\u{200d}', '\u{fe0f}']) {
+        return 2;
+    }
+    let width = if ambiguous_wide {
+        grapheme.width_cjk()
+    } else {
+        grapheme.width()
+    };
+    width.min(2)
+}
+
+/// 字符串占用的列数
+pub fn str_width(s: &str) -> usize {
+    s.graphemes(true).map(grapheme_width).sum()
+}
+
+/// 截取不超过 max_width 列的最长前缀，不会截断字素簇
+pub fn truncate(s: &str, max_width: usize) -> &str {
+    let mut width = 0;
+    for (index, grapheme) in s.grapheme_indices(true) {
+        width += grapheme_width(grapheme);
+        if width > max_width {
+            return &s[..index];
+        }
+    }
+    s
+}
+
+/// 截取不超过 max_width 列的最长后缀，不会截断字素簇
+pub fn truncate_start(s: &str, max_width: usize) -> &str {
+    let mut width = 0;
+    for (index, grapheme) in s.grapheme_indices(true).rev() {
+        width += grapheme_width(grapheme);
+        if width > max_width {
+            return &s[index + grapheme.len()..];
+        }
+    }
+    s
+}
+
+/// 把 s 截断到 max_width 列以内，被截断时末尾显示省略号
+///
+/// ```
+/// use ratatui_kit_principle::width::truncate_to_width;
+///
+/// assert_eq!(truncate_to_width("hello world", 8), "hello w…");
+/// assert_eq!(truncate_to_width("中文标题", 5), "中文…");
+/// assert_eq!(truncate_to_width("short", 8), "short");
+/// ```
+pub fn truncate_to_width(s: &str, max_width: usize) -> Cow<'_, str> {
+    if str_width(s) <= max_width {
+        return Cow::Borrowed(s);
+    }
+    let ellipsis = str_width(ELLIPSIS);
+    if max_width < ellipsis {
+        return Cow::Borrowed(truncate(s, max_width));
+    }
+    Cow::Owned(format!("{}{ELLIPSIS}", truncate(s, max_width - ellipsis)))
+}
+
+/// 把 s 截断到 max_width 列以内，被截断时保留首尾、在中间显示省略号，适合路径与标识符
+///
+/// ```
+/// use ratatui_kit_principle::width::middle_ellipsis;
+///
+/// assert_eq!(middle_ellipsis("/home/user/projects/app", 12), "/home/…s/app");
+/// assert_eq!(middle_ellipsis("配置文件目录/app", 9), "配置…/app");
+/// ```
+pub fn middle_ellipsis(s: &str, max_width: usize) -> Cow<'_, str> {
+    if str_width(s) <= max_width {
+        return Cow::Borrowed(s);
+    }
+    let ellipsis = str_width(ELLIPSIS);
+    if max_width < ellipsis {
+        return Cow::Borrowed(truncate(s, max_width));
+    }
+    let budget = max_width - ellipsis;
+    let head = truncate(s, budget.div_ceil(2));
+    let tail = truncate_start(s, budget - str_width(head));
+    Cow::Owned(format!("{head}{ELLIPSIS}{tail}"))
+}
+
+/// 把 s 截断或用空格补齐到恰好 width 列，用于对齐成列的文本
+///
+/// 与 `format!("{s:<width$}")` 不同，这里按显示宽度而不是字符数补齐；
+/// 宽字符恰好跨过边界时用空格补上空出的一列。
+pub fn pad_to_width(s: &str, width: usize) -> String {
+    let mut padded = truncate_to_width(s, width).into_owned();
+    let fill = width.saturating_sub(str_width(&padded));
+    padded.extend(std::iter::repeat_n(' ', fill));
+    padded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cjk_takes_two_columns() {
+        assert_eq!(grapheme_width("中"), 2);
+        assert_eq!(str_width("中文标题"), 8);
+        assert_eq!(str_width("ab中c"), 5);
+        assert_eq!(str_width("こんにちは"), 10);
+    }
+
+    #[test]
+    fn emoji_sequences_take_two_columns() {
+        // 👨‍👩‍👧‍👦：四个 emoji 由 ZWJ 连接成一个字形
+        let family = "\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f467}\u{200d}\u{1f466}";
+        assert_eq!(family.graphemes(true).count(), 1);
+        assert_eq!(grapheme_width(family), 2);
+        assert_eq!(str_width(&format!("a{family}b")), 4);
+        // ❤️：默认按文本显示的字符加上 VS16 后显示为 emoji
+        assert_eq!(grapheme_width("\u{2764}\u{fe0f}"), 2);
+        assert_eq!(grapheme_width("\u{1f600}"), 2);
+    }
+
+    #[test]
+    fn combining_marks_take_no_columns() {
+        // e + 组合重音符与预组合的 é 宽度相同
+        assert_eq!(grapheme_width("e\u{301}"), 1);
+        assert_eq!(str_width("e\u{301}"), str_width("\u{e9}"));
+        assert_eq!(str_width("cafe\u{301}"), 4);
+        assert_eq!(str_width("a\u{301}\u{323}b"), 2);
+    }
+
+    #[test]
+    fn truncation_keeps_graphemes_whole() {
+        let family = "\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f467}";
+        let text = format!("ab{family}cd");
+        // 宽字形放不下时整体去掉，而不是截断在 ZWJ 序列中间
+        assert_eq!(truncate(&text, 3), "ab");
+        assert_eq!(truncate(&text, 4), format!("ab{family}"));
+        assert_eq!(truncate_to_width(&text, 4), "ab…");
+        assert_eq!(truncate_start(&text, 3), "cd");
+        assert_eq!(truncate("e\u{301}e\u{301}", 1), "e\u{301}");
+        assert_eq!(truncate_to_width("中文", 3), "中…");
+        assert_eq!(middle_ellipsis("abcdefgh", 0), "");
+        assert_eq!(pad_to_width("中", 3), "中 ");
+        assert_eq!(pad_to_width("中文", 3), "中…");
+    }
+
+    #[test]
+    fn ambiguous_width_follows_setting() {
+        // 直接测量而不修改全局设置，避免影响并行的测试
+        for ambiguous in ["\u{b1}", "\u{25cb}", "\u{2500}"] {
+            assert_eq!(measure_grapheme(ambiguous, false), 1);
+            assert_eq!(measure_grapheme(ambiguous, true), 2);
+        }
+        assert_eq!(measure_grapheme("a", true), 1);
+        assert_eq!(measure_grapheme("中", false), 2);
+    }
+}
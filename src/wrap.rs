@@ -0,0 +1,174 @@
+//! 文本换行缓存：按内容与宽度缓存换行结果，尺寸不变时不必每帧重新换行
+
+use std::{
+    collections::HashMap,
+    hash::{DefaultHasher, Hash, Hasher},
+    sync::{Arc, Mutex},
+};
+
+use ratatui::{
+    style::Style,
+    text::{Line, Span, StyledGrapheme, Text},
+};
+
+use crate::width;
+
+/// 超过这个数量后淘汰最久未使用的一半
+const CAPACITY: usize = 256;
+
+struct CacheEntry {
+    lines: Arc<[Line<'static>]>,
+    used: u64,
+}
+
+#[derive(Default)]
+struct WrapCacheInner {
+    entries: HashMap<(u64, u16), CacheEntry>,
+    tick: u64,
+}
+
+/// WrapCache：换行结果的缓存
+///
+/// 由 [`SystemContext::wrap_cache`](crate::context::SystemContext::wrap_cache) 提供，
+/// 以内容的哈希与宽度作为 key。组件在 update 中取得缓存，在 draw 中按实际宽度换行：
+///
+/// ```ignore
+/// let lines = self.wrap_cache.wrap(&self.text, drawer.area.width);
+/// drawer.render_widget(Paragraph::new(lines.to_vec()), drawer.area);
+/// ```
+#[derive(Clone, Default)]
+pub struct WrapCache {
+    inner: Arc<Mutex<WrapCacheInner>>,
+}
+
+impl WrapCache {
+    /// 把 text 按 width 换行，内容与宽度都没有变化时直接返回缓存的结果
+    pub fn wrap(&self, text: &Text<'_>, width: u16) -> Arc<[Line<'static>]> {
+        let mut hasher = DefaultHasher::new();
+        text.hash(&mut hasher);
+        let key = (hasher.finish(), width);
+
+        let mut inner = self.inner.lock().unwrap();
+        inner.tick += 1;
+        let tick = inner.tick;
+        if let Some(entry) = inner.entries.get_mut(&key) {
+            entry.used = tick;
+            return entry.lines.clone();
+        }
+
+        let lines: Arc<[Line<'static>]> = text
+            .lines
+            .iter()
+            .flat_map(|line| wrap_line(line, width))
+            .collect();
+        if inner.entries.len() >= CAPACITY {
+            let mut used: Vec<u64> = inner.entries.values().map(|entry| entry.used).collect();
+            used.sort_unstable();
+            let threshold = used[used.len() / 2];
+            inner.entries.retain(|_, entry| entry.used > threshold);
+        }
+        inner.entries.insert(
+            key,
+            CacheEntry {
+                lines: lines.clone(),
+                used: tick,
+            },
+        );
+        lines
+    }
+
+    /// text 按 width 换行后的行数
+    pub fn height(&self, text: &Text<'_>, width: u16) -> usize {
+        self.wrap(text, width).len()
+    }
+
+    pub fn clear(&self) {
+        self.inner.lock().unwrap().entries.clear();
+    }
+}
+
+/// 按单词把一行换成多行，单词本身超过宽度时在字符之间断开
+///
+/// 断开处的空白会被丢弃，原有的行首缩进与行的对齐方式保留。
+pub fn wrap_line(line: &Line<'_>, width: u16) -> Vec<Line<'static>> {
+    let width = width.max(1) as usize;
+    let graphemes: Vec<StyledGrapheme> = line.styled_graphemes(Style::default()).collect();
+    let grapheme_width = |grapheme: &StyledGrapheme| width::grapheme_width(grapheme.symbol);
+    let is_whitespace =
+        |grapheme: &StyledGrapheme| grapheme.symbol.chars().all(char::is_whitespace);
+
+    let mut lines = Vec::new();
+    let mut current = LineBuilder::default();
+    let mut i = 0;
+    while i < graphemes.len() {
+        let whitespace_start = i;
+        while i < graphemes.len() && is_whitespace(&graphemes[i]) {
+            i += 1;
+        }
+        let word_start = i;
+        while i < graphemes.len() && !is_whitespace(&graphemes[i]) {
+            i += 1;
+        }
+        let whitespace = &graphemes[whitespace_start..word_start];
+        let word = &graphemes[word_start..i];
+        let whitespace_width: usize = whitespace.iter().map(grapheme_width).sum();
+        let word_width: usize = word.iter().map(grapheme_width).sum();
+
+        if current.width + whitespace_width + word_width <= width {
+            current.extend(whitespace, grapheme_width);
+            current.extend(word, grapheme_width);
+        } else if word_width <= width {
+            // 整个单词移到下一行
+            if current.width > 0 {
+                lines.push(current.finish(line));
+            }
+            current.extend(word, grapheme_width);
+        } else {
+            // 单词比整行还宽，从新的一行开始逐个字符断开
+            if current.width > 0 {
+                lines.push(current.finish(line));
+            }
+            for grapheme in word {
+                if current.width + grapheme_width(grapheme) > width && current.width > 0 {
+                    lines.push(current.finish(line));
+                }
+                current.push(grapheme, grapheme_width(grapheme));
+            }
+        }
+    }
+    lines.push(current.finish(line));
+    lines
+}
+
+#[derive(Default)]
+struct LineBuilder {
+    spans: Vec<Span<'static>>,
+    width: usize,
+}
+
+impl LineBuilder {
+    fn push(&mut self, grapheme: &StyledGrapheme, width: usize) {
+        match self.spans.last_mut() {
+            Some(span) if span.style == grapheme.style => {
+                span.content.to_mut().push_str(grapheme.symbol)
+            }
+            _ => self
+                .spans
+                .push(Span::styled(grapheme.symbol.to_string(), grapheme.style)),
+        }
+        self.width += width;
+    }
+
+    fn extend(&mut self, graphemes: &[StyledGrapheme], width: impl Fn(&StyledGrapheme) -> usize) {
+        for grapheme in graphemes {
+            self.push(grapheme, width(grapheme));
+        }
+    }
+
+    fn finish(&mut self, source: &Line<'_>) -> Line<'static> {
+        self.width = 0;
+        let mut line = Line::from(std::mem::take(&mut self.spans));
+        line.alignment = source.alignment;
+        line
+    }
+}